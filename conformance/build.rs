@@ -0,0 +1,41 @@
+use std::{env, path::PathBuf};
+
+use prost_build::Config;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+	println!("cargo:rerun-if-changed=proto");
+	println!("cargo:rerun-if-changed=proto_deps");
+
+	let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+	let descriptor_path = out_dir.join("conformance_cases.bin");
+
+	let include_paths = &["proto", "proto_deps"];
+
+	let files = &[
+		"proto/buf/validate/conformance/cases/strings.proto",
+		"proto/buf/validate/conformance/cases/bytes.proto",
+		"proto/buf/validate/conformance/cases/numbers.proto",
+		"proto/buf/validate/conformance/cases/repeated.proto",
+		"proto/buf/validate/conformance/cases/maps.proto",
+		"proto/buf/validate/conformance/cases/messages.proto",
+		"proto/buf/validate/conformance/cases/oneofs.proto",
+		"proto/buf/validate/conformance/cases/wkt_duration.proto",
+		"proto/buf/validate/conformance/cases/wkt_timestamp.proto",
+		"proto/buf/validate/conformance/cases/custom_rules.proto",
+	];
+
+	// Only the descriptors are used, the cases are validated dynamically
+	let mut config = Config::new();
+	config
+		.file_descriptor_set_path(&descriptor_path)
+		.out_dir(&out_dir);
+
+	config.compile_protos(files, include_paths)?;
+
+	println!(
+		"cargo:rustc-env=CONFORMANCE_CASES_DESCRIPTOR_SET={}",
+		descriptor_path.display()
+	);
+
+	Ok(())
+}
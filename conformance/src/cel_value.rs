@@ -0,0 +1,125 @@
+//! Conversions from the values of dynamic messages into CEL values, for the rules
+//! which are applied to messages and to fields without a dedicated validator.
+
+use std::{collections::HashMap, sync::Arc};
+
+use prost_reflect::{DynamicMessage, MapKey, ReflectMessage, Value};
+use protify::cel::{
+	Value as CelValue,
+	objects::{Key, Map},
+};
+use proto_types::{Duration, Timestamp};
+
+use crate::CaseError;
+
+fn conversion_error(e: impl std::fmt::Display) -> CaseError {
+	CaseError::Runtime(format!("failed to convert value to CEL: {e}"))
+}
+
+pub fn message_to_cel(msg: &DynamicMessage) -> Result<CelValue, CaseError> {
+	let descriptor = msg.descriptor();
+
+	let get_i64 = |name: &str| {
+		msg.get_field_by_name(name)
+			.and_then(|v| v.as_i64())
+			.unwrap_or_default()
+	};
+	let get_i32 = |name: &str| {
+		msg.get_field_by_name(name)
+			.and_then(|v| v.as_i32())
+			.unwrap_or_default()
+	};
+
+	match descriptor.full_name() {
+		"google.protobuf.Duration" => {
+			return Duration {
+				seconds: get_i64("seconds"),
+				nanos: get_i32("nanos"),
+			}
+			.try_into()
+			.map_err(conversion_error);
+		}
+		"google.protobuf.Timestamp" => {
+			return Timestamp {
+				seconds: get_i64("seconds"),
+				nanos: get_i32("nanos"),
+			}
+			.try_into()
+			.map_err(conversion_error);
+		}
+		"google.protobuf.DoubleValue"
+		| "google.protobuf.FloatValue"
+		| "google.protobuf.Int64Value"
+		| "google.protobuf.UInt64Value"
+		| "google.protobuf.Int32Value"
+		| "google.protobuf.UInt32Value"
+		| "google.protobuf.BoolValue"
+		| "google.protobuf.StringValue"
+		| "google.protobuf.BytesValue" => {
+			if let Some(field) = descriptor.get_field_by_name("value") {
+				return value_to_cel(&msg.get_field(&field));
+			}
+		}
+		_ => {}
+	};
+
+	let mut map: HashMap<Key, CelValue> = HashMap::new();
+
+	for field in descriptor.fields() {
+		// Fields with explicit presence are only added if they are set,
+		// so that `has()` works as expected
+		if field.supports_presence() && !msg.has_field(&field) {
+			continue;
+		}
+
+		map.insert(
+			field.name().to_string().into(),
+			value_to_cel(&msg.get_field(&field))?,
+		);
+	}
+
+	Ok(CelValue::Map(Map { map: Arc::new(map) }))
+}
+
+fn map_key_to_cel(key: &MapKey) -> Key {
+	match key {
+		MapKey::Bool(val) => (*val).into(),
+		MapKey::I32(val) => i64::from(*val).into(),
+		MapKey::I64(val) => (*val).into(),
+		MapKey::U32(val) => u64::from(*val).into(),
+		MapKey::U64(val) => (*val).into(),
+		MapKey::String(val) => val.clone().into(),
+	}
+}
+
+pub fn value_to_cel(value: &Value) -> Result<CelValue, CaseError> {
+	let output = match value {
+		Value::Bool(val) => CelValue::Bool(*val),
+		Value::I32(val) => CelValue::Int(i64::from(*val)),
+		Value::I64(val) => CelValue::Int(*val),
+		Value::U32(val) => CelValue::UInt(u64::from(*val)),
+		Value::U64(val) => CelValue::UInt(*val),
+		Value::F32(val) => CelValue::Float(f64::from(*val)),
+		Value::F64(val) => CelValue::Float(*val),
+		Value::String(val) => val.clone().into(),
+		Value::Bytes(val) => val.to_vec().into(),
+		Value::EnumNumber(val) => CelValue::Int(i64::from(*val)),
+		Value::Message(msg) => message_to_cel(msg)?,
+		Value::List(items) => items
+			.iter()
+			.map(value_to_cel)
+			.collect::<Result<Vec<CelValue>, CaseError>>()?
+			.into(),
+		Value::Map(entries) => {
+			let mut map: HashMap<Key, CelValue> = HashMap::new();
+
+			for (key, value) in entries {
+				map.insert(map_key_to_cel(key), value_to_cel(value)?);
+			}
+
+			CelValue::Map(Map { map: Arc::new(map) })
+		}
+	};
+
+	Ok(output)
+}
@@ -0,0 +1,746 @@
+//! Validates dynamic messages by reading their protovalidate rules from the descriptors
+//! and by delegating the validation of each field to the matching protify validator.
+
+use std::{
+	borrow::Borrow,
+	collections::{BTreeMap, HashMap},
+	panic::{AssertUnwindSafe, catch_unwind},
+};
+
+use prost::Message;
+use prost_reflect::{
+	DescriptorPool, DynamicMessage, ExtensionDescriptor, FieldDescriptor, Kind, MapKey,
+	MessageDescriptor, ReflectMessage, Value,
+};
+use prost_types::{Any as ProstAny, FileDescriptorSet};
+use protify::*;
+use proto_types::{
+	Any, Duration, FieldMask, Timestamp,
+	field_descriptor_proto::Type as ProtoPrimitive,
+	protovalidate::{
+		FieldPathElement, FieldRules, Ignore, MessageRules, OneofRules, ViolationKind,
+		field_path_element::Subscript, field_rules::Type as RulesType,
+	},
+};
+
+use crate::{
+	cel_value::message_to_cel,
+	harness::TestResult,
+	rules::{DynamicType, cel_programs, mismatched_rules, to_usize},
+};
+
+/// The reasons why a case could not be validated.
+#[derive(Debug, Clone)]
+pub enum CaseError {
+	/// The rules are invalid, or they cannot be applied to the field they are defined on.
+	Compilation(String),
+	/// A rule could not be evaluated.
+	Runtime(String),
+	/// The case uses a feature which is not supported by the executor.
+	Unsupported(String),
+}
+
+impl From<CaseError> for TestResult {
+	fn from(value: CaseError) -> Self {
+		match value {
+			CaseError::Compilation(msg) => Self::compilation_error(msg),
+			CaseError::Runtime(msg) => Self::runtime_error(msg),
+			CaseError::Unsupported(msg) => Self::unexpected_error(msg),
+		}
+	}
+}
+
+// The message that protify uses for the violations caused by CEL runtime errors
+const CEL_RUNTIME_ERROR_MSG: &str = "internal server error";
+
+const WRAPPERS: &[&str] = &[
+	"google.protobuf.DoubleValue",
+	"google.protobuf.FloatValue",
+	"google.protobuf.Int64Value",
+	"google.protobuf.UInt64Value",
+	"google.protobuf.Int32Value",
+	"google.protobuf.UInt32Value",
+	"google.protobuf.BoolValue",
+	"google.protobuf.StringValue",
+	"google.protobuf.BytesValue",
+];
+
+// Runs the given expression with `$T` set to the rust type that is validated
+// for a given protobuf kind, or falls back to the other branch.
+macro_rules! with_type {
+	($kind:expr, $T:ident => $body:expr, $other:pat => $fallback:expr) => {
+		match $kind {
+			Kind::Double => {
+				type $T = f64;
+				$body
+			}
+			Kind::Float => {
+				type $T = f32;
+				$body
+			}
+			Kind::Int32 => {
+				type $T = i32;
+				$body
+			}
+			Kind::Int64 => {
+				type $T = i64;
+				$body
+			}
+			Kind::Uint32 => {
+				type $T = u32;
+				$body
+			}
+			Kind::Uint64 => {
+				type $T = u64;
+				$body
+			}
+			Kind::Sint32 => {
+				type $T = Sint32;
+				$body
+			}
+			Kind::Sint64 => {
+				type $T = Sint64;
+				$body
+			}
+			Kind::Fixed32 => {
+				type $T = Fixed32;
+				$body
+			}
+			Kind::Fixed64 => {
+				type $T = Fixed64;
+				$body
+			}
+			Kind::Sfixed32 => {
+				type $T = Sfixed32;
+				$body
+			}
+			Kind::Sfixed64 => {
+				type $T = Sfixed64;
+				$body
+			}
+			Kind::Bool => {
+				type $T = bool;
+				$body
+			}
+			Kind::String => {
+				type $T = String;
+				$body
+			}
+			Kind::Bytes => {
+				type $T = Bytes;
+				$body
+			}
+			Kind::Message(desc) if desc.full_name() == "google.protobuf.Duration" => {
+				type $T = Duration;
+				$body
+			}
+			Kind::Message(desc) if desc.full_name() == "google.protobuf.Timestamp" => {
+				type $T = Timestamp;
+				$body
+			}
+			Kind::Message(desc) if desc.full_name() == "google.protobuf.Any" => {
+				type $T = Any;
+				$body
+			}
+			Kind::Message(desc) if desc.full_name() == "google.protobuf.FieldMask" => {
+				type $T = FieldMask;
+				$body
+			}
+			$other => $fallback,
+		}
+	};
+}
+
+// Same as above, but limited to the types that can be used as map keys
+macro_rules! with_key_type {
+	($kind:expr, $T:ident => $body:expr, $other:pat => $fallback:expr) => {
+		match $kind {
+			Kind::Int32 => {
+				type $T = i32;
+				$body
+			}
+			Kind::Int64 => {
+				type $T = i64;
+				$body
+			}
+			Kind::Uint32 => {
+				type $T = u32;
+				$body
+			}
+			Kind::Uint64 => {
+				type $T = u64;
+				$body
+			}
+			Kind::Sint32 => {
+				type $T = Sint32;
+				$body
+			}
+			Kind::Sint64 => {
+				type $T = Sint64;
+				$body
+			}
+			Kind::Fixed32 => {
+				type $T = Fixed32;
+				$body
+			}
+			Kind::Fixed64 => {
+				type $T = Fixed64;
+				$body
+			}
+			Kind::Sfixed32 => {
+				type $T = Sfixed32;
+				$body
+			}
+			Kind::Sfixed64 => {
+				type $T = Sfixed64;
+				$body
+			}
+			Kind::Bool => {
+				type $T = bool;
+				$body
+			}
+			Kind::String => {
+				type $T = String;
+				$body
+			}
+			$other => $fallback,
+		}
+	};
+}
+
+const fn proto_primitive(kind: &Kind) -> ProtoPrimitive {
+	match kind {
+		Kind::Double => ProtoPrimitive::Double,
+		Kind::Float => ProtoPrimitive::Float,
+		Kind::Int32 => ProtoPrimitive::Int32,
+		Kind::Int64 => ProtoPrimitive::Int64,
+		Kind::Uint32 => ProtoPrimitive::Uint32,
+		Kind::Uint64 => ProtoPrimitive::Uint64,
+		Kind::Sint32 => ProtoPrimitive::Sint32,
+		Kind::Sint64 => ProtoPrimitive::Sint64,
+		Kind::Fixed32 => ProtoPrimitive::Fixed32,
+		Kind::Fixed64 => ProtoPrimitive::Fixed64,
+		Kind::Sfixed32 => ProtoPrimitive::Sfixed32,
+		Kind::Sfixed64 => ProtoPrimitive::Sfixed64,
+		Kind::Bool => ProtoPrimitive::Bool,
+		Kind::String => ProtoPrimitive::String,
+		Kind::Bytes => ProtoPrimitive::Bytes,
+		Kind::Message(_) => ProtoPrimitive::Message,
+		Kind::Enum(_) => ProtoPrimitive::Enum,
+	}
+}
+
+fn map_key_subscript(key: &MapKey) -> Subscript {
+	match key {
+		MapKey::Bool(val) => Subscript::BoolKey(*val),
+		MapKey::I32(val) => Subscript::IntKey(i64::from(*val)),
+		MapKey::I64(val) => Subscript::IntKey(*val),
+		MapKey::U32(val) => Subscript::UintKey(u64::from(*val)),
+		MapKey::U64(val) => Subscript::UintKey(*val),
+		MapKey::String(val) => Subscript::StringKey(val.clone()),
+	}
+}
+
+fn validate_value<T>(
+	ctx: &mut ValidationCtx,
+	rules: &FieldRules,
+	value: Option<&Value>,
+) -> Result<(), CaseError>
+where
+	T: DynamicType,
+{
+	let validator = T::validator(rules)?;
+	let value = value.map(T::extract).transpose()?;
+
+	let _ = <T::Validator as Validator<T>>::execute_validation(
+		&validator,
+		ctx,
+		value.as_ref().map(Borrow::borrow),
+	);
+
+	Ok(())
+}
+
+fn validate_repeated<T>(
+	ctx: &mut ValidationCtx,
+	rules: &FieldRules,
+	list: &[Value],
+) -> Result<(), CaseError>
+where
+	T: DynamicType,
+	T::Stored: TryIntoCel + Clone,
+{
+	let mut validator = RepeatedValidator::<T>::default();
+
+	validator.cel = cel_programs(&rules.cel)?;
+	validator.ignore = rules.ignore();
+
+	match &rules.r#type {
+		Some(RulesType::Repeated(repeated_rules)) => {
+			validator.min_items = repeated_rules.min_items.map(to_usize);
+			validator.max_items = repeated_rules.max_items.map(to_usize);
+			validator.unique = repeated_rules.unique();
+			validator.items = repeated_rules
+				.items
+				.as_deref()
+				.map(T::validator)
+				.transpose()?;
+		}
+		Some(other) => return Err(mismatched_rules("repeated", other)),
+		None => {}
+	};
+
+	let items = list
+		.iter()
+		.map(T::extract)
+		.collect::<Result<Vec<T::Stored>, CaseError>>()?;
+
+	let _ = <RepeatedValidator<T> as Validator<Vec<T>>>::execute_validation(
+		&validator,
+		ctx,
+		Some(items.as_slice()),
+	);
+
+	Ok(())
+}
+
+fn validate_map<K, V>(
+	ctx: &mut ValidationCtx,
+	rules: &FieldRules,
+	entries: &HashMap<MapKey, Value>,
+) -> Result<(), CaseError>
+where
+	K: DynamicType + AsProtoType,
+	V: DynamicType + AsProtoType,
+	K::Stored: Clone + Ord + IntoCelKey + Into<Subscript>,
+	V::Stored: Clone + TryIntoCel,
+{
+	let mut validator = MapValidator::<K, V>::default();
+
+	validator.cel = cel_programs(&rules.cel)?;
+	validator.ignore = rules.ignore();
+
+	match &rules.r#type {
+		Some(RulesType::Map(map_rules)) => {
+			validator.min_pairs = map_rules.min_pairs.map(to_usize);
+			validator.max_pairs = map_rules.max_pairs.map(to_usize);
+			validator.keys = map_rules
+				.keys
+				.as_deref()
+				.map(K::validator)
+				.transpose()?;
+			validator.values = map_rules
+				.values
+				.as_deref()
+				.map(V::validator)
+				.transpose()?;
+		}
+		Some(other) => return Err(mismatched_rules("map", other)),
+		None => {}
+	};
+
+	let mut map: BTreeMap<K::Stored, V::Stored> = BTreeMap::new();
+
+	for (key, value) in entries {
+		map.insert(K::extract(&Value::from(key.clone()))?, V::extract(value)?);
+	}
+
+	let _ = validator.validate_map(ctx, Some(&map));
+
+	Ok(())
+}
+
+// Enum rules need a rust enum to check the defined values, so only the rules
+// which do not depend on the enum itself are supported
+fn validate_enum(
+	ctx: &mut ValidationCtx,
+	rules: &FieldRules,
+	value: Option<&Value>,
+) -> Result<(), CaseError> {
+	match &rules.r#type {
+		Some(RulesType::Enum(_)) => {
+			return Err(CaseError::Unsupported(
+				"enum rules cannot be applied to dynamic enums".to_string(),
+			));
+		}
+		Some(other) => return Err(mismatched_rules("enum", other)),
+		None => {}
+	};
+
+	let mut validator = IntValidator::<i32>::default();
+
+	validator.cel = cel_programs(&rules.cel)?;
+	validator.required = rules.required();
+	validator.ignore = rules.ignore();
+
+	let value = value.and_then(Value::as_enum_number);
+
+	let _ = validator.execute_validation(ctx, value.as_ref());
+
+	Ok(())
+}
+
+/// Validates the messages of a [`TestConformanceRequest`](crate::harness::TestConformanceRequest).
+pub struct Executor {
+	pool: DescriptorPool,
+	field_ext: Option<ExtensionDescriptor>,
+	message_ext: Option<ExtensionDescriptor>,
+	oneof_ext: Option<ExtensionDescriptor>,
+}
+
+impl Executor {
+	/// Creates a new executor for the messages described in the given [`FileDescriptorSet`].
+	pub fn new(fdset: FileDescriptorSet) -> Result<Self, String> {
+		let pool = DescriptorPool::from_file_descriptor_set(fdset)
+			.map_err(|e| format!("invalid file descriptor set: {e}"))?;
+
+		Ok(Self {
+			field_ext: pool.get_extension_by_name("buf.validate.field"),
+			message_ext: pool.get_extension_by_name("buf.validate.message"),
+			oneof_ext: pool.get_extension_by_name("buf.validate.oneof"),
+			pool,
+		})
+	}
+
+	/// Decodes the message contained in the [`Any`](ProstAny) and validates it.
+	pub fn run_case(&self, case: &ProstAny) -> TestResult {
+		let name = case
+			.type_url
+			.rsplit('/')
+			.next()
+			.unwrap_or_default();
+
+		let Some(descriptor) = self.pool.get_message_by_name(name) else {
+			return TestResult::unexpected_error(format!("unknown message `{name}`"));
+		};
+
+		let msg = match DynamicMessage::decode(descriptor, case.value.as_slice()) {
+			Ok(msg) => msg,
+			Err(e) => {
+				return TestResult::unexpected_error(format!("failed to decode `{name}`: {e}"));
+			}
+		};
+
+		let mut ctx = ValidationCtx::new();
+		ctx.fail_fast = false;
+
+		// Validators should never panic, but if they do it must be reported
+		// as a failed case rather than interrupting the whole run
+		let outcome = catch_unwind(AssertUnwindSafe(|| self.validate_message(&mut ctx, &msg)));
+
+		match outcome {
+			Ok(Ok(())) => {}
+			Ok(Err(e)) => return e.into(),
+			Err(panic) => {
+				let msg = panic
+					.downcast_ref::<String>()
+					.map(String::as_str)
+					.or_else(|| panic.downcast_ref::<&str>().copied())
+					.unwrap_or("unknown panic");
+
+				return TestResult::unexpected_error(format!("panic during validation: {msg}"));
+			}
+		};
+
+		let runtime_error = ctx.violations.iter().find(|v| {
+			matches!(v.meta.kind, ViolationKind::Cel) && v.data.message() == CEL_RUNTIME_ERROR_MSG
+		});
+
+		if let Some(violation) = runtime_error {
+			return TestResult::runtime_error(format!(
+				"failed to evaluate the rule `{}`",
+				violation.data.rule_id()
+			));
+		}
+
+		if ctx.violations.is_empty() {
+			TestResult::success()
+		} else {
+			TestResult::validation_error(ctx.violations.into_violations())
+		}
+	}
+
+	fn extension_rules<R: Message + Default>(
+		extension: Option<&ExtensionDescriptor>,
+		options: &DynamicMessage,
+	) -> Result<Option<R>, CaseError> {
+		let Some(extension) = extension else {
+			return Ok(None);
+		};
+
+		if !options.has_extension(extension) {
+			return Ok(None);
+		}
+
+		match options.get_extension(extension).as_ref() {
+			Value::Message(rules) => R::decode(rules.encode_to_vec().as_slice())
+				.map(Some)
+				.map_err(|e| CaseError::Compilation(format!("failed to decode the rules: {e}"))),
+			_ => Ok(None),
+		}
+	}
+
+	fn field_rules(&self, field: &FieldDescriptor) -> Result<FieldRules, CaseError> {
+		Self::extension_rules(self.field_ext.as_ref(), &field.options())
+			.map(Option::unwrap_or_default)
+	}
+
+	fn message_rules(
+		&self,
+		message: &MessageDescriptor,
+	) -> Result<Option<MessageRules>, CaseError> {
+		Self::extension_rules(self.message_ext.as_ref(), &message.options())
+	}
+
+	fn validate_message(
+		&self,
+		ctx: &mut ValidationCtx,
+		msg: &DynamicMessage,
+	) -> Result<(), CaseError> {
+		let descriptor = msg.descriptor();
+
+		if let Some(rules) = self.message_rules(&descriptor)? {
+			if !rules.oneof.is_empty() {
+				return Err(CaseError::Unsupported(
+					"message oneof rules are not supported".to_string(),
+				));
+			}
+
+			let programs = cel_programs(&rules.cel)?;
+
+			if !programs.is_empty() {
+				let _ = execute_cel_programs(ctx, &programs, message_to_cel(msg)?);
+			}
+		}
+
+		for oneof in descriptor.oneofs() {
+			if oneof.is_synthetic() {
+				continue;
+			}
+
+			let rules: Option<OneofRules> =
+				Self::extension_rules(self.oneof_ext.as_ref(), &oneof.options())?;
+
+			if rules.is_some_and(|rules| rules.required())
+				&& !oneof.fields().any(|field| msg.has_field(&field))
+			{
+				ctx.parent_elements.push(FieldPathElement {
+					field_name: Some(oneof.name().to_string()),
+					..Default::default()
+				});
+
+				let _ = ctx.add_violation(
+					ViolationKind::RequiredOneof,
+					"exactly one field is required in oneof",
+				);
+
+				ctx.parent_elements.pop();
+			}
+		}
+
+		for field in descriptor.fields() {
+			self.validate_field(ctx, msg, &field)?;
+		}
+
+		Ok(())
+	}
+
+	fn validate_field(
+		&self,
+		ctx: &mut ValidationCtx,
+		msg: &DynamicMessage,
+		field: &FieldDescriptor,
+	) -> Result<(), CaseError> {
+		let rules = self.field_rules(field)?;
+
+		if matches!(rules.ignore(), Ignore::Always) {
+			return Ok(());
+		}
+
+		#[allow(clippy::cast_possible_wrap)]
+		let field_context = FieldContext::new(
+			field.name().to_string().into(),
+			field.number() as i32,
+			proto_primitive(&field.kind()),
+		);
+
+		ctx.field_context = Some(field_context);
+
+		let value = msg.get_field(field);
+
+		let result = if let Some(entries) = value.as_map() {
+			self.validate_map_field(ctx, field, entries, &rules)
+		} else if let Some(list) = value.as_list() {
+			self.validate_list_field(ctx, field, list, &rules)
+		} else {
+			let is_set = !field.supports_presence() || msg.has_field(field);
+
+			self.validate_single(ctx, &field.kind(), is_set.then_some(&*value), &rules)
+		};
+
+		ctx.field_context = None;
+
+		result
+	}
+
+	fn validate_single(
+		&self,
+		ctx: &mut ValidationCtx,
+		kind: &Kind,
+		value: Option<&Value>,
+		rules: &FieldRules,
+	) -> Result<(), CaseError> {
+		with_type!(kind, T => validate_value::<T>(ctx, rules, value), other => match other {
+			Kind::Enum(_) => validate_enum(ctx, rules, value),
+			Kind::Message(desc) if WRAPPERS.contains(&desc.full_name()) => {
+				let Some(inner_field) = desc.get_field_by_name("value") else {
+					return Ok(());
+				};
+
+				// Wrappers are validated with the rules of the type that they contain
+				let inner = value
+					.and_then(Value::as_message)
+					.map(|msg| msg.get_field(&inner_field).into_owned());
+
+				self.validate_single(ctx, &inner_field.kind(), inner.as_ref(), rules)
+			}
+			_ => self.validate_message_field(ctx, value.and_then(Value::as_message), rules),
+		})
+	}
+
+	fn validate_message_field(
+		&self,
+		ctx: &mut ValidationCtx,
+		msg: Option<&DynamicMessage>,
+		rules: &FieldRules,
+	) -> Result<(), CaseError> {
+		if let Some(other) = &rules.r#type {
+			return Err(mismatched_rules("message", other));
+		}
+
+		let Some(msg) = msg else {
+			if rules.required() {
+				let _ = ctx.add_required_violation(None);
+			}
+
+			return Ok(());
+		};
+
+		let programs = cel_programs(&rules.cel)?;
+
+		if !programs.is_empty() {
+			let _ = execute_cel_programs(ctx, &programs, message_to_cel(msg)?);
+		}
+
+		self.validate_nested(ctx, msg)
+	}
+
+	fn validate_nested(
+		&self,
+		ctx: &mut ValidationCtx,
+		msg: &DynamicMessage,
+	) -> Result<(), CaseError> {
+		let Some(field_context) = ctx.field_context.take() else {
+			return self.validate_message(ctx, msg);
+		};
+
+		ctx.parent_elements
+			.push(field_context.as_path_element());
+
+		let result = self.validate_message(ctx, msg);
+
+		ctx.parent_elements.pop();
+		ctx.field_context = Some(field_context);
+
+		result
+	}
+
+	fn validate_list_field(
+		&self,
+		ctx: &mut ValidationCtx,
+		field: &FieldDescriptor,
+		list: &[Value],
+		rules: &FieldRules,
+	) -> Result<(), CaseError> {
+		if rules.required() {
+			return Err(CaseError::Unsupported(
+				"`required` is not supported for repeated fields".to_string(),
+			));
+		}
+
+		with_type!(&field.kind(), T => validate_repeated::<T>(ctx, rules, list), other => match other {
+			Kind::Message(desc) if !WRAPPERS.contains(&desc.full_name()) => {
+				if rules.r#type.is_some() || !rules.cel.is_empty() {
+					return Err(CaseError::Unsupported(
+						"rules for repeated message fields are not supported".to_string(),
+					));
+				}
+
+				for (i, item) in list.iter().enumerate() {
+					let Some(msg) = item.as_message() else {
+						continue;
+					};
+
+					if let Some(fc) = ctx.field_context.as_mut() {
+						fc.subscript = Some(Subscript::Index(i as u64));
+						fc.field_kind = FieldKind::RepeatedItem;
+					}
+
+					self.validate_nested(ctx, msg)?;
+				}
+
+				Ok(())
+			}
+			other => Err(CaseError::Unsupported(format!(
+				"repeated fields of type `{other:?}` are not supported"
+			))),
+		})
+	}
+
+	fn validate_map_field(
+		&self,
+		ctx: &mut ValidationCtx,
+		field: &FieldDescriptor,
+		entries: &HashMap<MapKey, Value>,
+		rules: &FieldRules,
+	) -> Result<(), CaseError> {
+		let Kind::Message(entry) = field.kind() else {
+			return Ok(());
+		};
+
+		let key_kind = entry.map_entry_key_field().kind();
+		let value_kind = entry.map_entry_value_field().kind();
+
+		with_key_type!(&key_kind, K => with_type!(&value_kind, V => validate_map::<K, V>(ctx, rules, entries), other => match other {
+			Kind::Message(desc) if !WRAPPERS.contains(&desc.full_name()) => {
+				if rules.r#type.is_some() || !rules.cel.is_empty() {
+					return Err(CaseError::Unsupported(
+						"rules for map fields with message values are not supported".to_string(),
+					));
+				}
+
+				for (key, value) in entries {
+					let Some(msg) = value.as_message() else {
+						continue;
+					};
+
+					if let Some(fc) = ctx.field_context.as_mut() {
+						fc.subscript = Some(map_key_subscript(key));
+						fc.map_key_type = Some(proto_primitive(&key_kind));
+						fc.map_value_type = Some(ProtoPrimitive::Message);
+						fc.field_kind = FieldKind::MapValue;
+					}
+
+					self.validate_nested(ctx, msg)?;
+				}
+
+				Ok(())
+			}
+			other => Err(CaseError::Unsupported(format!(
+				"map values of type `{other:?}` are not supported"
+			))),
+		}), other => Err(CaseError::Unsupported(format!(
+			"map keys of type `{other:?}` are not supported"
+		))))
+	}
+}
@@ -0,0 +1,91 @@
+//! The messages of `buf/validate/conformance/harness/harness.proto`, which are used by
+//! the protovalidate conformance runner to communicate with the executor.
+
+use std::collections::HashMap;
+
+use prost_types::{Any, FileDescriptorSet};
+use protify::Violations;
+
+/// The request sent by the conformance runner, containing the descriptors of the test messages
+/// and the cases to validate, indexed by their name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TestConformanceRequest {
+	#[prost(message, optional, tag = "2")]
+	pub fdset: Option<FileDescriptorSet>,
+	#[prost(map = "string, message", tag = "3")]
+	pub cases: HashMap<String, Any>,
+}
+
+/// The response sent back to the conformance runner, with the result of each case.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TestConformanceResponse {
+	#[prost(map = "string, message", tag = "1")]
+	pub results: HashMap<String, TestResult>,
+}
+
+/// The outcome of a single test case.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TestResult {
+	#[prost(oneof = "test_result::Result", tags = "1, 2, 3, 4, 5")]
+	pub result: Option<test_result::Result>,
+}
+
+pub mod test_result {
+	use super::*;
+
+	#[derive(Clone, PartialEq, ::prost::Oneof)]
+	pub enum Result {
+		/// The message passed validation.
+		#[prost(bool, tag = "1")]
+		Success(bool),
+		/// The message failed validation with the given violations.
+		#[prost(message, tag = "2")]
+		ValidationError(Violations),
+		/// The rules of the message could not be compiled.
+		#[prost(string, tag = "3")]
+		CompilationError(String),
+		/// A rule failed to be evaluated.
+		#[prost(string, tag = "4")]
+		RuntimeError(String),
+		/// The case could not be handled by the executor.
+		#[prost(string, tag = "5")]
+		UnexpectedError(String),
+	}
+}
+
+impl TestResult {
+	#[must_use]
+	pub const fn success() -> Self {
+		Self {
+			result: Some(test_result::Result::Success(true)),
+		}
+	}
+
+	#[must_use]
+	pub const fn validation_error(violations: Violations) -> Self {
+		Self {
+			result: Some(test_result::Result::ValidationError(violations)),
+		}
+	}
+
+	#[must_use]
+	pub const fn compilation_error(msg: String) -> Self {
+		Self {
+			result: Some(test_result::Result::CompilationError(msg)),
+		}
+	}
+
+	#[must_use]
+	pub const fn runtime_error(msg: String) -> Self {
+		Self {
+			result: Some(test_result::Result::RuntimeError(msg)),
+		}
+	}
+
+	#[must_use]
+	pub const fn unexpected_error(msg: String) -> Self {
+		Self {
+			result: Some(test_result::Result::UnexpectedError(msg)),
+		}
+	}
+}
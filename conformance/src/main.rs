@@ -0,0 +1,97 @@
+//! An executor for the [protovalidate conformance suite](https://github.com/bufbuild/protovalidate/tree/main/tools/protovalidate-conformance).
+//!
+//! When launched without arguments, it reads a `TestConformanceRequest` from stdin and writes
+//! the `TestConformanceResponse` to stdout, as expected by the conformance runner.
+//!
+//! With `--offline`, it runs the small, hand-picked set of cases in `suite.rs` and reports which ones pass.
+//! Their messages are in `proto/`, and the `validate.proto` file that they import is in `proto_deps/`.
+
+use std::{
+	io::{Read, Write},
+	process::ExitCode,
+};
+
+use prost::Message;
+
+mod cel_value;
+mod executor;
+mod harness;
+mod rules;
+mod suite;
+
+use executor::{CaseError, Executor};
+use harness::{TestConformanceRequest, TestConformanceResponse};
+
+const USAGE: &str = "Usage: protify-conformance [--offline [FILTER]]
+
+Without arguments, reads a TestConformanceRequest from stdin and writes
+the TestConformanceResponse to stdout.
+
+  --offline [FILTER]  Runs the hand-picked offline cases (optionally only those
+                      whose name contains FILTER) and reports the results.";
+
+pub fn run_request(request: TestConformanceRequest) -> Result<TestConformanceResponse, String> {
+	let executor = Executor::new(request.fdset.unwrap_or_default())?;
+
+	let results = request
+		.cases
+		.iter()
+		.map(|(name, case)| (name.clone(), executor.run_case(case)))
+		.collect();
+
+	Ok(TestConformanceResponse { results })
+}
+
+fn run_executor() -> ExitCode {
+	let mut input = Vec::new();
+
+	if let Err(e) = std::io::stdin().read_to_end(&mut input) {
+		eprintln!("failed to read the request: {e}");
+		return ExitCode::FAILURE;
+	}
+
+	let request = match TestConformanceRequest::decode(input.as_slice()) {
+		Ok(request) => request,
+		Err(e) => {
+			eprintln!("failed to decode the request: {e}");
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let response = match run_request(request) {
+		Ok(response) => response,
+		Err(e) => {
+			eprintln!("{e}");
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let mut stdout = std::io::stdout().lock();
+
+	if let Err(e) = stdout
+		.write_all(&response.encode_to_vec())
+		.and_then(|()| stdout.flush())
+	{
+		eprintln!("failed to write the response: {e}");
+		return ExitCode::FAILURE;
+	}
+
+	ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+	let args: Vec<String> = std::env::args().skip(1).collect();
+
+	match args.first().map(String::as_str) {
+		None => run_executor(),
+		Some("--offline") => suite::run_offline(args.get(1).map(String::as_str)),
+		Some("--help" | "-h") => {
+			println!("{USAGE}");
+			ExitCode::SUCCESS
+		}
+		Some(other) => {
+			eprintln!("unknown argument `{other}`\n\n{USAGE}");
+			ExitCode::FAILURE
+		}
+	}
+}
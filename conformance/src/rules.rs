@@ -0,0 +1,538 @@
+//! Converts the protovalidate rules read from the descriptors into protify validators,
+//! and the values of dynamic messages into the types that they expect.
+
+use ordered_float::OrderedFloat;
+use prost_reflect::{DynamicMessage, Value};
+use protify::*;
+use proto_types::{
+	Any, Duration, FieldMask, Timestamp,
+	protovalidate::{
+		FieldRules, KnownRegex, Rule, bytes_rules, duration_rules, field_rules::Type as RulesType,
+		string_rules, timestamp_rules,
+	},
+};
+
+use crate::CaseError;
+
+/// A type which can be validated dynamically, by building its validator from the [`FieldRules`]
+/// of a field, and by extracting its value from the field of a dynamic message.
+pub trait DynamicType: ProtoValidation + Send + Sync + Sized {
+	/// The name of the rules in the `type` oneof of [`FieldRules`] which can be used with this type.
+	const RULES_NAME: &str;
+
+	fn validator(rules: &FieldRules) -> Result<Self::Validator, CaseError>;
+
+	fn extract(value: &Value) -> Result<Self::Stored, CaseError>;
+}
+
+pub(crate) fn cel_programs(rules: &[Rule]) -> Result<Vec<CelProgram>, CaseError> {
+	rules
+		.iter()
+		.map(|rule| {
+			// Programs panic if they fail to compile, so they are compiled here first
+			// to report the error as a compilation error
+			cel::Program::compile(rule.expression()).map_err(|e| {
+				CaseError::Compilation(format!(
+					"failed to compile the expression of the rule `{}`: {e}",
+					rule.id()
+				))
+			})?;
+
+			Ok(CelProgram::new(CelRule {
+				id: rule.id().to_string().into(),
+				message: rule.message().to_string().into(),
+				expression: rule.expression().to_string().into(),
+			}))
+		})
+		.collect()
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) const fn to_usize(val: u64) -> usize {
+	val as usize
+}
+
+fn sorted_list<T: Ord>(items: impl IntoIterator<Item = T>) -> Option<SortedList<T>> {
+	let list: SortedList<T> = items.into_iter().collect();
+
+	(!list.is_empty()).then_some(list)
+}
+
+pub(crate) fn mismatched_rules(expected: &str, rules: &RulesType) -> CaseError {
+	CaseError::Compilation(format!(
+		"expected {expected} rules, found {}",
+		rules_name(rules)
+	))
+}
+
+const fn rules_name(rules: &RulesType) -> &'static str {
+	match rules {
+		RulesType::Float(_) => "float",
+		RulesType::Double(_) => "double",
+		RulesType::Int32(_) => "int32",
+		RulesType::Int64(_) => "int64",
+		RulesType::Uint32(_) => "uint32",
+		RulesType::Uint64(_) => "uint64",
+		RulesType::Sint32(_) => "sint32",
+		RulesType::Sint64(_) => "sint64",
+		RulesType::Fixed32(_) => "fixed32",
+		RulesType::Fixed64(_) => "fixed64",
+		RulesType::Sfixed32(_) => "sfixed32",
+		RulesType::Sfixed64(_) => "sfixed64",
+		RulesType::Bool(_) => "bool",
+		RulesType::String(_) => "string",
+		RulesType::Bytes(_) => "bytes",
+		RulesType::Enum(_) => "enum",
+		RulesType::Repeated(_) => "repeated",
+		RulesType::Map(_) => "map",
+		RulesType::Any(_) => "any",
+		RulesType::Duration(_) => "duration",
+		RulesType::Timestamp(_) => "timestamp",
+		RulesType::FieldMask(_) => "field_mask",
+	}
+}
+
+fn unexpected_value(expected: &str, value: &Value) -> CaseError {
+	CaseError::Unsupported(format!("expected a {expected} value, found {value:?}"))
+}
+
+fn message_value<'a>(value: &'a Value, full_name: &str) -> Result<&'a DynamicMessage, CaseError> {
+	value
+		.as_message()
+		.filter(|msg| msg.descriptor().full_name() == full_name)
+		.ok_or_else(|| unexpected_value(full_name, value))
+}
+
+fn seconds_and_nanos(msg: &DynamicMessage) -> (i64, i32) {
+	let seconds = msg
+		.get_field_by_name("seconds")
+		.and_then(|v| v.as_i64())
+		.unwrap_or_default();
+	let nanos = msg
+		.get_field_by_name("nanos")
+		.and_then(|v| v.as_i32())
+		.unwrap_or_default();
+
+	(seconds, nanos)
+}
+
+impl DynamicType for String {
+	const RULES_NAME: &str = "string";
+
+	fn validator(field_rules: &FieldRules) -> Result<Self::Validator, CaseError> {
+		let mut validator = StringValidator::default();
+
+		validator.cel = cel_programs(&field_rules.cel)?;
+		validator.required = field_rules.required();
+		validator.ignore = field_rules.ignore();
+
+		match &field_rules.r#type {
+			Some(RulesType::String(rules)) => {
+				validator.const_ = rules.r#const.clone().map(FixedStr::from);
+				validator.len = rules.len.map(to_usize);
+				validator.min_len = rules.min_len.map(to_usize);
+				validator.max_len = rules.max_len.map(to_usize);
+				validator.len_bytes = rules.len_bytes.map(to_usize);
+				validator.min_bytes = rules.min_bytes.map(to_usize);
+				validator.max_bytes = rules.max_bytes.map(to_usize);
+				validator.prefix = rules.prefix.clone().map(FixedStr::from);
+				validator.suffix = rules.suffix.clone().map(FixedStr::from);
+				validator.contains = rules.contains.clone().map(FixedStr::from);
+				validator.not_contains = rules.not_contains.clone().map(FixedStr::from);
+				validator.in_ = sorted_list(rules.r#in.iter().cloned().map(FixedStr::from));
+				validator.not_in = sorted_list(rules.not_in.iter().cloned().map(FixedStr::from));
+
+				if let Some(pattern) = &rules.pattern {
+					validator.pattern = Some(::regex::Regex::new(pattern).map_err(|e| {
+						CaseError::Compilation(format!("invalid regex `{pattern}`: {e}"))
+					})?);
+				}
+
+				validator.well_known = match rules.well_known {
+					Some(string_rules::WellKnown::WellKnownRegex(num)) => {
+						let strict = rules.strict();
+
+						match KnownRegex::try_from(num).unwrap_or_default() {
+							KnownRegex::HttpHeaderName if strict => {
+								Some(WellKnownStrings::HeaderNameStrict)
+							}
+							KnownRegex::HttpHeaderName => Some(WellKnownStrings::HeaderNameLoose),
+							KnownRegex::HttpHeaderValue if strict => {
+								Some(WellKnownStrings::HeaderValueStrict)
+							}
+							KnownRegex::HttpHeaderValue => Some(WellKnownStrings::HeaderValueLoose),
+							KnownRegex::Unspecified => None,
+						}
+					}
+					Some(well_known) => well_known_string(well_known),
+					None => None,
+				};
+			}
+			Some(other) => return Err(mismatched_rules(Self::RULES_NAME, other)),
+			None => {}
+		};
+
+		Ok(validator)
+	}
+
+	fn extract(value: &Value) -> Result<Self::Stored, CaseError> {
+		value
+			.as_str()
+			.map(ToString::to_string)
+			.ok_or_else(|| unexpected_value("string", value))
+	}
+}
+
+fn well_known_string(well_known: string_rules::WellKnown) -> Option<WellKnownStrings> {
+	use string_rules::WellKnown;
+
+	let output = match well_known {
+		WellKnown::Email(true) => WellKnownStrings::Email,
+		WellKnown::Hostname(true) => WellKnownStrings::Hostname,
+		WellKnown::Ip(true) => WellKnownStrings::Ip,
+		WellKnown::Ipv4(true) => WellKnownStrings::Ipv4,
+		WellKnown::Ipv6(true) => WellKnownStrings::Ipv6,
+		WellKnown::Uri(true) => WellKnownStrings::Uri,
+		WellKnown::UriRef(true) => WellKnownStrings::UriRef,
+		WellKnown::Address(true) => WellKnownStrings::Address,
+		WellKnown::Uuid(true) => WellKnownStrings::Uuid,
+		WellKnown::Tuuid(true) => WellKnownStrings::Tuuid,
+		WellKnown::IpWithPrefixlen(true) => WellKnownStrings::IpWithPrefixlen,
+		WellKnown::Ipv4WithPrefixlen(true) => WellKnownStrings::Ipv4WithPrefixlen,
+		WellKnown::Ipv6WithPrefixlen(true) => WellKnownStrings::Ipv6WithPrefixlen,
+		WellKnown::IpPrefix(true) => WellKnownStrings::IpPrefix,
+		WellKnown::Ipv4Prefix(true) => WellKnownStrings::Ipv4Prefix,
+		WellKnown::Ipv6Prefix(true) => WellKnownStrings::Ipv6Prefix,
+		WellKnown::HostAndPort(true) => WellKnownStrings::HostAndPort,
+		WellKnown::Ulid(true) => WellKnownStrings::Ulid,
+		_ => return None,
+	};
+
+	Some(output)
+}
+
+impl DynamicType for Bytes {
+	const RULES_NAME: &str = "bytes";
+
+	fn validator(field_rules: &FieldRules) -> Result<Self::Validator, CaseError> {
+		let mut validator = BytesValidator::default();
+
+		validator.cel = cel_programs(&field_rules.cel)?;
+		validator.required = field_rules.required();
+		validator.ignore = field_rules.ignore();
+
+		match &field_rules.r#type {
+			Some(RulesType::Bytes(rules)) => {
+				validator.const_ = rules.r#const.clone();
+				validator.len = rules.len.map(to_usize);
+				validator.min_len = rules.min_len.map(to_usize);
+				validator.max_len = rules.max_len.map(to_usize);
+				validator.prefix = rules.prefix.clone();
+				validator.suffix = rules.suffix.clone();
+				validator.contains = rules.contains.clone();
+				validator.in_ = sorted_list(rules.r#in.iter().cloned());
+				validator.not_in = sorted_list(rules.not_in.iter().cloned());
+
+				if let Some(pattern) = &rules.pattern {
+					validator.pattern = Some(::regex::bytes::Regex::new(pattern).map_err(|e| {
+						CaseError::Compilation(format!("invalid regex `{pattern}`: {e}"))
+					})?);
+				}
+
+				validator.well_known = match rules.well_known {
+					Some(bytes_rules::WellKnown::Ip(true)) => Some(WellKnownBytes::Ip),
+					Some(bytes_rules::WellKnown::Ipv4(true)) => Some(WellKnownBytes::Ipv4),
+					Some(bytes_rules::WellKnown::Ipv6(true)) => Some(WellKnownBytes::Ipv6),
+					Some(bytes_rules::WellKnown::Uuid(true)) => Some(WellKnownBytes::Uuid),
+					_ => None,
+				};
+			}
+			Some(other) => return Err(mismatched_rules(Self::RULES_NAME, other)),
+			None => {}
+		};
+
+		Ok(validator)
+	}
+
+	fn extract(value: &Value) -> Result<Self::Stored, CaseError> {
+		value
+			.as_bytes()
+			.cloned()
+			.ok_or_else(|| unexpected_value("bytes", value))
+	}
+}
+
+impl DynamicType for bool {
+	const RULES_NAME: &str = "bool";
+
+	fn validator(field_rules: &FieldRules) -> Result<Self::Validator, CaseError> {
+		let mut validator = BoolValidator::default();
+
+		validator.required = field_rules.required();
+		validator.ignore = field_rules.ignore();
+
+		match &field_rules.r#type {
+			Some(RulesType::Bool(rules)) => {
+				validator.const_ = rules.r#const;
+			}
+			Some(other) => return Err(mismatched_rules(Self::RULES_NAME, other)),
+			None => {}
+		};
+
+		Ok(validator)
+	}
+
+	fn extract(value: &Value) -> Result<Self::Stored, CaseError> {
+		value
+			.as_bool()
+			.ok_or_else(|| unexpected_value("bool", value))
+	}
+}
+
+macro_rules! impl_numeric {
+	(@lists $validator:ident, $rules:ident) => {
+		$validator.in_ = sorted_list($rules.r#in.iter().copied());
+		$validator.not_in = sorted_list($rules.not_in.iter().copied());
+	};
+
+	(@lists $validator:ident, $rules:ident, float) => {
+		$validator.finite = $rules.finite();
+		$validator.in_ = sorted_list($rules.r#in.iter().copied().map(OrderedFloat));
+		$validator.not_in = sorted_list($rules.not_in.iter().copied().map(OrderedFloat));
+	};
+
+	($target:ty, $name:ident, $unit:ty, $extract:ident, $validator:ident $(, $float:ident)?) => {
+		paste::paste! {
+			impl DynamicType for $target {
+				const RULES_NAME: &str = stringify!([< $name:lower >]);
+
+				fn validator(field_rules: &FieldRules) -> Result<Self::Validator, CaseError> {
+					use proto_types::protovalidate::[< $name:snake _rules >]::{GreaterThan, LessThan};
+
+					let mut validator = $validator::<$target>::default();
+
+					validator.cel = cel_programs(&field_rules.cel)?;
+					validator.required = field_rules.required();
+					validator.ignore = field_rules.ignore();
+
+					match &field_rules.r#type {
+						Some(RulesType::[< $name:camel >](rules)) => {
+							validator.const_ = rules.r#const;
+
+							match rules.less_than {
+								Some(LessThan::Lt(val)) => validator.lt = Some(val),
+								Some(LessThan::Lte(val)) => validator.lte = Some(val),
+								None => {}
+							};
+
+							match rules.greater_than {
+								Some(GreaterThan::Gt(val)) => validator.gt = Some(val),
+								Some(GreaterThan::Gte(val)) => validator.gte = Some(val),
+								None => {}
+							};
+
+							impl_numeric!(@lists validator, rules $(, $float)?);
+						}
+						Some(other) => return Err(mismatched_rules(Self::RULES_NAME, other)),
+						None => {}
+					};
+
+					Ok(validator)
+				}
+
+				fn extract(value: &Value) -> Result<Self::Stored, CaseError> {
+					value
+						.$extract()
+						.ok_or_else(|| unexpected_value(stringify!($unit), value))
+				}
+			}
+		}
+	};
+}
+
+impl_numeric!(i32, Int32, i32, as_i32, IntValidator);
+impl_numeric!(i64, Int64, i64, as_i64, IntValidator);
+impl_numeric!(u32, UInt32, u32, as_u32, IntValidator);
+impl_numeric!(u64, UInt64, u64, as_u64, IntValidator);
+impl_numeric!(Sint32, SInt32, i32, as_i32, IntValidator);
+impl_numeric!(Sint64, SInt64, i64, as_i64, IntValidator);
+impl_numeric!(Fixed32, Fixed32, u32, as_u32, IntValidator);
+impl_numeric!(Fixed64, Fixed64, u64, as_u64, IntValidator);
+impl_numeric!(Sfixed32, SFixed32, i32, as_i32, IntValidator);
+impl_numeric!(Sfixed64, SFixed64, i64, as_i64, IntValidator);
+impl_numeric!(f32, Float, f32, as_f32, FloatValidator, float);
+impl_numeric!(f64, Double, f64, as_f64, FloatValidator, float);
+
+impl DynamicType for Duration {
+	const RULES_NAME: &str = "duration";
+
+	fn validator(field_rules: &FieldRules) -> Result<Self::Validator, CaseError> {
+		use duration_rules::{GreaterThan, LessThan};
+
+		let mut validator = DurationValidator::default();
+
+		validator.cel = cel_programs(&field_rules.cel)?;
+		validator.required = field_rules.required();
+		validator.ignore = field_rules.ignore();
+
+		match &field_rules.r#type {
+			Some(RulesType::Duration(rules)) => {
+				validator.const_ = rules.r#const;
+
+				match rules.less_than {
+					Some(LessThan::Lt(val)) => validator.lt = Some(val),
+					Some(LessThan::Lte(val)) => validator.lte = Some(val),
+					None => {}
+				};
+
+				match rules.greater_than {
+					Some(GreaterThan::Gt(val)) => validator.gt = Some(val),
+					Some(GreaterThan::Gte(val)) => validator.gte = Some(val),
+					None => {}
+				};
+
+				validator.in_ = sorted_list(rules.r#in.iter().copied());
+				validator.not_in = sorted_list(rules.not_in.iter().copied());
+			}
+			Some(other) => return Err(mismatched_rules(Self::RULES_NAME, other)),
+			None => {}
+		};
+
+		Ok(validator)
+	}
+
+	fn extract(value: &Value) -> Result<Self::Stored, CaseError> {
+		let msg = message_value(value, "google.protobuf.Duration")?;
+		let (seconds, nanos) = seconds_and_nanos(msg);
+
+		Ok(Duration { seconds, nanos })
+	}
+}
+
+impl DynamicType for Timestamp {
+	const RULES_NAME: &str = "timestamp";
+
+	fn validator(field_rules: &FieldRules) -> Result<Self::Validator, CaseError> {
+		use timestamp_rules::{GreaterThan, LessThan};
+
+		let mut validator = TimestampValidator::default();
+
+		validator.cel = cel_programs(&field_rules.cel)?;
+		validator.required = field_rules.required();
+		validator.ignore = field_rules.ignore();
+
+		match &field_rules.r#type {
+			Some(RulesType::Timestamp(rules)) => {
+				validator.const_ = rules.r#const;
+				validator.within = rules.within;
+
+				match rules.less_than {
+					Some(LessThan::Lt(val)) => validator.lt = Some(val),
+					Some(LessThan::Lte(val)) => validator.lte = Some(val),
+					Some(LessThan::LtNow(lt_now)) => validator.lt_now = lt_now,
+					None => {}
+				};
+
+				match rules.greater_than {
+					Some(GreaterThan::Gt(val)) => validator.gt = Some(val),
+					Some(GreaterThan::Gte(val)) => validator.gte = Some(val),
+					Some(GreaterThan::GtNow(gt_now)) => validator.gt_now = gt_now,
+					None => {}
+				};
+			}
+			Some(other) => return Err(mismatched_rules(Self::RULES_NAME, other)),
+			None => {}
+		};
+
+		Ok(validator)
+	}
+
+	fn extract(value: &Value) -> Result<Self::Stored, CaseError> {
+		let msg = message_value(value, "google.protobuf.Timestamp")?;
+		let (seconds, nanos) = seconds_and_nanos(msg);
+
+		Ok(Timestamp { seconds, nanos })
+	}
+}
+
+impl DynamicType for Any {
+	const RULES_NAME: &str = "any";
+
+	fn validator(field_rules: &FieldRules) -> Result<Self::Validator, CaseError> {
+		let mut validator = AnyValidator::default();
+
+		validator.cel = cel_programs(&field_rules.cel)?;
+		validator.required = field_rules.required();
+		validator.ignore = field_rules.ignore();
+
+		match &field_rules.r#type {
+			Some(RulesType::Any(rules)) => {
+				validator.in_ = sorted_list(rules.r#in.iter().cloned().map(FixedStr::from));
+				validator.not_in = sorted_list(rules.not_in.iter().cloned().map(FixedStr::from));
+			}
+			Some(other) => return Err(mismatched_rules(Self::RULES_NAME, other)),
+			None => {}
+		};
+
+		Ok(validator)
+	}
+
+	fn extract(value: &Value) -> Result<Self::Stored, CaseError> {
+		let msg = message_value(value, "google.protobuf.Any")?;
+
+		let type_url = msg
+			.get_field_by_name("type_url")
+			.and_then(|v| v.as_str().map(ToString::to_string))
+			.unwrap_or_default();
+		let value = msg
+			.get_field_by_name("value")
+			.and_then(|v| v.as_bytes().map(|bytes| bytes.to_vec().into()))
+			.unwrap_or_default();
+
+		Ok(Any { type_url, value })
+	}
+}
+
+impl DynamicType for FieldMask {
+	const RULES_NAME: &str = "field_mask";
+
+	fn validator(field_rules: &FieldRules) -> Result<Self::Validator, CaseError> {
+		let mut validator = FieldMaskValidator::default();
+
+		validator.cel = cel_programs(&field_rules.cel)?;
+		validator.required = field_rules.required();
+		validator.ignore = field_rules.ignore();
+
+		match &field_rules.r#type {
+			Some(RulesType::FieldMask(rules)) => {
+				validator.const_ = rules
+					.r#const
+					.as_ref()
+					.and_then(|mask| sorted_list(mask.paths.iter().cloned().map(FixedStr::from)));
+				validator.in_ = sorted_list(rules.r#in.iter().cloned().map(FixedStr::from));
+				validator.not_in = sorted_list(rules.not_in.iter().cloned().map(FixedStr::from));
+			}
+			Some(other) => return Err(mismatched_rules(Self::RULES_NAME, other)),
+			None => {}
+		};
+
+		Ok(validator)
+	}
+
+	fn extract(value: &Value) -> Result<Self::Stored, CaseError> {
+		let msg = message_value(value, "google.protobuf.FieldMask")?;
+
+		let paths = msg
+			.get_field_by_name("paths")
+			.and_then(|v| {
+				v.as_list().map(|list| {
+					list.iter()
+						.filter_map(|path| path.as_str().map(ToString::to_string))
+						.collect()
+				})
+			})
+			.unwrap_or_default();
+
+		Ok(FieldMask { paths })
+	}
+}
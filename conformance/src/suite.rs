@@ -0,0 +1,344 @@
+//! A hand-picked set of cases that can be run without the upstream runner.
+//!
+//! The messages are adapted from a subset of the upstream cases, but the inputs and the expected
+//! results are written by hand, so passing them does not replace a run of the full conformance suite.
+
+use std::{collections::HashMap, process::ExitCode};
+
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage};
+use prost_types::{Any, FileDescriptorSet};
+use proto_types::protovalidate::{Violation, field_path_element::Subscript};
+
+use crate::{
+	harness::{TestConformanceRequest, TestResult, test_result::Result as CaseResult},
+	run_request,
+};
+
+const CASES_DESCRIPTOR_SET: &[u8] = include_bytes!(env!("CONFORMANCE_CASES_DESCRIPTOR_SET"));
+
+const PACKAGE: &str = "buf.validate.conformance.cases";
+
+enum Expected {
+	Valid,
+	/// The field path and rule id of each violation.
+	Violations(&'static [(&'static str, &'static str)]),
+	CompilationError,
+	RuntimeError,
+}
+
+struct Case {
+	name: &'static str,
+	message: &'static str,
+	/// The value of the message, in the protobuf text format.
+	input: &'static str,
+	expected: Expected,
+}
+
+macro_rules! cases {
+	($($name:literal: $message:ident { $input:literal } => $expected:expr),* $(,)?) => {
+		&[
+			$(
+				Case {
+					name: $name,
+					message: stringify!($message),
+					input: $input,
+					expected: $expected,
+				}
+			),*
+		]
+	};
+}
+
+use Expected::*;
+
+static CASES: &[Case] = cases! {
+	// Strings
+	"string/none/valid": StringNone { "val: 'quux'" } => Valid,
+	"string/const/valid": StringConst { "val: 'foo'" } => Valid,
+	"string/const/invalid": StringConst { "val: 'bar'" } => Violations(&[("val", "string.const")]),
+	"string/in/valid": StringIn { "val: 'bar'" } => Valid,
+	"string/in/invalid": StringIn { "val: 'quux'" } => Violations(&[("val", "string.in")]),
+	"string/not_in/invalid": StringNotIn { "val: 'fizz'" } => Violations(&[("val", "string.not_in")]),
+	"string/len/valid": StringLen { "val: 'baz'" } => Valid,
+	"string/len/valid/multibyte": StringLen { "val: '你好吖'" } => Valid,
+	"string/len/invalid": StringLen { "val: 'fizz'" } => Violations(&[("val", "string.len")]),
+	"string/min_len/invalid": StringMinLen { "val: 'ab'" } => Violations(&[("val", "string.min_len")]),
+	"string/max_len/invalid": StringMaxLen { "val: 'abcdef'" } => Violations(&[("val", "string.max_len")]),
+	"string/len_bytes/invalid": StringLenBytes { "val: 'abc'" } => Violations(&[("val", "string.len_bytes")]),
+	"string/pattern/valid": StringPattern { "val: 'Foo123'" } => Valid,
+	"string/pattern/invalid": StringPattern { "val: 'foo-bar'" } => Violations(&[("val", "string.pattern")]),
+	"string/prefix/invalid": StringPrefix { "val: 'bar'" } => Violations(&[("val", "string.prefix")]),
+	"string/contains/invalid": StringContains { "val: 'foo'" } => Violations(&[("val", "string.contains")]),
+	"string/email/valid": StringEmail { "val: 'foo@bar.com'" } => Valid,
+	"string/email/invalid": StringEmail { "val: 'foobar'" } => Violations(&[("val", "string.email")]),
+	"string/email/invalid/empty": StringEmail { "" } => Violations(&[("val", "string.email_empty")]),
+	"string/hostname/invalid": StringHostname { "val: '-bad.com'" } => Violations(&[("val", "string.hostname")]),
+	"string/ip/valid": StringIP { "val: '192.168.0.1'" } => Valid,
+	"string/ip/invalid": StringIP { "val: 'foobar'" } => Violations(&[("val", "string.ip")]),
+	"string/uuid/invalid": StringUUID { "val: 'foobar'" } => Violations(&[("val", "string.uuid")]),
+	"string/uri/invalid": StringURI { "val: '!@#$%^&*'" } => Violations(&[("val", "string.uri")]),
+	"string/well_known_regex/header_name/invalid": StringHttpHeaderName { "val: 'foo bar'" } => Violations(&[("val", "string.well_known_regex.header_name")]),
+	"string/ignore_empty/valid": StringIgnoreEmpty { "" } => Valid,
+	"string/ignore_empty/invalid": StringIgnoreEmpty { "val: 'ab'" } => Violations(&[("val", "string.min_len")]),
+	"string/required/valid": StringRequired { "val: ''" } => Valid,
+	"string/required/invalid": StringRequired { "" } => Violations(&[("val", "required")]),
+
+	// Bytes
+	"bytes/const/invalid": BytesConst { "val: 'bar'" } => Violations(&[("val", "bytes.const")]),
+	"bytes/len/invalid": BytesLen { "val: 'ab'" } => Violations(&[("val", "bytes.len")]),
+	"bytes/min_len/valid": BytesMinLen { "val: 'abc'" } => Valid,
+	"bytes/prefix/valid": BytesPrefix { "val: '\\x99foo'" } => Valid,
+	"bytes/prefix/invalid": BytesPrefix { "val: 'foo'" } => Violations(&[("val", "bytes.prefix")]),
+	"bytes/pattern/valid": BytesPattern { "val: 'abc'" } => Valid,
+	"bytes/pattern/invalid": BytesPattern { "" } => Violations(&[("val", "bytes.pattern")]),
+	"bytes/ipv4/valid": BytesIPv4 { "val: '\\xC0\\xA8\\x00\\x01'" } => Valid,
+	"bytes/ipv4/invalid": BytesIPv4 { "val: 'foobar'" } => Violations(&[("val", "bytes.ipv4")]),
+
+	// Numbers
+	"int32/const/valid": Int32Const { "val: 1" } => Valid,
+	"int32/const/invalid": Int32Const { "val: 2" } => Violations(&[("val", "int32.const")]),
+	"int32/in/invalid": Int32In { "val: 5" } => Violations(&[("val", "int32.in")]),
+	"int32/lt/valid": Int32LT { "val: -1" } => Valid,
+	"int32/lt/invalid": Int32LT { "val: 0" } => Violations(&[("val", "int32.lt")]),
+	"int32/gt_lt/valid": Int32GTLT { "val: 5" } => Valid,
+	"int32/gt_lt/invalid": Int32GTLT { "val: 10" } => Violations(&[("val", "int32.gt_lt")]),
+	"int32/incorrect_rule_type": Int32IncorrectRuleType { "val: 1" } => CompilationError,
+	"int64/gte/invalid": Int64GTE { "val: 7" } => Violations(&[("val", "int64.gte")]),
+	"uint32/lte/invalid": UInt32LTE { "val: 65" } => Violations(&[("val", "uint32.lte")]),
+	"uint64/not_in/invalid": UInt64NotIn { "" } => Violations(&[("val", "uint64.not_in")]),
+	"sint32/gt/invalid": SInt32GT { "val: 16" } => Violations(&[("val", "sint32.gt")]),
+	"sint64/const/invalid": SInt64Const { "val: 2" } => Violations(&[("val", "sint64.const")]),
+	"fixed32/lt/invalid": Fixed32LT { "val: 5" } => Violations(&[("val", "fixed32.lt")]),
+	"fixed64/gte/valid": Fixed64GTE { "val: 8" } => Valid,
+	"sfixed32/in/invalid": SFixed32In { "val: 4" } => Violations(&[("val", "sfixed32.in")]),
+	"sfixed64/lte/invalid": SFixed64LTE { "val: 65" } => Violations(&[("val", "sfixed64.lte")]),
+	"float/gt/invalid": FloatGT { "val: 16" } => Violations(&[("val", "float.gt")]),
+	"float/finite/invalid": FloatFinite { "val: inf" } => Violations(&[("val", "float.finite")]),
+	"double/in/valid": DoubleIn { "val: 4.56" } => Valid,
+	"double/in/invalid": DoubleIn { "val: 1.23" } => Violations(&[("val", "double.in")]),
+	"double/gte_lte/invalid": DoubleGTELTE { "val: 300" } => Violations(&[("val", "double.gte_lte")]),
+	"bool/const/valid": BoolConstTrue { "val: true" } => Valid,
+	"bool/const/invalid": BoolConstTrue { "val: false" } => Violations(&[("val", "bool.const")]),
+
+	// Repeated
+	"repeated/min/valid": RepeatedMin { "val: [{val: 1}, {val: 2}]" } => Valid,
+	"repeated/min/invalid": RepeatedMin { "val: [{val: 1}]" } => Violations(&[("val", "repeated.min_items")]),
+	"repeated/embed_skip/invalid": RepeatedEmbedSkip { "val: [{val: 1}, {val: -1}]" } => Violations(&[("val[1].val", "int64.gt")]),
+	"repeated/min_max/valid": RepeatedMinMax { "val: [1, 2, 3]" } => Valid,
+	"repeated/min_max/invalid": RepeatedMinMax { "val: [1]" } => Violations(&[("val", "repeated.min_items")]),
+	"repeated/unique/invalid": RepeatedUnique { "val: ['foo', 'foo']" } => Violations(&[("val", "repeated.unique")]),
+	"repeated/items/invalid": RepeatedItemRule { "val: [1, -1]" } => Violations(&[("val[1]", "float.gt")]),
+	"repeated/items/pattern/invalid": RepeatedItemPattern { "val: ['foo', 'bar-baz']" } => Violations(&[("val[1]", "string.pattern")]),
+
+	// Maps
+	"map/min/invalid": MapMin { "val: [{key: 1, value: 1.0}]" } => Violations(&[("val", "map.min_pairs")]),
+	"map/min_max/valid": MapMinMax { "val: [{key: 'a', value: true}, {key: 'b', value: false}]" } => Valid,
+	"map/keys/invalid": MapKeys { "val: [{key: 1, value: 'a'}]" } => Violations(&[("val[1]", "sint64.lt")]),
+	"map/values/invalid": MapValues { "val: [{key: 'a', value: 'ab'}]" } => Violations(&[("val[\"a\"]", "string.min_len")]),
+	"map/recursive/invalid": MapRecursive { "val: [{key: 1, value: {val: 'ab'}}]" } => Violations(&[("val[1].val", "string.min_len")]),
+
+	// Messages
+	"message/none/valid": MessageNone { "val: {}" } => Valid,
+	"message/nested/invalid": Message { "val: {const: 'bar'}" } => Violations(&[("val.const", "string.const")]),
+	"message/nested/deep/invalid": Message { "val: {const: 'foo', nested: {const: 'bar'}}" } => Violations(&[("val.nested.const", "string.const")]),
+	"message/required/valid": MessageRequired { "val: {const: 'foo'}" } => Valid,
+	"message/required/invalid": MessageRequired { "" } => Violations(&[("val", "required")]),
+	"message/cross_field/valid": MessageCrossFieldRule { "min: 1, max: 5" } => Valid,
+	"message/cross_field/invalid": MessageCrossFieldRule { "min: 5, max: 1" } => Violations(&[("", "message.cross_field")]),
+	"message/invalid_expression": MessageInvalidExpression { "min: 1" } => CompilationError,
+	"message/oneof_rule/invalid": MessageOneofRule { "" } => Violations(&[("", "message.oneof")]),
+
+	// Oneofs
+	"oneof/none/valid": OneofNone { "x: 'foo'" } => Valid,
+	"oneof/field/invalid": Oneof { "x: 'bar'" } => Violations(&[("x", "string.prefix")]),
+	"oneof/field/invalid/zero": Oneof { "y: 0" } => Violations(&[("y", "int32.gt")]),
+	"oneof/nested/invalid": Oneof { "z: {val: false}" } => Violations(&[("z.val", "bool.const")]),
+	"oneof/required/valid": OneofRequired { "y: 0" } => Valid,
+	"oneof/required/invalid": OneofRequired { "" } => Violations(&[("o", "required")]),
+
+	// Durations
+	"duration/required/invalid": DurationRequired { "" } => Violations(&[("val", "required")]),
+	"duration/const/invalid": DurationConst { "val: {seconds: 2}" } => Violations(&[("val", "duration.const")]),
+	"duration/lt/valid": DurationLT { "val: {nanos: -1}" } => Valid,
+	"duration/lt/invalid": DurationLT { "val: {seconds: 1}" } => Violations(&[("val", "duration.lt")]),
+	"duration/gte_lte/invalid": DurationGTELTE { "val: {seconds: 3601}" } => Violations(&[("val", "duration.gte_lte")]),
+
+	// Timestamps
+	"timestamp/required/valid": TimestampRequired { "val: {seconds: 1}" } => Valid,
+	"timestamp/gt/invalid": TimestampGT { "val: {seconds: 15}" } => Violations(&[("val", "timestamp.gt")]),
+	"timestamp/lt_now/valid": TimestampLTNow { "val: {seconds: 1}" } => Valid,
+	"timestamp/lt_now/invalid": TimestampLTNow { "val: {seconds: 9999999999}" } => Violations(&[("val", "timestamp.lt_now")]),
+
+	// Custom rules
+	"field_expression/scalar/valid": FieldExpressionScalar { "val: 43" } => Valid,
+	"field_expression/scalar/invalid": FieldExpressionScalar { "val: 1" } => Violations(&[("val", "field_expression.scalar")]),
+	"field_expression/repeated/invalid": FieldExpressionRepeatedScalar { "val: [1, 43]" } => Violations(&[("val", "field_expression.repeated.scalar")]),
+	"field_expression/nested/invalid": FieldExpressionNested { "val: {a: 1}" } => Violations(&[("val", "field_expression.nested")]),
+	"field_expression/runtime_error": FieldExpressionRuntimeError { "" } => RuntimeError,
+};
+
+fn field_path(violation: &Violation) -> String {
+	let mut path = String::new();
+
+	for element in violation
+		.field
+		.iter()
+		.flat_map(|field| &field.elements)
+	{
+		if !path.is_empty() {
+			path.push('.');
+		}
+
+		path.push_str(element.field_name());
+
+		match &element.subscript {
+			Some(Subscript::Index(i)) => path.push_str(&format!("[{i}]")),
+			Some(Subscript::BoolKey(key)) => path.push_str(&format!("[{key}]")),
+			Some(Subscript::IntKey(key)) => path.push_str(&format!("[{key}]")),
+			Some(Subscript::UintKey(key)) => path.push_str(&format!("[{key}]")),
+			Some(Subscript::StringKey(key)) => path.push_str(&format!("[{key:?}]")),
+			None => {}
+		};
+	}
+
+	path
+}
+
+fn describe(result: &TestResult) -> String {
+	match &result.result {
+		Some(CaseResult::Success(_)) => "valid".to_string(),
+		Some(CaseResult::ValidationError(violations)) => {
+			let list: Vec<String> = violations
+				.violations
+				.iter()
+				.map(|v| format!("{} ({})", field_path(v), v.rule_id()))
+				.collect();
+
+			format!("violations [{}]", list.join(", "))
+		}
+		Some(CaseResult::CompilationError(e)) => format!("compilation error: {e}"),
+		Some(CaseResult::RuntimeError(e)) => format!("runtime error: {e}"),
+		Some(CaseResult::UnexpectedError(e)) => format!("unexpected error: {e}"),
+		None => "no result".to_string(),
+	}
+}
+
+fn matches_expected(expected: &Expected, result: &TestResult) -> bool {
+	match (expected, &result.result) {
+		(Valid, Some(CaseResult::Success(_)))
+		| (CompilationError, Some(CaseResult::CompilationError(_)))
+		| (RuntimeError, Some(CaseResult::RuntimeError(_))) => true,
+		(Violations(expected), Some(CaseResult::ValidationError(violations))) => {
+			let mut expected: Vec<(String, String)> = expected
+				.iter()
+				.map(|(path, rule_id)| ((*path).to_string(), (*rule_id).to_string()))
+				.collect();
+			let mut actual: Vec<(String, String)> = violations
+				.violations
+				.iter()
+				.map(|v| (field_path(v), v.rule_id().to_string()))
+				.collect();
+
+			expected.sort();
+			actual.sort();
+
+			expected == actual
+		}
+		_ => false,
+	}
+}
+
+fn build_request(
+	pool: &DescriptorPool,
+	fdset: FileDescriptorSet,
+	cases: &[&Case],
+) -> Result<TestConformanceRequest, String> {
+	let mut request_cases: HashMap<String, Any> = HashMap::new();
+
+	for case in cases {
+		let full_name = format!("{PACKAGE}.{}", case.message);
+
+		let descriptor = pool
+			.get_message_by_name(&full_name)
+			.ok_or_else(|| format!("unknown message `{full_name}` in case `{}`", case.name))?;
+
+		let msg = DynamicMessage::parse_text_format(descriptor, case.input)
+			.map_err(|e| format!("invalid input for case `{}`: {e}", case.name))?;
+
+		request_cases.insert(
+			case.name.to_string(),
+			Any {
+				type_url: format!("type.googleapis.com/{full_name}"),
+				value: msg.encode_to_vec(),
+			},
+		);
+	}
+
+	Ok(TestConformanceRequest {
+		fdset: Some(fdset),
+		cases: request_cases,
+	})
+}
+
+/// Runs the hand-picked cases whose name contains the filter (or all of them), and prints the results.
+pub fn run_offline(filter: Option<&str>) -> ExitCode {
+	let fdset = match FileDescriptorSet::decode(CASES_DESCRIPTOR_SET) {
+		Ok(fdset) => fdset,
+		Err(e) => {
+			eprintln!("failed to decode the descriptors of the cases: {e}");
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let pool = match DescriptorPool::from_file_descriptor_set(fdset.clone()) {
+		Ok(pool) => pool,
+		Err(e) => {
+			eprintln!("invalid descriptors: {e}");
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let cases: Vec<&Case> = CASES
+		.iter()
+		.filter(|case| filter.is_none_or(|filter| case.name.contains(filter)))
+		.collect();
+
+	let response = match build_request(&pool, fdset, &cases).and_then(run_request) {
+		Ok(response) => response,
+		Err(e) => {
+			eprintln!("{e}");
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let mut failed: Vec<String> = Vec::new();
+
+	for case in &cases {
+		let result = response
+			.results
+			.get(case.name)
+			.cloned()
+			.unwrap_or_default();
+
+		if matches_expected(&case.expected, &result) {
+			println!("PASS {}", case.name);
+		} else {
+			println!("FAIL {}: got {}", case.name, describe(&result));
+			failed.push(case.name.to_string());
+		}
+	}
+
+	println!(
+		"\n{} passed, {} failed, {} total",
+		cases.len() - failed.len(),
+		failed.len(),
+		cases.len()
+	);
+
+	if failed.is_empty() {
+		ExitCode::SUCCESS
+	} else {
+		ExitCode::FAILURE
+	}
+}
@@ -5,12 +5,15 @@
 
 use std::fmt::Write;
 use std::fs;
-use std::io::{self, Read};
+use std::io;
 use std::path::Path;
-use std::{env, path::PathBuf};
+use std::path::PathBuf;
 
 use prost_build::Config;
-use prost_reflect::{prost::Message as ProstMessage, prost_types::FileDescriptorSet};
+use prost_reflect::DescriptorPool;
+
+mod models;
+pub use models::*;
 
 /// Configures a [`DescriptorData`] instance to gather information from a protobuf descriptor,
 /// which can be useful to apply attributes programmatically to the target items.
@@ -203,28 +206,12 @@ fn set_up_validators_inner(
 	include_paths: &[impl AsRef<Path>],
 	packages: &[&str],
 ) -> Result<DescriptorData, Box<dyn std::error::Error>> {
-	let out_dir = env::var("OUT_DIR")
-		.map(PathBuf::from)
-		.unwrap_or(env::temp_dir());
-
 	config
 		.extern_path(".google.protobuf", "::protify::proto_types")
 		.extern_path(".buf.validate", "::protify::proto_types::protovalidate")
 		.compile_well_known_types();
 
-	let temp_descriptor_path = out_dir.join("__temp_file_descriptor_set.bin");
-	{
-		let mut temp_config = prost_build::Config::new();
-		temp_config.file_descriptor_set_path(&temp_descriptor_path);
-		temp_config.out_dir(&out_dir);
-		temp_config.compile_protos(files, include_paths)?;
-	}
-
-	let mut fds_file = std::fs::File::open(&temp_descriptor_path)?;
-	let mut fds_bytes = Vec::new();
-	fds_file.read_to_end(&mut fds_bytes)?;
-	let fds = FileDescriptorSet::decode(fds_bytes.as_slice())?;
-	let pool = prost_reflect::DescriptorPool::from_file_descriptor_set(fds)?;
+	let pool = build_descriptor_pool(files, include_paths)?;
 
 	let mut desc_data = DescriptorData::default();
 
@@ -305,6 +292,17 @@ fn set_up_validators_inner(
 	Ok(desc_data)
 }
 
+// Only asks `protoc` for the descriptor set, which is written to a unique temporary directory,
+// so that nothing is written to the output directory (which is not set outside of build scripts)
+fn build_descriptor_pool(
+	files: &[impl AsRef<Path>],
+	include_paths: &[impl AsRef<Path>],
+) -> Result<DescriptorPool, Box<dyn std::error::Error>> {
+	let fds = Config::new().load_fds(files, include_paths)?;
+
+	Ok(DescriptorPool::from_file_descriptor_set(fds)?)
+}
+
 /// A small utility that recursively collects all .proto files in a given directory and its subdirectories.
 ///
 /// Useful if you want to avoid passing each individual .proto file to the prost config.
@@ -0,0 +1,1009 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Write;
+use std::path::Path;
+
+use prost_reflect::{
+	Cardinality, DescriptorPool, DynamicMessage, EnumDescriptor, ExtensionDescriptor,
+	FieldDescriptor, FileDescriptor, Kind, MessageDescriptor, OneofDescriptor, ReflectMessage,
+	ServiceDescriptor, Value,
+};
+
+use crate::build_descriptor_pool;
+
+const PROTOBUF_MAX_TAG: u32 = 536_870_911;
+
+/// Generates the Rust source for the models of a protobuf package, starting from pre-existing `.proto` files.
+///
+/// This is meant to be used as a one-time migration tool for repositories that already have their contracts defined in `.proto` files. The output will contain:
+/// - The package handle, created with [`proto_package`](https://docs.rs/protify/latest/protify/macro.proto_package.html)
+/// - A module for each file in the package, with its own [`define_proto_file`](https://docs.rs/protify/latest/protify/macro.define_proto_file.html) call
+/// - The messages, oneofs, enums and services of each file, defined with their respective macros. Field tags, enum values, reserved numbers/names and doc comments are preserved.
+///
+/// The `buf.validate` annotations are translated into the equivalent `validate = |v| ...` closures, whereas other custom options are not carried over and must be added manually with the `options` attribute.
+///
+/// Items that belong to other packages are referenced by their name, so they must be brought into scope manually (except for the `google.protobuf` types, which are taken from [`proto_types`](https://docs.rs/proto-types/latest/proto_types/)).
+///
+/// The output is not formatted, so it should be passed through `rustfmt` afterwards.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// let code = protify_build::generate_models(&["proto/my_pkg/service.proto"], &["proto"], "my_pkg").unwrap();
+///
+/// std::fs::write("src/my_pkg.rs", code).unwrap();
+/// ```
+pub fn generate_models(
+	files: &[impl AsRef<Path>],
+	include_paths: &[impl AsRef<Path>],
+	package: &str,
+) -> Result<String, Box<dyn Error>> {
+	let pool = build_descriptor_pool(files, include_paths)?;
+
+	generate_models_from_pool(&pool, package)
+}
+
+/// Generates the Rust source for the models of a protobuf package from a pre-built [`DescriptorPool`].
+///
+/// Refer to [`generate_models`] for more information.
+pub fn generate_models_from_pool(
+	pool: &DescriptorPool,
+	package: &str,
+) -> Result<String, Box<dyn Error>> {
+	let mut files: Vec<FileDescriptor> = pool
+		.files()
+		.filter(|f| f.package_name() == package)
+		.collect();
+
+	if files.is_empty() {
+		return Err(format!("Could not find any file for the package `{package}`").into());
+	}
+
+	files.sort_by(|a, b| a.name().cmp(b.name()));
+
+	let generator = ModelsGenerator {
+		package,
+		package_ident: package.replace('.', "_").to_uppercase(),
+		field_rules_ext: pool.get_extension_by_name("buf.validate.field"),
+		message_rules_ext: pool.get_extension_by_name("buf.validate.message"),
+		oneof_rules_ext: pool.get_extension_by_name("buf.validate.oneof"),
+	};
+
+	let mut output = String::new();
+
+	let _ = writeln!(output, "use protify::*;\nuse std::collections::HashMap;\n");
+	let _ = writeln!(
+		output,
+		"proto_package!({}, name = \"{package}\");\n",
+		generator.package_ident
+	);
+
+	let package_dir = format!("{}/", package.replace('.', "/"));
+
+	for file in &files {
+		let module_name = file_module_name(file.name(), &package_dir);
+
+		let _ = writeln!(output, "pub use {module_name}::*;");
+		let _ = writeln!(output, "pub mod {module_name} {{\n\tuse super::*;\n");
+		let _ = writeln!(
+			output,
+			"define_proto_file!({}_FILE, name = \"{}\", package = {});\n",
+			module_name.to_uppercase(),
+			file.name(),
+			generator.package_ident
+		);
+
+		generator.write_file(&mut output, file);
+
+		output.push_str("}\n\n");
+	}
+
+	Ok(output)
+}
+
+struct ModelsGenerator<'a> {
+	package: &'a str,
+	package_ident: String,
+	field_rules_ext: Option<ExtensionDescriptor>,
+	message_rules_ext: Option<ExtensionDescriptor>,
+	oneof_rules_ext: Option<ExtensionDescriptor>,
+}
+
+impl ModelsGenerator<'_> {
+	fn write_file(&self, output: &mut String, file: &FileDescriptor) {
+		for message in file.messages() {
+			self.write_message(output, &message);
+		}
+
+		for enum_ in file.enums() {
+			self.write_enum(output, &enum_);
+		}
+
+		for service in file.services() {
+			self.write_service(output, &service);
+		}
+	}
+
+	// Returns the rust ident of an item defined in this package, or the path to it otherwise
+	fn type_path(&self, full_name: &str) -> String {
+		if let Some(name) = full_name.strip_prefix("google.protobuf.") {
+			return format!("proto_types::{name}");
+		}
+
+		let name = full_name
+			.strip_prefix(&format!("{}.", self.package))
+			.unwrap_or_else(|| {
+				full_name
+					.rsplit_once('.')
+					.map_or(full_name, |(_, name)| name)
+			});
+
+		name.split('.').map(to_pascal_case).collect()
+	}
+
+	fn write_message(&self, output: &mut String, message: &MessageDescriptor) {
+		if message.is_map_entry() {
+			return;
+		}
+
+		let rust_ident = self.type_path(message.full_name());
+
+		write_comments(
+			output,
+			message.parent_file_descriptor_proto(),
+			message.path(),
+			1,
+		);
+		output.push_str("\t#[proto_message]\n");
+
+		let mut attrs: Vec<String> = Vec::new();
+
+		if rust_ident != message.name() {
+			attrs.push(format!("name = \"{}\"", message.name()));
+		}
+
+		if let Some(parent) = message.parent_message() {
+			attrs.push(format!(
+				"parent_message = {}",
+				self.type_path(parent.full_name())
+			));
+		}
+
+		let reserved_numbers: Vec<String> = message
+			.reserved_ranges()
+			.map(|range| {
+				if range.end - range.start == 1 {
+					range.start.to_string()
+				} else if range.end > PROTOBUF_MAX_TAG {
+					format!("{}..MAX", range.start)
+				} else {
+					format!("{}..{}", range.start, range.end)
+				}
+			})
+			.collect();
+
+		if !reserved_numbers.is_empty() {
+			attrs.push(format!("reserved_numbers({})", reserved_numbers.join(", ")));
+		}
+
+		if let Some(names) = reserved_names(message.reserved_names()) {
+			attrs.push(names);
+		}
+
+		if is_deprecated(&message.options()) {
+			attrs.push("deprecated = true".to_string());
+		}
+
+		if let Some(rules) = get_rules(&message.options(), self.message_rules_ext.as_ref()) {
+			let cel_rules = cel_rules_methods(&rules);
+
+			if !cel_rules.is_empty() {
+				attrs.push(format!("validate = |v| v{cel_rules}"));
+			}
+		}
+
+		write_attrs(output, &attrs, 1);
+
+		let _ = writeln!(output, "\tpub struct {rust_ident} {{");
+
+		let mut written_oneofs: HashSet<String> = HashSet::new();
+
+		for field in message.fields() {
+			if let Some(oneof) = field
+				.containing_oneof()
+				.filter(|oneof| !oneof.is_synthetic())
+			{
+				if written_oneofs.insert(oneof.name().to_string()) {
+					self.write_oneof_field(output, message, &oneof);
+				}
+			} else {
+				self.write_field(output, message, &field);
+			}
+		}
+
+		output.push_str("\t}\n\n");
+
+		for oneof in message
+			.oneofs()
+			.filter(|oneof| !oneof.is_synthetic())
+		{
+			self.write_oneof(output, message, &oneof);
+		}
+
+		for nested_message in message.child_messages() {
+			self.write_message(output, &nested_message);
+		}
+
+		for nested_enum in message.child_enums() {
+			self.write_enum(output, &nested_enum);
+		}
+	}
+
+	fn write_field(
+		&self,
+		output: &mut String,
+		message: &MessageDescriptor,
+		field: &FieldDescriptor,
+	) {
+		write_comments(
+			output,
+			message.parent_file_descriptor_proto(),
+			field.path(),
+			2,
+		);
+
+		let mut attrs = vec![format!("tag = {}", field.number())];
+
+		let (rust_type, type_attr) = if field.is_map() {
+			let Kind::Message(entry) = field.kind() else {
+				unreachable!("Map fields should always have a message kind")
+			};
+
+			let keys = self.field_type(None, &entry.map_entry_key_field().kind());
+			let values = self.field_type(None, &entry.map_entry_value_field().kind());
+
+			let type_attr = (keys.attr.is_some() || values.attr.is_some())
+				.then(|| format!("map({}, {})", keys.proto_name(), values.proto_name()));
+
+			(
+				format!("HashMap<{}, {}>", keys.rust_type, values.rust_type),
+				type_attr,
+			)
+		} else {
+			// Only singular messages need to be boxed
+			let field_type = self.field_type((!field.is_list()).then_some(message), &field.kind());
+			let is_message = matches!(field.kind(), Kind::Message(_));
+
+			if field.is_list() {
+				let type_attr = field_type
+					.attr
+					.as_ref()
+					.map(|_| format!("repeated({})", field_type.proto_name()));
+
+				(format!("Vec<{}>", field_type.rust_type), type_attr)
+			} else if is_message {
+				(format!("Option<{}>", field_type.rust_type), field_type.attr)
+			} else if field.field_descriptor_proto().proto3_optional() {
+				let type_attr = field_type
+					.attr
+					.as_ref()
+					.map(|_| format!("optional({})", field_type.proto_name()));
+
+				(format!("Option<{}>", field_type.rust_type), type_attr)
+			} else {
+				(field_type.rust_type, field_type.attr)
+			}
+		};
+
+		attrs.extend(type_attr);
+
+		let rust_ident = field_ident(field.name());
+
+		if rust_ident
+			.trim_start_matches("r#")
+			.trim_end_matches('_')
+			!= field.name()
+		{
+			attrs.push(format!("name = \"{}\"", field.name()));
+		}
+
+		if is_deprecated(&field.options()) {
+			attrs.push("deprecated = true".to_string());
+		}
+
+		if let Some(validator) = self.field_validator(field) {
+			attrs.push(format!("validate = |v| v{validator}"));
+		}
+
+		write_attrs(output, &attrs, 2);
+
+		let _ = writeln!(output, "\t\tpub {rust_ident}: {rust_type},");
+	}
+
+	fn oneof_ident(&self, message: &MessageDescriptor, oneof: &OneofDescriptor) -> String {
+		format!(
+			"{}{}",
+			self.type_path(message.full_name()),
+			to_pascal_case(oneof.name())
+		)
+	}
+
+	fn write_oneof_field(
+		&self,
+		output: &mut String,
+		message: &MessageDescriptor,
+		oneof: &OneofDescriptor,
+	) {
+		write_comments(
+			output,
+			message.parent_file_descriptor_proto(),
+			oneof.path(),
+			2,
+		);
+
+		let tags: Vec<String> = oneof
+			.fields()
+			.map(|f| f.number().to_string())
+			.collect();
+
+		let mut attrs = vec![format!("oneof(tags({}))", tags.join(", "))];
+
+		if let Some(rules) = get_rules(&oneof.options(), self.oneof_rules_ext.as_ref())
+			&& get_bool(&rules, "required")
+		{
+			attrs.push("validate = |v| v.required()".to_string());
+		}
+
+		write_attrs(output, &attrs, 2);
+
+		let _ = writeln!(
+			output,
+			"\t\tpub {}: Option<{}>,",
+			field_ident(oneof.name()),
+			self.oneof_ident(message, oneof)
+		);
+	}
+
+	fn write_oneof(
+		&self,
+		output: &mut String,
+		message: &MessageDescriptor,
+		oneof: &OneofDescriptor,
+	) {
+		output.push_str("\t#[proto_oneof]\n");
+		let _ = writeln!(output, "\tpub enum {} {{", self.oneof_ident(message, oneof));
+
+		for field in oneof.fields() {
+			write_comments(
+				output,
+				message.parent_file_descriptor_proto(),
+				field.path(),
+				2,
+			);
+
+			let field_type = self.field_type(Some(message), &field.kind());
+
+			let mut attrs = vec![format!("tag = {}", field.number())];
+
+			attrs.extend(field_type.attr);
+
+			let variant_ident = to_pascal_case(field.name());
+
+			if to_snake_case(&variant_ident) != field.name() {
+				attrs.push(format!("name = \"{}\"", field.name()));
+			}
+
+			if is_deprecated(&field.options()) {
+				attrs.push("deprecated = true".to_string());
+			}
+
+			if let Some(validator) = self.field_validator(&field) {
+				attrs.push(format!("validate = |v| v{validator}"));
+			}
+
+			write_attrs(output, &attrs, 2);
+
+			let _ = writeln!(output, "\t\t{variant_ident}({}),", field_type.rust_type);
+		}
+
+		output.push_str("\t}\n\n");
+	}
+
+	fn write_enum(&self, output: &mut String, enum_: &EnumDescriptor) {
+		let rust_ident = self.type_path(enum_.full_name());
+
+		write_comments(
+			output,
+			enum_.parent_file_descriptor_proto(),
+			enum_.path(),
+			1,
+		);
+		output.push_str("\t#[proto_enum]\n");
+
+		let mut attrs: Vec<String> = Vec::new();
+
+		if rust_ident != enum_.name() {
+			attrs.push(format!("name = \"{}\"", enum_.name()));
+		}
+
+		if let Some(parent) = enum_.parent_message() {
+			attrs.push(format!(
+				"parent_message = {}",
+				self.type_path(parent.full_name())
+			));
+		}
+
+		let reserved_numbers: Vec<String> = enum_
+			.reserved_ranges()
+			.map(|range| {
+				if range.start() == range.end() {
+					range.start().to_string()
+				} else {
+					format!("{}..={}", range.start(), range.end())
+				}
+			})
+			.collect();
+
+		if !reserved_numbers.is_empty() {
+			attrs.push(format!("reserved_numbers({})", reserved_numbers.join(", ")));
+		}
+
+		if let Some(names) = reserved_names(enum_.reserved_names()) {
+			attrs.push(names);
+		}
+
+		if is_deprecated(&enum_.options()) {
+			attrs.push("deprecated = true".to_string());
+		}
+
+		write_attrs(output, &attrs, 1);
+
+		let _ = writeln!(output, "\tpub enum {rust_ident} {{");
+
+		let prefix = format!("{}_", to_upper_snake_case(enum_.name()));
+
+		for value in enum_.values() {
+			write_comments(
+				output,
+				enum_.parent_file_descriptor_proto(),
+				value.path(),
+				2,
+			);
+
+			let plain_name = value
+				.name()
+				.strip_prefix(&prefix)
+				.unwrap_or(value.name());
+
+			let variant_ident = to_pascal_case(plain_name);
+
+			let mut attrs: Vec<String> = Vec::new();
+
+			if format!("{prefix}{}", to_upper_snake_case(&variant_ident)) != value.name() {
+				attrs.push(format!("name = \"{}\"", value.name()));
+			}
+
+			if is_deprecated(&value.options()) {
+				attrs.push("deprecated = true".to_string());
+			}
+
+			write_attrs(output, &attrs, 2);
+
+			let _ = writeln!(output, "\t\t{variant_ident} = {},", value.number());
+		}
+
+		output.push_str("\t}\n\n");
+	}
+
+	fn write_service(&self, output: &mut String, service: &ServiceDescriptor) {
+		write_comments(
+			output,
+			service.parent_file_descriptor_proto(),
+			service.path(),
+			1,
+		);
+		output.push_str("\t#[proto_service]\n");
+
+		if is_deprecated(&service.options()) {
+			output.push_str("\t#[proto(deprecated = true)]\n");
+		}
+
+		let _ = writeln!(output, "\tpub enum {} {{", service.name());
+
+		for method in service.methods() {
+			write_comments(
+				output,
+				service.parent_file_descriptor_proto(),
+				method.path(),
+				2,
+			);
+
+			if is_deprecated(&method.options()) {
+				output.push_str("\t\t#[proto(deprecated = true)]\n");
+			}
+
+			let stream_attr = |is_stream: bool| if is_stream { "#[stream] " } else { "" };
+
+			let _ = writeln!(
+				output,
+				"\t\t{} {{\n\t\t\t{}request: {},\n\t\t\t{}response: {},\n\t\t}},",
+				method.name(),
+				stream_attr(method.is_client_streaming()),
+				self.type_path(method.input().full_name()),
+				stream_attr(method.is_server_streaming()),
+				self.type_path(method.output().full_name()),
+			);
+		}
+
+		output.push_str("\t}\n\n");
+	}
+
+	fn field_type(&self, container: Option<&MessageDescriptor>, kind: &Kind) -> FieldType {
+		macro_rules! scalar {
+			($rust_type:literal) => {
+				FieldType {
+					rust_type: $rust_type.to_string(),
+					attr: None,
+				}
+			};
+
+			($rust_type:literal, $attr:literal) => {
+				FieldType {
+					rust_type: $rust_type.to_string(),
+					attr: Some($attr.to_string()),
+				}
+			};
+		}
+
+		match kind {
+			Kind::Double => scalar!("f64"),
+			Kind::Float => scalar!("f32"),
+			Kind::Int32 => scalar!("i32"),
+			Kind::Int64 => scalar!("i64"),
+			Kind::Uint32 => scalar!("u32"),
+			Kind::Uint64 => scalar!("u64"),
+			Kind::Bool => scalar!("bool"),
+			Kind::String => scalar!("String"),
+			Kind::Bytes => scalar!("Bytes"),
+			Kind::Sint32 => scalar!("i32", "sint32"),
+			Kind::Sint64 => scalar!("i64", "sint64"),
+			Kind::Fixed32 => scalar!("u32", "fixed32"),
+			Kind::Fixed64 => scalar!("u64", "fixed64"),
+			Kind::Sfixed32 => scalar!("i32", "sfixed32"),
+			Kind::Sfixed64 => scalar!("i64", "sfixed64"),
+			Kind::Enum(enum_) => FieldType {
+				rust_type: "i32".to_string(),
+				attr: Some(format!("enum_({})", self.type_path(enum_.full_name()))),
+			},
+			Kind::Message(msg) => {
+				let well_known_attr = match msg.full_name() {
+					"google.protobuf.Timestamp" => "timestamp",
+					"google.protobuf.Duration" => "duration",
+					"google.protobuf.Any" => "any",
+					"google.protobuf.FieldMask" => "field_mask",
+					_ => "message",
+				};
+
+				let path = self.type_path(msg.full_name());
+
+				let rust_type = if container.is_some_and(|container| is_recursive(msg, container)) {
+					format!("Box<{path}>")
+				} else {
+					path
+				};
+
+				FieldType {
+					rust_type,
+					attr: Some(well_known_attr.to_string()),
+				}
+			}
+		}
+	}
+
+	fn field_validator(&self, field: &FieldDescriptor) -> Option<String> {
+		let rules = get_rules(&field.options(), self.field_rules_ext.as_ref())?;
+
+		// Repeated and map validators do not have the `required` rule
+		field_rules_methods(&rules, !field.is_list() && !field.is_map())
+	}
+}
+
+struct FieldType {
+	rust_type: String,
+	// The attribute to use when the type cannot be inferred
+	attr: Option<String>,
+}
+
+impl FieldType {
+	// The name of the type, as used inside `repeated(..)`, `optional(..)` or `map(..)`
+	fn proto_name(&self) -> String {
+		if let Some(attr) = &self.attr {
+			return attr.clone();
+		}
+
+		match self.rust_type.as_str() {
+			"f64" => "double",
+			"f32" => "float",
+			"i32" => "int32",
+			"i64" => "int64",
+			"u32" => "uint32",
+			"u64" => "uint64",
+			"bool" => "bool",
+			"Bytes" => "bytes",
+			_ => "string",
+		}
+		.to_string()
+	}
+}
+
+// Checks if the target message contains the container (directly or indirectly), in which case it needs to be boxed
+fn is_recursive(target: &MessageDescriptor, container: &MessageDescriptor) -> bool {
+	fn visit(
+		current: &MessageDescriptor,
+		container: &MessageDescriptor,
+		visited: &mut HashSet<String>,
+	) -> bool {
+		if current.full_name() == container.full_name() {
+			return true;
+		}
+
+		if !visited.insert(current.full_name().to_string()) {
+			return false;
+		}
+
+		current
+			.fields()
+			.filter(|f| f.cardinality() != Cardinality::Repeated)
+			.any(|f| {
+				if let Kind::Message(msg) = f.kind() {
+					visit(&msg, container, visited)
+				} else {
+					false
+				}
+			})
+	}
+
+	visit(target, container, &mut HashSet::new())
+}
+
+fn get_rules(
+	options: &DynamicMessage,
+	ext: Option<&ExtensionDescriptor>,
+) -> Option<DynamicMessage> {
+	let ext = ext?;
+
+	if !options.has_extension(ext) {
+		return None;
+	}
+
+	options.get_extension(ext).as_message().cloned()
+}
+
+fn get_bool(message: &DynamicMessage, name: &str) -> bool {
+	message
+		.get_field_by_name(name)
+		.and_then(|v| v.as_bool())
+		.unwrap_or_default()
+}
+
+fn is_deprecated(options: &DynamicMessage) -> bool {
+	get_bool(options, "deprecated")
+}
+
+fn cel_rules_methods(rules: &DynamicMessage) -> String {
+	let mut output = String::new();
+
+	if let Some(cel_rules) = rules.get_field_by_name("cel")
+		&& let Some(list) = cel_rules.as_list()
+	{
+		for rule in list.iter().filter_map(|r| r.as_message()) {
+			let get_str = |name: &str| {
+				rule.get_field_by_name(name)
+					.and_then(|v| v.as_str().map(str::to_string))
+					.unwrap_or_default()
+			};
+
+			let _ = write!(
+				output,
+				".cel(cel_program!(id = {:?}, msg = {:?}, expr = {:?}))",
+				get_str("id"),
+				get_str("message"),
+				get_str("expression")
+			);
+		}
+	}
+
+	output
+}
+
+// Translates `buf.validate.FieldRules` into the methods of the validator builder
+fn field_rules_methods(rules: &DynamicMessage, allow_required: bool) -> Option<String> {
+	let mut output = String::new();
+
+	for (field, value) in rules.fields() {
+		match field.name() {
+			"ignore" => {
+				let ignore = value.as_enum_number().and_then(|num| {
+					field
+						.kind()
+						.as_enum()
+						.and_then(|e| e.get_value(num))
+						.map(|v| v.name().to_string())
+				});
+
+				match ignore.as_deref() {
+					Some("IGNORE_ALWAYS") => return None,
+					Some("IGNORE_IF_ZERO_VALUE") => output.push_str(".ignore_if_zero_value()"),
+					_ => {}
+				};
+			}
+			"required" => {
+				if allow_required && value.as_bool() == Some(true) {
+					output.push_str(".required()");
+				}
+			}
+			"cel" => output.push_str(&cel_rules_methods(rules)),
+			_ => {
+				if let Some(type_rules) = value.as_message() {
+					output.push_str(&type_rules_methods(type_rules));
+				}
+			}
+		};
+	}
+
+	(!output.is_empty()).then_some(output)
+}
+
+fn type_rules_methods(rules: &DynamicMessage) -> String {
+	let mut output = String::new();
+
+	for (field, value) in rules.fields() {
+		let name = field.name();
+
+		match name {
+			// Not a validation rule
+			"example" | "strict" => {}
+			"well_known_regex" => {
+				let suffix = if get_bool(rules, "strict") {
+					"strict"
+				} else {
+					"loose"
+				};
+
+				let regex = value
+					.as_enum_number()
+					.and_then(|num| {
+						field
+							.kind()
+							.as_enum()
+							.and_then(|e| e.get_value(num))
+							.map(|v| v.name().to_string())
+					})
+					.unwrap_or_default();
+
+				match regex.as_str() {
+					"KNOWN_REGEX_HTTP_HEADER_NAME" => {
+						let _ = write!(output, ".header_name_{suffix}()");
+					}
+					"KNOWN_REGEX_HTTP_HEADER_VALUE" => {
+						let _ = write!(output, ".header_value_{suffix}()");
+					}
+					_ => {}
+				};
+			}
+			"items" | "keys" | "values" => {
+				if let Some(inner_rules) = value.as_message()
+					&& let Some(methods) = field_rules_methods(inner_rules, true)
+				{
+					let _ = write!(output, ".{name}(|v| v{methods})");
+				}
+			}
+			"const" | "in" | "not_in" => {
+				let method = match name {
+					"const" => "const_",
+					"in" => "in_",
+					_ => "not_in",
+				};
+
+				if let Some(literal) = value_literal(value) {
+					let _ = write!(output, ".{method}({literal})");
+				}
+			}
+			_ => match value {
+				Value::Bool(true) => {
+					let _ = write!(output, ".{name}()");
+				}
+				Value::Bool(false) => {}
+				_ => {
+					if let Some(literal) = value_literal(value) {
+						let _ = write!(output, ".{name}({literal})");
+					}
+				}
+			},
+		};
+	}
+
+	output
+}
+
+fn value_literal(value: &Value) -> Option<String> {
+	let output = match value {
+		Value::Bool(val) => val.to_string(),
+		Value::I32(val) | Value::EnumNumber(val) => val.to_string(),
+		Value::I64(val) => val.to_string(),
+		Value::U32(val) => val.to_string(),
+		Value::U64(val) => val.to_string(),
+		Value::F32(val) if val.is_finite() => format!("{val:?}"),
+		Value::F64(val) if val.is_finite() => format!("{val:?}"),
+		Value::F32(val) => non_finite_float_literal(f64::from(*val), "f32"),
+		Value::F64(val) => non_finite_float_literal(*val, "f64"),
+		Value::String(val) => format!("{val:?}"),
+		Value::Bytes(val) => format!("b\"{}\"", val.escape_ascii()),
+		Value::List(list) => {
+			let items: Option<Vec<String>> = list.iter().map(value_literal).collect();
+
+			format!("[{}]", items?.join(", "))
+		}
+		Value::Message(msg) => {
+			let type_name = match msg.descriptor().full_name() {
+				"google.protobuf.Duration" => "Duration",
+				"google.protobuf.Timestamp" => "Timestamp",
+				_ => return None,
+			};
+
+			let seconds = msg
+				.get_field_by_name("seconds")
+				.and_then(|v| v.as_i64())
+				.unwrap_or_default();
+			let nanos = msg
+				.get_field_by_name("nanos")
+				.and_then(|v| v.as_i32())
+				.unwrap_or_default();
+
+			format!("proto_types::{type_name} {{ seconds: {seconds}, nanos: {nanos} }}")
+		}
+		Value::Map(_) => return None,
+	};
+
+	Some(output)
+}
+
+fn non_finite_float_literal(val: f64, type_name: &str) -> String {
+	if val.is_nan() {
+		format!("{type_name}::NAN")
+	} else if val.is_sign_positive() {
+		format!("{type_name}::INFINITY")
+	} else {
+		format!("{type_name}::NEG_INFINITY")
+	}
+}
+
+fn reserved_names<'a>(names: impl Iterator<Item = &'a str>) -> Option<String> {
+	let names: Vec<String> = names.map(|n| format!("\"{n}\"")).collect();
+
+	(!names.is_empty()).then(|| format!("reserved_names({})", names.join(", ")))
+}
+
+fn write_attrs(output: &mut String, attrs: &[String], indent: usize) {
+	if !attrs.is_empty() {
+		let _ = writeln!(
+			output,
+			"{}#[proto({})]",
+			"\t".repeat(indent),
+			attrs.join(", ")
+		);
+	}
+}
+
+// Writes the leading comments of the item with the given path as doc comments
+fn write_comments(
+	output: &mut String,
+	file: &prost_reflect::prost_types::FileDescriptorProto,
+	path: &[i32],
+	indent: usize,
+) {
+	let Some(comments) = file
+		.source_code_info
+		.as_ref()
+		.and_then(|info| info.location.iter().find(|l| l.path == path))
+		.and_then(|location| location.leading_comments.as_deref())
+	else {
+		return;
+	};
+
+	for line in comments.trim_end().lines() {
+		let _ = writeln!(output, "{}///{line}", "\t".repeat(indent));
+	}
+}
+
+fn file_module_name(file_name: &str, package_dir: &str) -> String {
+	let name = file_name
+		.strip_prefix(package_dir)
+		.unwrap_or(file_name)
+		.trim_end_matches(".proto");
+
+	let module_name = to_snake_case(
+		&name
+			.chars()
+			.map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+			.collect::<String>(),
+	);
+
+	if module_name.starts_with(|c: char| c.is_ascii_digit()) {
+		format!("_{module_name}")
+	} else {
+		module_name
+	}
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+	"as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+	"fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+	"ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where",
+	"while", "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try",
+	"typeof", "unsized", "virtual", "yield",
+];
+
+fn field_ident(name: &str) -> String {
+	let ident = to_snake_case(name);
+
+	match ident.as_str() {
+		// These cannot be used as raw identifiers
+		"self" | "super" | "crate" | "_" => format!("{ident}_"),
+		_ if RUST_KEYWORDS.contains(&ident.as_str()) => format!("r#{ident}"),
+		_ => ident,
+	}
+}
+
+fn to_snake_case(name: &str) -> String {
+	let chars: Vec<char> = name.chars().collect();
+	let mut output = String::with_capacity(name.len());
+
+	for (i, char) in chars.iter().enumerate() {
+		if char.is_ascii_uppercase() {
+			let prev = i.checked_sub(1).map(|i| chars[i]);
+			let next = chars.get(i + 1);
+
+			// Splits `myField` and `HTTPMethod` into `my_field` and `http_method`
+			let is_boundary = prev.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit())
+				|| (prev.is_some_and(|p| p.is_ascii_uppercase())
+					&& next.is_some_and(char::is_ascii_lowercase));
+
+			if is_boundary {
+				output.push('_');
+			}
+
+			output.push(char.to_ascii_lowercase());
+		} else {
+			output.push(*char);
+		}
+	}
+
+	output
+}
+
+fn to_upper_snake_case(name: &str) -> String {
+	to_snake_case(name).to_ascii_uppercase()
+}
+
+fn to_pascal_case(name: &str) -> String {
+	let mut output = String::with_capacity(name.len());
+
+	for segment in name.split('_').filter(|s| !s.is_empty()) {
+		let is_all_caps = !segment.chars().any(|c| c.is_ascii_lowercase());
+
+		let mut chars = segment.chars();
+
+		if let Some(first) = chars.next() {
+			output.push(first.to_ascii_uppercase());
+
+			if is_all_caps {
+				output.extend(chars.map(|c| c.to_ascii_lowercase()));
+			} else {
+				output.extend(chars);
+			}
+		}
+	}
+
+	output
+}
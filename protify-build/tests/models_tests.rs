@@ -0,0 +1,123 @@
+use std::sync::LazyLock;
+
+static MODELS: LazyLock<String> = LazyLock::new(|| {
+	let manifest_dir = env!("CARGO_MANIFEST_DIR");
+
+	protify_build::generate_models(
+		&[format!(
+			"{manifest_dir}/tests/proto/models_test/models.proto"
+		)],
+		&[
+			format!("{manifest_dir}/tests/proto"),
+			format!("{manifest_dir}/../test-reflection/proto_deps"),
+		],
+		"models_test",
+	)
+	.unwrap()
+});
+
+#[track_caller]
+fn assert_contains(expected: &str) {
+	assert!(
+		MODELS.contains(expected),
+		"Could not find\n{expected}\nin the generated code:\n{}",
+		*MODELS
+	);
+}
+
+#[test]
+fn valid_syntax() {
+	syn::parse_file(&MODELS).unwrap();
+}
+
+#[test]
+fn generated_code_compiles() {
+	let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("generated_models.rs");
+
+	std::fs::write(&path, format!("{}\nfn main() {{}}\n", *MODELS)).unwrap();
+
+	trybuild::TestCases::new().pass(path);
+}
+
+#[test]
+fn package_and_files() {
+	assert!(MODELS.starts_with("use protify::*;\nuse std::collections::HashMap;\n"));
+
+	assert_contains("proto_package!(MODELS_TEST, name = \"models_test\");");
+	assert_contains("pub use models::*;\npub mod models {\n\tuse super::*;\n");
+	assert_contains(
+		"define_proto_file!(MODELS_FILE, name = \"models_test/models.proto\", package = MODELS_TEST);",
+	);
+}
+
+#[test]
+fn messages() {
+	assert_contains(
+		"\t/// A user of the service.\n\t#[proto_message]\n\t#[proto(reserved_numbers(10, 20..26, 100..MAX), reserved_names(\"old_name\"), validate = |v| v.cel(cel_program!(id = \"user.names\", msg = \"the first and last name must be different\", expr = \"this.first_name != this.last_name\")))]\n\tpub struct User {",
+	);
+
+	assert_contains(
+		"\t\t/// The id of the user.\n\t\t#[proto(tag = 1, validate = |v| v.gt(0))]\n\t\tpub id: i32,",
+	);
+	assert_contains(
+		"\t\t#[proto(tag = 2, validate = |v| v.min_len(1))]\n\t\tpub first_name: String,",
+	);
+	assert_contains("\t\t#[proto(tag = 3)]\n\t\tpub last_name: String,");
+	assert_contains(
+		"\t\t#[proto(tag = 8, timestamp)]\n\t\tpub created_at: Option<proto_types::Timestamp>,",
+	);
+	assert_contains("\t\t#[proto(tag = 9, deprecated = true)]\n\t\tpub legacy_id: String,");
+
+	assert_contains("\t#[proto_message]\n\t#[proto(deprecated = true)]\n\tpub struct LegacyUser {");
+}
+
+#[test]
+fn nested_messages() {
+	assert_contains(
+		"\t\t#[proto(tag = 6, message, validate = |v| v.required())]\n\t\tpub address: Option<UserAddress>,",
+	);
+
+	assert_contains(
+		"\t/// An address of the user.\n\t#[proto_message]\n\t#[proto(name = \"Address\", parent_message = User)]\n\tpub struct UserAddress {",
+	);
+	assert_contains("\t\t#[proto(tag = 2)]\n\t\tpub city: Option<String>,");
+}
+
+#[test]
+fn repeated_and_maps() {
+	assert_contains(
+		"\t\t#[proto(tag = 4, validate = |v| v.items(|v| v.max_len(10)))]\n\t\tpub tags: Vec<String>,",
+	);
+	assert_contains(
+		"\t\t#[proto(tag = 5, map(string, sint32))]\n\t\tpub scores: HashMap<String, i32>,",
+	);
+}
+
+#[test]
+fn oneofs() {
+	assert_contains(
+		"\t\t#[proto(oneof(tags(11, 12)), validate = |v| v.required())]\n\t\tpub contact: Option<UserContact>,",
+	);
+
+	assert_contains(
+		"\t#[proto_oneof]\n\tpub enum UserContact {\n\t\t#[proto(tag = 11, validate = |v| v.email())]\n\t\tEmail(String),\n\t\t#[proto(tag = 12)]\n\t\tPhone(String),\n\t}",
+	);
+}
+
+#[test]
+fn enums() {
+	assert_contains(
+		"\t\t#[proto(tag = 7, enum_(Status), validate = |v| v.defined_only())]\n\t\tpub status: i32,",
+	);
+
+	assert_contains(
+		"\t#[proto_enum]\n\t#[proto(reserved_numbers(5..=10))]\n\tpub enum Status {\n\t\tUnspecified = 0,\n\t\tActive = 1,\n\t\tInactive = 2,\n\t}",
+	);
+}
+
+#[test]
+fn services() {
+	assert_contains(
+		"\t#[proto_service]\n\tpub enum UserService {\n\t\tGetUser {\n\t\t\trequest: User,\n\t\t\tresponse: User,\n\t\t},\n\t\tWatchUsers {\n\t\t\trequest: User,\n\t\t\t#[stream] response: User,\n\t\t},\n\t}",
+	);
+}
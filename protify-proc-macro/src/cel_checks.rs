@@ -0,0 +1,297 @@
+use proc_macro2::TokenTree;
+use syn::{ExprLit, MetaNameValue, punctuated::Punctuated};
+
+use crate::*;
+
+fn as_lit_str(expr: &Expr) -> Option<LitStr> {
+	match expr {
+		Expr::Lit(ExprLit {
+			lit: Lit::Str(lit), ..
+		}) => Some(lit.clone()),
+		// Expressions captured by declarative macros are wrapped in invisible groups
+		Expr::Group(group) => as_lit_str(&group.expr),
+		_ => None,
+	}
+}
+
+// Used by the `cel_program!` macro to check the syntax of the expression, if it's a literal
+pub fn cel_expression_macro(input: TokenStream2) -> syn::Result<TokenStream2> {
+	#[cfg(feature = "cel")]
+	if let Some(lit) = syn::parse2::<Expr>(input.clone())
+		.ok()
+		.as_ref()
+		.and_then(as_lit_str)
+	{
+		check_cel_syntax(&lit)?;
+	}
+
+	Ok(input)
+}
+
+#[cfg(feature = "cel")]
+pub fn check_cel_syntax(lit: &LitStr) -> syn::Result<()> {
+	::cel::Program::compile(&lit.value())
+		.map(|_| ())
+		.map_err(|e| error!(lit, "Invalid CEL expression: {e}"))
+}
+
+// Collects the literals used as the `expr` in `cel_program!` calls
+pub fn find_cel_expressions(tokens: TokenStream2, output: &mut Vec<LitStr>) {
+	let mut iter = tokens.into_iter().peekable();
+
+	while let Some(token) = iter.next() {
+		match token {
+			TokenTree::Ident(ident) if ident == "cel_program" => {
+				if !matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '!') {
+					continue;
+				}
+
+				iter.next();
+
+				let Some(TokenTree::Group(group)) = iter.next() else {
+					continue;
+				};
+
+				// Malformed calls are reported by the macro itself
+				let Ok(args) =
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(group.stream())
+				else {
+					continue;
+				};
+
+				output.extend(
+					args.iter()
+						.filter(|arg| arg.path.is_ident("expr"))
+						.filter_map(|arg| as_lit_str(&arg.value)),
+				);
+			}
+			TokenTree::Group(group) => find_cel_expressions(group.stream(), output),
+			_ => {}
+		}
+	}
+}
+
+// Extracts the names of the fields accessed on `this` (i.e. `name` in `this.name.size() > 0`).
+// For nested paths, only the first segment is returned.
+pub fn cel_this_fields(expression: &str) -> Vec<&str> {
+	let bytes = expression.as_bytes();
+	let mut fields: Vec<&str> = Vec::new();
+	let mut i = 0;
+
+	let is_ident_char = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+
+	let read_ident = |start: usize| {
+		let mut end = start;
+
+		while end < bytes.len() && is_ident_char(bytes[end]) {
+			end += 1;
+		}
+
+		end
+	};
+
+	let skip_whitespace = |mut pos: usize| {
+		while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+			pos += 1;
+		}
+
+		pos
+	};
+
+	while i < bytes.len() {
+		let b = bytes[i];
+
+		// String literals
+		if b == b'"' || b == b'\'' {
+			i += 1;
+
+			while i < bytes.len() && bytes[i] != b {
+				if bytes[i] == b'\\' {
+					i += 1;
+				}
+
+				i += 1;
+			}
+
+			i += 1;
+			continue;
+		}
+
+		if !is_ident_char(b) {
+			i += 1;
+			continue;
+		}
+
+		let start = i;
+		i = read_ident(start);
+
+		// Skipping members with the same name, like `other.this`
+		let is_member = bytes[..start]
+			.iter()
+			.rev()
+			.find(|b| !b.is_ascii_whitespace())
+			== Some(&b'.');
+
+		if is_member || &expression[start..i] != "this" {
+			continue;
+		}
+
+		let dot = skip_whitespace(i);
+
+		if bytes.get(dot) != Some(&b'.') {
+			continue;
+		}
+
+		let field_start = skip_whitespace(dot + 1);
+		let field_end = read_ident(field_start);
+		let field = &expression[field_start..field_end];
+
+		if !field.is_empty() && !fields.contains(&field) {
+			fields.push(field);
+		}
+	}
+
+	fields
+}
+
+// Checks that the fields referenced with `this` in the CEL programs of the top level validators exist in the message.
+// Only the first segment of nested paths is checked, and the types are left to the generated tests.
+//
+// The fields of oneofs are only known by the oneofs themselves, so if the field
+// is not found among the others, a const assertion is emitted instead.
+pub fn cel_field_checks(
+	validators: &Validators,
+	fields_data: &[FieldDataKind],
+	errors: &mut Vec<Error>,
+) -> TokenStream2 {
+	let mut expressions: Vec<LitStr> = Vec::new();
+
+	for validator in validators {
+		find_cel_expressions(validator.expr.clone(), &mut expressions);
+	}
+
+	if expressions.is_empty() {
+		return TokenStream2::new();
+	}
+
+	let mut field_names: Vec<&str> = Vec::new();
+	let mut oneof_paths: Vec<&Path> = Vec::new();
+
+	for data in fields_data.iter().filter_map(|d| d.as_normal()) {
+		if let ProtoField::Oneof(oneof) = &data.proto_field {
+			oneof_paths.push(&oneof.path);
+		} else {
+			field_names.push(&data.proto_name);
+		}
+	}
+
+	let mut tokens = TokenStream2::new();
+
+	for lit in &expressions {
+		let expression = lit.value();
+
+		for field in cel_this_fields(&expression) {
+			if field_names.contains(&field) {
+				continue;
+			}
+
+			if oneof_paths.is_empty() {
+				let available_fields = field_names.join(", ");
+
+				errors.push(error!(
+					lit,
+					"Unknown field `{field}` in CEL expression. The available fields are: {available_fields}"
+				));
+			} else {
+				let error_message = format!("Unknown field `{field}` in CEL expression");
+
+				tokens.extend(quote_spanned! {lit.span()=>
+				  const _: () = assert!(
+						::protify::__contains_field_name(
+							&[#(<#oneof_paths as ::protify::ValidatedOneof>::FIELD_NAMES),*],
+							#field
+						),
+						#error_message
+				  );
+				});
+			}
+		}
+	}
+
+	tokens
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn this_fields() {
+		assert_eq!(
+			cel_this_fields("this.name.size() > 0 && this.id != 0"),
+			["name", "id"]
+		);
+
+		// Duplicates are only reported once
+		assert_eq!(
+			cel_this_fields("this.name == '' || this.name.startsWith('a')"),
+			["name"]
+		);
+
+		// Whitespace around the dot
+		assert_eq!(cel_this_fields("this . name == ''"), ["name"]);
+
+		// Only the first segment of nested paths is collected
+		assert_eq!(cel_this_fields("this.a.b == 1"), ["a"]);
+
+		// Macros and functions with `this` as the argument
+		assert_eq!(
+			cel_this_fields("has(this.email) || size(this.tags) > 0"),
+			["email", "tags"]
+		);
+	}
+
+	#[test]
+	fn this_fields_in_string_literals() {
+		assert!(cel_this_fields("'this.name' == \"this.id\"").is_empty());
+
+		// Escaped quotes do not terminate the literal
+		assert!(cel_this_fields(r"'it\'s this.name' == ''").is_empty());
+		assert!(cel_this_fields(r#""\"this.name\"" == ''"#).is_empty());
+
+		// A quote of the other kind does not terminate the literal
+		assert_eq!(cel_this_fields(r#"'"this.a' == this.b"#), ["b"]);
+
+		// Unterminated literals do not panic
+		assert_eq!(cel_this_fields("this.name == 'abc"), ["name"]);
+		assert!(cel_this_fields(r"'abc\").is_empty());
+	}
+
+	#[test]
+	fn this_fields_in_member_access() {
+		// `this` as a member or as part of another identifier
+		assert!(cel_this_fields("other.this.name == ''").is_empty());
+		assert!(cel_this_fields("other. this.name == ''").is_empty());
+		assert!(cel_this_fields("thisValue.name == '' && my_this.name == ''").is_empty());
+
+		// `this` without a member access
+		assert!(cel_this_fields("this == other").is_empty());
+		assert!(cel_this_fields("this.").is_empty());
+		assert!(cel_this_fields("this.  ").is_empty());
+	}
+
+	#[cfg(feature = "cel")]
+	#[test]
+	fn cel_syntax() {
+		let valid: LitStr = parse_quote!("this.name != ''");
+		let invalid: LitStr = parse_quote!("this.name ==");
+
+		assert!(check_cel_syntax(&valid).is_ok());
+		assert!(
+			check_cel_syntax(&invalid)
+				.unwrap_err()
+				.to_string()
+				.starts_with("Invalid CEL expression")
+		);
+	}
+}
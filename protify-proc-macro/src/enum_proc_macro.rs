@@ -7,12 +7,14 @@ struct EnumVariantCtx {
 	ident: Ident,
 	deprecated: bool,
 	span: Span,
+	comments: Option<String>,
 }
 
 #[derive(Default)]
 struct EnumData {
 	variants_data: Vec<EnumVariantCtx>,
 	enum_attrs: EnumAttrs,
+	lockfile_tracking: Option<TokenStream2>,
 }
 
 fn extract_enum_data(item: &mut ItemEnum) -> syn::Result<EnumData> {
@@ -27,16 +29,60 @@ fn extract_enum_data(item: &mut ItemEnum) -> syn::Result<EnumData> {
 
 	let mut variants_data: Vec<EnumVariantCtx> = Vec::new();
 	let mut manually_set_tags: Vec<ParsedNum> = Vec::new();
+	let mut variants_attrs: Vec<EnumVariantAttrs> = Vec::with_capacity(variants.len());
+	let mut variants_tags: Vec<Option<ParsedNum>> = Vec::with_capacity(variants.len());
 
 	for variant in variants.iter() {
-		if let Some((_, expr)) = &variant.discriminant {
+		let variant_ident = &variant.ident;
+
+		if !variant.fields.is_empty() {
+			bail!(variant_ident, "Protobuf enums can only have unit variants");
+		}
+
+		let variant_attrs =
+			process_derive_enum_variants_attrs(&enum_attrs.name, variant_ident, &variant.attrs)?;
+
+		if enum_attrs
+			.reserved_names
+			.contains(&variant_attrs.name)
+		{
+			let name = &variant_attrs.name;
+
+			bail!(variant_ident, "Name `{name}` is reserved");
+		}
+
+		let tag = if let Some((_, expr)) = &variant.discriminant {
 			let num = expr.as_int::<i32>()?;
 
-			manually_set_tags.push(ParsedNum {
+			let tag = ParsedNum {
 				num,
 				span: variant.ident.span(),
-			});
-		}
+			};
+
+			manually_set_tags.push(tag);
+
+			Some(tag)
+		} else {
+			None
+		};
+
+		variants_attrs.push(variant_attrs);
+		variants_tags.push(tag);
+	}
+
+	let tag_lock = TagLock::load(&enum_attrs.name, enum_attrs.parent_message.as_ref())?;
+
+	if let Some(tag_lock) = &tag_lock {
+		tag_lock.apply(
+			variants_attrs
+				.iter()
+				.zip(variants_tags.iter_mut())
+				.zip(variants.iter())
+				.map(|((attrs, tag), variant)| (attrs.name.as_str(), tag, variant.ident.span())),
+			&mut manually_set_tags,
+			&enum_attrs.reserved_numbers,
+			enum_ident.span(),
+		)?;
 	}
 
 	let unavailable_ranges =
@@ -44,33 +90,35 @@ fn extract_enum_data(item: &mut ItemEnum) -> syn::Result<EnumData> {
 
 	let mut tag_allocator = TagAllocator::new(&unavailable_ranges);
 
-	for (i, variant) in variants.iter_mut().enumerate() {
+	for (i, ((variant, variant_attrs), variant_tag)) in variants
+		.iter_mut()
+		.zip(variants_attrs)
+		.zip(variants_tags)
+		.enumerate()
+	{
 		let variant_ident = &variant.ident;
 
-		if !variant.fields.is_empty() {
-			bail!(variant_ident, "Protobuf enums can only have unit variants");
-		}
-
 		let EnumVariantAttrs {
 			options,
 			name,
 			deprecated,
-		} = process_derive_enum_variants_attrs(&enum_attrs.name, variant_ident, &variant.attrs)?;
-
-		if enum_attrs.reserved_names.contains(&name) {
-			bail!(variant_ident, "Name `{name}` is reserved");
-		}
-
-		let tag = if let Some((_, expr)) = &variant.discriminant {
-			let tag = expr.as_int::<i32>()?;
+			comments,
+		} = variant_attrs;
 
+		let tag = if let Some(ParsedNum { num: tag, span }) = variant_tag {
 			if i == 0 && tag != 0 {
-				bail!(
-					expr,
+				bail_with_span!(
+					span,
 					"The first variant of a protobuf enum must have have a tag of 0"
 				);
 			}
 
+			// Tag coming from the lockfile
+			if variant.discriminant.is_none() {
+				let tag_expr: Expr = parse_quote!(#tag);
+				variant.discriminant = Some((token::Eq::default(), tag_expr));
+			}
+
 			tag
 		} else {
 			let next_tag = if i == 0 {
@@ -92,12 +140,21 @@ fn extract_enum_data(item: &mut ItemEnum) -> syn::Result<EnumData> {
 			ident: variant_ident.clone(),
 			deprecated,
 			span: variant_ident.span(),
+			comments,
 		});
 	}
 
+	let lockfile_tracking = tag_lock.map(|tag_lock| {
+		tag_lock.check_tokens(
+			enum_ident,
+			&quote! { <#enum_ident as ::protify::ProtoEnumSchema>::proto_schema() },
+		)
+	});
+
 	Ok(EnumData {
 		variants_data,
 		enum_attrs,
+		lockfile_tracking,
 	})
 }
 
@@ -116,8 +173,11 @@ pub fn enum_proc_macro(mut item: ItemEnum) -> TokenStream2 {
 				deprecated,
 				file,
 				module_path,
+				comments,
+				features_span,
 				..
 			},
+		lockfile_tracking,
 	} = extract_enum_data(&mut item).unwrap_or_else(|e| {
 		error = Some(e.into_compile_error());
 		EnumData::default()
@@ -144,6 +204,7 @@ pub fn enum_proc_macro(mut item: ItemEnum) -> TokenStream2 {
 	};
 
 	let rust_ident_str = enum_ident.to_string();
+	let enum_comments_tokens = comments_tokens(comments.as_ref());
 
 	let variants_tokens = if error.is_some() {
 		quote! { unimplemented!() }
@@ -155,14 +216,18 @@ pub fn enum_proc_macro(mut item: ItemEnum) -> TokenStream2 {
 				tag,
 				deprecated,
 				span,
+				comments,
 				..
 			} = var;
 
+			let comments_tokens = comments_tokens(comments.as_ref());
+
 			quote_spanned! {*span=>
 			  ::protify::EnumVariant::builder()
 						.name(#name.into())
 						.options(::protify::__collect_options(#options, #deprecated))
 						.tag(#tag)
+						#comments_tokens
 						.build()
 			}
 		});
@@ -257,11 +322,16 @@ pub fn enum_proc_macro(mut item: ItemEnum) -> TokenStream2 {
 		|path_override| path_override.to_token_stream(),
 	);
 
+	let features_check =
+		features_span.map(|span| Features::edition_check(file.as_ref(), span, &quote! { true }));
+
 	quote! {
 	  #[repr(i32)]
 	  #[derive(::protify::macros::__Enum, Hash, PartialEq, Eq, Debug, Clone, Copy)]
 	  #item
 
+	  #features_check
+
 	  ::protify::register_proto_data! {
 			::protify::RegistryEnum {
 				parent_message: #parent_message_registry,
@@ -358,10 +428,12 @@ pub fn enum_proc_macro(mut item: ItemEnum) -> TokenStream2 {
 					.reserved_numbers(#reserved_numbers)
 					.options(::protify::__collect_options(#enum_options, #deprecated))
 					.rust_path(::protify::format!("::{}::{}", #module_path, #rust_ident_str).into())
+					#enum_comments_tokens
 					.build()
 			}
 	  }
 
+	  #lockfile_tracking
 	  #error
 	}
 }
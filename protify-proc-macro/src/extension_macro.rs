@@ -33,6 +33,7 @@ pub fn process_extension_derive(
 			proto_name,
 			proto_field,
 			deprecated,
+			comments,
 		} = process_extension_field_attrs(field)?;
 
 		if tag.is_none() {
@@ -43,6 +44,7 @@ pub fn process_extension_derive(
 		}
 
 		let proto_field_trait_target = proto_field.proto_field_trait_target(field.ident.span());
+		let comments_tokens = comments_tokens(comments.as_ref());
 
 		fields_tokens.push(quote_spanned! {field.ident.span()=>
 			::protify::Field::builder()
@@ -51,6 +53,7 @@ pub fn process_extension_derive(
 				.options(::protify::__collect_options(#options, #deprecated))
 				.type_(<#proto_field_trait_target as ::protify::AsProtoField>::as_proto_field())
 				.validators(::protify::vec![])
+				#comments_tokens
 				.build()
 		});
 	}
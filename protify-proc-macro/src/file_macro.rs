@@ -101,6 +101,8 @@ pub fn process_file_macro(input: TokenStream2) -> syn::Result<TokenStream2> {
 	let mut imports = TokenStreamOr::new(|_| quote! { ::protify::Vec::<&'static str>::new() });
 	let mut extensions: Vec<Path> = Vec::new();
 	let mut edition = TokenStreamOr::new(|_| quote! { ::protify::Edition::Proto3 });
+	let mut is_proto3 = true;
+	let mut features: Option<Features> = None;
 	let mut messages: Vec<MessageExpr> = Vec::new();
 	let mut enums: Vec<Path> = Vec::new();
 	let mut services: Vec<Path> = Vec::new();
@@ -142,7 +144,17 @@ pub fn process_file_macro(input: TokenStream2) -> syn::Result<TokenStream2> {
 				extensions = parse_bracketed::<PathList>(meta.value()?)?.list;
 			}
 			"edition" => {
-				edition.set(meta.parse_value::<Path>()?.into_token_stream());
+				let path = meta.parse_value::<Path>()?;
+
+				is_proto3 = path
+					.segments
+					.last()
+					.is_some_and(|segment| segment.ident == "Proto3");
+
+				edition.set(path.into_token_stream());
+			}
+			"features" => {
+				features = Some(Features::parse(&meta, FeatureTarget::File)?);
 			}
 			_ => {
 				file_ident = Some(meta.ident()?.clone());
@@ -154,6 +166,14 @@ pub fn process_file_macro(input: TokenStream2) -> syn::Result<TokenStream2> {
 
 	parser.parse2(input)?;
 
+	if let Some(features) = features {
+		if is_proto3 {
+			bail_with_span!(features.span, "`features` cannot be used in proto3 files");
+		}
+
+		features.apply(&mut options);
+	}
+
 	let file_ident = file_ident
 		.ok_or_else(|| error_call_site!("Missing file ident (must be the first argument)"))?;
 	let file = name.unwrap_or_else(|| {
@@ -184,6 +204,7 @@ pub fn process_file_macro(input: TokenStream2) -> syn::Result<TokenStream2> {
 			name: #file,
 			package: <#package as ::protify::PackageSchema>::NAME,
 			extern_path: #extern_path,
+			edition: #edition,
 	  };
 
 	  #[allow(non_camel_case_types)]
@@ -193,6 +214,7 @@ pub fn process_file_macro(input: TokenStream2) -> syn::Result<TokenStream2> {
 			const NAME: &str = #file;
 			const PACKAGE: &str = <#package as ::protify::PackageSchema>::NAME;
 			const EXTERN_PATH: &str = #extern_path;
+			const EDITION: ::protify::Edition = #edition;
 
 			#[inline(never)]
 			#[cold]
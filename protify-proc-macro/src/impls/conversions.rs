@@ -17,6 +17,9 @@ pub struct ProtoConversions<'a> {
 	pub kind: ItemKind,
 	pub container_attrs: ContainerAttrs<'a>,
 	pub fields: &'a [FieldDataKind],
+	// Proxied oneofs always generate the fallible conversion used by fallible messages,
+	// but they only replace `From` with `TryFrom` when this is set
+	pub is_fallible: bool,
 }
 
 impl ProtoConversions<'_> {
@@ -25,31 +28,62 @@ impl ProtoConversions<'_> {
 			proxy_ident,
 			proto_ident,
 			kind,
+			is_fallible,
 			..
 		} = self;
 
-		let from_proto = self.create_from_proto_impl();
+		let from_proto = if *is_fallible {
+			match kind {
+				ItemKind::Message => self.create_try_from_proto_impl(),
+				ItemKind::Oneof => self.create_oneof_try_from_impl(),
+			}
+		} else {
+			self.create_from_proto_impl()
+		};
 		let into_proto = self.create_into_proto_impl();
 
 		let proxy_trait_impl = if kind.is_message() {
-			quote! {
-			  impl ::protify::MessageProxy for #proxy_ident {
-					type Message = #proto_ident;
-			  }
+			// Fallible proxies cannot be obtained with `Into`
+			if *is_fallible {
+				quote! {
+				  impl ::protify::FallibleMessageProxy for #proxy_ident {
+						type Message = #proto_ident;
+				  }
+				}
+			} else {
+				quote! {
+				  impl ::protify::MessageProxy for #proxy_ident {
+						type Message = #proto_ident;
+				  }
 
-			  impl ::protify::ProxiedMessage for #proto_ident {
-					type Proxy = #proxy_ident;
-			  }
+				  impl ::protify::ProxiedMessage for #proto_ident {
+						type Proxy = #proxy_ident;
+				  }
+				}
 			}
 		} else {
-			quote! {
-			  impl ::protify::OneofProxy for #proxy_ident {
-					type Oneof = #proto_ident;
-			  }
+			let try_from_oneof = self.create_try_from_oneof_impl();
 
-			  impl ::protify::ProxiedOneof for #proto_ident {
-					type Proxy = #proxy_ident;
-			  }
+			if *is_fallible {
+				quote! {
+				  impl ::protify::FallibleOneofProxy for #proxy_ident {
+						type Oneof = #proto_ident;
+				  }
+
+				  #try_from_oneof
+				}
+			} else {
+				quote! {
+				  impl ::protify::OneofProxy for #proxy_ident {
+						type Oneof = #proto_ident;
+				  }
+
+				  impl ::protify::ProxiedOneof for #proto_ident {
+						type Proxy = #proxy_ident;
+				  }
+
+				  #try_from_oneof
+				}
 			}
 		};
 
@@ -67,6 +101,7 @@ impl ProtoConversions<'_> {
 			kind,
 			container_attrs,
 			fields,
+			..
 		} = self;
 
 		let custom_from_proto = container_attrs.custom_from_proto_expr();
@@ -151,6 +186,263 @@ impl ProtoConversions<'_> {
 		}
 	}
 
+	// Missing required messages and oneofs, unknown enum values and the errors of custom conversions
+	// are collected in the ctx, and returned all together at the end
+	fn create_try_from_proto_impl(&self) -> TokenStream2 {
+		let Self {
+			proxy_ident,
+			proto_ident,
+			container_attrs,
+			fields,
+			..
+		} = self;
+
+		let custom_from_proto = container_attrs.custom_from_proto_expr();
+
+		let conversion_body = if let Some(from_proto) = custom_from_proto {
+			// The custom conversion must return `Result<Self, ValidationErrors>`
+			process_custom_expression(from_proto, &quote_spanned! {from_proto.span()=> value })
+		} else if fields.is_empty() {
+			quote! { unimplemented!() }
+		} else {
+			let mut conversions: Vec<TokenStream2> = Vec::new();
+			let mut converted_fields: Vec<&Ident> = Vec::new();
+
+			let field_tokens = fields
+				.iter()
+				.map(|d| {
+					let field_ident = d.ident();
+					let span = field_ident.span();
+
+					match d {
+						FieldDataKind::Ignored { from_proto, .. } => {
+							let value = if let Some(expr) = from_proto {
+								match expr {
+									// Field is ignored, so we don't pass any args here
+									PathOrClosure::Path(path) => quote_spanned! {span=> #path() },
+									PathOrClosure::Closure(closure) => {
+										let error = error!(
+											closure,
+											"Cannot use a closure for ignored fields"
+										);
+
+										error.into_compile_error()
+									}
+								}
+							} else {
+								quote_spanned! {span=> Default::default() }
+							};
+
+							quote_spanned! {span=> #field_ident: #value }
+						}
+						FieldDataKind::Normal(field_data) => {
+							let base_ident = quote_spanned! {span=> value.#field_ident };
+							let proto_field = &field_data.proto_field;
+
+							// Oneofs are not part of the field paths
+							let field_context = if proto_field.is_oneof() {
+								quote_spanned! {span=> ctx.without_field_context(); }
+							} else {
+								let proto_name = &field_data.proto_name;
+								let tag = &field_data.tag;
+								let field_type = field_data.descriptor_type_tokens();
+
+								quote_spanned! {span=>
+								  ctx.with_field_context(
+										::protify::FieldContext::new(
+											#proto_name.into(),
+											#tag,
+											#field_type
+										)
+								  );
+								}
+							};
+
+							let conversion_logic =
+								if let Some(expr) = field_data.from_proto.as_ref() {
+									let conversion = process_custom_expression(expr, &base_ident);
+
+									quote_spanned! {span=> ::protify::__try_convert(&mut ctx, #conversion) }
+								} else if let Some(conversion) =
+									proto_field.fallible_from_proto(&base_ident)
+								{
+									conversion
+								} else {
+									let conversion = proto_field.default_from_proto(&base_ident);
+
+									quote_spanned! {span=> Some(#conversion) }
+								};
+
+							conversions.push(quote_spanned! {span=>
+							  #field_context
+							  let #field_ident = #conversion_logic;
+							});
+							converted_fields.push(field_ident);
+
+							quote_spanned! {span=> #field_ident }
+						}
+					}
+				})
+				.collect::<Vec<_>>();
+
+			let check_tokens = (!converted_fields.is_empty()).then(|| {
+				quote! {
+				  let (#(Some(#converted_fields),)*) = (#(#converted_fields,)*) else {
+						return Err(ctx.violations);
+				  };
+				}
+			});
+
+			quote! {
+			  let mut ctx = ::protify::ValidationCtx::new();
+			  ctx.fail_fast = false;
+
+			  #(#conversions)*
+
+			  #check_tokens
+
+			  Ok(Self {
+					#(#field_tokens),*
+			  })
+			}
+		};
+
+		quote! {
+		  #[allow(clippy::useless_conversion)]
+		  impl ::core::convert::TryFrom<#proto_ident> for #proxy_ident {
+				type Error = ::protify::ValidationErrors;
+
+				fn try_from(value: #proto_ident) -> Result<Self, Self::Error> {
+					#conversion_body
+				}
+		  }
+		}
+	}
+
+	fn create_oneof_try_from_impl(&self) -> TokenStream2 {
+		let Self {
+			proxy_ident,
+			proto_ident,
+			container_attrs,
+			..
+		} = self;
+
+		let conversion_body = if let Some(from_proto) = container_attrs.custom_from_proto_expr() {
+			// The custom conversion must return `Result<Self, ValidationErrors>`
+			process_custom_expression(from_proto, &quote_spanned! {from_proto.span()=> value })
+		} else {
+			quote! {
+			  let mut ctx = ::protify::ValidationCtx::new();
+			  ctx.fail_fast = false;
+
+			  <Self as ::protify::__TryFromOneof<#proto_ident>>::__try_from_oneof(value, &mut ctx)
+					.ok_or(ctx.violations)
+			}
+		};
+
+		quote! {
+		  impl ::core::convert::TryFrom<#proto_ident> for #proxy_ident {
+				type Error = ::protify::ValidationErrors;
+
+				fn try_from(value: #proto_ident) -> Result<Self, Self::Error> {
+					#conversion_body
+				}
+		  }
+		}
+	}
+
+	// Used by the fallible proxies that contain this oneof, so that the unknown enum values
+	// and the failed conversions in the variants are reported instead of being replaced
+	fn create_try_from_oneof_impl(&self) -> TokenStream2 {
+		let Self {
+			proxy_ident,
+			proto_ident,
+			container_attrs,
+			fields,
+			is_fallible,
+			..
+		} = self;
+
+		// Custom conversions can only fail in fallible oneofs
+		let wrap_custom_conversion = |conversion: TokenStream2, span: Span| {
+			if *is_fallible {
+				quote_spanned! {span=> ::protify::__try_convert(ctx, #conversion) }
+			} else {
+				quote_spanned! {span=> Some(#conversion) }
+			}
+		};
+
+		let custom_from_proto = container_attrs.custom_from_proto_expr();
+
+		let conversion_body = if let Some(from_proto) = custom_from_proto {
+			let conversion =
+				process_custom_expression(from_proto, &quote_spanned! {from_proto.span()=> value });
+
+			wrap_custom_conversion(conversion, from_proto.span())
+		} else if fields.is_empty() {
+			quote! { unimplemented!() }
+		} else {
+			let tokens = fields
+				.iter()
+				.filter_map(|d| d.as_normal())
+				.map(|field_data| {
+					let field_ident = &field_data.ident;
+					let span = field_ident.span();
+					let base_ident = quote_spanned! {span=> v };
+					let proto_name = &field_data.proto_name;
+					let tag = &field_data.tag;
+					let field_type = field_data.descriptor_type_tokens();
+
+					let conversion_logic = if let Some(expr) = field_data.from_proto.as_ref() {
+						let conversion = process_custom_expression(expr, &base_ident);
+
+						wrap_custom_conversion(conversion, span)
+					} else if let Some(conversion) = field_data
+						.proto_field
+						.inner()
+						.and_then(|proto_type| proto_type.fallible_from_proto(&base_ident))
+					{
+						quote_spanned! {span=> ::protify::__try_convert(ctx, #conversion) }
+					} else {
+						let conversion = field_data
+							.proto_field
+							.default_from_proto(&base_ident);
+
+						quote_spanned! {span=> Some(#conversion) }
+					};
+
+					quote_spanned! {span=>
+					  #proto_ident::#field_ident(v) => {
+							ctx.with_field_context(
+								::protify::FieldContext::new(
+									#proto_name.into(),
+									#tag,
+									#field_type
+								)
+							);
+
+							#conversion_logic.map(#proxy_ident::#field_ident)
+					  }
+					}
+				});
+
+			quote! {
+			  match value {
+					#(#tokens),*
+			  }
+			}
+		};
+
+		quote! {
+		  #[allow(clippy::useless_conversion)]
+		  impl ::protify::__TryFromOneof<#proto_ident> for #proxy_ident {
+				fn __try_from_oneof(value: #proto_ident, ctx: &mut ::protify::ValidationCtx) -> Option<Self> {
+					#conversion_body
+				}
+		  }
+		}
+	}
+
 	fn create_into_proto_impl(&self) -> TokenStream2 {
 		let Self {
 			proxy_ident,
@@ -158,6 +450,7 @@ impl ProtoConversions<'_> {
 			kind,
 			container_attrs,
 			fields,
+			..
 		} = self;
 
 		let custom_into_proto = container_attrs.custom_into_proto_expr();
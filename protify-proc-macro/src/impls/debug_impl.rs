@@ -0,0 +1,129 @@
+use crate::*;
+
+// Removes `Debug` from the derives of a proxy, so that it can be replaced
+// with the impl that redacts the fields marked with `debug_redact`.
+// Returns true if it was found.
+pub fn take_debug_derive(attrs: &mut Vec<Attribute>) -> syn::Result<bool> {
+	let mut found = false;
+	let mut new_attrs: Vec<Attribute> = Vec::with_capacity(attrs.len());
+
+	for attr in attrs.drain(..) {
+		if !attr.path().is_ident("derive") {
+			new_attrs.push(attr);
+			continue;
+		}
+
+		let paths = attr.parse_args::<PunctuatedItems<Path>>()?.list;
+		let initial_len = paths.len();
+
+		let paths: Vec<Path> = paths
+			.into_iter()
+			.filter(|path| {
+				!path
+					.segments
+					.last()
+					.is_some_and(|segment| segment.ident == "Debug")
+			})
+			.collect();
+
+		if paths.len() == initial_len {
+			new_attrs.push(attr);
+			continue;
+		}
+
+		found = true;
+
+		if !paths.is_empty() {
+			new_attrs.push(parse_quote_spanned! {attr.span()=> #[derive(#(#paths),*)] });
+		}
+	}
+
+	*attrs = new_attrs;
+
+	Ok(found)
+}
+
+// Replaces the Debug impl of a struct (generated by prost, or derived for a proxy),
+// so that the fields marked with `debug_redact` are not shown.
+//
+// Ignored fields are only present in proxies.
+pub fn redacted_struct_debug_impl<'a>(
+	struct_ident: &Ident,
+	fields: impl IntoIterator<Item = &'a FieldDataKind>,
+) -> TokenStream2 {
+	let struct_name = struct_ident.to_string();
+
+	let fields = fields.into_iter().map(|data| match data {
+		FieldDataKind::Ignored { ident, .. } => {
+			let ident_str = ident.to_string();
+
+			quote_spanned! {ident.span()=>
+				.field(#ident_str, &self.#ident)
+			}
+		}
+		FieldDataKind::Normal(data) => {
+			let ident = &data.ident;
+			let ident_str = &data.ident_str;
+
+			if data.standard_options.debug_redact {
+				quote_spanned! {data.span=>
+					.field(#ident_str, &::core::format_args!("[REDACTED]"))
+				}
+			} else {
+				quote_spanned! {data.span=>
+					.field(#ident_str, &self.#ident)
+				}
+			}
+		}
+	});
+
+	quote! {
+		impl ::core::fmt::Debug for #struct_ident {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				f.debug_struct(#struct_name)
+					#(#fields)*
+					.finish()
+			}
+		}
+	}
+}
+
+// Same as above, but for the variants of a oneof
+pub fn redacted_oneof_debug_impl<'a>(
+	enum_ident: &Ident,
+	variants: impl IntoIterator<Item = &'a FieldDataKind>,
+) -> TokenStream2 {
+	let arms = variants.into_iter().map(|data| match data {
+		FieldDataKind::Ignored { ident, .. } => {
+			let ident_str = ident.to_string();
+
+			quote_spanned! {ident.span()=>
+				Self::#ident(v) => f.debug_tuple(#ident_str).field(v).finish()
+			}
+		}
+		FieldDataKind::Normal(data) => {
+			let ident = &data.ident;
+			let ident_str = &data.ident_str;
+
+			if data.standard_options.debug_redact {
+				quote_spanned! {data.span=>
+					Self::#ident(_) => f.debug_tuple(#ident_str).field(&::core::format_args!("[REDACTED]")).finish()
+				}
+			} else {
+				quote_spanned! {data.span=>
+					Self::#ident(v) => f.debug_tuple(#ident_str).field(v).finish()
+				}
+			}
+		}
+	});
+
+	quote! {
+		impl ::core::fmt::Debug for #enum_ident {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				match self {
+					#(#arms),*
+				}
+			}
+		}
+	}
+}
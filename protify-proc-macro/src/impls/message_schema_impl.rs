@@ -10,6 +10,7 @@ impl FieldData {
 			proto_field,
 			deprecated,
 			span,
+			comments,
 			..
 		} = self;
 
@@ -26,16 +27,23 @@ impl FieldData {
 			});
 
 		if let ProtoField::Oneof(OneofInfo { path, .. }) = proto_field {
+			// The docs of the field take precedence over those of the oneof enum
+			let comments_tokens = comments
+				.as_ref()
+				.map(|comments| quote! { .with_comments(#comments) });
+
 			quote_spanned! {*span=>
 			  ::protify::MessageEntry::Oneof(
 					<#path as ::protify::ProtoOneof>::proto_schema()
 						.with_name(#proto_name)
+						#comments_tokens
 						.with_options(::protify::__collect_options(#options, false))
 						.with_validators(::protify::__collect_validators([ #(#validator_schema_tokens),* ]))
 			  )
 			}
 		} else {
 			let proto_field_trait_target = proto_field.proto_field_trait_target(*span);
+			let comments_tokens = comments_tokens(comments.as_ref());
 
 			quote_spanned! {*span=>
 			  ::protify::Field::builder()
@@ -44,6 +52,7 @@ impl FieldData {
 					.options(::protify::__collect_options(#options, #deprecated))
 					.type_(<#proto_field_trait_target as ::protify::AsProtoField>::as_proto_field())
 					.validators(::protify::__collect_validators([ #(#validator_schema_tokens),* ]))
+					#comments_tokens
 					.build()
 			}
 		}
@@ -62,6 +71,7 @@ impl MessageCtx<'_> {
 			validators,
 			file,
 			module_path,
+			comments,
 			..
 		} = &self.message_attrs;
 
@@ -90,6 +100,7 @@ impl MessageCtx<'_> {
 		};
 
 		let proto_struct = self.proto_struct_ident;
+		let comments_tokens = comments_tokens(comments.as_ref());
 
 		let name_method = if let Some(parent) = parent_message {
 			quote_spanned! {parent.span()=>
@@ -139,7 +150,9 @@ impl MessageCtx<'_> {
 				::protify::RegistryMessage {
 					package: #package,
 					parent_message: #registry_parent_message,
-					message: || <#proto_struct as ::protify::ProtoMessage>::proto_schema()
+					message: || <#proto_struct as ::protify::ProtoMessage>::proto_schema(),
+					type_url: || <#proto_struct as ::protify::ProtoMessage>::type_url(),
+					validate_encoded: ::protify::__validate_encoded::<#proto_struct>
 				}
 		  }
 
@@ -221,6 +234,7 @@ impl MessageCtx<'_> {
 						.entries(vec![ #entries_tokens ])
 						.validators(::protify::__collect_validators([ #(::protify::Validator::<#proto_struct>::schema(&#validators)),* ]))
 						.rust_path(format!("::{}::{}", #module_path, #rust_ident_str).into())
+						#comments_tokens
 						.build()
 				}
 		  }
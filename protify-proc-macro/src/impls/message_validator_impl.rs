@@ -23,6 +23,7 @@ impl FieldData {
 			proto_name,
 			proto_field,
 			type_info,
+			sensitive,
 			..
 		} = self;
 
@@ -113,6 +114,8 @@ impl FieldData {
 				  #argument
 				}
 			} else {
+				let sensitive_tokens = sensitive.then(|| quote_spanned! {*span=> .sensitive() });
+
 				quote_spanned! {*span=>
 				  ctx.with_field_context(
 						::protify::FieldContext::new(
@@ -120,6 +123,7 @@ impl FieldData {
 							#tag,
 							#field_type
 						)
+						#sensitive_tokens
 				  ),
 				  #argument
 				}
@@ -165,6 +169,35 @@ impl FieldData {
 
 		tokens
 	}
+
+	// Wraps the validators so that they are skipped if the field is not covered by the field mask
+	// (if there is one), and so that nested messages only receive the subtree of the mask for this field
+	pub fn masked_validator_tokens(&self, validators: &[TokenStream2]) -> TokenStream2 {
+		let Self {
+			proto_name,
+			proto_field,
+			span,
+			..
+		} = self;
+
+		let enter_call = if let ProtoField::Oneof(oneof) = proto_field {
+			let path = &oneof.path;
+
+			quote_spanned! {*span=>
+			  ctx.__enter_masked_oneof(<#path as ::protify::ValidatedOneof>::FIELD_NAMES)
+			}
+		} else {
+			quote_spanned! {*span=> ctx.__enter_masked_field(#proto_name) }
+		};
+
+		quote_spanned! {*span=>
+		  if let Some(parent_mask) = #enter_call {
+				#(#validators)*
+
+				ctx.__exit_masked_field(parent_mask);
+		  }
+		}
+	}
 }
 
 pub fn generate_message_validator(
@@ -183,12 +216,15 @@ pub fn generate_message_validator(
 	} else {
 		let top_level = top_level_validators.iter().enumerate().map(|(i, v)| {
       if v.kind.is_custom() {
+        // Custom validators may access any field, so they are skipped if the message is not fully covered by the field mask
         quote_spanned! {v.span=>
-          is_valid &= ::protify::Validator::<#target_ident>::execute_validation(
-            &(#v),
-            ctx,
-            Some(self)
-          )?;
+          if ctx.field_mask.is_none() {
+            is_valid &= ::protify::Validator::<#target_ident>::execute_validation(
+              &(#v),
+              ctx,
+              Some(self)
+            )?;
+          }
         }
       } else {
         let validator_static_ident = format_ident!("__VALIDATOR_{i}");
@@ -213,8 +249,11 @@ pub fn generate_message_validator(
 		let field_validators = fields
 			.iter()
 			.filter_map(|d| d.as_normal())
-			.flat_map(|d| {
-				d.field_validator_tokens(target_ident, &mut validators_data, ItemKind::Message)
+			.filter_map(|d| {
+				let tokens =
+					d.field_validator_tokens(target_ident, &mut validators_data, ItemKind::Message);
+
+				(!tokens.is_empty()).then(|| d.masked_validator_tokens(&tokens))
 			});
 
 		let top_level_tokens = quote! { #(#top_level)* };
@@ -10,8 +10,12 @@ mod oneof_validator_impl;
 pub use conversions::*;
 mod consistency_checks;
 pub use consistency_checks::*;
+mod debug_impl;
+pub use debug_impl::*;
 mod message_validator_impl;
 pub use message_validator_impl::*;
+mod tonic_service_impl;
+pub use tonic_service_impl::*;
 
 pub fn wrap_with_imports(tokens: &TokenStream2) -> TokenStream2 {
 	quote! {
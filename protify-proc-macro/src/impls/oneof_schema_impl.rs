@@ -19,15 +19,34 @@ impl OneofCtx<'_> {
 		let OneofAttrs {
 			options: options_tokens,
 			validators,
+			comments,
+			features_span,
 			..
 		} = &self.oneof_attrs;
 		let tags = &self.tags;
+		let comments_tokens = comments_tokens(comments.as_ref());
+
+		// Checked by the messages that use the oneof
+		let has_features = features_span.is_some()
+			|| self
+				.variants
+				.iter()
+				.filter_map(|v| v.as_normal())
+				.any(|data| data.features_span.is_some());
+		let has_features_tokens = has_features.then(|| {
+			quote! {
+				#[doc(hidden)]
+				const __HAS_FEATURES: bool = true;
+			}
+		});
 
 		quote! {
 		  impl ::protify::ProtoOneof for #enum_ident {
 				#[doc(hidden)]
 				const TAGS: &[i32] = &[ #(#tags),* ];
 
+				#has_features_tokens
+
 				#[inline(never)]
 				#[cold]
 				fn proto_schema() -> ::protify::Oneof {
@@ -36,6 +55,7 @@ impl OneofCtx<'_> {
 						.fields(vec![ #variants_tokens ])
 						.options(#options_tokens.into_iter().collect())
 						.validators(::protify::__collect_validators([ #(::protify::Validator::<#enum_ident>::schema(&#validators)),* ]))
+						#comments_tokens
 						.build()
 				}
 		  }
@@ -16,11 +16,13 @@ pub fn generate_oneof_validator(
 	} else {
 		let top_level = top_level_validators.iter().map(|v| {
 			quote_spanned! {v.span=>
-			  is_valid &= ::protify::Validator::<#oneof_ident>::execute_validation(
-					&(#v),
-					ctx.without_field_context(),
-					Some(self)
-			  )?;
+			  if ctx.field_mask.is_none() {
+					is_valid &= ::protify::Validator::<#oneof_ident>::execute_validation(
+						&(#v),
+						ctx.without_field_context(),
+						Some(self)
+					)?;
+			  }
 			}
 		});
 
@@ -35,10 +37,15 @@ pub fn generate_oneof_validator(
 			})
 			.map(|(data, validators)| {
 				let ident = &data.ident;
+				let proto_name = &data.proto_name;
 
 				quote_spanned! {data.span=>
 				  Self::#ident(v) => {
-						#(#validators)*
+						if let Some(parent_mask) = ctx.__enter_masked_field(#proto_name) {
+							#(#validators)*
+
+							ctx.__exit_masked_field(parent_mask);
+						}
 				  }
 				}
 			});
@@ -94,8 +101,16 @@ pub fn generate_oneof_validator(
 
 	let inline_if_empty = (!has_validators).then(|| quote! { #[inline(always)] });
 
+	let field_names = variants
+		.iter()
+		.filter_map(|d| d.as_normal())
+		.map(|d| &d.proto_name);
+
 	quote! {
 	  impl ::protify::ValidatedOneof for #oneof_ident {
+			#[doc(hidden)]
+			const FIELD_NAMES: &'static [&'static str] = &[ #(#field_names),* ];
+
 			#inline_if_empty
 			fn validate_with_ctx(&self, ctx: &mut ::protify::ValidationCtx) -> ::protify::ValidationResult {
 				if !<Self as ::protify::ProtoValidation>::HAS_DEFAULT_VALIDATOR {
@@ -0,0 +1,445 @@
+use crate::*;
+
+// The generated code mirrors the output of `tonic-build`, but all of the paths
+// are fully qualified so that they don't collide with the items in the user's module,
+// which are brought into scope with a glob import.
+pub fn tonic_service_impl(
+	service_name: &str,
+	vis: &Visibility,
+	handlers: &[HandlerCtx],
+	service_comments: Option<&String>,
+) -> TokenStream2 {
+	let snake_name = to_snake_case(service_name);
+	let server_mod = format_ident!("{snake_name}_server");
+	let client_mod = format_ident!("{snake_name}_client");
+	let trait_ident = format_ident!("{service_name}");
+	let server_ident = format_ident!("{service_name}Server");
+	let client_ident = format_ident!("{service_name}Client");
+
+	let tonic = quote! { ::protify::tonic };
+	let codegen = quote! { ::protify::tonic::codegen };
+
+	let path_consts = handlers.iter().map(|handler| {
+		let HandlerCtx { name, span, .. } = handler;
+		let path_const = path_const_ident(name);
+
+		quote_spanned! {*span=>
+			pub const #path_const: &str = ::protify::__const_concat!("/", SERVICE_NAME, "/", #name);
+		}
+	});
+
+	let consts_tokens = quote! {
+		// Services in the root package have no leading dot
+		pub const SERVICE_NAME: &str = ::protify::__const_concat!(
+			super::__PROTO_FILE.package,
+			if super::__PROTO_FILE.package.is_empty() { "" } else { "." },
+			#service_name
+		);
+
+		#(#path_consts)*
+	};
+
+	let service_docs = service_comments.map(|comments| quote! { #[doc = #comments] });
+
+	let mut trait_items: Vec<TokenStream2> = Vec::new();
+	let mut server_arms: Vec<TokenStream2> = Vec::new();
+	let mut client_methods: Vec<TokenStream2> = Vec::new();
+
+	for handler in handlers {
+		let HandlerCtx {
+			name,
+			span,
+			request,
+			request_is_stream,
+			response,
+			response_is_stream,
+			deprecated,
+			comments,
+			..
+		} = handler;
+
+		let method_ident = format_ident!("{}", to_snake_case(name), span = *span);
+		let svc_ident = format_ident!("{name}Svc");
+		let stream_ident = format_ident!("{name}Stream");
+		let path_const = path_const_ident(name);
+		let docs = comments
+			.as_ref()
+			.map(|comments| quote! { #[doc = #comments] });
+
+		let request_type = if *request_is_stream {
+			quote! { #tonic::Streaming<#request> }
+		} else {
+			request.clone()
+		};
+
+		let response_type = if *response_is_stream {
+			quote! { Self::#stream_ident }
+		} else {
+			response.clone()
+		};
+
+		if *response_is_stream {
+			let stream_docs =
+				format!("Server streaming response type for the {method_ident} method.");
+
+			trait_items.push(quote_spanned! {*span=>
+				#[doc = #stream_docs]
+				type #stream_ident: #codegen::tokio_stream::Stream<
+						Item = ::core::result::Result<#response, #tonic::Status>,
+					>
+					+ ::core::marker::Send
+					+ 'static;
+			});
+		}
+
+		trait_items.push(quote_spanned! {*span=>
+			#docs
+			async fn #method_ident(
+				&self,
+				request: #tonic::Request<#request_type>,
+			) -> ::core::result::Result<#tonic::Response<#response_type>, #tonic::Status>;
+		});
+
+		let (service_trait, grpc_method) = match (*request_is_stream, *response_is_stream) {
+			(false, false) => (quote! { UnaryService }, quote! { unary }),
+			(false, true) => (
+				quote! { ServerStreamingService },
+				quote! { server_streaming },
+			),
+			(true, false) => (
+				quote! { ClientStreamingService },
+				quote! { client_streaming },
+			),
+			(true, true) => (quote! { StreamingService }, quote! { streaming }),
+		};
+
+		let (svc_response_stream, svc_future_output) = if *response_is_stream {
+			(
+				Some(quote! { type ResponseStream = T::#stream_ident; }),
+				quote! { Self::ResponseStream },
+			)
+		} else {
+			(None, quote! { Self::Response })
+		};
+
+		server_arms.push(quote_spanned! {*span=>
+			#path_const => {
+				#[allow(non_camel_case_types)]
+				struct #svc_ident<T: #trait_ident>(pub #codegen::Arc<T>);
+
+				impl<T: #trait_ident> #tonic::server::#service_trait<#request> for #svc_ident<T> {
+					type Response = #response;
+					#svc_response_stream
+					type Future = #codegen::BoxFuture<#tonic::Response<#svc_future_output>, #tonic::Status>;
+
+					fn call(&mut self, request: #tonic::Request<#request_type>) -> Self::Future {
+						let inner = #codegen::Arc::clone(&self.0);
+						let fut = async move { <T as #trait_ident>::#method_ident(&inner, request).await };
+						Box::pin(fut)
+					}
+				}
+
+				let accept_compression_encodings = self.accept_compression_encodings;
+				let send_compression_encodings = self.send_compression_encodings;
+				let max_decoding_message_size = self.max_decoding_message_size;
+				let max_encoding_message_size = self.max_encoding_message_size;
+				let inner = self.inner.clone();
+
+				let fut = async move {
+					// Picks up the validation settings from the `ValidationLayer`, if present
+					let codec = ::protify::ValidatingCodec::from_extensions(req.extensions());
+					let method = #svc_ident(inner);
+					let mut grpc = #tonic::server::Grpc::new(codec)
+						.apply_compression_config(accept_compression_encodings, send_compression_encodings)
+						.apply_max_message_size_config(max_decoding_message_size, max_encoding_message_size);
+					let res = grpc.#grpc_method(method, req).await;
+					Ok(res)
+				};
+
+				Box::pin(fut)
+			}
+		});
+
+		let client_request_type = if *request_is_stream {
+			quote! { impl #tonic::IntoStreamingRequest<Message = #request> }
+		} else {
+			quote! { impl #tonic::IntoRequest<#request> }
+		};
+
+		let into_request = if *request_is_stream {
+			quote! { into_streaming_request }
+		} else {
+			quote! { into_request }
+		};
+
+		let client_response_type = if *response_is_stream {
+			quote! { #tonic::codec::Streaming<#response> }
+		} else {
+			response.clone()
+		};
+
+		let deprecated_attr = deprecated.then(|| quote! { #[deprecated] });
+
+		client_methods.push(quote_spanned! {*span=>
+			#docs
+			#deprecated_attr
+			pub async fn #method_ident(
+				&mut self,
+				request: #client_request_type,
+			) -> ::core::result::Result<#tonic::Response<#client_response_type>, #tonic::Status> {
+				self.inner.ready().await.map_err(|e| {
+					#tonic::Status::unknown(::protify::alloc::format!("Service was not ready: {}", e.into()))
+				})?;
+				let codec = ::protify::tonic_prost::ProstCodec::default();
+				let path = #codegen::http::uri::PathAndQuery::from_static(#path_const);
+				let mut req = request.#into_request();
+				req.extensions_mut()
+					.insert(#codegen::GrpcMethod::new(SERVICE_NAME, #name));
+				self.inner.#grpc_method(req, path, codec).await
+			}
+		});
+	}
+
+	quote! {
+		#[doc = concat!("Generated server implementations for the `", #service_name, "` service.")]
+		#vis mod #server_mod {
+			#![allow(
+				unused_variables,
+				dead_code,
+				missing_docs,
+				clippy::all,
+				clippy::pedantic,
+				clippy::nursery
+			)]
+
+			use super::*;
+
+			#consts_tokens
+
+			#service_docs
+			#[#codegen::async_trait]
+			pub trait #trait_ident: ::core::marker::Send + ::core::marker::Sync + 'static {
+				#(#trait_items)*
+			}
+
+			#service_docs
+			#[derive(Debug)]
+			pub struct #server_ident<T> {
+				inner: #codegen::Arc<T>,
+				accept_compression_encodings: #codegen::EnabledCompressionEncodings,
+				send_compression_encodings: #codegen::EnabledCompressionEncodings,
+				max_decoding_message_size: ::core::option::Option<usize>,
+				max_encoding_message_size: ::core::option::Option<usize>,
+			}
+
+			impl<T> #server_ident<T> {
+				pub fn new(inner: T) -> Self {
+					Self::from_arc(#codegen::Arc::new(inner))
+				}
+
+				pub fn from_arc(inner: #codegen::Arc<T>) -> Self {
+					Self {
+						inner,
+						accept_compression_encodings: Default::default(),
+						send_compression_encodings: Default::default(),
+						max_decoding_message_size: None,
+						max_encoding_message_size: None,
+					}
+				}
+
+				pub fn with_interceptor<F>(inner: T, interceptor: F) -> #codegen::InterceptedService<Self, F>
+				where
+					F: #tonic::service::Interceptor,
+				{
+					#codegen::InterceptedService::new(Self::new(inner), interceptor)
+				}
+
+				/// Enable decompressing requests with the given encoding.
+				#[must_use]
+				pub fn accept_compressed(mut self, encoding: #codegen::CompressionEncoding) -> Self {
+					self.accept_compression_encodings.enable(encoding);
+					self
+				}
+
+				/// Compress responses with the given encoding, if the client supports it.
+				#[must_use]
+				pub fn send_compressed(mut self, encoding: #codegen::CompressionEncoding) -> Self {
+					self.send_compression_encodings.enable(encoding);
+					self
+				}
+
+				/// Limits the maximum size of a decoded message.
+				///
+				/// Default: `4MB`
+				#[must_use]
+				pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+					self.max_decoding_message_size = Some(limit);
+					self
+				}
+
+				/// Limits the maximum size of an encoded message.
+				///
+				/// Default: `usize::MAX`
+				#[must_use]
+				pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+					self.max_encoding_message_size = Some(limit);
+					self
+				}
+			}
+
+			impl<T, B> #codegen::Service<#codegen::http::Request<B>> for #server_ident<T>
+			where
+				T: #trait_ident,
+				B: #codegen::Body + ::core::marker::Send + 'static,
+				B::Error: Into<#codegen::StdError> + ::core::marker::Send + 'static,
+			{
+				type Response = #codegen::http::Response<#tonic::body::Body>;
+				type Error = ::core::convert::Infallible;
+				type Future = #codegen::BoxFuture<Self::Response, Self::Error>;
+
+				fn poll_ready(
+					&mut self,
+					_cx: &mut #codegen::Context<'_>,
+				) -> #codegen::Poll<::core::result::Result<(), Self::Error>> {
+					#codegen::Poll::Ready(Ok(()))
+				}
+
+				fn call(&mut self, req: #codegen::http::Request<B>) -> Self::Future {
+					match req.uri().path() {
+						#(#server_arms)*
+						_ => Box::pin(async move {
+							let mut response = #codegen::http::Response::new(#tonic::body::Body::default());
+							let headers = response.headers_mut();
+							headers.insert(
+								#tonic::Status::GRPC_STATUS,
+								(#tonic::Code::Unimplemented as i32).into(),
+							);
+							headers.insert(
+								#codegen::http::header::CONTENT_TYPE,
+								#tonic::metadata::GRPC_CONTENT_TYPE,
+							);
+							Ok(response)
+						}),
+					}
+				}
+			}
+
+			impl<T> Clone for #server_ident<T> {
+				fn clone(&self) -> Self {
+					let inner = self.inner.clone();
+					Self {
+						inner,
+						accept_compression_encodings: self.accept_compression_encodings,
+						send_compression_encodings: self.send_compression_encodings,
+						max_decoding_message_size: self.max_decoding_message_size,
+						max_encoding_message_size: self.max_encoding_message_size,
+					}
+				}
+			}
+
+			impl<T> #tonic::server::NamedService for #server_ident<T> {
+				const NAME: &'static str = SERVICE_NAME;
+			}
+		}
+
+		#[doc = concat!("Generated client implementations for the `", #service_name, "` service.")]
+		#vis mod #client_mod {
+			#![allow(
+				unused_variables,
+				dead_code,
+				missing_docs,
+				clippy::all,
+				clippy::pedantic,
+				clippy::nursery
+			)]
+
+			use super::*;
+
+			#consts_tokens
+
+			#service_docs
+			#[derive(Debug, Clone)]
+			pub struct #client_ident<T> {
+				inner: #tonic::client::Grpc<T>,
+			}
+
+			impl<T> #client_ident<T>
+			where
+				T: #tonic::client::GrpcService<#tonic::body::Body>,
+				T::Error: Into<#codegen::StdError>,
+				T::ResponseBody: #codegen::Body<Data = #codegen::Bytes> + ::core::marker::Send + 'static,
+				<T::ResponseBody as #codegen::Body>::Error: Into<#codegen::StdError> + ::core::marker::Send,
+			{
+				pub fn new(inner: T) -> Self {
+					let inner = #tonic::client::Grpc::new(inner);
+					Self { inner }
+				}
+
+				pub fn with_origin(inner: T, origin: #codegen::http::Uri) -> Self {
+					let inner = #tonic::client::Grpc::with_origin(inner, origin);
+					Self { inner }
+				}
+
+				pub fn with_interceptor<F>(
+					inner: T,
+					interceptor: F,
+				) -> #client_ident<#codegen::InterceptedService<T, F>>
+				where
+					F: #tonic::service::Interceptor,
+					T::ResponseBody: Default,
+					T: #codegen::Service<
+						#codegen::http::Request<#tonic::body::Body>,
+						Response = #codegen::http::Response<
+							<T as #tonic::client::GrpcService<#tonic::body::Body>>::ResponseBody,
+						>,
+					>,
+					<T as #codegen::Service<#codegen::http::Request<#tonic::body::Body>>>::Error:
+						Into<#codegen::StdError> + ::core::marker::Send + ::core::marker::Sync,
+				{
+					#client_ident::new(#codegen::InterceptedService::new(inner, interceptor))
+				}
+
+				/// Compress requests with the given encoding.
+				///
+				/// This requires the server to support it otherwise it might respond with an
+				/// error.
+				#[must_use]
+				pub fn send_compressed(mut self, encoding: #codegen::CompressionEncoding) -> Self {
+					self.inner = self.inner.send_compressed(encoding);
+					self
+				}
+
+				/// Enable decompressing responses.
+				#[must_use]
+				pub fn accept_compressed(mut self, encoding: #codegen::CompressionEncoding) -> Self {
+					self.inner = self.inner.accept_compressed(encoding);
+					self
+				}
+
+				/// Limits the maximum size of a decoded message.
+				///
+				/// Default: `4MB`
+				#[must_use]
+				pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+					self.inner = self.inner.max_decoding_message_size(limit);
+					self
+				}
+
+				/// Limits the maximum size of an encoded message.
+				///
+				/// Default: `usize::MAX`
+				#[must_use]
+				pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+					self.inner = self.inner.max_encoding_message_size(limit);
+					self
+				}
+
+				#(#client_methods)*
+			}
+		}
+	}
+}
+
+fn path_const_ident(handler_name: &str) -> Ident {
+	format_ident!("{}_PATH", to_upper_snake_case(handler_name))
+}
@@ -28,10 +28,11 @@ use syn::{
 use syn_utils::*;
 
 use crate::{
-	enum_proc_macro::*, extension_macro::*, file_macro::*, impls::*, internals::*,
+	cel_checks::*, enum_proc_macro::*, extension_macro::*, file_macro::*, impls::*, internals::*,
 	message_proc_macro::*, oneof_proc_macro::*, package_macro::*, service_macro::*,
 };
 
+mod cel_checks;
 #[cfg(feature = "cel")]
 mod cel_try_into;
 mod enum_proc_macro;
@@ -123,6 +124,15 @@ pub fn impl_known_type(input: TokenStream) -> TokenStream {
 	}
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __cel_expression(input: TokenStream) -> TokenStream {
+	match cel_expression_macro(input.into()) {
+		Ok(output) => output.into(),
+		Err(e) => e.into_compile_error().into(),
+	}
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn builder_state_macro(input: TokenStream) -> TokenStream {
@@ -231,10 +241,15 @@ pub fn extension_derive(_input: TokenStream) -> TokenStream {
 
 #[doc = include_str!("../docs/service_macro.md")]
 #[proc_macro_attribute]
-pub fn proto_service(_args: TokenStream, input: TokenStream) -> TokenStream {
+pub fn proto_service(args: TokenStream, input: TokenStream) -> TokenStream {
 	let item = parse_macro_input!(input as ItemEnum);
 
-	let output = match process_service_macro(&item) {
+	let macro_args = match ServiceMacroArgs::parse(args.into()) {
+		Ok(args) => args,
+		Err(e) => return e.to_compile_error().into(),
+	};
+
+	let output = match process_service_macro(&item, macro_args) {
 		Ok(output) => output,
 		Err(e) => return e.to_compile_error().into(),
 	};
@@ -30,6 +30,27 @@ pub fn message_proc_macro(mut item: ItemStruct, macro_attrs: TokenStream2) -> To
 	)
 	.unwrap_or_default_and_push_error(&mut errors);
 
+	let tag_lock = TagLock::load(&message_attrs.name, message_attrs.parent_message.as_ref())
+		.unwrap_or_default_and_push_error(&mut errors);
+
+	// Oneofs are skipped, since their tags are always set manually
+	if let Some(tag_lock) = &tag_lock
+		&& let Err(e) = tag_lock.apply(
+			fields_data
+				.iter_mut()
+				.filter_map(|data| match data {
+					FieldDataKind::Normal(data) if !data.proto_field.is_oneof() => {
+						Some((data.proto_name.as_str(), &mut data.tag, data.span))
+					}
+					_ => None,
+				}),
+			&mut manually_set_tags,
+			&message_attrs.reserved_numbers,
+			item.ident.span(),
+		) {
+		errors.push(e);
+	}
+
 	let used_ranges =
 		build_unavailable_ranges(&message_attrs.reserved_numbers, &mut manually_set_tags)
 			.unwrap_or_default_and_push_error(&mut errors);
@@ -58,6 +79,26 @@ pub fn message_proc_macro(mut item: ItemStruct, macro_attrs: TokenStream2) -> To
 	.process_fields_data()
 	.unwrap_or_default_and_push_error(&mut errors);
 
+	let lockfile_tracking = tag_lock.map(|tag_lock| {
+		let proto_struct_ident = proto_struct
+			.as_ref()
+			.map_or(&item.ident, |ps| &ps.ident);
+
+		tag_lock.check_tokens(
+			&item.ident,
+			&quote! { <#proto_struct_ident as ::protify::ProtoMessage>::proto_schema() },
+		)
+	});
+
+	// The Debug impl from prost gets replaced if some fields must be redacted
+	let has_redacted_fields = errors.is_empty()
+		&& fields_data
+			.iter()
+			.filter_map(|data| data.as_normal())
+			.any(|data| data.standard_options.debug_redact);
+
+	let skip_debug_attr = has_redacted_fields.then(|| quote! { #[prost(skip_debug)] });
+
 	let proto_derives = if !errors.is_empty() {
 		fallback_derive_impls(&struct_to_process.ident, ItemKind::Message)
 	} else if cfg!(feature = "cel") {
@@ -66,15 +107,27 @@ pub fn message_proc_macro(mut item: ItemStruct, macro_attrs: TokenStream2) -> To
 		  #[allow(clippy::derive_partial_eq_without_eq)]
 		  #[derive(::protify::prost::Message, Clone, PartialEq, ::protify::CelValue)]
 		  #[prost(prost_path = "::protify::prost")]
+		  #skip_debug_attr
 		}
 	} else {
 		quote! {
 		  #[allow(clippy::derive_partial_eq_without_eq)]
 		  #[derive(::protify::prost::Message, Clone, PartialEq)]
 		  #[prost(prost_path = "::protify::prost")]
+		  #skip_debug_attr
 		}
 	};
 
+	// The Debug derive of a proxy is replaced as well
+	let proxy_debug_impl = if has_redacted_fields
+		&& is_proxied
+		&& take_debug_derive(&mut item.attrs).unwrap_or_default_and_push_error(&mut errors)
+	{
+		Some(redacted_struct_debug_impl(&item.ident, &fields_data))
+	} else {
+		None
+	};
+
 	if !errors.is_empty() {
 		// This will trigger all of the fallback impls that expand to unimplemented!
 		fields_data.clear();
@@ -106,6 +159,7 @@ pub fn message_proc_macro(mut item: ItemStruct, macro_attrs: TokenStream2) -> To
 			kind: ItemKind::Message,
 			container_attrs: ContainerAttrs::Message(&message_attrs),
 			fields: &fields_data,
+			is_fallible: message_attrs.is_fallible,
 		}
 		.generate_proto_conversions();
 
@@ -135,6 +189,11 @@ pub fn message_proc_macro(mut item: ItemStruct, macro_attrs: TokenStream2) -> To
 		}
 	};
 
+	// If there are errors, the fields may have been cleared
+	let cel_checks = errors
+		.is_empty()
+		.then(|| cel_field_checks(&message_attrs.validators, &fields_data, &mut errors));
+
 	let message_ctx = MessageCtx {
 		proto_struct_ident: proto_struct
 			.as_ref()
@@ -147,8 +206,18 @@ pub fn message_proc_macro(mut item: ItemStruct, macro_attrs: TokenStream2) -> To
 	let consistency_checks = errors
 		.is_empty()
 		.then(|| message_ctx.generate_consistency_checks());
+	let features_check = message_ctx.generate_features_check();
 	let validator_impl = message_ctx.generate_validator();
 	let schema_impls = message_ctx.generate_schema_impls();
+	let debug_impl = has_redacted_fields.then(|| {
+		redacted_struct_debug_impl(
+			message_ctx.proto_struct_ident,
+			message_ctx
+				.fields_data
+				.iter()
+				.filter(|data| data.as_normal().is_some()),
+		)
+	});
 
 	let wrapped_items = wrap_multiple_with_imports(&[schema_impls, validator_impl]);
 
@@ -157,7 +226,12 @@ pub fn message_proc_macro(mut item: ItemStruct, macro_attrs: TokenStream2) -> To
 	quote! {
 	  #main_struct_tokens
 	  #wrapped_items
+	  #debug_impl
+	  #proxy_debug_impl
 	  #consistency_checks
+	  #features_check
+	  #cel_checks
+	  #lockfile_tracking
 	  #(#errors)*
 	}
 }
@@ -168,6 +242,47 @@ pub struct MessageCtx<'a> {
 	pub message_attrs: &'a MessageAttrs,
 }
 
+impl MessageCtx<'_> {
+	// Features cannot be used in proto3 files, including those of the oneofs used by the message
+	pub fn generate_features_check(&self) -> Option<TokenStream2> {
+		let fields = self
+			.fields_data
+			.iter()
+			.filter_map(|data| data.as_normal());
+
+		let features_span = self
+			.message_attrs
+			.features_span
+			.or_else(|| fields.clone().find_map(|data| data.features_span));
+
+		let oneof_paths: Vec<&Path> = fields
+			.filter_map(|data| {
+				if let ProtoField::Oneof(OneofInfo { path, .. }) = &data.proto_field {
+					Some(path)
+				} else {
+					None
+				}
+			})
+			.collect();
+
+		if features_span.is_none() && oneof_paths.is_empty() {
+			return None;
+		}
+
+		let has_features = if features_span.is_some() {
+			quote! { true }
+		} else {
+			quote! { #(<#oneof_paths as ::protify::ProtoOneof>::__HAS_FEATURES)||* }
+		};
+
+		Some(Features::edition_check(
+			self.message_attrs.file.as_ref(),
+			features_span.unwrap_or_else(|| self.proto_struct_ident.span()),
+			&has_features,
+		))
+	}
+}
+
 #[derive(Default)]
 pub struct FieldsCtx {
 	pub fields_data: Vec<FieldDataKind>,
@@ -209,6 +324,15 @@ impl ContainerAttrs<'_> {
 		}
 	}
 
+	// Oneofs are not supported, since the name of the
+	// variants would be used as the tag of the enum
+	pub const fn derives_serde(&self) -> bool {
+		match self {
+			ContainerAttrs::Message(message_attrs) => message_attrs.derives_serde,
+			ContainerAttrs::Oneof(_) => false,
+		}
+	}
+
 	pub const fn has_custom_conversions(&self) -> bool {
 		match self {
 			ContainerAttrs::Message(message_attrs) => message_attrs.has_custom_conversions(),
@@ -267,6 +391,12 @@ where
 				dst_field.inject_attr(parse_quote!(#[#attr]));
 			}
 
+			if let Some(json_name) = &field_data.standard_options.json_name
+				&& container_attrs.derives_serde()
+			{
+				dst_field.inject_attr(parse_quote!(#[serde(rename = #json_name)]));
+			}
+
 			if !field_data.proto_field.is_oneof() && field_data.tag.is_none() {
 				// Tag allocator being missing implies this being a oneof
 				if let Some(tag_allocator) = tag_allocator.as_mut() {
@@ -65,6 +65,15 @@ pub fn process_oneof_proc_macro(mut item: ItemEnum, macro_attrs: TokenStream2) -
 	.process_fields_data()
 	.unwrap_or_default_and_push_error(&mut errors);
 
+	// The Debug impl from prost gets replaced if some variants must be redacted
+	let has_redacted_variants = errors.is_empty()
+		&& fields_data
+			.iter()
+			.filter_map(|data| data.as_normal())
+			.any(|data| data.standard_options.debug_redact);
+
+	let skip_debug_attr = has_redacted_variants.then(|| quote! { #[prost(skip_debug)] });
+
 	let proto_derives = if !errors.is_empty() {
 		fallback_derive_impls(&enum_to_process.ident, ItemKind::Oneof)
 	} else if cfg!(feature = "cel") {
@@ -73,15 +82,36 @@ pub fn process_oneof_proc_macro(mut item: ItemEnum, macro_attrs: TokenStream2) -
 		  #[allow(clippy::derive_partial_eq_without_eq)]
 		  #[derive(::protify::prost::Oneof, Clone, PartialEq, ::protify::CelOneof)]
 		  #[prost(prost_path = "::protify::prost")]
+		  #skip_debug_attr
 		}
 	} else {
 		quote! {
 		  #[allow(clippy::derive_partial_eq_without_eq)]
 		  #[derive(::protify::prost::Oneof, Clone, PartialEq)]
 		  #[prost(prost_path = "::protify::prost")]
+		  #skip_debug_attr
 		}
 	};
 
+	let debug_impl = has_redacted_variants.then(|| {
+		redacted_oneof_debug_impl(
+			&enum_to_process.ident,
+			fields_data
+				.iter()
+				.filter(|data| data.as_normal().is_some()),
+		)
+	});
+
+	// The Debug derive of a proxy is replaced as well
+	let proxy_debug_impl = if has_redacted_variants
+		&& is_proxied
+		&& take_debug_derive(&mut item.attrs).unwrap_or_default_and_push_error(&mut errors)
+	{
+		Some(redacted_oneof_debug_impl(&item.ident, &fields_data))
+	} else {
+		None
+	};
+
 	if !errors.is_empty() {
 		// This will trigger all of the fallback impls that expand to unimplemented!
 		fields_data.clear();
@@ -110,6 +140,7 @@ pub fn process_oneof_proc_macro(mut item: ItemEnum, macro_attrs: TokenStream2) -
 			kind: ItemKind::Oneof,
 			container_attrs: ContainerAttrs::Oneof(&oneof_attrs),
 			fields: &fields_data,
+			is_fallible: oneof_attrs.is_fallible,
 		}
 		.generate_proto_conversions();
 
@@ -161,6 +192,8 @@ pub fn process_oneof_proc_macro(mut item: ItemEnum, macro_attrs: TokenStream2) -
 
 	quote! {
 	  #main_enum_tokens
+	  #debug_impl
+	  #proxy_debug_impl
 	  #wrapped_items
 	  #consistency_checks_impl
 	  #(#errors)*
@@ -0,0 +1,36 @@
+use crate::*;
+
+/// Collects the content of the `#[doc]` attributes of an item, so that they can be rendered as comments in the protobuf schema.
+pub fn extract_doc_comments<'a>(attrs: impl IntoIterator<Item = &'a Attribute>) -> Option<String> {
+	let mut lines: Vec<String> = Vec::new();
+
+	for attr in attrs {
+		if let Meta::NameValue(nv) = &attr.meta
+			&& nv.path.is_ident("doc")
+			&& let Expr::Lit(syn::ExprLit {
+				lit: Lit::Str(lit), ..
+			}) = &nv.value
+		{
+			let content = lit.value();
+
+			for line in content.lines() {
+				// Strips the space that is added after `///`
+				let line = line.strip_prefix(' ').unwrap_or(line);
+
+				lines.push(line.trim_end().to_string());
+			}
+		}
+	}
+
+	while lines.last().is_some_and(|line| line.is_empty()) {
+		lines.pop();
+	}
+
+	let first_line = lines.iter().position(|line| !line.is_empty())?;
+
+	Some(lines[first_line..].join("\n"))
+}
+
+pub fn comments_tokens(comments: Option<&String>) -> Option<TokenStream2> {
+	comments.map(|comments| quote! { .comments(#comments.into()) })
+}
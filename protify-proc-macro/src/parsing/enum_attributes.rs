@@ -10,6 +10,8 @@ pub struct EnumAttrs {
 	pub deprecated: bool,
 	pub file: Option<Ident>,
 	pub module_path: Option<TokenStream2>,
+	pub comments: Option<String>,
+	pub features_span: Option<Span>,
 }
 
 pub fn process_derive_enum_attrs(
@@ -19,6 +21,7 @@ pub fn process_derive_enum_attrs(
 	let mut reserved_names: Vec<String> = Vec::new();
 	let mut reserved_numbers = ReservedNumbers::default();
 	let mut options = TokenStreamOr::new(|_| quote! { [] });
+	let mut features: Option<Features> = None;
 	let mut proto_name: Option<ParsedStr> = None;
 	let mut parent_message: Option<Ident> = None;
 	let mut deprecated = false;
@@ -69,6 +72,9 @@ pub fn process_derive_enum_attrs(
 							options.span = meta.input.span();
 							options.set(meta.expr_value()?.into_token_stream());
 						}
+						"features" => {
+							features = Some(Features::parse(&meta, FeatureTarget::Enum)?);
+						}
 						"name" => {
 							proto_name = Some(meta.parse_value::<ParsedStr>()?);
 						}
@@ -82,6 +88,12 @@ pub fn process_derive_enum_attrs(
 		}
 	}
 
+	let features_span = features.as_ref().map(|features| features.span);
+
+	if let Some(features) = features {
+		features.apply(&mut options);
+	}
+
 	let name = proto_name.unwrap_or_else(|| ParsedStr::with_default_span(enum_ident.to_string()));
 
 	Ok(EnumAttrs {
@@ -93,5 +105,7 @@ pub fn process_derive_enum_attrs(
 		deprecated,
 		file,
 		module_path,
+		comments: extract_doc_comments(attrs),
+		features_span,
 	})
 }
@@ -7,6 +7,7 @@ pub struct ExtensionFieldAttrs {
 	pub proto_name: String,
 	pub proto_field: ProtoField,
 	pub deprecated: bool,
+	pub comments: Option<String>,
 }
 
 pub fn process_extension_field_attrs(field: &Field) -> Result<ExtensionFieldAttrs, Error> {
@@ -93,5 +94,6 @@ pub fn process_extension_field_attrs(field: &Field) -> Result<ExtensionFieldAttr
 		proto_name: name.unwrap_or_else(|| rust_ident_to_proto_name(&field_ident)),
 		proto_field,
 		deprecated,
+		comments: extract_doc_comments(&field.attrs),
 	})
 }
@@ -0,0 +1,202 @@
+use crate::*;
+
+// The kinds of items that can have features
+#[derive(Clone, Copy)]
+pub enum FeatureTarget {
+	File,
+	Message,
+	Field,
+	Enum,
+	Oneof,
+}
+
+impl FeatureTarget {
+	const fn name(self) -> &'static str {
+		match self {
+			Self::File => "files",
+			Self::Message => "messages",
+			Self::Field => "fields",
+			Self::Enum => "enums",
+			Self::Oneof => "oneofs",
+		}
+	}
+
+	// Mirrors the targets of the features in `descriptor.proto`, so that
+	// the errors that protoc would emit are caught at compile time
+	fn allows(self, feature: &str) -> bool {
+		match feature {
+			"field_presence"
+			| "repeated_field_encoding"
+			| "utf8_validation"
+			| "message_encoding" => {
+				matches!(self, Self::File | Self::Field)
+			}
+			"enum_type" => matches!(self, Self::File | Self::Enum),
+			"json_format" => matches!(self, Self::File | Self::Message | Self::Enum),
+			// The targets of the Rust features are checked by protoc
+			_ => true,
+		}
+	}
+}
+
+pub struct Features {
+	pub span: Span,
+	setters: Vec<TokenStream2>,
+}
+
+impl Features {
+	pub fn parse(nested_meta: &ParseNestedMeta, target: FeatureTarget) -> syn::Result<Self> {
+		let mut setters: Vec<TokenStream2> = Vec::new();
+
+		nested_meta.parse_nested_meta(|meta| {
+			let ident_str = meta.ident_str()?;
+
+			if !target.allows(&ident_str) {
+				return Err(
+					meta.error(format!("`{ident_str}` cannot be used in {}", target.name()))
+				);
+			}
+
+			let enum_ident = match ident_str.as_str() {
+				"field_presence" => "FieldPresence",
+				"enum_type" => "EnumType",
+				"repeated_field_encoding" => "RepeatedFieldEncoding",
+				"utf8_validation" => "Utf8Validation",
+				"message_encoding" => "MessageEncoding",
+				"json_format" => "JsonFormat",
+				"enforce_naming_style" => "EnforceNamingStyle",
+				"rust" => {
+					let mut rust_features: Vec<TokenStream2> = Vec::new();
+
+					meta.parse_nested_meta(|rust_meta| {
+						let name = rust_meta.ident_str()?;
+						let value = rust_meta.expr_value()?;
+
+						rust_features.push(quote! { ::protify::proto_option!(#name => #value) });
+
+						Ok(())
+					})?;
+
+					setters.push(quote! { .rust(::protify::vec![ #(#rust_features),* ]) });
+
+					return Ok(());
+				}
+				_ => return Err(meta.error("Unknown feature")),
+			};
+
+			let setter = meta.path.require_ident()?;
+			let enum_ident = format_ident!("{enum_ident}");
+			let variant = meta.parse_value::<Ident>()?;
+
+			setters.push(quote! { .#setter(::protify::#enum_ident::#variant) });
+
+			Ok(())
+		})?;
+
+		Ok(Self {
+			span: nested_meta.path.span(),
+			setters,
+		})
+	}
+
+	// The edition of the file is only known after the expansion, so it's checked with a const assertion
+	pub fn edition_check(
+		file: Option<&Ident>,
+		span: Span,
+		has_features: &TokenStream2,
+	) -> TokenStream2 {
+		let edition = if let Some(file) = file {
+			quote! { <#file as ::protify::FileSchema>::EDITION }
+		} else {
+			quote! { __PROTO_FILE.edition }
+		};
+
+		quote_spanned! {span=>
+			const _: () = ::protify::__check_features_edition(#edition, #has_features);
+		}
+	}
+
+	// Appends the features to the options of the item
+	pub fn apply(self, options: &mut TokenStreamOr) {
+		let Self { setters, .. } = self;
+
+		let current_options = options.to_token_stream();
+
+		options.set(quote! {
+			::protify::__with_features(
+				#current_options,
+				::protify::FeatureSet::builder()#(#setters)*.build()
+			)
+		});
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use syn::parse::Parser;
+
+	fn parse(tokens: TokenStream2, target: FeatureTarget) -> syn::Result<()> {
+		syn::meta::parser(|meta| Features::parse(&meta, target).map(|_| ())).parse2(tokens)
+	}
+
+	#[test]
+	fn feature_targets() {
+		assert!(
+			parse(
+				quote! { features(field_presence = Implicit) },
+				FeatureTarget::File
+			)
+			.is_ok()
+		);
+		assert!(
+			parse(
+				quote! { features(field_presence = Implicit, utf8_validation = None) },
+				FeatureTarget::Field
+			)
+			.is_ok()
+		);
+		assert!(
+			parse(
+				quote! { features(json_format = Allow) },
+				FeatureTarget::Message
+			)
+			.is_ok()
+		);
+		assert!(
+			parse(
+				quote! { features(enforce_naming_style = StyleLegacy) },
+				FeatureTarget::Oneof
+			)
+			.is_ok()
+		);
+
+		assert_eq!(
+			parse(
+				quote! { features(field_presence = Implicit) },
+				FeatureTarget::Message
+			)
+			.unwrap_err()
+			.to_string(),
+			"`field_presence` cannot be used in messages"
+		);
+		assert_eq!(
+			parse(
+				quote! { features(utf8_validation = None) },
+				FeatureTarget::Oneof
+			)
+			.unwrap_err()
+			.to_string(),
+			"`utf8_validation` cannot be used in oneofs"
+		);
+		assert_eq!(
+			parse(
+				quote! { features(enum_type = Closed) },
+				FeatureTarget::Field
+			)
+			.unwrap_err()
+			.to_string(),
+			"`enum_type` cannot be used in fields"
+		);
+	}
+}
@@ -5,6 +5,8 @@ mod proto_types;
 pub use proto_types::*;
 mod proto_map;
 pub use proto_map::*;
+mod standard_options;
+pub use standard_options::*;
 
 use crate::*;
 
@@ -23,6 +25,10 @@ pub struct FieldData {
 	pub into_proto: Option<PathOrClosure>,
 	pub deprecated: bool,
 	pub forwarded_attrs: Vec<Meta>,
+	pub comments: Option<String>,
+	pub standard_options: StandardFieldOptions,
+	pub sensitive: bool,
+	pub features_span: Option<Span>,
 }
 
 impl FieldData {
@@ -76,9 +82,12 @@ pub fn process_field_data(field: FieldOrVariant) -> Result<FieldDataKind, Error>
 	let mut validators = Validators::default();
 	let mut tag: Option<ParsedNum> = None;
 	let mut options = TokenStreamOr::new(|_| quote! { [] });
+	let mut features: Option<Features> = None;
+	let mut standard_options = StandardFieldOptions::default();
 	let mut name: Option<String> = None;
 	let mut proto_field: Option<ProtoField> = None;
 	let mut is_ignored = false;
+	let mut sensitive = false;
 	let mut from_proto: Option<PathOrClosure> = None;
 	let mut into_proto: Option<PathOrClosure> = None;
 	let mut deprecated = false;
@@ -115,6 +124,9 @@ pub fn process_field_data(field: FieldOrVariant) -> Result<FieldDataKind, Error>
 							options.span = meta.input.span();
 							options.set(meta.expr_value()?.into_token_stream());
 						}
+						"features" => {
+							features = Some(Features::parse(&meta, FeatureTarget::Field)?);
+						}
 						"tag" => {
 							tag = Some(meta.parse_value::<ParsedNum>()?);
 						}
@@ -133,9 +145,16 @@ pub fn process_field_data(field: FieldOrVariant) -> Result<FieldDataKind, Error>
 						"ignore" => {
 							is_ignored = true;
 						}
+						"sensitive" => {
+							sensitive = true;
+							standard_options.debug_redact = true;
+						}
 
 						_ => {
-							proto_field = Some(ProtoField::from_meta(&ident, &meta, &type_info)?);
+							if !standard_options.parse(&ident, &meta)? {
+								proto_field =
+									Some(ProtoField::from_meta(&ident, &meta, &type_info)?);
+							}
 						}
 					};
 
@@ -146,6 +165,14 @@ pub fn process_field_data(field: FieldOrVariant) -> Result<FieldDataKind, Error>
 		}
 	}
 
+	standard_options.apply(&mut options);
+
+	let features_span = features.as_ref().map(|features| features.span);
+
+	if let Some(features) = features {
+		features.apply(&mut options);
+	}
+
 	if is_ignored {
 		return Ok(FieldDataKind::Ignored {
 			from_proto,
@@ -219,6 +246,8 @@ pub fn process_field_data(field: FieldOrVariant) -> Result<FieldDataKind, Error>
 		}
 	};
 
+	standard_options.check(&proto_field, field_span)?;
+
 	// We don't add the default validator if we had a closure
 	// because those handle the default validator logic on thier own
 	if !validators.has_closure_validator
@@ -253,6 +282,10 @@ pub fn process_field_data(field: FieldOrVariant) -> Result<FieldDataKind, Error>
 		type_info,
 		deprecated,
 		forwarded_attrs,
+		comments: extract_doc_comments(field.attributes()),
+		standard_options,
+		sensitive,
+		features_span,
 	}))
 }
 
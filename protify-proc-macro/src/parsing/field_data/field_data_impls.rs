@@ -67,7 +67,13 @@ impl FieldData {
 			ProtoField::Repeated(proto_type) => {
 				let p_type = proto_type.as_prost_attr_type(self.span);
 
-				quote_spanned! {self.span=> #p_type, repeated }
+				// Packed is the default for proto3, so we only
+				// need to change the encoding if it's disabled
+				if self.standard_options.packed == Some(false) {
+					quote_spanned! {self.span=> #p_type, repeated, packed = "false" }
+				} else {
+					quote_spanned! {self.span=> #p_type, repeated }
+				}
 			}
 			ProtoField::Optional(proto_type) => {
 				let p_type = proto_type.as_prost_attr_type(self.span);
@@ -266,6 +266,55 @@ impl ProtoField {
 		}
 	}
 
+	// Used by fallible proxies. The output evaluates to `Option<T>`, where `None` means that the violations
+	// have been added to the `ctx`. Returns `None` if the conversion cannot fail.
+	pub fn fallible_from_proto(&self, base_ident: &TokenStream2) -> Option<TokenStream2> {
+		let span = base_ident.span();
+		let base_ident2 = quote_spanned! {span=> v };
+
+		match self {
+			Self::Oneof(OneofInfo { default, .. }) => Some(if *default {
+				quote_spanned! {span=> ::protify::__try_convert_required_oneof(&mut ctx, #base_ident) }
+			} else {
+				quote_spanned! {span=> ::protify::__try_convert_optional_oneof(&mut ctx, #base_ident) }
+			}),
+			Self::Map(ProtoMap { values, .. }) => {
+				let values_converter = values.fallible_from_proto(&base_ident2)?;
+
+				Some(quote_spanned! {span=>
+				  ::protify::__try_convert_map(&mut ctx, #base_ident, |v| #values_converter)
+				})
+			}
+			Self::Repeated(proto_type) => {
+				let inner = proto_type.fallible_from_proto(&base_ident2)?;
+
+				Some(quote_spanned! {span=>
+				  ::protify::__try_convert_repeated(&mut ctx, #base_ident, |v| #inner)
+				})
+			}
+			Self::Optional(proto_type) => {
+				let inner = proto_type.fallible_from_proto(&base_ident2)?;
+
+				Some(quote_spanned! {span=>
+				  ::protify::__try_convert_optional(&mut ctx, #base_ident, |v| #inner)
+				})
+			}
+			// Messages with `default` are required in the proxy
+			Self::Single(proto_type @ ProtoType::Message(MessageInfo { default: true, .. })) => {
+				let inner = proto_type.fallible_from_proto(&base_ident2)?;
+
+				Some(quote_spanned! {span=>
+				  ::protify::__try_convert_required(&mut ctx, #base_ident, |v| #inner)
+				})
+			}
+			Self::Single(proto_type) => {
+				let inner = proto_type.fallible_from_proto(base_ident)?;
+
+				Some(quote_spanned! {span=> ::protify::__try_convert(&mut ctx, #inner) })
+			}
+		}
+	}
+
 	pub const fn message_info(&self) -> Option<&MessageInfo> {
 		match self {
 			Self::Map(map) => map.values.as_message(),
@@ -37,6 +37,19 @@ impl ProtoType {
 		)
 	}
 
+	// Only scalar numeric types (and enums) can use the packed encoding
+	pub const fn is_packable(&self) -> bool {
+		!matches!(
+			self,
+			Self::String
+				| Self::Bytes
+				| Self::Message(_)
+				| Self::Duration
+				| Self::Timestamp
+				| Self::Any | Self::FieldMask
+		)
+	}
+
 	/// Returns `true` if the proto type is [`Enum`].
 	///
 	/// [`Enum`]: ProtoType::Enum
@@ -224,6 +237,28 @@ impl ProtoType {
 		}
 	}
 
+	// Used by fallible proxies. Returns a `Result` for the types whose conversion can fail,
+	// and `None` for the others, which are converted with `default_from_proto`
+	pub fn fallible_from_proto(&self, base_ident: &TokenStream2) -> Option<TokenStream2> {
+		let span = base_ident.span();
+
+		match self {
+			Self::Enum(_) => {
+				Some(quote_spanned! {span=> ::core::convert::TryFrom::try_from(#base_ident) })
+			}
+			Self::Message(MessageInfo { boxed, .. }) => {
+				if *boxed {
+					Some(
+						quote_spanned! {span=> ::core::convert::TryFrom::try_from(*#base_ident).map(Box::new) },
+					)
+				} else {
+					Some(quote_spanned! {span=> ::core::convert::TryFrom::try_from(#base_ident) })
+				}
+			}
+			_ => None,
+		}
+	}
+
 	pub fn validator_target_type(&self, span: Span) -> TokenStream2 {
 		match self {
 			Self::String => quote_spanned! {span=> String },
@@ -255,9 +290,12 @@ impl ProtoType {
 			Self::Bool => quote_spanned! {span=> ::protify::BoolValidator },
 			Self::Bytes => quote_spanned! {span=> ::protify::BytesValidator },
 			Self::Enum(path) => quote_spanned! {span=> ::protify::EnumValidator<#path> },
-			Self::Message(MessageInfo { .. }) => {
-				quote_spanned! {span=> ::protify::MessageValidator }
+			Self::Message(info) if info.has_own_validator() => {
+				let path = &info.path;
+
+				quote_spanned! {span=> <#path as ::protify::ProtoValidation>::Validator }
 			}
+			Self::Message(_) => quote_spanned! {span=> ::protify::MessageValidator },
 			Self::Int32 => quote_spanned! {span=> ::protify::IntValidator<i32> },
 			Self::Sint32 => quote_spanned! {span=> ::protify::IntValidator<::protify::Sint32> },
 			Self::Duration => quote_spanned! {span=> ::protify::DurationValidator },
@@ -0,0 +1,144 @@
+use crate::*;
+
+// The built-in field options, which are set with typed attributes
+// rather than with `proto_option!`
+#[derive(Clone, Default)]
+pub struct StandardFieldOptions {
+	pub json_name: Option<String>,
+	pub packed: Option<bool>,
+	pub retention: Option<&'static str>,
+	pub targets: Vec<&'static str>,
+	pub debug_redact: bool,
+	pub lazy: bool,
+}
+
+impl StandardFieldOptions {
+	// Returns `false` if the attribute is not a standard option
+	pub fn parse(&mut self, ident: &str, meta: &ParseNestedMeta) -> syn::Result<bool> {
+		match ident {
+			"json_name" => {
+				self.json_name = Some(meta.expr_value()?.as_string()?);
+			}
+			"packed" => {
+				let boolean = meta.parse_value::<LitBool>()?;
+
+				self.packed = Some(boolean.value());
+			}
+			"retention" => {
+				let variant = meta.parse_value::<Ident>()?;
+
+				self.retention = Some(match variant.to_string().as_str() {
+					"Runtime" => "RETENTION_RUNTIME",
+					"Source" => "RETENTION_SOURCE",
+					_ => bail!(variant, "Expected one of `Runtime` or `Source`"),
+				});
+			}
+			"targets" => {
+				meta.parse_nested_meta(|target_meta| {
+					let target = target_meta.path.require_ident()?;
+
+					self.targets
+						.push(match target.to_string().as_str() {
+							"File" => "TARGET_TYPE_FILE",
+							"ExtensionRange" => "TARGET_TYPE_EXTENSION_RANGE",
+							"Message" => "TARGET_TYPE_MESSAGE",
+							"Field" => "TARGET_TYPE_FIELD",
+							"Oneof" => "TARGET_TYPE_ONEOF",
+							"Enum" => "TARGET_TYPE_ENUM",
+							"EnumEntry" => "TARGET_TYPE_ENUM_ENTRY",
+							"Service" => "TARGET_TYPE_SERVICE",
+							"Method" => "TARGET_TYPE_METHOD",
+							_ => bail!(target, "Unknown target type"),
+						});
+
+					Ok(())
+				})?;
+			}
+			"debug_redact" => {
+				self.debug_redact = true;
+			}
+			"lazy" => {
+				self.lazy = true;
+			}
+			_ => return Ok(false),
+		};
+
+		Ok(true)
+	}
+
+	pub fn check(&self, proto_field: &ProtoField, span: Span) -> syn::Result<()> {
+		if self.packed.is_some()
+			&& !matches!(proto_field, ProtoField::Repeated(inner) if inner.is_packable())
+		{
+			return Err(error_with_span!(
+				span,
+				"`packed` can only be used on repeated fields with scalar or enum values"
+			));
+		}
+
+		if self.lazy && !proto_field.is_message() {
+			return Err(error_with_span!(
+				span,
+				"`lazy` can only be used on message fields"
+			));
+		}
+
+		Ok(())
+	}
+
+	// Appends the options to the options of the field
+	pub fn apply(&self, options: &mut TokenStreamOr) {
+		let Self {
+			json_name,
+			packed,
+			retention,
+			targets,
+			debug_redact,
+			lazy,
+		} = self;
+
+		let mut new_options: Vec<TokenStream2> = Vec::new();
+
+		if let Some(json_name) = json_name {
+			new_options.push(quote! { ::protify::proto_option!("json_name" => #json_name) });
+		}
+
+		if let Some(packed) = packed {
+			new_options.push(quote! { ::protify::proto_option!("packed" => #packed) });
+		}
+
+		if let Some(retention) = retention {
+			new_options.push(quote! {
+				::protify::proto_option!("retention" => ::protify::OptionValue::Enum(#retention.into()))
+			});
+		}
+
+		for target in targets {
+			new_options.push(quote! {
+				::protify::proto_option!("targets" => ::protify::OptionValue::Enum(#target.into()))
+			});
+		}
+
+		if *debug_redact {
+			new_options.push(quote! { ::protify::proto_option!("debug_redact" => true) });
+		}
+
+		if *lazy {
+			new_options.push(quote! { ::protify::proto_option!("lazy" => true) });
+		}
+
+		if new_options.is_empty() {
+			return;
+		}
+
+		let current_options = options.to_token_stream();
+
+		options.set(quote! {
+			::protify::__collect_options(
+				::core::iter::IntoIterator::into_iter(#current_options)
+					.chain([ #(#new_options),* ]),
+				false
+			)
+		});
+	}
+}
@@ -14,11 +14,15 @@ pub struct MessageAttrs {
 	pub forwarded_derives: Vec<Path>,
 	pub forwarded_attrs: Vec<Meta>,
 	pub is_proxied: bool,
+	pub is_fallible: bool,
 	pub auto_tests: AutoTests,
 	pub deprecated: bool,
 	pub validators: Validators,
 	pub file: Option<Ident>,
 	pub module_path: Option<TokenStream2>,
+	pub comments: Option<String>,
+	pub derives_serde: bool,
+	pub features_span: Option<Span>,
 }
 
 impl MessageAttrs {
@@ -30,11 +34,13 @@ impl MessageAttrs {
 #[derive(Default, Clone, Copy)]
 pub struct MessageMacroArgs {
 	pub is_proxied: bool,
+	pub is_fallible: bool,
 }
 
 impl MessageMacroArgs {
 	pub fn parse(macro_args: TokenStream2) -> syn::Result<Self> {
 		let mut is_proxied = false;
+		let mut fallible_span: Option<Span> = None;
 
 		let parser = syn::meta::parser(|meta| {
 			if let Some(ident) = meta.path.get_ident() {
@@ -42,6 +48,7 @@ impl MessageMacroArgs {
 
 				match ident.as_str() {
 					"proxied" => is_proxied = true,
+					"fallible" => fallible_span = Some(meta.path.span()),
 					_ => return Err(meta.error("Unknown attribute")),
 				};
 			}
@@ -51,7 +58,19 @@ impl MessageMacroArgs {
 
 		parser.parse2(macro_args)?;
 
-		Ok(Self { is_proxied })
+		if let Some(span) = fallible_span
+			&& !is_proxied
+		{
+			return Err(Error::new(
+				span,
+				"`fallible` can only be used for proxied messages",
+			));
+		}
+
+		Ok(Self {
+			is_proxied,
+			is_fallible: fallible_span.is_some(),
+		})
 	}
 }
 
@@ -63,6 +82,7 @@ pub fn process_message_attrs(
 	let mut reserved_names: Vec<String> = Vec::new();
 	let mut reserved_numbers = ReservedNumbers::default();
 	let mut options = TokenStreamOr::new(|_| quote! { [] });
+	let mut features: Option<Features> = None;
 	let mut proto_name: Option<ParsedStr> = None;
 	let mut from_proto: Option<PathOrClosure> = None;
 	let mut into_proto: Option<PathOrClosure> = None;
@@ -74,6 +94,7 @@ pub fn process_message_attrs(
 	let mut forwarded_attrs: Vec<Meta> = Vec::new();
 	let mut file: Option<Ident> = None;
 	let mut module_path: Option<TokenStream2> = None;
+	let mut derives_serde = false;
 
 	for attr in attrs {
 		let ident = if let Some(ident) = attr.path().get_ident() {
@@ -86,6 +107,15 @@ pub fn process_message_attrs(
 			"deprecated" => {
 				deprecated = true;
 			}
+			// For direct impls, the derives of the struct are
+			// applied to the struct used by prost
+			"derive" if !macro_args.is_proxied => {
+				derives_serde |= attr
+					.parse_args::<PathList>()?
+					.list
+					.iter()
+					.any(is_serde_derive);
+			}
 			"proto" => {
 				attr.parse_nested_meta(|meta| {
 					let ident = meta.path.require_ident()?.to_string();
@@ -131,6 +161,9 @@ pub fn process_message_attrs(
 							options.span = meta.input.span();
 							options.set(meta.expr_value()?.into_token_stream());
 						}
+						"features" => {
+							features = Some(Features::parse(&meta, FeatureTarget::Message)?);
+						}
 						"from_proto" => {
 							from_proto = Some(meta.expr_value()?.as_path_or_closure()?);
 						}
@@ -150,6 +183,12 @@ pub fn process_message_attrs(
 		}
 	}
 
+	let features_span = features.as_ref().map(|features| features.span);
+
+	if let Some(features) = features {
+		features.apply(&mut options);
+	}
+
 	for validator in validators.validators.iter_mut() {
 		if validator.kind.is_closure() {
 			validator.expr = quote_spanned! {validator.span=>
@@ -158,9 +197,14 @@ pub fn process_message_attrs(
 		}
 	}
 
+	if macro_args.is_proxied {
+		derives_serde = forwarded_derives.iter().any(is_serde_derive);
+	}
+
 	let name = proto_name.unwrap_or_else(|| ParsedStr::with_default_span(struct_ident.to_string()));
 
 	Ok(MessageAttrs {
+		comments: extract_doc_comments(attrs),
 		reserved_names,
 		reserved_numbers,
 		options,
@@ -170,11 +214,20 @@ pub fn process_message_attrs(
 		into_proto,
 		forwarded_derives,
 		is_proxied: macro_args.is_proxied,
+		is_fallible: macro_args.is_fallible,
 		auto_tests,
 		deprecated,
 		validators,
 		forwarded_attrs,
 		file,
 		module_path,
+		derives_serde,
+		features_span,
 	})
 }
+
+fn is_serde_derive(path: &Path) -> bool {
+	path.segments
+		.last()
+		.is_some_and(|segment| segment.ident == "Serialize" || segment.ident == "Deserialize")
+}
@@ -1,5 +1,25 @@
 use crate::*;
 
+// The `google.protobuf` wrappers and the `google.type` messages with a dedicated validator
+const TYPES_WITH_OWN_VALIDATOR: &[&str] = &[
+	"DoubleValue",
+	"FloatValue",
+	"Int64Value",
+	"UInt64Value",
+	"Int32Value",
+	"UInt32Value",
+	"BoolValue",
+	"StringValue",
+	"BytesValue",
+	"Date",
+	"DateTime",
+	"Interval",
+	"LatLng",
+	"Money",
+	"PostalAddress",
+	"TimeOfDay",
+];
+
 #[derive(Clone, Debug)]
 pub struct MessageInfo {
 	pub path: Path,
@@ -14,6 +34,16 @@ impl MessageInfo {
 			.is_some_and(|i| i == "Self" || i == input_ident)
 	}
 
+	// Ordinary messages always use `MessageValidator`, while these types
+	// are validated like the values that they represent
+	pub fn has_own_validator(&self) -> bool {
+		self.path.segments.last().is_some_and(|segment| {
+			TYPES_WITH_OWN_VALIDATOR
+				.iter()
+				.any(|name| segment.ident == name)
+		})
+	}
+
 	pub fn parse(meta: &ParseNestedMeta, type_info: Option<&TypeInfo>) -> syn::Result<Self> {
 		let mut item_path = ItemPathEntry::default();
 		let mut boxed = false;
@@ -42,8 +42,11 @@ mod validator_tokens;
 pub use validator_tokens::*;
 mod extension_field_attributes;
 pub use extension_field_attributes::*;
+mod doc_comments;
+pub use doc_comments::*;
 mod enum_attributes;
 mod enum_variant_attributes;
+mod features;
 mod field_data;
 mod message_attributes;
 mod message_info;
@@ -52,9 +55,11 @@ mod oneof_info;
 mod reserved_numbers;
 mod service_attributes;
 mod tag_allocator;
+mod tag_lock;
 
 pub use enum_attributes::*;
 pub use enum_variant_attributes::*;
+pub use features::*;
 pub use field_data::*;
 pub use message_attributes::*;
 pub use message_info::*;
@@ -63,3 +68,4 @@ pub use oneof_info::*;
 pub use reserved_numbers::*;
 pub use service_attributes::*;
 pub use tag_allocator::*;
+pub use tag_lock::*;
@@ -8,18 +8,23 @@ pub struct OneofAttrs {
 	pub forwarded_derives: Vec<Path>,
 	pub forwarded_attrs: Vec<Meta>,
 	pub is_proxied: bool,
+	pub is_fallible: bool,
 	pub auto_tests: AutoTests,
 	pub validators: Validators,
+	pub comments: Option<String>,
+	pub features_span: Option<Span>,
 }
 
 #[derive(Default)]
 pub struct OneofMacroAttrs {
 	pub is_proxied: bool,
+	pub is_fallible: bool,
 }
 
 impl OneofMacroAttrs {
 	pub fn parse(macro_attrs: TokenStream2) -> syn::Result<Self> {
 		let mut is_proxied = false;
+		let mut fallible_span: Option<Span> = None;
 
 		let macro_attrs_parser = syn::meta::parser(|meta| {
 			let ident_str = meta.ident_str()?;
@@ -28,6 +33,9 @@ impl OneofMacroAttrs {
 				"proxied" => {
 					is_proxied = true;
 				}
+				"fallible" => {
+					fallible_span = Some(meta.path.span());
+				}
 				_ => return Err(meta.error("Unknown attribute")),
 			};
 
@@ -36,7 +44,19 @@ impl OneofMacroAttrs {
 
 		macro_attrs_parser.parse2(macro_attrs)?;
 
-		Ok(Self { is_proxied })
+		if let Some(span) = fallible_span
+			&& !is_proxied
+		{
+			return Err(Error::new(
+				span,
+				"`fallible` can only be used for proxied oneofs",
+			));
+		}
+
+		Ok(Self {
+			is_proxied,
+			is_fallible: fallible_span.is_some(),
+		})
 	}
 }
 
@@ -46,6 +66,7 @@ pub fn process_oneof_attrs(
 	attrs: &[Attribute],
 ) -> Result<OneofAttrs, Error> {
 	let mut options = TokenStreamOr::new(|_| quote! { [] });
+	let mut features: Option<Features> = None;
 	let mut from_proto: Option<PathOrClosure> = None;
 	let mut into_proto: Option<PathOrClosure> = None;
 	let mut forwarded_derives: Vec<Path> = Vec::new();
@@ -73,6 +94,9 @@ pub fn process_oneof_attrs(
 				options.span = meta.input.span();
 				options.set(meta.expr_value()?.into_token_stream());
 			}
+			"features" => {
+				features = Some(Features::parse(&meta, FeatureTarget::Oneof)?);
+			}
 			"from_proto" => {
 				from_proto = Some(meta.expr_value()?.as_path_or_closure()?);
 			}
@@ -85,6 +109,12 @@ pub fn process_oneof_attrs(
 		Ok(())
 	})?;
 
+	let features_span = features.as_ref().map(|features| features.span);
+
+	if let Some(features) = features {
+		features.apply(&mut options);
+	}
+
 	for validator in &validators {
 		if validator.kind.is_closure() {
 			bail_with_span!(
@@ -100,8 +130,11 @@ pub fn process_oneof_attrs(
 		into_proto,
 		forwarded_derives,
 		is_proxied: macro_attrs.is_proxied,
+		is_fallible: macro_attrs.is_fallible,
 		auto_tests,
 		validators,
 		forwarded_attrs,
+		comments: extract_doc_comments(attrs),
+		features_span,
 	})
 }
@@ -1,8 +1,43 @@
 use crate::*;
 
+#[derive(Default, Clone, Copy)]
+pub struct ServiceMacroArgs {
+	pub tonic: bool,
+}
+
+impl ServiceMacroArgs {
+	pub fn parse(macro_args: TokenStream2) -> syn::Result<Self> {
+		let mut tonic = false;
+
+		let parser = syn::meta::parser(|meta| {
+			let ident_str = meta.ident_str()?;
+
+			match ident_str.as_str() {
+				"tonic" => {
+					if !cfg!(feature = "tonic") {
+						return Err(meta.error(
+							"The `tonic` feature must be enabled to generate tonic servers and clients",
+						));
+					}
+
+					tonic = true;
+				}
+				_ => return Err(meta.error("Unknown attribute")),
+			};
+
+			Ok(())
+		});
+
+		parser.parse2(macro_args)?;
+
+		Ok(Self { tonic })
+	}
+}
+
 pub struct ServiceOrHandlerAttrs {
 	pub options: TokensOr<TokenStream2>,
 	pub deprecated: bool,
+	pub comments: Option<String>,
 }
 
 pub fn process_service_or_handler_attrs(
@@ -49,5 +84,6 @@ pub fn process_service_or_handler_attrs(
 	Ok(ServiceOrHandlerAttrs {
 		options,
 		deprecated,
+		comments: extract_doc_comments(attrs),
 	})
 }
@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use protify_tag_lock::{LOCKFILE_NAME, LockSection, UPDATE_LOCKFILE_VAR, read_lockfile};
+
+use crate::*;
+
+// The tags recorded in the lockfile for a single message or enum.
+//
+// The lockfile is only used if it exists in the root of the crate (or if the update variable is set,
+// in which case it gets created by the generated tests).
+pub struct TagLock {
+	path: PathBuf,
+	source: String,
+	tags: BTreeMap<String, i32>,
+	is_recorded: bool,
+	update: bool,
+}
+
+impl TagLock {
+	// The sections are keyed by the fully qualified name of the items, but the package and the names of the parent
+	// messages are only known after the expansion, so the section is selected by the name of the item.
+	// If there are several candidates, they are narrowed down by the rust file that defines the item, and then by the parent message.
+	//
+	// The generated test then checks the tags against the section of the fully qualified name.
+	pub fn load(name: &str, parent_message: Option<&Ident>) -> syn::Result<Option<Self>> {
+		let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") else {
+			return Ok(None);
+		};
+
+		let path = PathBuf::from(manifest_dir).join(LOCKFILE_NAME);
+		let update = std::env::var_os(UPDATE_LOCKFILE_VAR).is_some();
+
+		if !update && !path.exists() {
+			return Ok(None);
+		}
+
+		let source = proc_macro::Span::call_site().file();
+		let suffix = format!(".{name}");
+
+		let mut sections: Vec<(String, LockSection)> = read_lockfile(&path)
+			.map_err(|e| error_call_site!("{e}"))?
+			.into_iter()
+			.filter(|(item, _)| item == name || item.ends_with(&suffix))
+			.collect();
+
+		let is_recorded = !sections.is_empty();
+
+		if sections.len() > 1
+			&& sections
+				.iter()
+				.any(|(_, section)| section.source.as_ref() == Some(&source))
+		{
+			sections.retain(|(_, section)| section.source.as_ref() == Some(&source));
+		}
+
+		if sections.len() > 1
+			&& let Some(parent) = parent_message
+		{
+			let parent_suffix = format!(".{parent}.{name}");
+
+			sections.retain(|(item, _)| item.ends_with(&parent_suffix));
+		}
+
+		// Otherwise, the tags are only checked by the generated test
+		let tags = if sections.len() == 1 {
+			sections.remove(0).1.tags
+		} else {
+			BTreeMap::new()
+		};
+
+		Ok(Some(Self {
+			path,
+			source,
+			tags,
+			is_recorded,
+			update,
+		}))
+	}
+
+	// Assigns the locked tags to the elements without a manually set tag, and checks that
+	// no locked element was removed without reserving its tag
+	pub fn apply<'a>(
+		&self,
+		elements: impl IntoIterator<Item = (&'a str, &'a mut Option<ParsedNum>, Span)>,
+		manually_set_tags: &mut Vec<ParsedNum>,
+		reserved_numbers: &ReservedNumbers,
+		item_span: Span,
+	) -> syn::Result<()> {
+		let mut found: Vec<&str> = Vec::new();
+
+		for (name, tag, span) in elements {
+			found.push(name);
+
+			let Some(&locked) = self.tags.get(name) else {
+				continue;
+			};
+
+			// Removed and then reserved, so it's a new element with the same name
+			if reserved_numbers.contains(locked) {
+				continue;
+			}
+
+			if let Some(tag) = tag {
+				let current = tag.num;
+
+				if current != locked && !self.update {
+					bail_with_span!(
+						tag.span,
+						"The tag of `{name}` is locked to {locked} in {LOCKFILE_NAME}, but it is set to {current}. If this change is intended, run the tests with the {UPDATE_LOCKFILE_VAR} environment variable to update the lockfile"
+					);
+				}
+			} else {
+				let locked_tag = ParsedNum { num: locked, span };
+
+				*tag = Some(locked_tag);
+				manually_set_tags.push(locked_tag);
+			}
+		}
+
+		for (name, locked) in &self.tags {
+			if !found.contains(&name.as_str()) && !reserved_numbers.contains(*locked) {
+				bail_with_span!(
+					item_span,
+					"`{name}` (with tag {locked}) is recorded in {LOCKFILE_NAME} but it no longer exists. Add {locked} to the `reserved_numbers` to prevent it from being reused"
+				);
+			}
+		}
+
+		Ok(())
+	}
+
+	// Makes the compiler track the lockfile, so that the items get recompiled when it changes,
+	// and generates the test that checks the tags (or records them, if the update variable is set)
+	pub fn check_tokens(&self, item_ident: &Ident, schema: &TokenStream2) -> TokenStream2 {
+		let tracking_tokens = self.path.exists().then(|| {
+			let path = self.path.display().to_string();
+
+			quote! {
+			  const _: &[u8] = include_bytes!(#path);
+			}
+		});
+
+		// Items without a section only need the test to be recorded
+		let test_fn = (self.is_recorded || self.update).then(|| {
+			let test_fn_ident =
+				format_ident!("{}_tag_lock", to_snake_case(&item_ident.to_string()));
+			let source = &self.source;
+
+			quote! {
+			  #[cfg(test)]
+			  #[test]
+			  fn #test_fn_ident() {
+					if let Err(e) = #schema.__check_tag_lock(env!("CARGO_MANIFEST_DIR"), #source) {
+						panic!("{e}")
+					}
+			  }
+			}
+		});
+
+		quote! {
+		  #tracking_tokens
+		  #test_fn
+		}
+	}
+}
@@ -18,6 +18,7 @@ mod field_rules;
 mod map_rules;
 mod message_rules;
 mod oneof_reflection;
+mod predefined_rules;
 mod repeated_rules;
 mod timestamp_rules;
 pub use oneof_reflection::*;
@@ -77,6 +78,7 @@ impl BuilderTokens {
 pub struct RulesCtx {
 	pub field_span: Span,
 	pub rules: FieldRules,
+	pub predefined: Vec<TokenStream2>,
 }
 
 impl RulesCtx {
@@ -89,7 +91,11 @@ impl RulesCtx {
 			let rules = FieldRules::decode(field_rules_msg.encode_to_vec().as_ref())
 				.expect("Failed to decode field rules");
 
-			Self::from_non_empty_rules(rules, field_span)
+			let mut ctx = Self::from_non_empty_rules(rules, field_span)?;
+
+			ctx.predefined = Self::extract_predefined_rules(field_rules_msg, field_span);
+
+			Some(ctx)
 		} else {
 			None
 		}
@@ -101,7 +107,11 @@ impl RulesCtx {
 		{
 			None
 		} else {
-			Some(Self { field_span, rules })
+			Some(Self {
+				field_span,
+				rules,
+				predefined: Vec::new(),
+			})
 		}
 	}
 
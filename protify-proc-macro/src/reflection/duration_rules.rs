@@ -18,6 +18,7 @@ impl RulesCtx {
 		self.tokenize_ignore(&mut builder);
 		self.tokenize_required(&mut builder);
 		self.tokenize_cel_rules(&mut builder);
+		self.tokenize_predefined_rules(&mut builder);
 
 		if let Some(RulesType::Duration(rules)) = &self.rules.r#type {
 			if let Some(val) = rules.r#const {
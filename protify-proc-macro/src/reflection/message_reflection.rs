@@ -132,6 +132,10 @@ fn extract_fields_data(item: &mut ItemStruct) -> Result<ReflectionMsgData, Error
 					into_proto: None,
 					deprecated: false,
 					forwarded_attrs: vec![],
+					comments: None,
+					standard_options: StandardFieldOptions::default(),
+					sensitive: false,
+					features_span: None,
 				}));
 
 				continue;
@@ -183,6 +187,10 @@ fn extract_fields_data(item: &mut ItemStruct) -> Result<ReflectionMsgData, Error
 				into_proto: None,
 				deprecated: false,
 				forwarded_attrs: vec![],
+				comments: None,
+				standard_options: StandardFieldOptions::default(),
+				sensitive: false,
+				features_span: None,
 			}));
 		}
 	}
@@ -119,6 +119,10 @@ fn extract_oneof_data(item: &mut ItemEnum) -> Result<OneofDataReflection, Error>
 			into_proto: None,
 			deprecated: false,
 			forwarded_attrs: vec![],
+			comments: None,
+			standard_options: StandardFieldOptions::default(),
+			sensitive: false,
+			features_span: None,
 		}));
 	}
 
@@ -29,3 +29,9 @@ pub static MESSAGE_RULES_EXT_DESCRIPTOR: LazyLock<ExtensionDescriptor> = LazyLoc
 		.get_extension_by_name("buf.validate.message")
 		.expect("buf.validate.message extension not found in descriptor pool")
 });
+
+pub static PREDEFINED_RULES_EXT_DESCRIPTOR: LazyLock<ExtensionDescriptor> = LazyLock::new(|| {
+	DESCRIPTOR_POOL
+		.get_extension_by_name("buf.validate.predefined")
+		.expect("buf.validate.predefined extension not found in descriptor pool")
+});
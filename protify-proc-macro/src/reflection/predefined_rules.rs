@@ -0,0 +1,111 @@
+use ::proto_types::protovalidate::PredefinedRules;
+use syn::LitByteStr;
+
+use super::*;
+
+fn tokenize_rule_value(value: &ProstValue, kind: &Kind, span: Span) -> TokenStream2 {
+	match value {
+		ProstValue::Bool(val) => quote_spanned! {span=> #val },
+		ProstValue::I32(val) => quote_spanned! {span=> #val },
+		ProstValue::I64(val) => quote_spanned! {span=> #val },
+		ProstValue::U32(val) => quote_spanned! {span=> #val },
+		ProstValue::U64(val) => quote_spanned! {span=> #val },
+		ProstValue::F32(val) => quote_spanned! {span=> #val },
+		ProstValue::F64(val) => quote_spanned! {span=> #val },
+		ProstValue::String(val) => quote_spanned! {span=> #val },
+		ProstValue::Bytes(val) => {
+			let lit = LitByteStr::new(val, span);
+
+			quote_spanned! {span=> #lit }
+		}
+		ProstValue::EnumNumber(number) => {
+			if let Kind::Enum(enum_desc) = kind
+				&& let Some(variant) = enum_desc.get_value(*number)
+			{
+				let name = variant.name();
+
+				quote_spanned! {span=> ::protify::OptionValue::Enum(#name.into()) }
+			} else {
+				quote_spanned! {span=> #number }
+			}
+		}
+		ProstValue::List(items) => {
+			let items = items
+				.iter()
+				.map(|item| tokenize_rule_value(item, kind, span));
+
+			quote_spanned! {span=> [ #(::protify::OptionValue::from(#items)),* ] }
+		}
+		ProstValue::Message(_) | ProstValue::Map(_) => {
+			quote_spanned! {span=> compile_error!("Predefined rules with message or map values are not supported") }
+		}
+	}
+}
+
+impl RulesCtx {
+	// Predefined rules are extensions of the type-specific rules (like `StringRules`),
+	// which are lost when decoding `FieldRules`, so they must be read from the dynamic message
+	pub(super) fn extract_predefined_rules(
+		field_rules_msg: &DynamicMessage,
+		field_span: Span,
+	) -> Vec<TokenStream2> {
+		let mut output = Vec::new();
+
+		for (field, value) in field_rules_msg.fields() {
+			if field.containing_oneof().is_none() {
+				continue;
+			}
+
+			let ProstValue::Message(type_rules) = value else {
+				continue;
+			};
+
+			for (ext, ext_value) in type_rules.extensions() {
+				let ext_options = ext.options();
+
+				if !ext_options.has_extension(&PREDEFINED_RULES_EXT_DESCRIPTOR) {
+					continue;
+				}
+
+				let ProstValue::Message(predefined_msg) = ext_options
+					.get_extension(&PREDEFINED_RULES_EXT_DESCRIPTOR)
+					.as_ref()
+				else {
+					continue;
+				};
+
+				let predefined = PredefinedRules::decode(predefined_msg.encode_to_vec().as_ref())
+					.expect("Failed to decode predefined rules");
+
+				let extension_name = ext.full_name();
+				let programs = predefined.cel.iter().map(|rule| {
+					let Rule {
+						id,
+						message,
+						expression,
+					} = rule;
+
+					quote_spanned! {field_span=>
+					  ::protify::cel_program!(id = #id, msg = #message, expr = #expression)
+					}
+				});
+				let value_tokens = tokenize_rule_value(ext_value, &ext.kind(), field_span);
+
+				output.push(quote_spanned! {field_span=>
+				  .predefined(
+					&::protify::PredefinedRule::new(#extension_name, [ #(#programs),* ]),
+					#value_tokens
+				  )
+				});
+			}
+		}
+
+		output
+	}
+
+	pub fn tokenize_predefined_rules(&self, validator: &mut BuilderTokens) {
+		for tokens in &self.predefined {
+			validator.extend(tokens.clone());
+		}
+	}
+}
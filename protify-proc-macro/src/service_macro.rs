@@ -1,17 +1,21 @@
 use crate::*;
 
-struct HandlerCtx {
-	name: String,
-	span: Span,
-	request: TokenStream2,
-	request_is_stream: bool,
-	response: TokenStream2,
-	response_is_stream: bool,
-	options: TokensOr<TokenStream2>,
-	deprecated: bool,
+pub struct HandlerCtx {
+	pub name: String,
+	pub span: Span,
+	pub request: TokenStream2,
+	pub request_is_stream: bool,
+	pub response: TokenStream2,
+	pub response_is_stream: bool,
+	pub options: TokensOr<TokenStream2>,
+	pub deprecated: bool,
+	pub comments: Option<String>,
 }
 
-pub fn process_service_macro(item: &ItemEnum) -> Result<TokenStream2, Error> {
+pub fn process_service_macro(
+	item: &ItemEnum,
+	macro_args: ServiceMacroArgs,
+) -> Result<TokenStream2, Error> {
 	let ItemEnum {
 		attrs,
 		ident,
@@ -25,6 +29,7 @@ pub fn process_service_macro(item: &ItemEnum) -> Result<TokenStream2, Error> {
 	let ServiceOrHandlerAttrs {
 		options: service_options,
 		deprecated,
+		comments: service_comments,
 	} = process_service_or_handler_attrs(attrs)?;
 
 	let service_name = to_pascal_case(&ident.to_string());
@@ -33,6 +38,7 @@ pub fn process_service_macro(item: &ItemEnum) -> Result<TokenStream2, Error> {
 		let ServiceOrHandlerAttrs {
 			options: handler_options,
 			deprecated,
+			comments,
 		} = process_service_or_handler_attrs(&variant.attrs)?;
 
 		let handler_name = variant.ident.to_string();
@@ -98,6 +104,7 @@ pub fn process_service_macro(item: &ItemEnum) -> Result<TokenStream2, Error> {
 			options: handler_options,
 			deprecated,
 			span: variant.ident.span(),
+			comments,
 		});
 	}
 
@@ -114,8 +121,11 @@ pub fn process_service_macro(item: &ItemEnum) -> Result<TokenStream2, Error> {
 			options,
 			deprecated,
 			span,
+			comments,
 		} = data;
 
+		let comments_tokens = comments_tokens(comments.as_ref());
+
 		quote_spanned! {*span=>
 		  ::protify::ServiceHandler::builder()
 				.name(#name.into())
@@ -132,10 +142,22 @@ pub fn process_service_macro(item: &ItemEnum) -> Result<TokenStream2, Error> {
 						.build()
 				)
 				.options(::protify::__collect_options(#options, #deprecated))
+				#comments_tokens
 				.build()
 		}
 	});
 
+	let service_comments_tokens = comments_tokens(service_comments.as_ref());
+
+	let tonic_impl = macro_args.tonic.then(|| {
+		tonic_service_impl(
+			&service_name,
+			vis,
+			&handlers_data,
+			service_comments.as_ref(),
+		)
+	});
+
 	Ok(quote! {
 	  #[derive(::protify::macros::__Service)]
 	  #vis struct #ident;
@@ -157,8 +179,11 @@ pub fn process_service_macro(item: &ItemEnum) -> Result<TokenStream2, Error> {
 					.package(__PROTO_FILE.package.into())
 					.handlers(::protify::vec![ #(#handlers_tokens),* ])
 					.options(::protify::__collect_options(#service_options, #deprecated))
+					#service_comments_tokens
 					.build()
 			}
 	  }
+
+	  #tonic_impl
 	})
 }
@@ -0,0 +1,4 @@
+#[test]
+fn cel_compile_checks() {
+	trybuild::TestCases::new().compile_fail("tests/ui/cel_*.rs");
+}
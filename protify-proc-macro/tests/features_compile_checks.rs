@@ -0,0 +1,4 @@
+#[test]
+fn features_compile_checks() {
+	trybuild::TestCases::new().compile_fail("tests/ui/features_*.rs");
+}
@@ -0,0 +1,12 @@
+use protify::*;
+
+proto_package!(MY_PKG, name = "my_pkg");
+define_proto_file!(MY_FILE, name = "my_file.proto", package = MY_PKG);
+
+#[proto_message]
+#[proto(validate = |v| v.cel(cel_program!(id = "name", msg = "invalid name", expr = "this.name ==")))]
+pub struct User {
+	pub name: String,
+}
+
+fn main() {}
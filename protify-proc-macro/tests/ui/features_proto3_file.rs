@@ -0,0 +1,6 @@
+use protify::*;
+
+proto_package!(MY_PKG, name = "my_pkg");
+define_proto_file!(MY_FILE, name = "my_file.proto", package = MY_PKG, features(enum_type = Closed));
+
+fn main() {}
@@ -0,0 +1,203 @@
+//! The format of the `protify.lock` file, which records the tags that were assigned automatically
+//! to the fields of messages and the variants of enums.
+//!
+//! It's shared by `protify` and `protify-proc-macro`, so that the lockfile is parsed in the same way by both.
+
+use std::{
+	collections::BTreeMap,
+	fmt::Write as _,
+	fs::{File, OpenOptions},
+	io::{self, Read, Seek, Write},
+	path::Path,
+};
+
+/// The name of the lockfile, placed in the root of the crate.
+pub const LOCKFILE_NAME: &str = "protify.lock";
+/// The environment variable that makes the generated tests update the lockfile.
+pub const UPDATE_LOCKFILE_VAR: &str = "PROTIFY_UPDATE_LOCKFILE";
+
+const LOCKFILE_HEADER: &str = "\
+# This file is generated by protify. It records the tags that were assigned automatically
+# to the fields of messages and the variants of enums, so that they remain stable.
+# Run the tests with the PROTIFY_UPDATE_LOCKFILE environment variable set to update it.
+";
+
+/// The tags recorded for a single message or enum.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LockSection {
+	/// The rust file where the item is defined, used by the macros to tell apart
+	/// the items with the same name in different packages.
+	pub source: Option<String>,
+	pub tags: BTreeMap<String, i32>,
+}
+
+/// The sections of the lockfile, keyed by the fully qualified name of the items.
+pub type LockEntries = BTreeMap<String, LockSection>;
+
+/// Reads the lockfile, or returns no entries if it does not exist.
+///
+/// It waits for the updates that are in progress in other threads or processes.
+pub fn read_lockfile(path: &Path) -> Result<LockEntries, String> {
+	let mut file = match File::open(path) {
+		Ok(file) => file,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(LockEntries::new()),
+		Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+	};
+
+	let mut content = String::new();
+
+	file.lock_shared()
+		.and_then(|()| file.read_to_string(&mut content))
+		.map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+
+	parse_lockfile(&content)
+}
+
+/// Applies the changes to the entries of the lockfile, creating it if it does not exist.
+///
+/// The file is locked for the whole read-modify-write cycle, so that the tests running at the same time
+/// (including the ones in separate processes, like with cargo-nextest) do not overwrite each other's sections.
+pub fn update_lockfile(path: &Path, update: impl FnOnce(&mut LockEntries)) -> Result<(), String> {
+	let map_err = |e: io::Error| format!("Failed to update {}: {e}", path.display());
+
+	let mut file = OpenOptions::new()
+		.read(true)
+		.write(true)
+		.create(true)
+		.truncate(false)
+		.open(path)
+		.map_err(map_err)?;
+
+	// Released when the file is closed
+	file.lock().map_err(map_err)?;
+
+	let mut content = String::new();
+	file.read_to_string(&mut content)
+		.map_err(map_err)?;
+
+	let mut entries = parse_lockfile(&content)?;
+
+	update(&mut entries);
+
+	let new_content = render_lockfile(&entries);
+
+	if new_content == content {
+		return Ok(());
+	}
+
+	file.set_len(0)
+		.and_then(|()| file.rewind())
+		.and_then(|()| file.write_all(new_content.as_bytes()))
+		.map_err(map_err)
+}
+
+/// Parses the content of a lockfile.
+pub fn parse_lockfile(content: &str) -> Result<LockEntries, String> {
+	let mut entries = LockEntries::new();
+	let mut current_item: Option<String> = None;
+
+	for (i, line) in content.lines().enumerate() {
+		let line = line.trim();
+		let line_num = i + 1;
+
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+
+		if let Some(item) = line
+			.strip_prefix('[')
+			.and_then(|l| l.strip_suffix(']'))
+		{
+			let item = item.trim().to_string();
+
+			entries.entry(item.clone()).or_default();
+			current_item = Some(item);
+			continue;
+		}
+
+		let Some(section) = current_item
+			.as_ref()
+			.and_then(|item| entries.get_mut(item))
+		else {
+			return Err(format!(
+				"Invalid {LOCKFILE_NAME} at line {line_num}: missing item section"
+			));
+		};
+
+		let Some((name, value)) = line.split_once('=') else {
+			return Err(format!(
+				"Invalid {LOCKFILE_NAME} at line {line_num}: expected `name = tag`"
+			));
+		};
+
+		if name.trim() == "@source" {
+			section.source = Some(value.trim().to_string());
+			continue;
+		}
+
+		let Ok(tag) = value.trim().parse::<i32>() else {
+			return Err(format!(
+				"Invalid {LOCKFILE_NAME} at line {line_num}: invalid tag"
+			));
+		};
+
+		section.tags.insert(name.trim().to_string(), tag);
+	}
+
+	Ok(entries)
+}
+
+/// Renders the entries of a lockfile, with the tags of each section sorted by number.
+#[must_use]
+pub fn render_lockfile(entries: &LockEntries) -> String {
+	let mut output = String::from(LOCKFILE_HEADER);
+
+	for (item, section) in entries {
+		let _ = write!(output, "\n[{item}]\n");
+
+		if let Some(source) = &section.source {
+			let _ = writeln!(output, "@source = {source}");
+		}
+
+		let mut tags: Vec<(&String, &i32)> = section.tags.iter().collect();
+		tags.sort_by_key(|(_, tag)| **tag);
+
+		for (name, tag) in tags {
+			let _ = writeln!(output, "{name} = {tag}");
+		}
+	}
+
+	output
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		let content = format!(
+			"{LOCKFILE_HEADER}\n[pkg.Msg]\n@source = src/lib.rs\nid = 1\nname = 2\n\n[pkg.Parent.Enum]\nUNSPECIFIED = 0\n"
+		);
+
+		let entries = parse_lockfile(&content).unwrap();
+
+		assert_eq!(entries["pkg.Msg"].source.as_deref(), Some("src/lib.rs"));
+		assert_eq!(entries["pkg.Msg"].tags["name"], 2);
+		assert_eq!(entries["pkg.Parent.Enum"].source, None);
+
+		assert_eq!(render_lockfile(&entries), content);
+	}
+
+	#[test]
+	fn invalid_lines() {
+		assert_eq!(
+			parse_lockfile("id = 1").unwrap_err(),
+			"Invalid protify.lock at line 1: missing item section"
+		);
+		assert_eq!(
+			parse_lockfile("[Msg]\nid = abc").unwrap_err(),
+			"Invalid protify.lock at line 2: invalid tag"
+		);
+	}
+}
@@ -0,0 +1,667 @@
+//! Breaking change detection between two versions of a [`Package`].
+//!
+//! The checks are modeled after the [breaking change rules](https://buf.build/docs/breaking/rules/) of `buf`, and each [`BreakingChange`] is assigned the least strict [`Severity`] category that it belongs to.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use protify::{compat::*, *};
+//!
+//! // With the `serde` feature, a previous version can be stored as a snapshot
+//! let old: Package = serde_json::from_str(&std::fs::read_to_string("snapshot.json")?)?;
+//! let new = MY_PKG::get_package();
+//!
+//! let report = check_compatibility(&old, &new);
+//!
+//! for change in report.breaking_changes(Severity::WireJson) {
+//!   eprintln!("{change}");
+//! }
+//!
+//! assert!(report.is_compatible(Severity::Wire));
+//! ```
+
+use crate::*;
+
+/// The category of a [`BreakingChange`], following the `WIRE`, `WIRE_JSON` and `FILE` categories used by `buf`.
+///
+/// The categories are ordered by their impact, so that a change that breaks the binary encoding is also considered as breaking for the JSON encoding and for the generated source code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+	/// The change breaks the generated source code, but not the encoding of the messages.
+	File,
+	/// The change breaks the JSON encoding of the messages.
+	WireJson,
+	/// The change breaks the binary encoding of the messages.
+	Wire,
+}
+
+impl Display for Severity {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::File => write!(f, "FILE"),
+			Self::WireJson => write!(f, "WIRE_JSON"),
+			Self::Wire => write!(f, "WIRE"),
+		}
+	}
+}
+
+/// The kinds of breaking changes that can be detected by [`check_compatibility`].
+///
+/// Messages and enums are identified by their full name within their package (i.e. `Parent.Child`), while services are identified by their name.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum BreakingChangeKind {
+	/// A message was removed from the package.
+	MessageRemoved {
+		package: FixedStr,
+		message: FixedStr,
+	},
+	/// A message was moved to a different package.
+	MessageMoved {
+		message: FixedStr,
+		old_package: FixedStr,
+		new_package: FixedStr,
+	},
+	/// A field was removed from a message.
+	FieldRemoved {
+		message: FixedStr,
+		field: FixedStr,
+		tag: i32,
+		number_reserved: bool,
+		name_reserved: bool,
+	},
+	/// The tag of a field is now used by a field with a different name.
+	TagReused {
+		message: FixedStr,
+		tag: i32,
+		old_field: FixedStr,
+		new_field: FixedStr,
+	},
+	/// A field is using a tag that was reserved in the previous version.
+	ReservedTagUsed {
+		message: FixedStr,
+		field: FixedStr,
+		tag: i32,
+	},
+	/// The type of a field has changed.
+	FieldTypeChanged {
+		message: FixedStr,
+		field: FixedStr,
+		old_type: FieldType,
+		new_type: FieldType,
+	},
+	/// An enum was removed from the package.
+	EnumRemoved { package: FixedStr, enum_: FixedStr },
+	/// A variant was removed from an enum.
+	EnumVariantRemoved {
+		enum_: FixedStr,
+		variant: FixedStr,
+		tag: i32,
+		number_reserved: bool,
+		name_reserved: bool,
+	},
+	/// An enum variant now has a different number.
+	EnumVariantRenumbered {
+		enum_: FixedStr,
+		variant: FixedStr,
+		old_tag: i32,
+		new_tag: i32,
+	},
+	/// A service was removed from the package.
+	ServiceRemoved {
+		package: FixedStr,
+		service: FixedStr,
+	},
+	/// A handler was removed from a service.
+	HandlerRemoved {
+		service: FixedStr,
+		handler: FixedStr,
+	},
+	/// The request of a handler has changed, either in its message or in its streaming mode.
+	HandlerRequestChanged {
+		service: FixedStr,
+		handler: FixedStr,
+		old: HandlerTarget,
+		new: HandlerTarget,
+	},
+	/// The response of a handler has changed, either in its message or in its streaming mode.
+	HandlerResponseChanged {
+		service: FixedStr,
+		handler: FixedStr,
+		old: HandlerTarget,
+		new: HandlerTarget,
+	},
+}
+
+fn fmt_target(target: &HandlerTarget) -> String {
+	if target.is_stream {
+		format!("stream {}", target.message)
+	} else {
+		target.message.to_string()
+	}
+}
+
+impl Display for BreakingChangeKind {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::MessageRemoved { package, message } => {
+				write!(f, "Message `{package}.{message}` was removed")
+			}
+			Self::MessageMoved {
+				message,
+				old_package,
+				new_package,
+			} => write!(
+				f,
+				"Message `{message}` was moved from package `{old_package}` to package `{new_package}`"
+			),
+			Self::FieldRemoved {
+				message,
+				field,
+				tag,
+				number_reserved,
+				name_reserved,
+			} => {
+				write!(
+					f,
+					"Field `{field}` ({tag}) was removed from message `{message}`"
+				)?;
+
+				match (number_reserved, name_reserved) {
+					(false, _) => write!(f, " without reserving its number"),
+					(true, false) => write!(f, " without reserving its name"),
+					(true, true) => Ok(()),
+				}
+			}
+			Self::TagReused {
+				message,
+				tag,
+				old_field,
+				new_field,
+			} => write!(
+				f,
+				"Tag {tag} of message `{message}` was used by field `{old_field}` and is now used by field `{new_field}`"
+			),
+			Self::ReservedTagUsed {
+				message,
+				field,
+				tag,
+			} => write!(
+				f,
+				"Field `{field}` of message `{message}` is using the reserved tag {tag}"
+			),
+			Self::FieldTypeChanged {
+				message,
+				field,
+				old_type,
+				new_type,
+			} => write!(
+				f,
+				"Field `{field}` of message `{message}` changed its type from `{old_type}` to `{new_type}`"
+			),
+			Self::EnumRemoved { package, enum_ } => {
+				write!(f, "Enum `{package}.{enum_}` was removed")
+			}
+			Self::EnumVariantRemoved {
+				enum_,
+				variant,
+				tag,
+				number_reserved,
+				name_reserved,
+			} => {
+				write!(
+					f,
+					"Variant `{variant}` ({tag}) was removed from enum `{enum_}`"
+				)?;
+
+				match (number_reserved, name_reserved) {
+					(false, _) => write!(f, " without reserving its number"),
+					(true, false) => write!(f, " without reserving its name"),
+					(true, true) => Ok(()),
+				}
+			}
+			Self::EnumVariantRenumbered {
+				enum_,
+				variant,
+				old_tag,
+				new_tag,
+			} => write!(
+				f,
+				"Variant `{variant}` of enum `{enum_}` changed its number from {old_tag} to {new_tag}"
+			),
+			Self::ServiceRemoved { package, service } => {
+				write!(f, "Service `{package}.{service}` was removed")
+			}
+			Self::HandlerRemoved { service, handler } => {
+				write!(
+					f,
+					"Handler `{handler}` was removed from service `{service}`"
+				)
+			}
+			Self::HandlerRequestChanged {
+				service,
+				handler,
+				old,
+				new,
+			} => write!(
+				f,
+				"The request of handler `{service}.{handler}` changed from `{}` to `{}`",
+				fmt_target(old),
+				fmt_target(new)
+			),
+			Self::HandlerResponseChanged {
+				service,
+				handler,
+				old,
+				new,
+			} => write!(
+				f,
+				"The response of handler `{service}.{handler}` changed from `{}` to `{}`",
+				fmt_target(old),
+				fmt_target(new)
+			),
+		}
+	}
+}
+
+/// A breaking change between two versions of a [`Package`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BreakingChange {
+	pub severity: Severity,
+	pub kind: BreakingChangeKind,
+}
+
+impl Display for BreakingChange {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "[{}] {}", self.severity, self.kind)
+	}
+}
+
+/// The list of [`BreakingChange`]s detected by [`check_compatibility`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompatReport {
+	pub changes: Vec<BreakingChange>,
+}
+
+impl CompatReport {
+	/// Returns the changes that are breaking for the given category.
+	///
+	/// Checking for [`Severity::File`] returns all changes, whereas checking for [`Severity::Wire`] only returns the changes that break the binary encoding.
+	pub fn breaking_changes(&self, category: Severity) -> impl Iterator<Item = &BreakingChange> {
+		self.changes
+			.iter()
+			.filter(move |c| c.severity >= category)
+	}
+
+	/// Returns `true` if there are no breaking changes for the given category.
+	#[must_use]
+	pub fn is_compatible(&self, category: Severity) -> bool {
+		self.breaking_changes(category).next().is_none()
+	}
+
+	fn push(&mut self, severity: Severity, kind: BreakingChangeKind) {
+		self.changes
+			.push(BreakingChange { severity, kind });
+	}
+}
+
+impl Display for CompatReport {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		for change in &self.changes {
+			writeln!(f, "{change}")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Checks the compatibility of the new version of a package with the old one.
+///
+/// If the two packages have different names, the messages that exist in both of them will be reported as moved.
+#[must_use]
+pub fn check_compatibility(old: &Package, new: &Package) -> CompatReport {
+	check_packages_compatibility(core::slice::from_ref(old), core::slice::from_ref(new))
+}
+
+/// Checks the compatibility of the new versions of several packages with the old ones.
+///
+/// Items are matched by their package and full name. Messages that cannot be found in the same package, but exist with the same name in another package, are reported as moved and are then compared as usual.
+#[must_use]
+pub fn check_packages_compatibility(old: &[Package], new: &[Package]) -> CompatReport {
+	let old = Items::collect(old);
+	let new = Items::collect(new);
+
+	let mut report = CompatReport::default();
+
+	for (&(package, name), old_msg) in &old.messages {
+		let new_msg = if let Some(msg) = new.messages.get(&(package, name)) {
+			*msg
+		} else if let Some(msg) = new
+			.messages
+			.iter()
+			.find_map(|(&(new_package, new_name), msg)| {
+				(new_name == name && !old.messages.contains_key(&(new_package, name)))
+					.then_some(*msg)
+			}) {
+			report.push(
+				Severity::File,
+				BreakingChangeKind::MessageMoved {
+					message: old_msg.name.clone(),
+					old_package: old_msg.package.clone(),
+					new_package: msg.package.clone(),
+				},
+			);
+
+			msg
+		} else {
+			report.push(
+				Severity::File,
+				BreakingChangeKind::MessageRemoved {
+					package: old_msg.package.clone(),
+					message: old_msg.name.clone(),
+				},
+			);
+
+			continue;
+		};
+
+		check_message(old_msg, new_msg, &mut report);
+	}
+
+	for (key, old_enum) in &old.enums {
+		if let Some(new_enum) = new.enums.get(key) {
+			check_enum(old_enum, new_enum, &mut report);
+		} else {
+			report.push(
+				Severity::File,
+				BreakingChangeKind::EnumRemoved {
+					package: old_enum.package.clone(),
+					enum_: old_enum.name.clone(),
+				},
+			);
+		}
+	}
+
+	for (key, old_service) in &old.services {
+		if let Some(new_service) = new.services.get(key) {
+			check_service(old_service, new_service, &mut report);
+		} else {
+			report.push(
+				Severity::File,
+				BreakingChangeKind::ServiceRemoved {
+					package: old_service.package.clone(),
+					service: old_service.name.clone(),
+				},
+			);
+		}
+	}
+
+	report
+}
+
+type ItemKey<'a> = (&'a str, &'a str);
+
+#[derive(Default)]
+struct Items<'a> {
+	messages: BTreeMap<ItemKey<'a>, &'a MessageSchema>,
+	enums: BTreeMap<ItemKey<'a>, &'a EnumSchema>,
+	services: BTreeMap<ItemKey<'a>, &'a Service>,
+}
+
+impl<'a> Items<'a> {
+	fn collect(packages: &'a [Package]) -> Self {
+		let mut items = Self::default();
+
+		for file in packages.iter().flat_map(|p| &p.files) {
+			for msg in &file.messages {
+				items.insert_message(msg);
+			}
+
+			for enum_ in &file.enums {
+				items
+					.enums
+					.insert((enum_.package.as_str(), enum_.name.as_str()), enum_);
+			}
+
+			for service in &file.services {
+				items
+					.services
+					.insert((service.package.as_str(), service.name.as_str()), service);
+			}
+		}
+
+		items
+	}
+
+	fn insert_message(&mut self, msg: &'a MessageSchema) {
+		self.messages
+			.insert((msg.package.as_str(), msg.name.as_str()), msg);
+
+		for nested in &msg.messages {
+			self.insert_message(nested);
+		}
+
+		for enum_ in &msg.enums {
+			self.enums
+				.insert((enum_.package.as_str(), enum_.name.as_str()), enum_);
+		}
+	}
+}
+
+fn is_reserved(ranges: &[Range<i32>], tag: i32) -> bool {
+	ranges.iter().any(|range| range.contains(&tag))
+}
+
+fn removal_severity(number_reserved: bool, name_reserved: bool) -> Severity {
+	match (number_reserved, name_reserved) {
+		(false, _) => Severity::Wire,
+		(true, false) => Severity::WireJson,
+		(true, true) => Severity::File,
+	}
+}
+
+// Messages and enums are compared by their name only, since moving
+// them across files or packages is reported separately
+fn same_proto_type(old: &ProtoType, new: &ProtoType) -> bool {
+	match (old, new) {
+		(ProtoType::Scalar(old), ProtoType::Scalar(new)) => old == new,
+		(ProtoType::Message(old), ProtoType::Message(new))
+		| (ProtoType::Enum(old), ProtoType::Enum(new)) => old.name == new.name,
+		_ => false,
+	}
+}
+
+fn field_type_change_severity(old: &FieldType, new: &FieldType) -> Option<Severity> {
+	if old == new {
+		return None;
+	}
+
+	let severity = match (old, new) {
+		(FieldType::Normal(old), FieldType::Normal(new))
+		| (FieldType::Repeated(old), FieldType::Repeated(new))
+		| (FieldType::Optional(old), FieldType::Optional(new)) => {
+			if same_proto_type(old, new) {
+				Severity::File
+			} else {
+				Severity::Wire
+			}
+		}
+		(
+			FieldType::Map {
+				keys: old_keys,
+				values: old_values,
+			},
+			FieldType::Map {
+				keys: new_keys,
+				values: new_values,
+			},
+		) => {
+			if old_keys == new_keys && same_proto_type(old_values, new_values) {
+				Severity::File
+			} else {
+				Severity::Wire
+			}
+		}
+		// Switching between explicit and implicit presence does not affect the encoding
+		(FieldType::Normal(old), FieldType::Optional(new))
+		| (FieldType::Optional(old), FieldType::Normal(new))
+			if same_proto_type(old, new) =>
+		{
+			Severity::File
+		}
+		_ => Severity::Wire,
+	};
+
+	Some(severity)
+}
+
+fn check_message(old: &MessageSchema, new: &MessageSchema, report: &mut CompatReport) {
+	for old_field in old.fields() {
+		let Some(new_field) = new.fields().find(|f| f.tag == old_field.tag) else {
+			let number_reserved = is_reserved(&new.reserved_numbers, old_field.tag);
+			let name_reserved = new.reserved_names.contains(&old_field.name);
+
+			report.push(
+				removal_severity(number_reserved, name_reserved),
+				BreakingChangeKind::FieldRemoved {
+					message: old.name.clone(),
+					field: old_field.name.clone(),
+					tag: old_field.tag,
+					number_reserved,
+					name_reserved,
+				},
+			);
+
+			continue;
+		};
+
+		if new_field.name != old_field.name {
+			report.push(
+				Severity::WireJson,
+				BreakingChangeKind::TagReused {
+					message: old.name.clone(),
+					tag: old_field.tag,
+					old_field: old_field.name.clone(),
+					new_field: new_field.name.clone(),
+				},
+			);
+		}
+
+		if let Some(severity) = field_type_change_severity(&old_field.type_, &new_field.type_) {
+			report.push(
+				severity,
+				BreakingChangeKind::FieldTypeChanged {
+					message: old.name.clone(),
+					field: new_field.name.clone(),
+					old_type: old_field.type_.clone(),
+					new_type: new_field.type_.clone(),
+				},
+			);
+		}
+	}
+
+	for new_field in new.fields() {
+		if is_reserved(&old.reserved_numbers, new_field.tag) {
+			report.push(
+				Severity::Wire,
+				BreakingChangeKind::ReservedTagUsed {
+					message: new.name.clone(),
+					field: new_field.name.clone(),
+					tag: new_field.tag,
+				},
+			);
+		}
+	}
+}
+
+fn check_enum(old: &EnumSchema, new: &EnumSchema, report: &mut CompatReport) {
+	for old_variant in &old.variants {
+		let Some(new_variant) = new
+			.variants
+			.iter()
+			.find(|v| v.name == old_variant.name)
+		else {
+			let number_reserved = is_reserved(&new.reserved_numbers, old_variant.tag);
+			let name_reserved = new.reserved_names.contains(&old_variant.name);
+
+			report.push(
+				removal_severity(number_reserved, name_reserved),
+				BreakingChangeKind::EnumVariantRemoved {
+					enum_: old.name.clone(),
+					variant: old_variant.name.clone(),
+					tag: old_variant.tag,
+					number_reserved,
+					name_reserved,
+				},
+			);
+
+			continue;
+		};
+
+		if new_variant.tag != old_variant.tag {
+			report.push(
+				Severity::Wire,
+				BreakingChangeKind::EnumVariantRenumbered {
+					enum_: old.name.clone(),
+					variant: old_variant.name.clone(),
+					old_tag: old_variant.tag,
+					new_tag: new_variant.tag,
+				},
+			);
+		}
+	}
+}
+
+fn same_target(old: &HandlerTarget, new: &HandlerTarget) -> bool {
+	old.is_stream == new.is_stream
+		&& old.message.name == new.message.name
+		&& old.message.package == new.message.package
+}
+
+fn check_service(old: &Service, new: &Service, report: &mut CompatReport) {
+	for old_handler in &old.handlers {
+		let Some(new_handler) = new
+			.handlers
+			.iter()
+			.find(|h| h.name == old_handler.name)
+		else {
+			report.push(
+				Severity::File,
+				BreakingChangeKind::HandlerRemoved {
+					service: old.name.clone(),
+					handler: old_handler.name.clone(),
+				},
+			);
+
+			continue;
+		};
+
+		if !same_target(&old_handler.request, &new_handler.request) {
+			report.push(
+				Severity::Wire,
+				BreakingChangeKind::HandlerRequestChanged {
+					service: old.name.clone(),
+					handler: old_handler.name.clone(),
+					old: old_handler.request.clone(),
+					new: new_handler.request.clone(),
+				},
+			);
+		}
+
+		if !same_target(&old_handler.response, &new_handler.response) {
+			report.push(
+				Severity::Wire,
+				BreakingChangeKind::HandlerResponseChanged {
+					service: old.name.clone(),
+					handler: old_handler.name.clone(),
+					old: old_handler.response.clone(),
+					new: new_handler.response.clone(),
+				},
+			);
+		}
+	}
+}
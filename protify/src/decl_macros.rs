@@ -49,6 +49,53 @@ macro_rules! register_proto_data {
 	($($tokens:tt)*) => {};
 }
 
+// Used to build the (static) paths of the gRPC methods from the package name
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_concat {
+	($($str:expr),+ $(,)?) => {{
+		const PARTS: &[&str] = &[$($str),+];
+
+		const LEN: usize = {
+			let mut len = 0;
+			let mut i = 0;
+
+			while i < PARTS.len() {
+				len += PARTS[i].len();
+				i += 1;
+			}
+
+			len
+		};
+
+		const BYTES: [u8; LEN] = {
+			let mut bytes = [0u8; LEN];
+			let mut pos = 0;
+			let mut i = 0;
+
+			while i < PARTS.len() {
+				let part = PARTS[i].as_bytes();
+				let mut j = 0;
+
+				while j < part.len() {
+					bytes[pos] = part[j];
+					pos += 1;
+					j += 1;
+				}
+
+				i += 1;
+			}
+
+			bytes
+		};
+
+		match ::core::str::from_utf8(&BYTES) {
+			Ok(str) => str,
+			Err(_) => panic!("Failed to concatenate the strings"),
+		}
+	}};
+}
+
 /// This macro can be used to generate a [`ProtoOption`] with a concise syntax.
 ///
 /// The input can be a single `key => value`, where the key should support [`Into`] [`FixedStr`]  and the value should support [`Into`] [`OptionValue`], or a bracketed series or key-value pairs to generate an [`OptionValue::Message`] for the value.
@@ -188,6 +235,7 @@ macro_rules! inherit_proto_file {
 			name: <$file as ::protify::FileSchema>::NAME,
 			package: <$file as ::protify::FileSchema>::PACKAGE,
 			extern_path: <$file as ::protify::FileSchema>::EXTERN_PATH,
+			edition: <$file as ::protify::FileSchema>::EDITION,
 		};
 	};
 }
@@ -241,6 +289,7 @@ macro_rules! use_proto_file {
 			name: <$file as ::protify::FileSchema>::NAME,
 			package: <$file as ::protify::FileSchema>::PACKAGE,
 			extern_path: ::core::module_path!(),
+			edition: <$file as ::protify::FileSchema>::EDITION,
 		};
 	};
 }
@@ -300,13 +349,15 @@ macro_rules! impl_testing_methods {
 /// - id (expr, Into<[`FixedStr`]>): The id of the specific CEL rule. It should be unique within the same message scope.
 /// - msg (expr, Into<[`FixedStr`]>): The error message associated with the given rule.
 /// - expr (expr, Into<[`FixedStr`]>): The actual CEL expression to use when validating the target.
+///
+/// If the expression is a string literal and the `cel` feature is enabled, its syntax is checked at compile time.
 #[macro_export]
 macro_rules! cel_program {
 	(id = $id:expr, msg = $msg:expr, expr = $expr:expr) => {
 		$crate::CelRule {
 			id: $id.into(),
 			message: $msg.into(),
-			expression: $expr.into(),
+			expression: $crate::macros::__cel_expression!($expr).into(),
 		}
 		.into()
 	};
@@ -339,3 +390,88 @@ macro_rules! impl_proto_map_key {
 		}
 	};
 }
+
+// Implements validation for the `google.protobuf` wrapper types,
+// by delegating it to the validator of the wrapped value
+macro_rules! impl_wrapper_validation {
+	($wrapper:ty, $inner:ty, $validator:ty, $builder:ident $(<$num:ty>)?) => {
+		impl ProtoValidation for $wrapper {
+			#[doc(hidden)]
+			type Target = Self;
+			#[doc(hidden)]
+			type Stored = Self;
+			type Validator = $validator;
+			type ValidatorBuilder = $builder$(<$num>)?;
+
+			#[doc(hidden)]
+			type UniqueStore<'a>
+				= LinearRefStore<'a, Self>
+			where
+				Self: 'a;
+
+			#[doc(hidden)]
+			const HAS_DEFAULT_VALIDATOR: bool = false;
+		}
+
+		impl<S: builder::state::State> ValidatorBuilderFor<$wrapper> for $builder<$($num,)? S> {
+			type Validator = $validator;
+
+			#[inline]
+			fn build_validator(self) -> Self::Validator {
+				self.build()
+			}
+		}
+
+		impl Validator<$wrapper> for $validator {
+			type Target = $wrapper;
+
+			#[cfg(feature = "cel")]
+			#[inline(never)]
+			#[cold]
+			#[allow(clippy::useless_conversion)]
+			fn check_cel_programs_with(&self, val: $wrapper) -> Result<(), Vec<CelError>> {
+				<Self as Validator<$inner>>::check_cel_programs_with(self, val.value.into())
+			}
+
+			#[cfg(feature = "cel")]
+			#[inline(never)]
+			#[cold]
+			#[doc(hidden)]
+			fn __check_cel_programs(&self) -> Result<(), Vec<CelError>> {
+				<Self as Validator<$inner>>::__check_cel_programs(self)
+			}
+
+			#[doc(hidden)]
+			#[inline(never)]
+			#[cold]
+			fn __cel_rules(&self) -> Vec<CelRule> {
+				<Self as Validator<$inner>>::__cel_rules(self)
+			}
+
+			#[inline(never)]
+			#[cold]
+			fn schema(&self) -> Option<ValidatorSchema> {
+				<Self as Validator<$inner>>::schema(self)
+			}
+
+			#[inline(never)]
+			#[cold]
+			fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+				<Self as Validator<$inner>>::check_consistency(self)
+			}
+
+			#[inline]
+			fn execute_validation(
+				&self,
+				ctx: &mut ValidationCtx,
+				val: Option<&Self::Target>,
+			) -> ValidationResult {
+				<Self as Validator<$inner>>::execute_validation(
+					self,
+					ctx,
+					val.map(|v| Borrow::<<Self as Validator<$inner>>::Target>::borrow(&v.value)),
+				)
+			}
+		}
+	};
+}
@@ -0,0 +1,894 @@
+use prost::Message;
+use prost_types::{
+	DescriptorProto, EnumDescriptorProto, EnumOptions, EnumValueDescriptorProto, EnumValueOptions,
+	FieldDescriptorProto, FieldOptions, FileDescriptorProto, FileDescriptorSet, FileOptions,
+	MessageOptions, MethodDescriptorProto, MethodOptions, OneofDescriptorProto, OneofOptions,
+	ServiceDescriptorProto, ServiceOptions, UninterpretedOption,
+	descriptor_proto::ReservedRange,
+	enum_descriptor_proto::EnumReservedRange,
+	feature_set,
+	field_descriptor_proto::{Label, Type as FieldKind},
+	field_options::{CType, JsType, OptionRetention, OptionTargetType},
+	file_options::OptimizeMode,
+	method_options::IdempotencyLevel,
+	uninterpreted_option::NamePart,
+};
+use proto_types::{Duration, Timestamp, field_descriptor_proto::Type as DescriptorType};
+
+use crate::*;
+
+mod buf_validate;
+use buf_validate::*;
+
+impl Package {
+	/// Converts this package into a [`FileDescriptorSet`], without using `protoc`.
+	///
+	/// The files are sorted so that each file comes after the files of the same package that it depends on. Imported files that are outside of this package (such as `google/protobuf/timestamp.proto` or `buf/validate/validate.proto`) are listed as dependencies, but they are not included in the set.
+	///
+	/// The built-in options (such as `deprecated`, `packed` or `java_package`) are mapped to their respective descriptor fields, while custom options (like `(buf.validate.field)`) are stored as [`UninterpretedOption`]s, just like `protoc` does before resolving the extensions.
+	///
+	/// Since the [`prost_types`] options cannot hold extension fields, the [`encode_file_descriptor_set`](Package::encode_file_descriptor_set) method should be used when the consumer needs to read the `buf.validate` rules.
+	#[must_use]
+	pub fn to_file_descriptor_set(&self) -> FileDescriptorSet {
+		FileDescriptorSet {
+			file: self
+				.sorted_files()
+				.into_iter()
+				.map(ProtoFile::to_file_descriptor_proto)
+				.collect(),
+		}
+	}
+
+	/// Converts this package into an encoded [`FileDescriptorSet`], without using `protoc`.
+	///
+	/// Unlike [`to_file_descriptor_set`](Package::to_file_descriptor_set), the `buf.validate` rules are encoded as actual extension fields of the options, so they can be read by tools like `prost-reflect` or by gRPC reflection clients, just like the descriptors produced by `protoc`. Other custom options are still stored as [`UninterpretedOption`]s.
+	#[must_use]
+	pub fn encode_file_descriptor_set(&self) -> Vec<u8> {
+		let mut buf = Vec::new();
+
+		for file in self.sorted_files() {
+			encode_bytes_field(1, &file.encode_file_descriptor_proto(), &mut buf);
+		}
+
+		buf
+	}
+
+	fn sorted_files(&self) -> Vec<&ProtoFile> {
+		let mut sorted: Vec<&ProtoFile> = Vec::with_capacity(self.files.len());
+
+		for file in &self.files {
+			insert_sorted_file(file, &self.files, &mut sorted);
+		}
+
+		sorted
+	}
+}
+
+fn insert_sorted_file<'a>(
+	file: &'a ProtoFile,
+	files: &'a [ProtoFile],
+	sorted: &mut Vec<&'a ProtoFile>,
+) {
+	if sorted.iter().any(|f| f.name == file.name) {
+		return;
+	}
+
+	// Pushing the file first prevents infinite recursion with circular imports
+	let idx = sorted.len();
+	sorted.push(file);
+
+	for import in file.imports.set.iter() {
+		if let Some(dep) = files.iter().find(|f| f.name == *import) {
+			insert_sorted_file(dep, files, sorted);
+		}
+	}
+
+	// Moving the file after its dependencies
+	let file = sorted.remove(idx);
+	sorted.push(file);
+}
+
+impl ProtoFile {
+	/// Converts this file into a [`FileDescriptorProto`], without using `protoc`.
+	///
+	/// For more information, refer to the [`to_file_descriptor_set`](Package::to_file_descriptor_set) method.
+	#[must_use]
+	pub fn to_file_descriptor_proto(&self) -> FileDescriptorProto {
+		let (syntax, edition) = match self.edition {
+			Edition::Proto3 => ("proto3", None),
+			Edition::E2023 => ("editions", Some(prost_types::Edition::Edition2023)),
+			Edition::E2024 => ("editions", Some(prost_types::Edition::Edition2024)),
+		};
+
+		let is_proto3 = matches!(self.edition, Edition::Proto3);
+
+		FileDescriptorProto {
+			name: Some(self.name.to_string()),
+			package: (!self.package.is_empty()).then(|| self.package.to_string()),
+			dependency: self
+				.imports
+				.as_sorted_vec()
+				.into_iter()
+				.map(|import| import.to_string())
+				.collect(),
+			message_type: self
+				.messages
+				.iter()
+				.map(|msg| message_descriptor(msg, is_proto3))
+				.collect(),
+			enum_type: self.enums.iter().map(enum_descriptor).collect(),
+			service: self
+				.services
+				.iter()
+				.map(service_descriptor)
+				.collect(),
+			extension: self
+				.extensions
+				.iter()
+				.flat_map(|ext| {
+					let extendee = format!(".{}", ext.target);
+
+					ext.fields.iter().map(move |field| {
+						let mut descriptor = field_descriptor(field, None, false).0;
+						descriptor.extendee = Some(extendee.clone());
+						descriptor
+					})
+				})
+				.collect(),
+			options: build_options(&self.options),
+			syntax: Some(syntax.to_string()),
+			edition: edition.map(|edition| edition as i32),
+			..Default::default()
+		}
+	}
+
+	/// Converts this file into an encoded [`FileDescriptorProto`], with the `buf.validate` rules stored as extension fields.
+	///
+	/// For more information, refer to the [`encode_file_descriptor_set`](Package::encode_file_descriptor_set) method.
+	#[must_use]
+	pub fn encode_file_descriptor_proto(&self) -> Vec<u8> {
+		let mut descriptor = self.to_file_descriptor_proto();
+
+		// The messages and the extensions are encoded separately, so that their options can include the extension fields
+		descriptor.message_type.clear();
+		let extensions = core::mem::take(&mut descriptor.extension);
+
+		let mut buf = descriptor.encode_to_vec();
+
+		let is_proto3 = matches!(self.edition, Edition::Proto3);
+
+		for msg in &self.messages {
+			encode_bytes_field(4, &encode_message(msg, is_proto3), &mut buf);
+		}
+
+		let extension_fields = self.extensions.iter().flat_map(|ext| &ext.fields);
+
+		for (descriptor, field) in extensions.into_iter().zip(extension_fields) {
+			encode_bytes_field(7, &encode_field(descriptor, field), &mut buf);
+		}
+
+		buf
+	}
+}
+
+fn encode_message(msg: &MessageSchema, is_proto3: bool) -> Vec<u8> {
+	let mut descriptor = message_descriptor(msg, is_proto3);
+
+	let fields = core::mem::take(&mut descriptor.field);
+	let nested_types = core::mem::take(&mut descriptor.nested_type);
+	let oneofs = core::mem::take(&mut descriptor.oneof_decl);
+	descriptor.options = None;
+
+	let mut buf = descriptor.encode_to_vec();
+
+	encode_options::<MessageOptions>(
+		7,
+		msg.options_with_validators(),
+		MESSAGE_EXTENSIONS,
+		&mut buf,
+	);
+
+	// Same order as in `message_descriptor`
+	let field_schemas = msg.entries.iter().flat_map(|entry| match entry {
+		MessageEntry::Field(field) => core::slice::from_ref(field),
+		MessageEntry::Oneof(oneof) => oneof.fields.as_slice(),
+	});
+
+	for (descriptor, field) in fields.into_iter().zip(field_schemas) {
+		encode_bytes_field(2, &encode_field(descriptor, field), &mut buf);
+	}
+
+	for nested in &msg.messages {
+		encode_bytes_field(3, &encode_message(nested, is_proto3), &mut buf);
+	}
+
+	// The map entries come after the nested messages
+	for map_entry in nested_types.into_iter().skip(msg.messages.len()) {
+		encode_bytes_field(3, &map_entry.encode_to_vec(), &mut buf);
+	}
+
+	let mut oneof_schemas = msg
+		.entries
+		.iter()
+		.filter_map(|entry| match entry {
+			MessageEntry::Oneof(oneof) => Some(oneof),
+			MessageEntry::Field(_) => None,
+		});
+
+	// The synthetic oneofs come after the real ones, and they have no options
+	for mut oneof in oneofs {
+		let oneof_bytes = if let Some(schema) = oneof_schemas.next() {
+			oneof.options = None;
+
+			let mut oneof_bytes = oneof.encode_to_vec();
+
+			encode_options::<OneofOptions>(
+				2,
+				schema.options_with_validators(),
+				ONEOF_EXTENSIONS,
+				&mut oneof_bytes,
+			);
+
+			oneof_bytes
+		} else {
+			oneof.encode_to_vec()
+		};
+
+		encode_bytes_field(8, &oneof_bytes, &mut buf);
+	}
+
+	buf
+}
+
+fn encode_field(mut descriptor: FieldDescriptorProto, field: &Field) -> Vec<u8> {
+	descriptor.options = None;
+
+	let mut buf = descriptor.encode_to_vec();
+
+	encode_options::<FieldOptions>(8, field_options(field), FIELD_EXTENSIONS, &mut buf);
+
+	buf
+}
+
+// Encodes the options, with the known extensions stored as extension fields
+fn encode_options<'a, O: DescriptorOptions>(
+	tag: u32,
+	options: impl IntoIterator<Item = &'a ProtoOption>,
+	extensions: &[KnownExtension],
+	buf: &mut Vec<u8>,
+) {
+	let mut output: Option<O> = None;
+	let mut extension_fields: Vec<u8> = Vec::new();
+
+	for option in options {
+		let output = output.get_or_insert_default();
+
+		if !output.set_builtin(&option.name, &option.value)
+			&& !encode_known_extension(option, extensions, &mut extension_fields)
+		{
+			output
+				.uninterpreted_options()
+				.extend(uninterpreted_options(option));
+		}
+	}
+
+	if let Some(output) = output {
+		let mut options_bytes = output.encode_to_vec();
+		options_bytes.append(&mut extension_fields);
+
+		encode_bytes_field(tag, &options_bytes, buf);
+	}
+}
+
+fn message_descriptor(msg: &MessageSchema, is_proto3: bool) -> DescriptorProto {
+	let mut descriptor = DescriptorProto {
+		name: Some(msg.short_name.to_string()),
+		nested_type: msg
+			.messages
+			.iter()
+			.map(|nested| message_descriptor(nested, is_proto3))
+			.collect(),
+		enum_type: msg.enums.iter().map(enum_descriptor).collect(),
+		options: build_options(msg.options_with_validators()),
+		reserved_range: msg
+			.reserved_numbers
+			.iter()
+			.map(|range| ReservedRange {
+				start: Some(range.start),
+				end: Some(range.end),
+			})
+			.collect(),
+		reserved_name: msg
+			.reserved_names
+			.iter()
+			.map(|name| name.to_string())
+			.collect(),
+		..Default::default()
+	};
+
+	let msg_type_name = type_name(&msg.package, &msg.name);
+
+	// Synthetic oneofs for proto3 optional fields must come after all the real oneofs
+	let mut synthetic_oneofs: Vec<OneofDescriptorProto> = Vec::new();
+
+	for entry in &msg.entries {
+		match entry {
+			MessageEntry::Field(field) => {
+				let is_proto3_optional = is_proto3
+					&& matches!(&field.type_, FieldType::Optional(inner) if !inner.is_message());
+
+				let (mut field_desc, map_entry) =
+					field_descriptor(field, Some(&msg_type_name), is_proto3_optional);
+
+				if is_proto3_optional {
+					#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+					let synthetic_index = synthetic_oneofs.len() as i32;
+
+					field_desc.oneof_index = Some(synthetic_index);
+
+					synthetic_oneofs.push(OneofDescriptorProto {
+						name: Some(format!("_{}", field.name)),
+						options: None,
+					});
+				}
+
+				descriptor.field.push(field_desc);
+				descriptor.nested_type.extend(map_entry);
+			}
+			MessageEntry::Oneof(oneof) => {
+				#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+				let oneof_index = descriptor.oneof_decl.len() as i32;
+
+				descriptor.oneof_decl.push(OneofDescriptorProto {
+					name: Some(oneof.name.to_string()),
+					options: build_options(oneof.options_with_validators()),
+				});
+
+				for field in &oneof.fields {
+					let (mut field_desc, _) = field_descriptor(field, Some(&msg_type_name), false);
+
+					field_desc.oneof_index = Some(oneof_index);
+
+					descriptor.field.push(field_desc);
+				}
+			}
+		}
+	}
+
+	if !synthetic_oneofs.is_empty() {
+		#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+		let offset = descriptor.oneof_decl.len() as i32;
+
+		for field in &mut descriptor.field {
+			if field.proto3_optional == Some(true)
+				&& let Some(idx) = &mut field.oneof_index
+			{
+				*idx += offset;
+			}
+		}
+
+		descriptor
+			.oneof_decl
+			.append(&mut synthetic_oneofs);
+	}
+
+	descriptor
+}
+
+fn field_descriptor(
+	field: &Field,
+	parent_type_name: Option<&str>,
+	is_proto3_optional: bool,
+) -> (FieldDescriptorProto, Option<DescriptorProto>) {
+	let json_name = field.json_name();
+
+	let mut descriptor = FieldDescriptorProto {
+		name: Some(field.name.to_string()),
+		number: Some(field.tag),
+		label: Some(Label::Optional as i32),
+		json_name: Some(json_name),
+		options: build_options(field_options(field)),
+		proto3_optional: is_proto3_optional.then_some(true),
+		..Default::default()
+	};
+
+	let mut map_entry: Option<DescriptorProto> = None;
+
+	match &field.type_ {
+		FieldType::Normal(type_) | FieldType::Optional(type_) => {
+			set_field_type(&mut descriptor, type_);
+		}
+		FieldType::Repeated(type_) => {
+			set_field_type(&mut descriptor, type_);
+			descriptor.label = Some(Label::Repeated as i32);
+		}
+		FieldType::Map { keys, values } => {
+			let entry_name = format!("{}Entry", to_pascal_case(&field.name));
+
+			let entry_field = |name: &str, number: i32, type_: &ProtoType| {
+				let mut entry_field = FieldDescriptorProto {
+					name: Some(name.to_string()),
+					number: Some(number),
+					label: Some(Label::Optional as i32),
+					json_name: Some(name.to_string()),
+					..Default::default()
+				};
+
+				set_field_type(&mut entry_field, type_);
+
+				entry_field
+			};
+
+			map_entry = Some(DescriptorProto {
+				name: Some(entry_name.clone()),
+				field: vec![
+					entry_field("key", 1, &keys.into_type()),
+					entry_field("value", 2, values),
+				],
+				options: Some(MessageOptions {
+					map_entry: Some(true),
+					..Default::default()
+				}),
+				..Default::default()
+			});
+
+			descriptor.label = Some(Label::Repeated as i32);
+			descriptor.r#type = Some(FieldKind::Message as i32);
+			descriptor.type_name = Some(match parent_type_name {
+				Some(parent) => format!("{parent}.{entry_name}"),
+				None => entry_name,
+			});
+		}
+	};
+
+	(descriptor, map_entry)
+}
+
+// `json_name` is a field of the descriptor and not an actual option
+fn field_options(field: &Field) -> impl Iterator<Item = &ProtoOption> {
+	field
+		.options_with_validators()
+		.filter(|opt| !(opt.name == "json_name" && matches!(opt.value, OptionValue::String(_))))
+}
+
+// Items in the root package have no package segment
+fn type_name(package: &str, name: &str) -> String {
+	if package.is_empty() {
+		format!(".{name}")
+	} else {
+		format!(".{package}.{name}")
+	}
+}
+
+fn set_field_type(descriptor: &mut FieldDescriptorProto, type_: &ProtoType) {
+	descriptor.r#type = Some(DescriptorType::from(type_.clone()) as i32);
+
+	if let ProtoType::Message(path) | ProtoType::Enum(path) = type_ {
+		descriptor.type_name = Some(type_name(&path.package, &path.name));
+	}
+}
+
+fn enum_descriptor(enum_: &EnumSchema) -> EnumDescriptorProto {
+	EnumDescriptorProto {
+		name: Some(enum_.short_name.to_string()),
+		value: enum_
+			.variants
+			.iter()
+			.map(|variant| EnumValueDescriptorProto {
+				name: Some(variant.name.to_string()),
+				number: Some(variant.tag),
+				options: build_options(&variant.options),
+			})
+			.collect(),
+		options: build_options(&enum_.options),
+		// Unlike message ranges, enum ranges are inclusive
+		reserved_range: enum_
+			.reserved_numbers
+			.iter()
+			.map(|range| EnumReservedRange {
+				start: Some(range.start),
+				end: Some(range.end - 1),
+			})
+			.collect(),
+		reserved_name: enum_
+			.reserved_names
+			.iter()
+			.map(|name| name.to_string())
+			.collect(),
+	}
+}
+
+fn service_descriptor(service: &Service) -> ServiceDescriptorProto {
+	ServiceDescriptorProto {
+		name: Some(service.name.to_string()),
+		method: service
+			.handlers
+			.iter()
+			.map(|handler| MethodDescriptorProto {
+				name: Some(handler.name.to_string()),
+				input_type: Some(type_name(
+					&handler.request.message.package,
+					&handler.request.message.name,
+				)),
+				output_type: Some(type_name(
+					&handler.response.message.package,
+					&handler.response.message.name,
+				)),
+				options: build_options(&handler.options),
+				client_streaming: handler.request.is_stream.then_some(true),
+				server_streaming: handler.response.is_stream.then_some(true),
+			})
+			.collect(),
+		options: build_options(&service.options),
+	}
+}
+
+fn to_pascal_case(name: &str) -> String {
+	let mut output = to_json_name(name);
+
+	if let Some(first) = output.get_mut(..1) {
+		first.make_ascii_uppercase();
+	}
+
+	output
+}
+
+/// Implemented by the descriptor options, to map the built-in options to their dedicated fields.
+trait DescriptorOptions: Default + Message {
+	/// Returns `false` if the option is not built-in or if the value is not of the expected type.
+	///
+	/// The `features.*` options are also considered as built-in.
+	fn set_builtin(&mut self, name: &str, value: &OptionValue) -> bool;
+
+	fn uninterpreted_options(&mut self) -> &mut Vec<UninterpretedOption>;
+}
+
+fn build_options<'a, O: DescriptorOptions>(
+	options: impl IntoIterator<Item = &'a ProtoOption>,
+) -> Option<O> {
+	let mut output: Option<O> = None;
+
+	for option in options {
+		let output = output.get_or_insert_default();
+
+		if !output.set_builtin(&option.name, &option.value) {
+			output
+				.uninterpreted_options()
+				.extend(uninterpreted_options(option));
+		}
+	}
+
+	output
+}
+
+fn set_feature(
+	features: &mut Option<prost_types::FeatureSet>,
+	name: &str,
+	value: &OptionValue,
+) -> bool {
+	let (Some(name), OptionValue::Enum(value)) = (name.strip_prefix("features."), value) else {
+		return false;
+	};
+
+	macro_rules! match_features {
+		($($field:ident => $enum_type:ident),*) => {
+			match name {
+				$(
+					stringify!($field) => {
+						if let Some(variant) = feature_set::$enum_type::from_str_name(value) {
+							features.get_or_insert_default().$field = Some(variant as i32);
+							true
+						} else {
+							false
+						}
+					}
+				)*
+				_ => false,
+			}
+		};
+	}
+
+	match_features!(
+		field_presence => FieldPresence,
+		enum_type => EnumType,
+		repeated_field_encoding => RepeatedFieldEncoding,
+		utf8_validation => Utf8Validation,
+		message_encoding => MessageEncoding,
+		json_format => JsonFormat
+	)
+}
+
+macro_rules! impl_descriptor_options {
+	(
+		$target:ty
+		$(, bool: [ $($bool_field:ident),* ])?
+		$(, string: [ $($str_field:ident),* ])?
+		$(, enum: [ $($enum_field:ident => $enum_type:ty),* ])?
+		$(, repeated_enum: [ $($repeated_enum_field:ident => $repeated_enum_type:ty),* ])?
+	) => {
+		impl DescriptorOptions for $target {
+			fn set_builtin(&mut self, name: &str, value: &OptionValue) -> bool {
+				if set_feature(&mut self.features, name, value) {
+					return true;
+				}
+
+				match (name, value) {
+					$($(
+						(stringify!($bool_field), OptionValue::Bool(val)) => {
+							self.$bool_field = Some(*val);
+							true
+						}
+					)*)?
+					$($(
+						(stringify!($str_field), OptionValue::String(val)) => {
+							self.$str_field = Some(val.to_string());
+							true
+						}
+					)*)?
+					$($(
+						(stringify!($enum_field), OptionValue::Enum(val)) => {
+							if let Some(variant) = <$enum_type>::from_str_name(val) {
+								self.$enum_field = Some(variant as i32);
+								true
+							} else {
+								false
+							}
+						}
+					)*)?
+					$($(
+						(stringify!($repeated_enum_field), OptionValue::Enum(val)) => {
+							if let Some(variant) = <$repeated_enum_type>::from_str_name(val) {
+								self.$repeated_enum_field.push(variant as i32);
+								true
+							} else {
+								false
+							}
+						}
+					)*)?
+					_ => false,
+				}
+			}
+
+			#[inline]
+			fn uninterpreted_options(&mut self) -> &mut Vec<UninterpretedOption> {
+				&mut self.uninterpreted_option
+			}
+		}
+	};
+}
+
+impl_descriptor_options!(
+	FileOptions,
+	bool: [
+		java_multiple_files,
+		java_generate_equals_and_hash,
+		java_string_check_utf8,
+		cc_generic_services,
+		java_generic_services,
+		py_generic_services,
+		php_generic_services,
+		deprecated,
+		cc_enable_arenas
+	],
+	string: [
+		java_package,
+		java_outer_classname,
+		go_package,
+		objc_class_prefix,
+		csharp_namespace,
+		swift_prefix,
+		php_class_prefix,
+		php_namespace,
+		php_metadata_namespace,
+		ruby_package
+	],
+	enum: [optimize_for => OptimizeMode]
+);
+
+impl_descriptor_options!(
+	MessageOptions,
+	bool: [
+		message_set_wire_format,
+		no_standard_descriptor_accessor,
+		deprecated,
+		map_entry
+	]
+);
+
+impl_descriptor_options!(
+	FieldOptions,
+	bool: [packed, lazy, unverified_lazy, deprecated, weak, debug_redact],
+	string: [],
+	enum: [
+		ctype => CType,
+		jstype => JsType,
+		retention => OptionRetention
+	],
+	repeated_enum: [targets => OptionTargetType]
+);
+
+// Oneofs have no built-in options aside from `features`
+impl DescriptorOptions for OneofOptions {
+	#[inline]
+	fn set_builtin(&mut self, name: &str, value: &OptionValue) -> bool {
+		set_feature(&mut self.features, name, value)
+	}
+
+	#[inline]
+	fn uninterpreted_options(&mut self) -> &mut Vec<UninterpretedOption> {
+		&mut self.uninterpreted_option
+	}
+}
+
+impl_descriptor_options!(EnumOptions, bool: [allow_alias, deprecated]);
+
+impl_descriptor_options!(EnumValueOptions, bool: [deprecated]);
+
+impl_descriptor_options!(ServiceOptions, bool: [deprecated]);
+
+impl_descriptor_options!(
+	MethodOptions,
+	bool: [deprecated],
+	string: [],
+	enum: [idempotency_level => IdempotencyLevel]
+);
+
+// Repeated options are split into one uninterpreted option per item, just like they would be written in a file
+fn uninterpreted_options(option: &ProtoOption) -> Vec<UninterpretedOption> {
+	match &option.value {
+		OptionValue::List(list) => list
+			.iter()
+			.map(|item| uninterpreted_option(&option.name, item))
+			.collect(),
+		value => vec![uninterpreted_option(&option.name, value)],
+	}
+}
+
+fn uninterpreted_option(name: &str, value: &OptionValue) -> UninterpretedOption {
+	let mut output = UninterpretedOption {
+		name: option_name_parts(name),
+		..Default::default()
+	};
+
+	match value {
+		OptionValue::Bool(val) => output.identifier_value = Some(val.to_string()),
+		OptionValue::Enum(val) => output.identifier_value = Some(val.to_string()),
+		OptionValue::Int(val) => {
+			if *val < 0 {
+				output.negative_int_value = Some(*val);
+			} else {
+				output.positive_int_value = Some(val.cast_unsigned());
+			}
+		}
+		OptionValue::Uint(val) => output.positive_int_value = Some(*val),
+		OptionValue::Float(val) => output.double_value = Some(*val),
+		OptionValue::String(val) => output.string_value = Some(val.as_bytes().to_vec()),
+		OptionValue::Bytes(val) => output.string_value = Some(val.to_vec()),
+		OptionValue::Message(val) => {
+			let mut aggregate = String::new();
+
+			// The aggregate value does not include the outer braces
+			write_message_fields(&mut aggregate, val);
+
+			output.aggregate_value = Some(aggregate);
+		}
+		OptionValue::Duration(Duration { seconds, nanos })
+		| OptionValue::Timestamp(Timestamp { seconds, nanos }) => {
+			output.aggregate_value = Some(format!("seconds: {seconds} nanos: {nanos}"));
+		}
+		// Nested lists cannot be represented in an option
+		OptionValue::List(_) => {
+			let mut aggregate = String::new();
+
+			write_text_value(&mut aggregate, value);
+
+			output.aggregate_value = Some(aggregate);
+		}
+	};
+
+	output
+}
+
+// Splits names like `(buf.validate.field).string.min_len` into their components
+fn option_name_parts(name: &str) -> Vec<NamePart> {
+	let mut parts = Vec::new();
+	let mut rest = name;
+
+	while !rest.is_empty() {
+		if let Some(stripped) = rest.strip_prefix('(')
+			&& let Some(end) = stripped.find(')')
+		{
+			parts.push(NamePart {
+				name_part: stripped[..end].to_string(),
+				is_extension: true,
+			});
+
+			rest = &stripped[end + 1..];
+		} else {
+			let end = rest.find('.').unwrap_or(rest.len());
+
+			if end > 0 {
+				parts.push(NamePart {
+					name_part: rest[..end].to_string(),
+					is_extension: false,
+				});
+			}
+
+			rest = &rest[end..];
+		}
+
+		rest = rest.strip_prefix('.').unwrap_or(rest);
+	}
+
+	parts
+}
+
+fn write_message_fields(output: &mut String, message: &OptionMessage) {
+	for (i, opt) in message.iter().enumerate() {
+		if i != 0 {
+			output.push(' ');
+		}
+
+		let _ = write!(output, "{}: ", opt.name);
+
+		write_text_value(output, &opt.value);
+	}
+}
+
+// Writes the value in the protobuf text format
+fn write_text_value(output: &mut String, value: &OptionValue) {
+	match value {
+		OptionValue::Bool(val) => {
+			let _ = write!(output, "{val}");
+		}
+		OptionValue::Int(val) => {
+			let _ = write!(output, "{val}");
+		}
+		OptionValue::Uint(val) => {
+			let _ = write!(output, "{val}");
+		}
+		OptionValue::Float(val) => {
+			let _ = write!(output, "{val}");
+		}
+		OptionValue::Enum(val) => output.push_str(val),
+		OptionValue::String(val) => {
+			output.push('"');
+
+			for char in val.chars() {
+				match char {
+					'"' => output.push_str("\\\""),
+					'\\' => output.push_str("\\\\"),
+					'\n' => output.push_str("\\n"),
+					_ => output.push(char),
+				}
+			}
+
+			output.push('"');
+		}
+		OptionValue::Bytes(val) => {
+			let _ = write!(output, "\"{}\"", format_bytes_as_proto_string_literal(val));
+		}
+		OptionValue::List(list) => {
+			output.push('[');
+
+			for (i, item) in list.iter().enumerate() {
+				if i != 0 {
+					output.push_str(", ");
+				}
+
+				write_text_value(output, item);
+			}
+
+			output.push(']');
+		}
+		OptionValue::Message(message) => {
+			output.push_str("{ ");
+			write_message_fields(output, message);
+			output.push_str(" }");
+		}
+		OptionValue::Duration(Duration { seconds, nanos })
+		| OptionValue::Timestamp(Timestamp { seconds, nanos }) => {
+			let _ = write!(output, "{{ seconds: {seconds} nanos: {nanos} }}");
+		}
+	};
+}
@@ -0,0 +1,623 @@
+use prost::encoding::{WireType, encode_key, encode_varint};
+
+use super::*;
+
+/// A custom option that can be encoded as an actual extension field, because its schema is known.
+pub(super) struct KnownExtension {
+	name: &'static str,
+	number: u32,
+	message: &'static str,
+}
+
+pub(super) const FIELD_EXTENSIONS: &[KnownExtension] = &[
+	KnownExtension {
+		name: "buf.validate.field",
+		number: 1159,
+		message: "FieldRules",
+	},
+	KnownExtension {
+		name: "buf.validate.predefined",
+		number: 1160,
+		message: "PredefinedRules",
+	},
+];
+
+pub(super) const MESSAGE_EXTENSIONS: &[KnownExtension] = &[KnownExtension {
+	name: "buf.validate.message",
+	number: 1159,
+	message: "MessageRules",
+}];
+
+pub(super) const ONEOF_EXTENSIONS: &[KnownExtension] = &[KnownExtension {
+	name: "buf.validate.oneof",
+	number: 1159,
+	message: "OneofRules",
+}];
+
+#[derive(Clone, Copy)]
+enum Kind {
+	Bool,
+	Int32,
+	Int64,
+	Uint32,
+	Uint64,
+	Sint32,
+	Sint64,
+	Fixed32,
+	Fixed64,
+	Sfixed32,
+	Sfixed64,
+	Float,
+	Double,
+	String,
+	Bytes,
+	Enum(&'static [(&'static str, i32)]),
+	Message(&'static str),
+}
+
+type SchemaField = (&'static str, u32, Kind);
+
+const IGNORE: &[(&str, i32)] = &[
+	("IGNORE_UNSPECIFIED", 0),
+	("IGNORE_IF_ZERO_VALUE", 1),
+	("IGNORE_ALWAYS", 3),
+];
+
+const KNOWN_REGEX: &[(&str, i32)] = &[
+	("KNOWN_REGEX_UNSPECIFIED", 0),
+	("KNOWN_REGEX_HTTP_HEADER_NAME", 1),
+	("KNOWN_REGEX_HTTP_HEADER_VALUE", 2),
+];
+
+// Taken from `buf/validate/validate.proto`, along with the well known types that it uses
+#[allow(clippy::too_many_lines)]
+fn message_fields(name: &str) -> Option<&'static [SchemaField]> {
+	let fields: &'static [SchemaField] = match name {
+		"Rule" => &[
+			("id", 1, Kind::String),
+			("message", 2, Kind::String),
+			("expression", 3, Kind::String),
+		],
+		"MessageRules" => &[
+			("cel_expression", 5, Kind::String),
+			("cel", 3, Kind::Message("Rule")),
+			("oneof", 4, Kind::Message("MessageOneofRule")),
+		],
+		"MessageOneofRule" => &[("fields", 1, Kind::String), ("required", 2, Kind::Bool)],
+		"OneofRules" => &[("required", 1, Kind::Bool)],
+		"FieldRules" => &[
+			("cel_expression", 29, Kind::String),
+			("cel", 23, Kind::Message("Rule")),
+			("required", 25, Kind::Bool),
+			("ignore", 27, Kind::Enum(IGNORE)),
+			("float", 1, Kind::Message("FloatRules")),
+			("double", 2, Kind::Message("DoubleRules")),
+			("int32", 3, Kind::Message("Int32Rules")),
+			("int64", 4, Kind::Message("Int64Rules")),
+			("uint32", 5, Kind::Message("UInt32Rules")),
+			("uint64", 6, Kind::Message("UInt64Rules")),
+			("sint32", 7, Kind::Message("SInt32Rules")),
+			("sint64", 8, Kind::Message("SInt64Rules")),
+			("fixed32", 9, Kind::Message("Fixed32Rules")),
+			("fixed64", 10, Kind::Message("Fixed64Rules")),
+			("sfixed32", 11, Kind::Message("SFixed32Rules")),
+			("sfixed64", 12, Kind::Message("SFixed64Rules")),
+			("bool", 13, Kind::Message("BoolRules")),
+			("string", 14, Kind::Message("StringRules")),
+			("bytes", 15, Kind::Message("BytesRules")),
+			("enum", 16, Kind::Message("EnumRules")),
+			("repeated", 18, Kind::Message("RepeatedRules")),
+			("map", 19, Kind::Message("MapRules")),
+			("any", 20, Kind::Message("AnyRules")),
+			("duration", 21, Kind::Message("DurationRules")),
+			("field_mask", 28, Kind::Message("FieldMaskRules")),
+			("timestamp", 22, Kind::Message("TimestampRules")),
+		],
+		"PredefinedRules" => &[("cel", 1, Kind::Message("Rule"))],
+		"FloatRules" => &[
+			("const", 1, Kind::Float),
+			("lt", 2, Kind::Float),
+			("lte", 3, Kind::Float),
+			("gt", 4, Kind::Float),
+			("gte", 5, Kind::Float),
+			("in", 6, Kind::Float),
+			("not_in", 7, Kind::Float),
+			("finite", 8, Kind::Bool),
+			("example", 9, Kind::Float),
+		],
+		"DoubleRules" => &[
+			("const", 1, Kind::Double),
+			("lt", 2, Kind::Double),
+			("lte", 3, Kind::Double),
+			("gt", 4, Kind::Double),
+			("gte", 5, Kind::Double),
+			("in", 6, Kind::Double),
+			("not_in", 7, Kind::Double),
+			("finite", 8, Kind::Bool),
+			("example", 9, Kind::Double),
+		],
+		"Int32Rules" => &[
+			("const", 1, Kind::Int32),
+			("lt", 2, Kind::Int32),
+			("lte", 3, Kind::Int32),
+			("gt", 4, Kind::Int32),
+			("gte", 5, Kind::Int32),
+			("in", 6, Kind::Int32),
+			("not_in", 7, Kind::Int32),
+			("example", 8, Kind::Int32),
+		],
+		"Int64Rules" => &[
+			("const", 1, Kind::Int64),
+			("lt", 2, Kind::Int64),
+			("lte", 3, Kind::Int64),
+			("gt", 4, Kind::Int64),
+			("gte", 5, Kind::Int64),
+			("in", 6, Kind::Int64),
+			("not_in", 7, Kind::Int64),
+			("example", 9, Kind::Int64),
+		],
+		"UInt32Rules" => &[
+			("const", 1, Kind::Uint32),
+			("lt", 2, Kind::Uint32),
+			("lte", 3, Kind::Uint32),
+			("gt", 4, Kind::Uint32),
+			("gte", 5, Kind::Uint32),
+			("in", 6, Kind::Uint32),
+			("not_in", 7, Kind::Uint32),
+			("example", 8, Kind::Uint32),
+		],
+		"UInt64Rules" => &[
+			("const", 1, Kind::Uint64),
+			("lt", 2, Kind::Uint64),
+			("lte", 3, Kind::Uint64),
+			("gt", 4, Kind::Uint64),
+			("gte", 5, Kind::Uint64),
+			("in", 6, Kind::Uint64),
+			("not_in", 7, Kind::Uint64),
+			("example", 8, Kind::Uint64),
+		],
+		"SInt32Rules" => &[
+			("const", 1, Kind::Sint32),
+			("lt", 2, Kind::Sint32),
+			("lte", 3, Kind::Sint32),
+			("gt", 4, Kind::Sint32),
+			("gte", 5, Kind::Sint32),
+			("in", 6, Kind::Sint32),
+			("not_in", 7, Kind::Sint32),
+			("example", 8, Kind::Sint32),
+		],
+		"SInt64Rules" => &[
+			("const", 1, Kind::Sint64),
+			("lt", 2, Kind::Sint64),
+			("lte", 3, Kind::Sint64),
+			("gt", 4, Kind::Sint64),
+			("gte", 5, Kind::Sint64),
+			("in", 6, Kind::Sint64),
+			("not_in", 7, Kind::Sint64),
+			("example", 8, Kind::Sint64),
+		],
+		"Fixed32Rules" => &[
+			("const", 1, Kind::Fixed32),
+			("lt", 2, Kind::Fixed32),
+			("lte", 3, Kind::Fixed32),
+			("gt", 4, Kind::Fixed32),
+			("gte", 5, Kind::Fixed32),
+			("in", 6, Kind::Fixed32),
+			("not_in", 7, Kind::Fixed32),
+			("example", 8, Kind::Fixed32),
+		],
+		"Fixed64Rules" => &[
+			("const", 1, Kind::Fixed64),
+			("lt", 2, Kind::Fixed64),
+			("lte", 3, Kind::Fixed64),
+			("gt", 4, Kind::Fixed64),
+			("gte", 5, Kind::Fixed64),
+			("in", 6, Kind::Fixed64),
+			("not_in", 7, Kind::Fixed64),
+			("example", 8, Kind::Fixed64),
+		],
+		"SFixed32Rules" => &[
+			("const", 1, Kind::Sfixed32),
+			("lt", 2, Kind::Sfixed32),
+			("lte", 3, Kind::Sfixed32),
+			("gt", 4, Kind::Sfixed32),
+			("gte", 5, Kind::Sfixed32),
+			("in", 6, Kind::Sfixed32),
+			("not_in", 7, Kind::Sfixed32),
+			("example", 8, Kind::Sfixed32),
+		],
+		"SFixed64Rules" => &[
+			("const", 1, Kind::Sfixed64),
+			("lt", 2, Kind::Sfixed64),
+			("lte", 3, Kind::Sfixed64),
+			("gt", 4, Kind::Sfixed64),
+			("gte", 5, Kind::Sfixed64),
+			("in", 6, Kind::Sfixed64),
+			("not_in", 7, Kind::Sfixed64),
+			("example", 8, Kind::Sfixed64),
+		],
+		"BoolRules" => &[("const", 1, Kind::Bool), ("example", 2, Kind::Bool)],
+		"StringRules" => &[
+			("const", 1, Kind::String),
+			("len", 19, Kind::Uint64),
+			("min_len", 2, Kind::Uint64),
+			("max_len", 3, Kind::Uint64),
+			("len_bytes", 20, Kind::Uint64),
+			("min_bytes", 4, Kind::Uint64),
+			("max_bytes", 5, Kind::Uint64),
+			("pattern", 6, Kind::String),
+			("prefix", 7, Kind::String),
+			("suffix", 8, Kind::String),
+			("contains", 9, Kind::String),
+			("not_contains", 23, Kind::String),
+			("in", 10, Kind::String),
+			("not_in", 11, Kind::String),
+			("email", 12, Kind::Bool),
+			("hostname", 13, Kind::Bool),
+			("ip", 14, Kind::Bool),
+			("ipv4", 15, Kind::Bool),
+			("ipv6", 16, Kind::Bool),
+			("uri", 17, Kind::Bool),
+			("uri_ref", 18, Kind::Bool),
+			("address", 21, Kind::Bool),
+			("uuid", 22, Kind::Bool),
+			("tuuid", 33, Kind::Bool),
+			("ip_with_prefixlen", 26, Kind::Bool),
+			("ipv4_with_prefixlen", 27, Kind::Bool),
+			("ipv6_with_prefixlen", 28, Kind::Bool),
+			("ip_prefix", 29, Kind::Bool),
+			("ipv4_prefix", 30, Kind::Bool),
+			("ipv6_prefix", 31, Kind::Bool),
+			("host_and_port", 32, Kind::Bool),
+			("ulid", 35, Kind::Bool),
+			("well_known_regex", 24, Kind::Enum(KNOWN_REGEX)),
+			("strict", 25, Kind::Bool),
+			("example", 34, Kind::String),
+		],
+		"BytesRules" => &[
+			("const", 1, Kind::Bytes),
+			("len", 13, Kind::Uint64),
+			("min_len", 2, Kind::Uint64),
+			("max_len", 3, Kind::Uint64),
+			("pattern", 4, Kind::String),
+			("prefix", 5, Kind::Bytes),
+			("suffix", 6, Kind::Bytes),
+			("contains", 7, Kind::Bytes),
+			("in", 8, Kind::Bytes),
+			("not_in", 9, Kind::Bytes),
+			("ip", 10, Kind::Bool),
+			("ipv4", 11, Kind::Bool),
+			("ipv6", 12, Kind::Bool),
+			("uuid", 15, Kind::Bool),
+			("example", 14, Kind::Bytes),
+		],
+		"EnumRules" => &[
+			("const", 1, Kind::Int32),
+			("defined_only", 2, Kind::Bool),
+			("in", 3, Kind::Int32),
+			("not_in", 4, Kind::Int32),
+			("example", 5, Kind::Int32),
+		],
+		"RepeatedRules" => &[
+			("min_items", 1, Kind::Uint64),
+			("max_items", 2, Kind::Uint64),
+			("unique", 3, Kind::Bool),
+			("items", 4, Kind::Message("FieldRules")),
+		],
+		"MapRules" => &[
+			("min_pairs", 1, Kind::Uint64),
+			("max_pairs", 2, Kind::Uint64),
+			("keys", 4, Kind::Message("FieldRules")),
+			("values", 5, Kind::Message("FieldRules")),
+		],
+		"AnyRules" => &[("in", 2, Kind::String), ("not_in", 3, Kind::String)],
+		"DurationRules" => &[
+			("const", 2, Kind::Message("google.protobuf.Duration")),
+			("lt", 3, Kind::Message("google.protobuf.Duration")),
+			("lte", 4, Kind::Message("google.protobuf.Duration")),
+			("gt", 5, Kind::Message("google.protobuf.Duration")),
+			("gte", 6, Kind::Message("google.protobuf.Duration")),
+			("in", 7, Kind::Message("google.protobuf.Duration")),
+			("not_in", 8, Kind::Message("google.protobuf.Duration")),
+			("example", 9, Kind::Message("google.protobuf.Duration")),
+		],
+		"FieldMaskRules" => &[
+			("const", 1, Kind::Message("google.protobuf.FieldMask")),
+			("in", 2, Kind::String),
+			("not_in", 3, Kind::String),
+			("example", 4, Kind::Message("google.protobuf.FieldMask")),
+		],
+		"TimestampRules" => &[
+			("const", 2, Kind::Message("google.protobuf.Timestamp")),
+			("lt", 3, Kind::Message("google.protobuf.Timestamp")),
+			("lte", 4, Kind::Message("google.protobuf.Timestamp")),
+			("lt_now", 7, Kind::Bool),
+			("gt", 5, Kind::Message("google.protobuf.Timestamp")),
+			("gte", 6, Kind::Message("google.protobuf.Timestamp")),
+			("gt_now", 8, Kind::Bool),
+			("within", 9, Kind::Message("google.protobuf.Duration")),
+			("example", 10, Kind::Message("google.protobuf.Timestamp")),
+		],
+		"google.protobuf.Duration" | "google.protobuf.Timestamp" => {
+			&[("seconds", 1, Kind::Int64), ("nanos", 2, Kind::Int32)]
+		}
+		"google.protobuf.FieldMask" => &[("paths", 1, Kind::String)],
+		_ => return None,
+	};
+
+	Some(fields)
+}
+
+/// Encodes the option as an extension field, if it belongs to one of the known extensions.
+///
+/// Returns `false` if the extension is unknown or if the value does not match its schema, so that the option can be stored as an [`UninterpretedOption`] instead.
+pub(super) fn encode_known_extension(
+	option: &ProtoOption,
+	extensions: &[KnownExtension],
+	buf: &mut Vec<u8>,
+) -> bool {
+	let parts = option_name_parts(&option.name);
+
+	let Some((first, path)) = parts.split_first() else {
+		return false;
+	};
+
+	let Some(extension) = extensions
+		.iter()
+		.find(|ext| first.is_extension && ext.name == first.name_part)
+	else {
+		return false;
+	};
+
+	if path.iter().any(|part| part.is_extension) {
+		return false;
+	}
+
+	let path: Vec<&str> = path
+		.iter()
+		.map(|part| part.name_part.as_str())
+		.collect();
+
+	let mut rules = Vec::new();
+
+	if !encode_path(extension.message, &path, &option.value, &mut rules) {
+		return false;
+	}
+
+	encode_bytes_field(extension.number, &rules, buf);
+
+	true
+}
+
+// Encodes a value found at paths like `(buf.validate.field).string.min_len`
+fn encode_path(message: &str, path: &[&str], value: &OptionValue, buf: &mut Vec<u8>) -> bool {
+	let Some((name, rest)) = path.split_first() else {
+		return match value {
+			OptionValue::Message(fields) => encode_message_fields(message, fields, buf),
+			_ => false,
+		};
+	};
+
+	let Some(field) = find_field(message, name) else {
+		return false;
+	};
+
+	if rest.is_empty() {
+		return encode_value(field, value, buf);
+	}
+
+	let Kind::Message(inner_message) = field.2 else {
+		return false;
+	};
+
+	let mut inner = Vec::new();
+
+	if !encode_path(inner_message, rest, value, &mut inner) {
+		return false;
+	}
+
+	encode_bytes_field(field.1, &inner, buf);
+
+	true
+}
+
+fn find_field(message: &str, name: &str) -> Option<SchemaField> {
+	message_fields(message)?
+		.iter()
+		.find(|(field_name, ..)| *field_name == name)
+		.copied()
+}
+
+fn encode_message_fields(message: &str, fields: &OptionMessage, buf: &mut Vec<u8>) -> bool {
+	fields.iter().all(|opt| {
+		find_field(message, &opt.name).is_some_and(|field| encode_value(field, &opt.value, buf))
+	})
+}
+
+fn encode_value(field: SchemaField, value: &OptionValue, buf: &mut Vec<u8>) -> bool {
+	// Repeated values are encoded as separate entries, which is valid whether or not the field is packed
+	if let OptionValue::List(list) = value {
+		list.iter()
+			.all(|item| encode_single_value(field, item, buf))
+	} else {
+		encode_single_value(field, value, buf)
+	}
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn encode_single_value(field: SchemaField, value: &OptionValue, buf: &mut Vec<u8>) -> bool {
+	let (_, number, kind) = field;
+
+	match (kind, value) {
+		(Kind::Message(message), OptionValue::Message(fields)) => {
+			let mut inner = Vec::new();
+
+			if !encode_message_fields(message, fields, &mut inner) {
+				return false;
+			}
+
+			encode_bytes_field(number, &inner, buf);
+		}
+		(
+			Kind::Message("google.protobuf.Duration"),
+			OptionValue::Duration(Duration { seconds, nanos }),
+		)
+		| (
+			Kind::Message("google.protobuf.Timestamp"),
+			OptionValue::Timestamp(Timestamp { seconds, nanos }),
+		) => {
+			let mut inner = Vec::new();
+
+			encode_varint_field(1, seconds.cast_unsigned(), &mut inner);
+			encode_varint_field(2, i64::from(*nanos).cast_unsigned(), &mut inner);
+
+			encode_bytes_field(number, &inner, buf);
+		}
+		(Kind::Bool, OptionValue::Bool(val)) => encode_varint_field(number, u64::from(*val), buf),
+		(Kind::Int32 | Kind::Int64, _) => {
+			let Some(val) = as_int(value) else {
+				return false;
+			};
+
+			if matches!(kind, Kind::Int32) && i32::try_from(val).is_err() {
+				return false;
+			}
+
+			encode_varint_field(number, val.cast_unsigned(), buf);
+		}
+		(Kind::Uint32 | Kind::Uint64, _) => {
+			let Some(val) = as_uint(value) else {
+				return false;
+			};
+
+			if matches!(kind, Kind::Uint32) && u32::try_from(val).is_err() {
+				return false;
+			}
+
+			encode_varint_field(number, val, buf);
+		}
+		(Kind::Sint32, _) => {
+			let Some(val) = as_int(value).and_then(|val| i32::try_from(val).ok()) else {
+				return false;
+			};
+
+			encode_varint_field(
+				number,
+				u64::from(((val << 1) ^ (val >> 31)).cast_unsigned()),
+				buf,
+			);
+		}
+		(Kind::Sint64, _) => {
+			let Some(val) = as_int(value) else {
+				return false;
+			};
+
+			encode_varint_field(number, ((val << 1) ^ (val >> 63)).cast_unsigned(), buf);
+		}
+		(Kind::Fixed32, _) => {
+			let Some(val) = as_uint(value).and_then(|val| u32::try_from(val).ok()) else {
+				return false;
+			};
+
+			encode_key(number, WireType::ThirtyTwoBit, buf);
+			buf.extend_from_slice(&val.to_le_bytes());
+		}
+		(Kind::Sfixed32, _) => {
+			let Some(val) = as_int(value).and_then(|val| i32::try_from(val).ok()) else {
+				return false;
+			};
+
+			encode_key(number, WireType::ThirtyTwoBit, buf);
+			buf.extend_from_slice(&val.to_le_bytes());
+		}
+		(Kind::Fixed64, _) => {
+			let Some(val) = as_uint(value) else {
+				return false;
+			};
+
+			encode_key(number, WireType::SixtyFourBit, buf);
+			buf.extend_from_slice(&val.to_le_bytes());
+		}
+		(Kind::Sfixed64, _) => {
+			let Some(val) = as_int(value) else {
+				return false;
+			};
+
+			encode_key(number, WireType::SixtyFourBit, buf);
+			buf.extend_from_slice(&val.to_le_bytes());
+		}
+		(Kind::Float, _) => {
+			let Some(val) = as_float(value) else {
+				return false;
+			};
+
+			encode_key(number, WireType::ThirtyTwoBit, buf);
+			buf.extend_from_slice(&(val as f32).to_le_bytes());
+		}
+		(Kind::Double, _) => {
+			let Some(val) = as_float(value) else {
+				return false;
+			};
+
+			encode_key(number, WireType::SixtyFourBit, buf);
+			buf.extend_from_slice(&val.to_le_bytes());
+		}
+		(Kind::String | Kind::Bytes, OptionValue::String(val)) => {
+			encode_bytes_field(number, val.as_bytes(), buf)
+		}
+		(Kind::Bytes, OptionValue::Bytes(val)) => encode_bytes_field(number, val, buf),
+		(Kind::Enum(variants), OptionValue::Enum(name)) => {
+			let Some((_, val)) = variants
+				.iter()
+				.find(|(variant, _)| *variant == &**name)
+			else {
+				return false;
+			};
+
+			encode_varint_field(number, i64::from(*val).cast_unsigned(), buf);
+		}
+		(Kind::Enum(_), OptionValue::Int(val)) => {
+			encode_varint_field(number, val.cast_unsigned(), buf)
+		}
+		_ => return false,
+	};
+
+	true
+}
+
+const fn as_int(value: &OptionValue) -> Option<i64> {
+	match value {
+		OptionValue::Int(val) => Some(*val),
+		OptionValue::Uint(val) if *val <= i64::MAX.cast_unsigned() => Some(val.cast_signed()),
+		_ => None,
+	}
+}
+
+const fn as_uint(value: &OptionValue) -> Option<u64> {
+	match value {
+		OptionValue::Uint(val) => Some(*val),
+		OptionValue::Int(val) if *val >= 0 => Some(val.cast_unsigned()),
+		_ => None,
+	}
+}
+
+#[allow(clippy::cast_precision_loss)]
+const fn as_float(value: &OptionValue) -> Option<f64> {
+	match value {
+		OptionValue::Float(val) => Some(*val),
+		OptionValue::Int(val) => Some(*val as f64),
+		OptionValue::Uint(val) => Some(*val as f64),
+		_ => None,
+	}
+}
+
+fn encode_varint_field(number: u32, value: u64, buf: &mut Vec<u8>) {
+	encode_key(number, WireType::Varint, buf);
+	encode_varint(value, buf);
+}
+
+pub(super) fn encode_bytes_field(number: u32, bytes: &[u8], buf: &mut Vec<u8>) {
+	encode_key(number, WireType::LengthDelimited, buf);
+	encode_varint(bytes.len() as u64, buf);
+	buf.extend_from_slice(bytes);
+}
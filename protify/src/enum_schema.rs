@@ -69,6 +69,8 @@ pub struct EnumSchema {
 	pub reserved_names: Vec<FixedStr>,
 	pub options: Vec<ProtoOption>,
 	pub rust_path: FixedStr,
+	/// The comments that will be rendered above the enum. Populated with the rust doc comments when using the [`proto_enum`] macro.
+	pub comments: Option<FixedStr>,
 }
 
 impl EnumSchema {
@@ -84,6 +86,10 @@ impl EnumSchema {
 		render_reserved_names(&self.reserved_names)
 	}
 
+	pub(crate) fn render_comments(&self) -> Option<String> {
+		render_comments(self.comments.as_ref(), 0)
+	}
+
 	pub(crate) fn render_reserved_numbers(&self) -> Option<String> {
 		render_reserved_numbers(&self.reserved_numbers)
 	}
@@ -97,4 +103,12 @@ pub struct EnumVariant {
 	pub name: FixedStr,
 	pub tag: i32,
 	pub options: Vec<ProtoOption>,
+	/// The comments that will be rendered above the variant.
+	pub comments: Option<FixedStr>,
+}
+
+impl EnumVariant {
+	pub(crate) fn render_comments(&self, indent: usize) -> Option<String> {
+		render_comments(self.comments.as_ref(), indent)
+	}
 }
@@ -15,6 +15,8 @@ pub trait ProtoExtension {
 }
 
 /// Valid extension targets for a Proto3 file.
+///
+/// Besides the descriptor options, the protovalidate rules messages (such as `buf.validate.StringRules`) can also be extended, in order to define [`PredefinedRule`]s.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27,6 +29,28 @@ pub enum ExtensionTarget {
 	EnumValueOptions,
 	ServiceOptions,
 	MethodOptions,
+	BoolRules,
+	StringRules,
+	BytesRules,
+	Int32Rules,
+	Int64Rules,
+	UInt32Rules,
+	UInt64Rules,
+	SInt32Rules,
+	SInt64Rules,
+	Fixed32Rules,
+	Fixed64Rules,
+	SFixed32Rules,
+	SFixed64Rules,
+	FloatRules,
+	DoubleRules,
+	EnumRules,
+	RepeatedRules,
+	MapRules,
+	AnyRules,
+	DurationRules,
+	TimestampRules,
+	FieldMaskRules,
 }
 
 impl ExtensionTarget {
@@ -42,6 +66,44 @@ impl ExtensionTarget {
 			Self::EnumValueOptions => "google.protobuf.EnumValueOptions",
 			Self::ServiceOptions => "google.protobuf.ServiceOptions",
 			Self::MethodOptions => "google.protobuf.MethodOptions",
+			Self::BoolRules => "buf.validate.BoolRules",
+			Self::StringRules => "buf.validate.StringRules",
+			Self::BytesRules => "buf.validate.BytesRules",
+			Self::Int32Rules => "buf.validate.Int32Rules",
+			Self::Int64Rules => "buf.validate.Int64Rules",
+			Self::UInt32Rules => "buf.validate.UInt32Rules",
+			Self::UInt64Rules => "buf.validate.UInt64Rules",
+			Self::SInt32Rules => "buf.validate.SInt32Rules",
+			Self::SInt64Rules => "buf.validate.SInt64Rules",
+			Self::Fixed32Rules => "buf.validate.Fixed32Rules",
+			Self::Fixed64Rules => "buf.validate.Fixed64Rules",
+			Self::SFixed32Rules => "buf.validate.SFixed32Rules",
+			Self::SFixed64Rules => "buf.validate.SFixed64Rules",
+			Self::FloatRules => "buf.validate.FloatRules",
+			Self::DoubleRules => "buf.validate.DoubleRules",
+			Self::EnumRules => "buf.validate.EnumRules",
+			Self::RepeatedRules => "buf.validate.RepeatedRules",
+			Self::MapRules => "buf.validate.MapRules",
+			Self::AnyRules => "buf.validate.AnyRules",
+			Self::DurationRules => "buf.validate.DurationRules",
+			Self::TimestampRules => "buf.validate.TimestampRules",
+			Self::FieldMaskRules => "buf.validate.FieldMaskRules",
+		}
+	}
+
+	/// Returns the path of the file that defines the target message.
+	#[must_use]
+	pub const fn import_path(&self) -> &'static str {
+		match self {
+			Self::FileOptions
+			| Self::MessageOptions
+			| Self::FieldOptions
+			| Self::OneofOptions
+			| Self::EnumOptions
+			| Self::EnumValueOptions
+			| Self::ServiceOptions
+			| Self::MethodOptions => "google/protobuf/descriptor.proto",
+			_ => "buf/validate/validate.proto",
 		}
 	}
 }
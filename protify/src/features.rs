@@ -0,0 +1,352 @@
+use crate::*;
+
+macro_rules! feature_enum {
+	(
+		$(#[$meta:meta])*
+		$name:ident {
+			$($(#[$variant_meta:meta])* $variant:ident => $proto_name:literal),* $(,)?
+		}
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+		pub enum $name {
+			$($(#[$variant_meta])* $variant),*
+		}
+
+		impl $name {
+			/// Returns the name of the variant, as it appears in the protobuf schema.
+			#[must_use]
+			pub const fn as_str_name(&self) -> &'static str {
+				match self {
+					$(Self::$variant => $proto_name),*
+				}
+			}
+
+			/// Creates the variant from its protobuf name.
+			#[must_use]
+			pub fn from_str_name(name: &str) -> Option<Self> {
+				match name {
+					$($proto_name => Some(Self::$variant),)*
+					_ => None,
+				}
+			}
+		}
+
+		impl From<$name> for OptionValue {
+			#[inline]
+			fn from(value: $name) -> Self {
+				Self::Enum(value.as_str_name().into())
+			}
+		}
+	};
+}
+
+feature_enum!(
+	/// The values for the `field_presence` feature.
+	FieldPresence {
+		Explicit => "EXPLICIT",
+		Implicit => "IMPLICIT",
+		LegacyRequired => "LEGACY_REQUIRED",
+	}
+);
+
+feature_enum!(
+	/// The values for the `enum_type` feature.
+	EnumType {
+		Open => "OPEN",
+		Closed => "CLOSED",
+	}
+);
+
+feature_enum!(
+	/// The values for the `repeated_field_encoding` feature.
+	RepeatedFieldEncoding {
+		Packed => "PACKED",
+		Expanded => "EXPANDED",
+	}
+);
+
+feature_enum!(
+	/// The values for the `utf8_validation` feature.
+	Utf8Validation {
+		Verify => "VERIFY",
+		None => "NONE",
+	}
+);
+
+feature_enum!(
+	/// The values for the `message_encoding` feature.
+	MessageEncoding {
+		LengthPrefixed => "LENGTH_PREFIXED",
+		Delimited => "DELIMITED",
+	}
+);
+
+feature_enum!(
+	/// The values for the `json_format` feature.
+	JsonFormat {
+		Allow => "ALLOW",
+		LegacyBestEffort => "LEGACY_BEST_EFFORT",
+	}
+);
+
+feature_enum!(
+	/// The values for the `enforce_naming_style` feature, available from edition 2024.
+	EnforceNamingStyle {
+		Style2024 => "STYLE2024",
+		StyleLegacy => "STYLE_LEGACY",
+	}
+);
+
+/// A set of protobuf [features](https://protobuf.dev/editions/features/), which can be applied to files, messages, fields, oneofs and enums that belong to a file that uses editions.
+///
+/// Each feature that is set is rendered as a `features.<name>` option, and it overrides the value inherited from the parent element.
+///
+/// # Examples
+///
+/// ```
+/// use protify::*;
+///
+/// let features = FeatureSet::builder()
+///   .field_presence(FieldPresence::Implicit)
+///   .rust(vec![proto_option!("string_type" => OptionValue::Enum("VIEW".into()))])
+///   .build();
+///
+/// let options = features.to_options();
+///
+/// assert_eq!(options[0], proto_option!("features.field_presence" => FieldPresence::Implicit));
+/// assert_eq!(options[1].name, "features.(pb.rust).string_type");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Builder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct FeatureSet {
+	pub field_presence: Option<FieldPresence>,
+	pub enum_type: Option<EnumType>,
+	pub repeated_field_encoding: Option<RepeatedFieldEncoding>,
+	pub utf8_validation: Option<Utf8Validation>,
+	pub message_encoding: Option<MessageEncoding>,
+	pub json_format: Option<JsonFormat>,
+	pub enforce_naming_style: Option<EnforceNamingStyle>,
+	/// The Rust-specific features, rendered as `features.(pb.rust).<name>`.
+	///
+	/// The file that defines the `pb.rust` extension must be added to the imports of the file manually.
+	#[builder(default)]
+	pub rust: Vec<ProtoOption>,
+}
+
+impl FeatureSet {
+	/// Returns `true` if no feature is set.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+
+	/// Converts the features into a list of [`ProtoOption`]s.
+	#[must_use]
+	pub fn to_options(&self) -> Vec<ProtoOption> {
+		let Self {
+			field_presence,
+			enum_type,
+			repeated_field_encoding,
+			utf8_validation,
+			message_encoding,
+			json_format,
+			enforce_naming_style,
+			rust,
+		} = self;
+
+		let mut options = Vec::new();
+
+		let mut push = |name: &str, value: Option<OptionValue>| {
+			if let Some(value) = value {
+				options.push(ProtoOption {
+					name: format!("features.{name}").into(),
+					value,
+				});
+			}
+		};
+
+		push("field_presence", field_presence.map(Into::into));
+		push("enum_type", enum_type.map(Into::into));
+		push(
+			"repeated_field_encoding",
+			repeated_field_encoding.map(Into::into),
+		);
+		push("utf8_validation", utf8_validation.map(Into::into));
+		push("message_encoding", message_encoding.map(Into::into));
+		push("json_format", json_format.map(Into::into));
+		push("enforce_naming_style", enforce_naming_style.map(Into::into));
+
+		for option in rust {
+			push(
+				&format!("(pb.rust).{}", option.name),
+				Some(option.value.clone()),
+			);
+		}
+
+		options
+	}
+}
+
+#[doc(hidden)]
+pub fn __with_features<I>(options: I, features: FeatureSet) -> Vec<ProtoOption>
+where
+	I: IntoIterator<Item = ProtoOption>,
+{
+	options
+		.into_iter()
+		.chain(features.to_options())
+		.collect()
+}
+
+// Used by the macros, since the file of an item is only known after the expansion
+#[doc(hidden)]
+pub const fn __check_features_edition(edition: Edition, has_features: bool) {
+	assert!(
+		!(has_features && matches!(edition, Edition::Proto3)),
+		"`features` cannot be used in proto3 files"
+	);
+}
+
+fn enum_feature_in<T>(
+	options: &[ProtoOption],
+	name: &str,
+	from_str_name: fn(&str) -> Option<T>,
+) -> Option<T> {
+	options.iter().find_map(|opt| {
+		if opt.name == name
+			&& let OptionValue::Enum(value) = &opt.value
+		{
+			from_str_name(value)
+		} else {
+			None
+		}
+	})
+}
+
+fn field_presence_in(options: &[ProtoOption]) -> Option<FieldPresence> {
+	enum_feature_in(
+		options,
+		"features.field_presence",
+		FieldPresence::from_str_name,
+	)
+}
+
+fn repeated_field_encoding_in(options: &[ProtoOption]) -> Option<RepeatedFieldEncoding> {
+	enum_feature_in(
+		options,
+		"features.repeated_field_encoding",
+		RepeatedFieldEncoding::from_str_name,
+	)
+}
+
+// The `packed` option does not exist in editions, so it is
+// replaced with the `repeated_field_encoding` feature
+fn apply_packed_to_field(field: &mut Field, inherited: RepeatedFieldEncoding) {
+	let Some(idx) = field
+		.options
+		.iter()
+		.position(|opt| opt.name == "packed")
+	else {
+		return;
+	};
+
+	let option = field.options.remove(idx);
+
+	if let OptionValue::Bool(packed) = option.value
+		&& repeated_field_encoding_in(&field.options).is_none()
+	{
+		let encoding = if packed {
+			RepeatedFieldEncoding::Packed
+		} else {
+			RepeatedFieldEncoding::Expanded
+		};
+
+		if encoding != inherited {
+			field
+				.options
+				.push(proto_option!("features.repeated_field_encoding" => encoding));
+		}
+	}
+}
+
+fn apply_presence_to_field(field: &mut Field, inherited: FieldPresence) {
+	let has_presence_feature = field_presence_in(&field.options).is_some();
+
+	match &field.type_ {
+		// The `optional` label does not exist in editions, where
+		// presence is explicit by default
+		FieldType::Optional(ty) => {
+			field.type_ = FieldType::Normal(ty.clone());
+
+			if !has_presence_feature && inherited != FieldPresence::Explicit {
+				field
+					.options
+					.push(proto_option!("features.field_presence" => FieldPresence::Explicit));
+			}
+		}
+		// Singular fields without `optional` have implicit presence in proto3,
+		// so we preserve that behaviour (and the type used by prost)
+		FieldType::Normal(ty) if !ty.is_message() => {
+			if !has_presence_feature && inherited != FieldPresence::Implicit {
+				field
+					.options
+					.push(proto_option!("features.field_presence" => FieldPresence::Implicit));
+			}
+		}
+		_ => {}
+	};
+}
+
+// `field_presence` and `repeated_field_encoding` can only be set on files and fields,
+// so messages just pass down the values of the file
+fn apply_features_to_message(
+	message: &mut MessageSchema,
+	presence: FieldPresence,
+	encoding: RepeatedFieldEncoding,
+) {
+	for entry in &mut message.entries {
+		// Oneof fields always have explicit presence
+		if let MessageEntry::Field(field) = entry {
+			apply_presence_to_field(field, presence);
+			apply_packed_to_field(field, encoding);
+		}
+	}
+
+	for nested in &mut message.messages {
+		apply_features_to_message(nested, presence, encoding);
+	}
+}
+
+impl ProtoFile {
+	// Adapts the presence of the fields and the `packed` option to the syntax of editions,
+	// so that they keep the same semantics that they have in proto3
+	pub(crate) fn apply_edition_features(&mut self) {
+		if self.edition == Edition::Proto3 {
+			return;
+		}
+
+		let presence = field_presence_in(&self.options).unwrap_or(FieldPresence::Explicit);
+		let encoding =
+			repeated_field_encoding_in(&self.options).unwrap_or(RepeatedFieldEncoding::Packed);
+
+		for message in &mut self.messages {
+			apply_features_to_message(message, presence, encoding);
+		}
+
+		// Extensions always have explicit presence
+		for field in self
+			.extensions
+			.iter_mut()
+			.flat_map(|ext| &mut ext.fields)
+		{
+			if let FieldType::Optional(ty) = &field.type_ {
+				field.type_ = FieldType::Normal(ty.clone());
+			}
+
+			apply_packed_to_field(field, encoding);
+		}
+	}
+}
@@ -10,9 +10,15 @@ pub struct Field {
 	pub type_: FieldType,
 	pub options: Vec<ProtoOption>,
 	pub validators: Vec<ValidatorSchema>,
+	/// The comments that will be rendered above the field.
+	pub comments: Option<FixedStr>,
 }
 
 impl Field {
+	pub(crate) fn render_comments(&self, indent: usize) -> Option<String> {
+		render_comments(self.comments.as_ref(), indent)
+	}
+
 	/// Collects all the options of the field, including those created by the [`ValidatorSchema`]s.
 	pub fn options_with_validators(&self) -> impl Iterator<Item = &options::ProtoOption> {
 		self.options
@@ -20,6 +26,21 @@ impl Field {
 			.chain(self.validators.iter().map(|v| &v.schema))
 	}
 
+	/// Returns the JSON name of the field, which is the value of the `json_name` option if there is one,
+	/// or the name of the field converted to lowerCamelCase like `protoc` does.
+	#[must_use]
+	pub fn json_name(&self) -> String {
+		self.options
+			.iter()
+			.find_map(|opt| match &opt.value {
+				OptionValue::String(custom_name) if opt.name == "json_name" => {
+					Some(custom_name.to_string())
+				}
+				_ => None,
+			})
+			.unwrap_or_else(|| to_json_name(&self.name))
+	}
+
 	pub(crate) fn register_import_path(&self, imports: &mut FileImports) {
 		for import in self
 			.validators
@@ -126,10 +126,10 @@ impl ProtoFile {
 
 	#[doc(hidden)]
 	pub fn with_extensions(&mut self, mut extensions: Vec<Extension>) -> &mut Self {
-		if !extensions.is_empty() {
+		for ext in &extensions {
 			self.imports
 				.set
-				.insert("google/protobuf/descriptor.proto".into());
+				.insert(ext.target.import_path().into());
 		}
 
 		self.extensions.append(&mut extensions);
@@ -154,6 +154,7 @@ pub trait FileSchema {
 	const NAME: &str;
 	const PACKAGE: &str;
 	const EXTERN_PATH: &str;
+	const EDITION: Edition;
 	fn file_schema() -> ProtoFile;
 }
 
@@ -162,15 +163,21 @@ pub struct FileReference {
 	pub name: &'static str,
 	pub package: &'static str,
 	pub extern_path: &'static str,
+	pub edition: Edition,
 }
 
 /// The protobuf edition for a file.
+///
+/// When a file uses editions, the fields are adapted to the new syntax when the package is collected, so that they retain the presence semantics of proto3 (and the types generated by prost). Fields with an [`Option`] type will be rendered without the `optional` label, whereas singular fields with a non-message type will have the `features.field_presence` option set to `IMPLICIT`, unless the file is already using it as the default value.
+///
+/// Other features can be set with the `features` attribute in the macros, or with a [`FeatureSet`].
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Edition {
 	#[default]
 	Proto3,
 	E2023,
+	E2024,
 }
 
 impl Display for Edition {
@@ -178,6 +185,7 @@ impl Display for Edition {
 		match self {
 			Self::Proto3 => write!(f, "syntax = \"proto3\""),
 			Self::E2023 => write!(f, "edition = \"2023\""),
+			Self::E2024 => write!(f, "edition = \"2024\""),
 		}
 	}
 }
@@ -0,0 +1,592 @@
+use serde_json::{Map, Value, json};
+
+use crate::*;
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl Package {
+	/// Generates a JSON Schema (draft 2020-12) document that contains all the messages and enums of this package in its `$defs`, keyed by their fully qualified name (i.e. `my_pkg.MyMsg`).
+	///
+	/// The schemas follow the [proto3 JSON mapping](https://protobuf.dev/programming-guides/json/), so fields use their JSON names, 64-bit integers are represented as strings and the well-known types are mapped to their JSON representation (such as RFC 3339 strings for `google.protobuf.Timestamp`).
+	///
+	/// The rules of the validators (as they appear in the `(buf.validate.field)` option) are translated into their JSON Schema equivalents where possible, such as `minLength` for `min_len` or `uniqueItems` for `unique`. Rules that have no equivalent, like CEL expressions, are ignored.
+	#[must_use]
+	pub fn to_json_schema(&self) -> Value {
+		let generator = JsonSchemaGenerator::new(self, "#/$defs/");
+
+		json!({
+			"$schema": JSON_SCHEMA_DIALECT,
+			"$defs": generator.definitions(),
+		})
+	}
+
+	/// Generates the schemas for the `components.schemas` object of an OpenAPI 3.1 document.
+	///
+	/// The schemas are the same as those generated by [`to_json_schema`](Package::to_json_schema), except that the references point to `#/components/schemas/`.
+	#[must_use]
+	pub fn to_openapi_schemas(&self) -> Map<String, Value> {
+		JsonSchemaGenerator::new(self, "#/components/schemas/").definitions()
+	}
+}
+
+impl MessageSchema {
+	/// Generates a standalone JSON Schema (draft 2020-12) document for this message.
+	///
+	/// The nested messages and enums are placed in the `$defs` of the document, while references to messages and enums outside of this message are represented as generic objects (or strings, for enums). To resolve them, use [`Package::to_json_schema`] instead.
+	#[must_use]
+	pub fn to_json_schema(&self) -> Value {
+		let mut generator = JsonSchemaGenerator {
+			messages: BTreeMap::new(),
+			enums: BTreeMap::new(),
+			ref_prefix: "#/$defs/",
+		};
+
+		for msg in &self.messages {
+			generator.collect_message(msg);
+		}
+
+		for enum_ in &self.enums {
+			generator.collect_enum(enum_);
+		}
+
+		let mut schema = generator.message_schema(self);
+
+		if let Value::Object(obj) = &mut schema {
+			obj.insert("$schema".into(), JSON_SCHEMA_DIALECT.into());
+
+			let definitions = generator.definitions();
+
+			if !definitions.is_empty() {
+				obj.insert("$defs".into(), Value::Object(definitions));
+			}
+		}
+
+		schema
+	}
+}
+
+struct JsonSchemaGenerator<'a> {
+	messages: BTreeMap<String, &'a MessageSchema>,
+	enums: BTreeMap<String, &'a EnumSchema>,
+	ref_prefix: &'static str,
+}
+
+impl<'a> JsonSchemaGenerator<'a> {
+	fn new(package: &'a Package, ref_prefix: &'static str) -> Self {
+		let mut generator = Self {
+			messages: BTreeMap::new(),
+			enums: BTreeMap::new(),
+			ref_prefix,
+		};
+
+		for file in &package.files {
+			for msg in &file.messages {
+				generator.collect_message(msg);
+			}
+
+			for enum_ in &file.enums {
+				generator.collect_enum(enum_);
+			}
+		}
+
+		generator
+	}
+
+	fn collect_message(&mut self, msg: &'a MessageSchema) {
+		self.messages
+			.insert(format!("{}.{}", msg.package, msg.name), msg);
+
+		for nested in &msg.messages {
+			self.collect_message(nested);
+		}
+
+		for enum_ in &msg.enums {
+			self.collect_enum(enum_);
+		}
+	}
+
+	fn collect_enum(&mut self, enum_: &'a EnumSchema) {
+		self.enums
+			.insert(format!("{}.{}", enum_.package, enum_.name), enum_);
+	}
+
+	fn definitions(&self) -> Map<String, Value> {
+		let mut defs = Map::new();
+
+		for (name, msg) in &self.messages {
+			defs.insert(name.clone(), self.message_schema(msg));
+		}
+
+		for (name, enum_) in &self.enums {
+			defs.insert(name.clone(), enum_schema(enum_));
+		}
+
+		defs
+	}
+
+	fn message_schema(&self, msg: &MessageSchema) -> Value {
+		let mut properties = Map::new();
+		let mut required: Vec<Value> = Vec::new();
+		let mut oneofs: Vec<Value> = Vec::new();
+
+		for entry in &msg.entries {
+			match entry {
+				MessageEntry::Field(field) => {
+					let (name, schema, is_required) = self.field_property(field);
+
+					if is_required {
+						required.push(name.clone().into());
+					}
+
+					properties.insert(name, schema);
+				}
+				MessageEntry::Oneof(oneof) => {
+					let mut variants: Vec<Value> = Vec::new();
+
+					for field in &oneof.fields {
+						let (name, schema, _) = self.field_property(field);
+
+						variants.push(json!({ "required": [name] }));
+						properties.insert(name, schema);
+					}
+
+					// Exactly one of the variants (or none of them,
+					// if the oneof is not required) must be set
+					if !is_oneof_required(oneof) {
+						let none_set = json!({ "not": { "anyOf": variants.clone() } });
+						variants.push(none_set);
+					}
+
+					oneofs.push(json!({ "oneOf": variants }));
+				}
+			}
+		}
+
+		let mut schema = Map::new();
+
+		schema.insert("type".into(), "object".into());
+		insert_description(&mut schema, msg.comments.as_ref());
+		schema.insert("properties".into(), Value::Object(properties));
+
+		if !required.is_empty() {
+			schema.insert("required".into(), Value::Array(required));
+		}
+
+		if !oneofs.is_empty() {
+			schema.insert("allOf".into(), Value::Array(oneofs));
+		}
+
+		Value::Object(schema)
+	}
+
+	fn field_property(&self, field: &Field) -> (String, Value, bool) {
+		let rules = field_rules(field);
+
+		let mut schema = match &field.type_ {
+			FieldType::Normal(ty) | FieldType::Optional(ty) => {
+				let mut schema = self.type_schema(ty);
+
+				if let Some(rules) = rules {
+					self.apply_type_rules(&mut schema, ty, rules);
+				}
+
+				schema
+			}
+			FieldType::Repeated(ty) => {
+				let repeated_rules = rules.and_then(|r| get_message(r, "repeated"));
+
+				let mut items = self.type_schema(ty);
+
+				if let Some(items_rules) = repeated_rules.and_then(|r| get_message(r, "items")) {
+					self.apply_type_rules(&mut items, ty, items_rules);
+				}
+
+				let mut schema = Map::new();
+				schema.insert("type".into(), "array".into());
+				schema.insert("items".into(), items);
+
+				if let Some(rules) = repeated_rules {
+					insert_rule(&mut schema, "minItems", rules.get("min_items"));
+					insert_rule(&mut schema, "maxItems", rules.get("max_items"));
+
+					if let Some(OptionValue::Bool(true)) = rules.get("unique") {
+						schema.insert("uniqueItems".into(), true.into());
+					}
+				}
+
+				Value::Object(schema)
+			}
+			FieldType::Map { keys, values } => {
+				let map_rules = rules.and_then(|r| get_message(r, "map"));
+
+				let key_type = keys.into_type();
+				let mut keys_schema = map_key_schema(*keys);
+				let mut values_schema = self.type_schema(values);
+
+				if let Some(map_rules) = map_rules {
+					if let Some(keys_rules) = get_message(map_rules, "keys") {
+						self.apply_type_rules(&mut keys_schema, &key_type, keys_rules);
+					}
+
+					if let Some(values_rules) = get_message(map_rules, "values") {
+						self.apply_type_rules(&mut values_schema, values, values_rules);
+					}
+				}
+
+				let mut schema = Map::new();
+				schema.insert("type".into(), "object".into());
+				schema.insert("propertyNames".into(), keys_schema);
+				schema.insert("additionalProperties".into(), values_schema);
+
+				if let Some(rules) = map_rules {
+					insert_rule(&mut schema, "minProperties", rules.get("min_pairs"));
+					insert_rule(&mut schema, "maxProperties", rules.get("max_pairs"));
+				}
+
+				Value::Object(schema)
+			}
+		};
+
+		if let Value::Object(obj) = &mut schema {
+			insert_description(obj, field.comments.as_ref());
+
+			if field
+				.options
+				.iter()
+				.any(|opt| opt.name == "deprecated" && opt.value == OptionValue::Bool(true))
+			{
+				obj.insert("deprecated".into(), true.into());
+			}
+		}
+
+		let is_required = matches!(
+			rules.and_then(|r| r.get("required")),
+			Some(OptionValue::Bool(true))
+		);
+
+		(field.json_name(), schema, is_required)
+	}
+
+	fn type_schema(&self, ty: &ProtoType) -> Value {
+		match ty {
+			ProtoType::Scalar(scalar) => scalar_schema(*scalar),
+			ProtoType::Enum(path) => {
+				let name = path.to_string();
+
+				if self.enums.contains_key(&name) {
+					json!({ "$ref": format!("{}{name}", self.ref_prefix) })
+				} else {
+					json!({ "type": "string" })
+				}
+			}
+			ProtoType::Message(path) => {
+				if let Some(schema) = well_known_type_schema(path) {
+					return schema;
+				}
+
+				let name = path.to_string();
+
+				if self.messages.contains_key(&name) {
+					json!({ "$ref": format!("{}{name}", self.ref_prefix) })
+				} else {
+					json!({ "type": "object" })
+				}
+			}
+		}
+	}
+
+	// Applies the rules for a single value, like `(buf.validate.field).string`
+	fn apply_type_rules(&self, schema: &mut Value, ty: &ProtoType, rules: &OptionMessage) {
+		let Value::Object(schema) = schema else {
+			return;
+		};
+
+		match ty {
+			ProtoType::Scalar(ProtoScalar::String) => {
+				if let Some(rules) = get_message(rules, "string") {
+					apply_string_rules(schema, rules);
+				}
+			}
+			ProtoType::Scalar(ProtoScalar::Bool) => {
+				if let Some(rules) = get_message(rules, "bool") {
+					insert_rule(schema, "const", rules.get("const"));
+				}
+			}
+			ProtoType::Scalar(ProtoScalar::Bytes) => {}
+			ProtoType::Scalar(scalar) => {
+				if let Some(rules) = get_message(rules, &scalar.to_string()) {
+					apply_number_rules(schema, rules, is_string_encoded(*scalar));
+				}
+			}
+			ProtoType::Enum(path) => {
+				if let Some(rules) = get_message(rules, "enum") {
+					let variants = self
+						.enums
+						.get(&path.to_string())
+						.map(|e| &e.variants);
+
+					let variant_name = |value: &OptionValue| -> Value {
+						match (value, variants) {
+							(OptionValue::Int(number), Some(variants)) => variants
+								.iter()
+								.find(|v| i64::from(v.tag) == *number)
+								.map_or_else(|| (*number).into(), |v| v.name.as_str().into()),
+							_ => option_value_to_json(value, false),
+						}
+					};
+
+					if let Some(value) = rules.get("const") {
+						schema.insert("const".into(), variant_name(value));
+					}
+
+					if let Some(OptionValue::List(list)) = rules.get("in") {
+						schema.insert("enum".into(), list.iter().map(variant_name).collect());
+					}
+
+					if let Some(OptionValue::List(list)) = rules.get("not_in") {
+						schema.insert(
+							"not".into(),
+							json!({ "enum": list.iter().map(variant_name).collect::<Vec<_>>() }),
+						);
+					}
+				}
+			}
+			ProtoType::Message(_) => {}
+		}
+	}
+}
+
+fn enum_schema(enum_: &EnumSchema) -> Value {
+	let mut schema = Map::new();
+
+	schema.insert("type".into(), "string".into());
+	insert_description(&mut schema, enum_.comments.as_ref());
+	schema.insert(
+		"enum".into(),
+		enum_
+			.variants
+			.iter()
+			.map(|v| Value::from(v.name.as_str()))
+			.collect(),
+	);
+
+	Value::Object(schema)
+}
+
+fn field_rules(field: &Field) -> Option<&OptionMessage> {
+	field.validators.iter().find_map(|v| {
+		if v.schema.name == "(buf.validate.field)"
+			&& let OptionValue::Message(rules) = &v.schema.value
+		{
+			Some(rules)
+		} else {
+			None
+		}
+	})
+}
+
+fn is_oneof_required(oneof: &Oneof) -> bool {
+	oneof.options_with_validators().any(|opt| {
+		opt.name == "(buf.validate.oneof).required" && opt.value == OptionValue::Bool(true)
+	})
+}
+
+fn get_message<'a>(rules: &'a OptionMessage, name: &str) -> Option<&'a OptionMessage> {
+	match rules.get(name) {
+		Some(OptionValue::Message(msg)) => Some(msg),
+		_ => None,
+	}
+}
+
+fn insert_rule(schema: &mut Map<String, Value>, keyword: &str, value: Option<&OptionValue>) {
+	if let Some(value) = value {
+		schema.insert(keyword.into(), option_value_to_json(value, false));
+	}
+}
+
+fn insert_description(schema: &mut Map<String, Value>, comments: Option<&FixedStr>) {
+	if let Some(comments) = comments {
+		schema.insert("description".into(), comments.as_str().into());
+	}
+}
+
+fn option_value_to_json(value: &OptionValue, as_string: bool) -> Value {
+	match value {
+		OptionValue::Int(val) if as_string => val.to_string().into(),
+		OptionValue::Uint(val) if as_string => val.to_string().into(),
+		OptionValue::Bool(val) => (*val).into(),
+		OptionValue::Int(val) => (*val).into(),
+		OptionValue::Uint(val) => (*val).into(),
+		OptionValue::Float(val) => (*val).into(),
+		OptionValue::String(val) | OptionValue::Enum(val) => val.as_str().into(),
+		OptionValue::List(list) => list
+			.iter()
+			.map(|v| option_value_to_json(v, as_string))
+			.collect(),
+		// Not used by the rules that can be translated
+		_ => Value::Null,
+	}
+}
+
+fn apply_string_rules(schema: &mut Map<String, Value>, rules: &OptionMessage) {
+	insert_rule(schema, "minLength", rules.get("min_len"));
+	insert_rule(schema, "maxLength", rules.get("max_len"));
+
+	if let Some(len) = rules.get("len") {
+		insert_rule(schema, "minLength", Some(len));
+		insert_rule(schema, "maxLength", Some(len));
+	}
+
+	insert_rule(schema, "pattern", rules.get("pattern"));
+	insert_rule(schema, "const", rules.get("const"));
+	insert_rule(schema, "enum", rules.get("in"));
+
+	if let Some(not_in) = rules.get("not_in") {
+		schema.insert(
+			"not".into(),
+			json!({ "enum": option_value_to_json(not_in, false) }),
+		);
+	}
+
+	let format = |name: &str| json!({ "format": name });
+
+	for option in rules {
+		if option.value != OptionValue::Bool(true) {
+			continue;
+		}
+
+		let (keyword, value): (&str, Value) = match option.name.as_str() {
+			"email" => ("format", "email".into()),
+			"hostname" => ("format", "hostname".into()),
+			"ipv4" => ("format", "ipv4".into()),
+			"ipv6" => ("format", "ipv6".into()),
+			"uri" => ("format", "uri".into()),
+			"uri_ref" => ("format", "uri-reference".into()),
+			"uuid" => ("format", "uuid".into()),
+			"ip" => ("anyOf", json!([format("ipv4"), format("ipv6")])),
+			"address" => (
+				"anyOf",
+				json!([format("hostname"), format("ipv4"), format("ipv6")]),
+			),
+			"tuuid" => ("pattern", "^[0-9a-fA-F]{32}$".into()),
+			"ulid" => (
+				"pattern",
+				"^[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$".into(),
+			),
+			_ => continue,
+		};
+
+		schema.insert(keyword.into(), value);
+	}
+}
+
+fn apply_number_rules(schema: &mut Map<String, Value>, rules: &OptionMessage, is_string: bool) {
+	// Range keywords only apply to numbers
+	if !is_string {
+		insert_rule(schema, "exclusiveMinimum", rules.get("gt"));
+		insert_rule(schema, "minimum", rules.get("gte"));
+		insert_rule(schema, "exclusiveMaximum", rules.get("lt"));
+		insert_rule(schema, "maximum", rules.get("lte"));
+	}
+
+	if let Some(value) = rules.get("const") {
+		schema.insert("const".into(), option_value_to_json(value, is_string));
+	}
+
+	if let Some(list) = rules.get("in") {
+		schema.insert("enum".into(), option_value_to_json(list, is_string));
+	}
+
+	if let Some(list) = rules.get("not_in") {
+		schema.insert(
+			"not".into(),
+			json!({ "enum": option_value_to_json(list, is_string) }),
+		);
+	}
+}
+
+// In the proto3 JSON mapping, 64-bit integers are encoded as strings
+const fn is_string_encoded(scalar: ProtoScalar) -> bool {
+	matches!(
+		scalar,
+		ProtoScalar::Int64
+			| ProtoScalar::Uint64
+			| ProtoScalar::Sint64
+			| ProtoScalar::Fixed64
+			| ProtoScalar::Sfixed64
+	)
+}
+
+fn scalar_schema(scalar: ProtoScalar) -> Value {
+	match scalar {
+		ProtoScalar::Double | ProtoScalar::Float => json!({ "type": "number" }),
+		ProtoScalar::Int32 | ProtoScalar::Sint32 | ProtoScalar::Sfixed32 => json!({
+			"type": "integer",
+			"format": "int32",
+			"minimum": i32::MIN,
+			"maximum": i32::MAX,
+		}),
+		ProtoScalar::Uint32 | ProtoScalar::Fixed32 => json!({
+			"type": "integer",
+			"format": "uint32",
+			"minimum": 0,
+			"maximum": u32::MAX,
+		}),
+		ProtoScalar::Int64 | ProtoScalar::Sint64 | ProtoScalar::Sfixed64 => json!({
+			"type": "string",
+			"format": "int64",
+			"pattern": "^-?[0-9]+$",
+		}),
+		ProtoScalar::Uint64 | ProtoScalar::Fixed64 => json!({
+			"type": "string",
+			"format": "uint64",
+			"pattern": "^[0-9]+$",
+		}),
+		ProtoScalar::Bool => json!({ "type": "boolean" }),
+		ProtoScalar::String => json!({ "type": "string" }),
+		ProtoScalar::Bytes => json!({ "type": "string", "contentEncoding": "base64" }),
+	}
+}
+
+fn map_key_schema(key: ProtoMapKey) -> Value {
+	match key {
+		ProtoMapKey::String => json!({ "type": "string" }),
+		ProtoMapKey::Bool => json!({ "enum": ["true", "false"] }),
+		ProtoMapKey::Uint32 | ProtoMapKey::Uint64 | ProtoMapKey::Fixed32 | ProtoMapKey::Fixed64 => {
+			json!({ "type": "string", "pattern": "^[0-9]+$" })
+		}
+		_ => json!({ "type": "string", "pattern": "^-?[0-9]+$" }),
+	}
+}
+
+fn well_known_type_schema(path: &ProtoPath) -> Option<Value> {
+	if path.package != "google.protobuf" {
+		return None;
+	}
+
+	let schema = match path.name.as_str() {
+		"Timestamp" => json!({ "type": "string", "format": "date-time" }),
+		"Duration" => json!({ "type": "string", "pattern": "^-?[0-9]+(\\.[0-9]{1,9})?s$" }),
+		"FieldMask" => json!({ "type": "string" }),
+		"Empty" => json!({ "type": "object", "maxProperties": 0 }),
+		"Struct" => json!({ "type": "object" }),
+		"Value" => json!({}),
+		"ListValue" => json!({ "type": "array" }),
+		"Any" => json!({
+			"type": "object",
+			"properties": { "@type": { "type": "string" } },
+			"required": ["@type"],
+		}),
+		"DoubleValue" | "FloatValue" => scalar_schema(ProtoScalar::Double),
+		"Int64Value" => scalar_schema(ProtoScalar::Int64),
+		"UInt64Value" => scalar_schema(ProtoScalar::Uint64),
+		"Int32Value" => scalar_schema(ProtoScalar::Int32),
+		"UInt32Value" => scalar_schema(ProtoScalar::Uint32),
+		"BoolValue" => scalar_schema(ProtoScalar::Bool),
+		"StringValue" => scalar_schema(ProtoScalar::String),
+		"BytesValue" => scalar_schema(ProtoScalar::Bytes),
+		_ => return None,
+	};
+
+	Some(schema)
+}
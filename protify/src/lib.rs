@@ -54,6 +54,16 @@ pub extern crate alloc;
 #[cfg(feature = "cel")]
 pub use ::cel;
 
+#[cfg(feature = "descriptors")]
+pub use prost_types;
+
+#[doc(hidden)]
+#[cfg(feature = "tonic")]
+pub use tonic;
+#[doc(hidden)]
+#[cfg(feature = "tonic")]
+pub use tonic_prost;
+
 #[macro_use]
 mod decl_macros;
 
@@ -96,6 +106,8 @@ pub mod macros {
 	#[cfg(feature = "reflection")]
 	pub use protify_proc_macro::{ProtoEnum, ValidatedMessage, ValidatedOneof};
 
+	#[doc(hidden)]
+	pub use protify_proc_macro::__cel_expression;
 	pub use protify_proc_macro::{
 		__Enum, __Extension, __Message, __Oneof, __Service, define_proto_file, proto_enum,
 		proto_extension, proto_message, proto_oneof, proto_package, proto_service,
@@ -149,7 +161,7 @@ mod options;
 #[doc(inline)]
 pub use options::*;
 
-mod well_known_types;
+pub mod well_known_types;
 
 mod message_schema;
 #[doc(inline)]
@@ -166,9 +178,29 @@ mod extension;
 #[doc(inline)]
 pub use extension::*;
 
+mod features;
+#[doc(inline)]
+pub use features::*;
+
 #[cfg(feature = "serde")]
 pub(crate) mod serde_impls;
 
+pub mod compat;
+
+#[cfg(feature = "descriptors")]
+mod descriptors;
+
+#[cfg(feature = "json-schema")]
+mod json_schema;
+
+#[cfg(feature = "std")]
+mod tag_lock;
+
+#[cfg(feature = "tonic")]
+mod tonic_validation;
+#[cfg(feature = "tonic")]
+pub use tonic_validation::*;
+
 #[cfg(not(feature = "std"))]
 mod lazy;
 #[cfg(not(feature = "std"))]
@@ -186,6 +218,49 @@ where
 	f(input)
 }
 
+const fn str_eq(a: &str, b: &str) -> bool {
+	let (a, b) = (a.as_bytes(), b.as_bytes());
+
+	if a.len() != b.len() {
+		return false;
+	}
+
+	let mut i = 0;
+
+	while i < a.len() {
+		if a[i] != b[i] {
+			return false;
+		}
+
+		i += 1;
+	}
+
+	true
+}
+
+// Used by the compile time checks for the fields referenced in CEL expressions
+#[doc(hidden)]
+#[must_use]
+pub const fn __contains_field_name(lists: &[&[&str]], name: &str) -> bool {
+	let mut i = 0;
+
+	while i < lists.len() {
+		let mut j = 0;
+
+		while j < lists[i].len() {
+			if str_eq(lists[i][j], name) {
+				return true;
+			}
+
+			j += 1;
+		}
+
+		i += 1;
+	}
+
+	false
+}
+
 /// Helper trait to convert a type to [`Bytes`].
 ///
 /// It retains `& 'static` when possible, otherwise takes ownership of the value.
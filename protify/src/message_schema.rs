@@ -42,6 +42,50 @@ pub trait MessageProxy: From<Self::Message> + Into<Self::Message> {
 	}
 }
 
+/// Implemented by the [`proto_message`] macro for the message proxies with the `fallible` attribute, instead of [`MessageProxy`].
+///
+/// The conversion from the message can fail, so these proxies implement [`TryFrom`] rather than [`From`] for the associated message.
+pub trait FallibleMessageProxy:
+	TryFrom<Self::Message, Error = ValidationErrors> + Into<Self::Message>
+{
+	type Message: ProtoMessage + ValidatedMessage + From<Self>;
+
+	/// Converts into the associated message.
+	#[inline]
+	fn into_message(self) -> Self::Message {
+		self.into()
+	}
+
+	/// Converts from the associated message.
+	///
+	/// The missing required messages and oneofs, the unknown enum values and the failures of the custom `from_proto` conversions
+	/// are returned as [`ValidationErrors`].
+	#[inline]
+	fn try_from_message(msg: Self::Message) -> Result<Self, ValidationErrors> {
+		Self::try_from(msg)
+	}
+
+	/// Consumes the proxy and converts into the related message, and then validates the result.
+	#[inline]
+	fn into_validated_message(self) -> Result<Self::Message, ValidationErrors> {
+		let msg = self.into_message();
+
+		match msg.validate() {
+			Ok(()) => Ok(msg),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Validates the message, and converts to the proxy if both the validation and the conversion are successful.
+	#[inline]
+	fn from_validated_message(msg: Self::Message) -> Result<Self, ValidationErrors> {
+		match msg.validate() {
+			Ok(()) => Self::try_from_message(msg),
+			Err(e) => Err(e),
+		}
+	}
+}
+
 /// A trait that returns the protobuf path of a given message.
 ///
 /// It must be implemented for the targets of the enums annotated with the [`proto_service`] macro.
@@ -90,6 +134,8 @@ pub struct MessageSchema {
 	pub reserved_numbers: Vec<Range<i32>>,
 	pub validators: Vec<ValidatorSchema>,
 	pub rust_path: FixedStr,
+	/// The comments that will be rendered above the message. Populated with the rust doc comments when using the [`proto_message`] macro.
+	pub comments: Option<FixedStr>,
 }
 
 impl MessageSchema {
@@ -99,6 +145,10 @@ impl MessageSchema {
 		self.render()
 	}
 
+	pub(crate) fn render_comments(&self) -> Option<String> {
+		render_comments(self.comments.as_ref(), 0)
+	}
+
 	/// Iterates all the options of the field, including those created by the [`ValidatorSchema`]s..
 	pub fn options_with_validators(&self) -> impl Iterator<Item = &options::ProtoOption> {
 		self.options
@@ -22,11 +22,34 @@ pub trait OneofProxy: From<Self::Oneof> + Into<Self::Oneof> {
 	}
 }
 
+/// Implemented by the [`proto_oneof`] macro for the oneof proxies with the `fallible` attribute, instead of [`OneofProxy`].
+pub trait FallibleOneofProxy:
+	TryFrom<Self::Oneof, Error = ValidationErrors> + Into<Self::Oneof>
+{
+	type Oneof: ProtoOneof + From<Self>;
+
+	/// Converts to the paired oneof.
+	#[inline]
+	fn into_oneof(self) -> Self::Oneof {
+		self.into()
+	}
+
+	/// Converts from the paired oneof, returning the unknown enum values and the failures of the custom `from_proto` conversions
+	/// as [`ValidationErrors`].
+	#[inline]
+	fn try_from_oneof(oneof: Self::Oneof) -> Result<Self, ValidationErrors> {
+		Self::try_from(oneof)
+	}
+}
+
 /// Trait responsible for generating the schema representation of a oneof.
 pub trait ProtoOneof {
 	#[doc(hidden)]
 	const TAGS: &[i32];
 
+	#[doc(hidden)]
+	const __HAS_FEATURES: bool = false;
+
 	/// Returns the protobuf schema representation.
 	fn proto_schema() -> Oneof;
 
@@ -63,6 +86,11 @@ pub trait ProtoOneof {
 ///
 /// Implemented by the [`proto_oneof`] macro.
 pub trait ValidatedOneof: ProtoValidation + Clone {
+	// The names of the fields, used to check if the oneof is covered by a field mask.
+	// If empty, the oneof is always validated.
+	#[doc(hidden)]
+	const FIELD_NAMES: &'static [&'static str] = &[];
+
 	/// Executes validation on this oneof, triggering the validators that have been assigned to it
 	/// via macro attributes, if there are any.
 	///
@@ -127,6 +155,8 @@ pub struct Oneof {
 	pub fields: Vec<Field>,
 	pub options: Vec<ProtoOption>,
 	pub validators: Vec<ValidatorSchema>,
+	/// The comments that will be rendered above the oneof.
+	pub comments: Option<FixedStr>,
 }
 
 impl Oneof {
@@ -135,6 +165,10 @@ impl Oneof {
 		!self.options.is_empty() || !self.validators.is_empty()
 	}
 
+	pub(crate) fn render_comments(&self, indent: usize) -> Option<String> {
+		render_comments(self.comments.as_ref(), indent)
+	}
+
 	/// Iterates all the options of the oneof, including those created by the [`ValidatorSchema`]s.
 	pub fn options_with_validators(&self) -> impl Iterator<Item = &options::ProtoOption> {
 		self.options
@@ -150,6 +184,14 @@ impl Oneof {
 		self
 	}
 
+	#[doc(hidden)]
+	#[must_use]
+	#[inline]
+	pub fn with_comments(mut self, comments: impl Into<FixedStr>) -> Self {
+		self.comments = Some(comments.into());
+		self
+	}
+
 	#[doc(hidden)]
 	#[must_use]
 	#[inline]
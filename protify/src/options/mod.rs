@@ -285,11 +285,12 @@ impl OptionMessageBuilder {
 	}
 
 	pub(crate) fn add_cel_options(&mut self, rules: Vec<CelProgram>) -> &mut Self {
+		self.add_cel_rules(rules.into_iter().map(CelRule::from).collect())
+	}
+
+	pub(crate) fn add_cel_rules(&mut self, rules: Vec<CelRule>) -> &mut Self {
 		if !rules.is_empty() {
-			let rule_options: Vec<OptionValue> = rules
-				.into_iter()
-				.map(|program| program.rule().clone().into())
-				.collect();
+			let rule_options: Vec<OptionValue> = rules.into_iter().map(OptionValue::from).collect();
 			self.set("cel", OptionValue::List(rule_options.into()));
 		}
 		self
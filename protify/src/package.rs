@@ -28,6 +28,7 @@ pub trait PackageSchema {
 
 			for file in &mut files {
 				file.sort_items();
+				file.apply_edition_features();
 			}
 
 			Package {
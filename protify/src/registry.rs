@@ -129,6 +129,7 @@ pub(crate) mod inventory_collection {
 			.into_values()
 			.map(|mut file| {
 				file.sort_items();
+				file.apply_edition_features();
 
 				file
 			})
@@ -141,11 +142,24 @@ pub(crate) mod inventory_collection {
 	}
 }
 
+// Finds a message collected with `inventory` by its full name
+#[cfg(feature = "inventory")]
+pub(crate) fn find_message(full_name: &str) -> Option<(&'static str, MessageSchema)> {
+	inventory::iter::<RegistryMessage>().find_map(|entry| {
+		let type_url = (entry.type_url)();
+		let name = type_url.strip_prefix('/').unwrap_or(type_url);
+
+		(name == full_name).then(|| (name, (entry.message)()))
+	})
+}
+
 #[doc(hidden)]
 pub struct RegistryMessage {
 	pub package: &'static str,
 	pub parent_message: Option<fn() -> &'static str>,
 	pub message: fn() -> MessageSchema,
+	pub type_url: fn() -> &'static str,
+	pub validate_encoded: ValidateEncodedFn,
 }
 
 #[doc(hidden)]
@@ -2,6 +2,25 @@ use crate::*;
 
 pub(crate) const PROTOBUF_MAX_TAG: i32 = 536_870_911;
 
+// Follows the same logic as `protoc`
+pub(crate) fn to_json_name(name: &str) -> String {
+	let mut output = String::with_capacity(name.len());
+	let mut capitalize_next = false;
+
+	for char in name.chars() {
+		if char == '_' {
+			capitalize_next = true;
+		} else if capitalize_next {
+			output.push(char.to_ascii_uppercase());
+			capitalize_next = false;
+		} else {
+			output.push(char);
+		}
+	}
+
+	output
+}
+
 pub(crate) fn render_reserved_numbers(ranges: &[Range<i32>]) -> Option<String> {
 	if ranges.is_empty() {
 		return None;
@@ -53,3 +72,26 @@ pub(crate) fn render_reserved_names(names: &[FixedStr]) -> Option<String> {
 
 	Some(output_str)
 }
+
+pub(crate) fn render_comments(comments: Option<&FixedStr>, indent: usize) -> Option<String> {
+	let comments = comments?;
+
+	let mut output_str = String::new();
+
+	for line in comments.lines() {
+		if line.is_empty() {
+			output_str.push_str("//");
+		} else {
+			write!(output_str, "// {line}").unwrap();
+		}
+
+		output_str.push('\n');
+
+		// So that the item that follows is rendered at the same indentation level
+		for _ in 0..indent {
+			output_str.push(' ');
+		}
+	}
+
+	Some(output_str)
+}
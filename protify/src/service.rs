@@ -19,6 +19,8 @@ pub struct Service {
 	pub options: Vec<ProtoOption>,
 	pub handlers: Vec<ServiceHandler>,
 	pub package: FixedStr,
+	/// The comments that will be rendered above the service. Populated with the rust doc comments when using the [`proto_service`] macro.
+	pub comments: Option<FixedStr>,
 }
 
 impl Service {
@@ -27,6 +29,10 @@ impl Service {
 	pub fn render_schema(&self) -> Result<String, askama::Error> {
 		self.render()
 	}
+
+	pub(crate) fn render_comments(&self) -> Option<String> {
+		render_comments(self.comments.as_ref(), 0)
+	}
 }
 
 /// A struct that represents a protobuf service handler.
@@ -38,10 +44,18 @@ pub struct ServiceHandler {
 	pub options: Vec<ProtoOption>,
 	pub request: HandlerTarget,
 	pub response: HandlerTarget,
+	/// The comments that will be rendered above the handler.
+	pub comments: Option<FixedStr>,
+}
+
+impl ServiceHandler {
+	pub(crate) fn render_comments(&self, indent: usize) -> Option<String> {
+		render_comments(self.comments.as_ref(), indent)
+	}
 }
 
 /// A struct that represents the target of a service handler, either request or response.
-#[derive(Debug, PartialEq, Builder)]
+#[derive(Debug, Clone, PartialEq, Builder)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct HandlerTarget {
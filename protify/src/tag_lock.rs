@@ -0,0 +1,125 @@
+use core::ops::Range;
+use std::path::Path;
+
+use protify_tag_lock::{
+	LOCKFILE_NAME, LockSection, UPDATE_LOCKFILE_VAR, read_lockfile, update_lockfile,
+};
+
+use crate::*;
+
+impl MessageSchema {
+	// Used by the tests generated by the `proto_message` macro when the lockfile is in use
+	#[doc(hidden)]
+	pub fn __check_tag_lock(&self, manifest_dir: &str, source: &str) -> Result<(), String> {
+		// The tags of oneofs are always set manually
+		let tags = self.entries.iter().filter_map(|entry| {
+			if let MessageEntry::Field(field) = entry {
+				Some((field.name.as_str(), field.tag))
+			} else {
+				None
+			}
+		});
+
+		check_tag_lock(
+			manifest_dir,
+			source,
+			&full_name(&self.package, &self.name),
+			tags,
+			&self.reserved_numbers,
+		)
+	}
+}
+
+impl EnumSchema {
+	// Used by the tests generated by the `proto_enum` macro when the lockfile is in use
+	#[doc(hidden)]
+	pub fn __check_tag_lock(&self, manifest_dir: &str, source: &str) -> Result<(), String> {
+		let tags = self
+			.variants
+			.iter()
+			.map(|variant| (variant.name.as_str(), variant.tag));
+
+		check_tag_lock(
+			manifest_dir,
+			source,
+			&full_name(&self.package, &self.name),
+			tags,
+			&self.reserved_numbers,
+		)
+	}
+}
+
+fn full_name(package: &str, name: &str) -> String {
+	if package.is_empty() {
+		name.to_string()
+	} else {
+		format!("{package}.{name}")
+	}
+}
+
+// The macros can only select the section of an item by its name, because the package is not known
+// at that stage, so the tags are checked here against the section of the fully qualified name
+fn check_tag_lock<'a>(
+	manifest_dir: &str,
+	source: &str,
+	full_name: &str,
+	tags: impl IntoIterator<Item = (&'a str, i32)>,
+	reserved_numbers: &[Range<i32>],
+) -> Result<(), String> {
+	let path = Path::new(manifest_dir).join(LOCKFILE_NAME);
+	let tags: BTreeMap<String, i32> = tags
+		.into_iter()
+		.map(|(name, tag)| (name.to_string(), tag))
+		.collect();
+
+	if std::env::var_os(UPDATE_LOCKFILE_VAR).is_some() {
+		let section = LockSection {
+			source: Some(source.to_string()),
+			tags,
+		};
+
+		return update_lockfile(&path, |entries| {
+			if section.tags.is_empty() {
+				entries.remove(full_name);
+			} else {
+				entries.insert(full_name.to_string(), section);
+			}
+		});
+	}
+
+	let entries = read_lockfile(&path)?;
+
+	// New items are recorded when the lockfile is updated
+	let Some(section) = entries.get(full_name) else {
+		return Ok(());
+	};
+
+	let is_reserved = |tag: i32| {
+		reserved_numbers
+			.iter()
+			.any(|range| range.contains(&tag))
+	};
+
+	for (name, &locked) in &section.tags {
+		// Removed and then reserved, so it's a new element with the same name
+		if is_reserved(locked) {
+			continue;
+		}
+
+		match tags.get(name) {
+			Some(&tag) if tag != locked => {
+				return Err(format!(
+					"The tag of `{name}` in `{full_name}` is locked to {locked} in {LOCKFILE_NAME}, but it is set to {tag}. If this change is intended, run the tests with the {UPDATE_LOCKFILE_VAR} environment variable to update the lockfile"
+				));
+			}
+			None => {
+				return Err(format!(
+					"`{name}` in `{full_name}` (with tag {locked}) is recorded in {LOCKFILE_NAME} but it no longer exists. Add {locked} to the `reserved_numbers` to prevent it from being reused"
+				));
+			}
+			_ => {}
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,285 @@
+use core::{
+	cell::Cell,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use prost::Message;
+use tonic::{
+	Status,
+	codec::{BufferSettings, Codec, DecodeBuf, Decoder},
+	codegen::{Service, http},
+};
+use tonic_prost::{ProstCodec, ProstDecoder, ProstEncoder};
+use tower_layer::Layer;
+
+use crate::*;
+
+std::thread_local! {
+	// The mode of the request currently being polled by a `ValidationService`,
+	// waiting to be picked up by the codec of the server that handles it
+	static PENDING_MODE: Cell<Option<ValidationMode>> = const { Cell::new(None) };
+}
+
+/// The kind of validation that should be performed on the incoming requests of a tonic service.
+///
+/// Servers that are not wrapped by a [`ValidationLayer`] do not perform any validation, regardless of how they were generated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ValidationMode {
+	/// No validation is performed.
+	Disabled,
+	/// Validates the requests with [`validate`](ValidatedMessage::validate), stopping at the first violation.
+	#[default]
+	FailFast,
+	/// Validates the requests with [`validate_all`](ValidatedMessage::validate_all), collecting all of the violations.
+	All,
+}
+
+impl ValidationMode {
+	/// Validates the message according to this mode.
+	#[inline]
+	pub fn validate<T: ValidatedMessage>(self, msg: &T) -> Result<(), ValidationErrors> {
+		match self {
+			Self::Disabled => Ok(()),
+			Self::FailFast => msg.validate(),
+			Self::All => msg.validate_all(),
+		}
+	}
+}
+
+/// A tower [`Layer`] that enables the automatic validation of the incoming requests for the services generated with the [`proto_service`] macro.
+///
+/// Each request will be validated as it gets decoded, and if validation fails, the request will be rejected with a [`tonic::Status`] with the [`InvalidArgument`](tonic::Code::InvalidArgument) code, containing the violations in its details, before the handler is invoked.
+///
+/// For streaming requests, each message is validated individually as it is received.
+///
+/// Services generated with `tonic-build` can pick up the mode of this layer as well, if they use the [`ValidatingCodec`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// tonic::transport::Server::builder()
+///   .layer(ValidationLayer::new().validate_all())
+///   .add_service(user_service_server::UserServiceServer::new(MyService))
+///   .serve(addr)
+///   .await?;
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ValidationLayer {
+	mode: ValidationMode,
+}
+
+impl ValidationLayer {
+	/// Creates a new layer that uses [`ValidationMode::FailFast`].
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			mode: ValidationMode::FailFast,
+		}
+	}
+
+	/// Creates a new layer with the given [`ValidationMode`].
+	#[inline]
+	#[must_use]
+	pub const fn with_mode(mode: ValidationMode) -> Self {
+		Self { mode }
+	}
+
+	/// Uses [`ValidationMode::All`], so that all of the violations are collected before rejecting a request.
+	#[inline]
+	#[must_use]
+	pub const fn validate_all(mut self) -> Self {
+		self.mode = ValidationMode::All;
+		self
+	}
+
+	/// Returns the [`ValidationMode`] of this layer.
+	#[inline]
+	#[must_use]
+	pub const fn mode(&self) -> ValidationMode {
+		self.mode
+	}
+}
+
+impl<S> Layer<S> for ValidationLayer {
+	type Service = ValidationService<S>;
+
+	#[inline]
+	fn layer(&self, inner: S) -> Self::Service {
+		ValidationService {
+			inner,
+			mode: self.mode,
+		}
+	}
+}
+
+/// The service created by the [`ValidationLayer`].
+///
+/// It stores the [`ValidationMode`] in the extensions of each request, so that it can be picked up by the services generated with the [`proto_service`] macro, and hands it over to the [`ValidatingCodec`] created by the services generated with `tonic-build`.
+#[derive(Debug, Clone)]
+pub struct ValidationService<S> {
+	inner: S,
+	mode: ValidationMode,
+}
+
+impl<S, B> Service<http::Request<B>> for ValidationService<S>
+where
+	S: Service<http::Request<B>>,
+{
+	type Response = S::Response;
+	type Error = S::Error;
+	type Future = ValidationFuture<S::Future>;
+
+	#[inline]
+	fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		self.inner.poll_ready(cx)
+	}
+
+	#[inline]
+	fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+		req.extensions_mut().insert(self.mode);
+
+		ValidationFuture {
+			inner: Box::pin(self.inner.call(req)),
+			pending_mode: Some(self.mode),
+		}
+	}
+}
+
+/// The future returned by the [`ValidationService`].
+///
+/// While it is being polled, the [`ValidationMode`] is made available to the first [`ValidatingCodec`] that gets created for the request, which is the one used by the server to decode it.
+#[derive(Debug)]
+pub struct ValidationFuture<F> {
+	inner: Pin<Box<F>>,
+	pending_mode: Option<ValidationMode>,
+}
+
+impl<F: Future> Future for ValidationFuture<F> {
+	type Output = F::Output;
+
+	#[inline]
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let outer_mode = PENDING_MODE.replace(self.pending_mode);
+
+		let output = self.inner.as_mut().poll(cx);
+
+		// If the codec has not been created yet, the mode is kept for the next poll
+		self.pending_mode = PENDING_MODE.replace(outer_mode);
+
+		output
+	}
+}
+
+/// A [`Codec`] that wraps [`ProstCodec`] and validates each decoded message according to its [`ValidationMode`].
+///
+/// It can be used with the `codec_path` setting of `tonic-build` to validate the requests of the generated services. The default instance picks up the mode of the [`ValidationLayer`] that wraps the server, so only the decoding of the incoming requests is affected, whereas the generated clients never validate the responses that they receive.
+///
+/// Without a [`ValidationLayer`], the default instance uses [`ValidationMode::Disabled`].
+///
+/// ```rust,ignore
+/// tonic_prost_build::configure()
+///   .codec_path("::protify::ValidatingCodec")
+///   .compile_with_config(config, files, include_paths)?;
+/// ```
+#[derive(Debug)]
+pub struct ValidatingCodec<T, U> {
+	inner: ProstCodec<T, U>,
+	mode: ValidationMode,
+}
+
+impl<T, U> Default for ValidatingCodec<T, U> {
+	#[inline]
+	fn default() -> Self {
+		Self::new(
+			PENDING_MODE
+				.take()
+				.unwrap_or(ValidationMode::Disabled),
+		)
+	}
+}
+
+impl<T, U> ValidatingCodec<T, U> {
+	/// Creates a new codec with the given [`ValidationMode`].
+	#[inline]
+	#[must_use]
+	pub fn new(mode: ValidationMode) -> Self {
+		Self {
+			inner: ProstCodec::default(),
+			mode,
+		}
+	}
+
+	/// Creates a new codec with the [`ValidationMode`] stored in the extensions by the [`ValidationLayer`], or with [`ValidationMode::Disabled`] if it's missing.
+	#[doc(hidden)]
+	#[inline]
+	#[must_use]
+	pub fn from_extensions(extensions: &http::Extensions) -> Self {
+		// The mode is already in the extensions, so it must not leak into the codecs of the clients used inside the handler
+		PENDING_MODE.set(None);
+
+		Self::new(
+			extensions
+				.get::<ValidationMode>()
+				.copied()
+				.unwrap_or(ValidationMode::Disabled),
+		)
+	}
+}
+
+impl<T, U> Codec for ValidatingCodec<T, U>
+where
+	T: Message + Send + 'static,
+	U: ValidatedMessage + Message + Default + Send + 'static,
+{
+	type Encode = T;
+	type Decode = U;
+
+	type Encoder = ProstEncoder<T>;
+	type Decoder = ValidatingDecoder<U>;
+
+	#[inline]
+	fn encoder(&mut self) -> Self::Encoder {
+		self.inner.encoder()
+	}
+
+	#[inline]
+	fn decoder(&mut self) -> Self::Decoder {
+		ValidatingDecoder {
+			inner: self.inner.decoder(),
+			mode: self.mode,
+		}
+	}
+}
+
+/// The [`Decoder`] used by the [`ValidatingCodec`].
+#[derive(Debug)]
+pub struct ValidatingDecoder<U> {
+	inner: ProstDecoder<U>,
+	mode: ValidationMode,
+}
+
+impl<U> Decoder for ValidatingDecoder<U>
+where
+	U: ValidatedMessage + Message + Default,
+{
+	type Item = U;
+	type Error = Status;
+
+	#[inline]
+	fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+		let item = self.inner.decode(src)?;
+
+		if let Some(msg) = &item {
+			self.mode.validate(msg)?;
+		}
+
+		Ok(item)
+	}
+
+	#[inline]
+	fn buffer_settings(&self) -> BufferSettings {
+		self.inner.buffer_settings()
+	}
+}
@@ -52,6 +52,17 @@ pub enum FieldType {
 	Optional(ProtoType),
 }
 
+impl Display for FieldType {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::Normal(ty) => write!(f, "{ty}"),
+			Self::Map { keys, values } => write!(f, "map<{keys}, {values}>"),
+			Self::Repeated(ty) => write!(f, "repeated {ty}"),
+			Self::Optional(ty) => write!(f, "optional {ty}"),
+		}
+	}
+}
+
 pub(crate) struct Sealed;
 
 /// A sealed trait that defines the kind of protobuf map key that a rust type maps to.
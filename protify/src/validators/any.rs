@@ -25,6 +25,15 @@ pub struct AnyValidator {
 	/// Specifies that the type URLs in this list will be considered NOT valid for this field.
 	pub not_in: Option<SortedList<FixedStr>>,
 
+	/// Specifies that the value should be decoded into its concrete type and validated with its own validators.
+	///
+	/// The type is resolved with the [`TypeRegistry`] stored in the [`extensions`](ValidationCtx::extensions) of the context or, if there is none, with the [`global`](TypeRegistry::global) registry.
+	/// The resulting violations are nested under the path of this field.
+	pub validate_contents: bool,
+
+	/// Specifies that type URLs which are not found in the [`TypeRegistry`] should be considered NOT valid, when `validate_contents` is set.
+	pub reject_unknown_types: bool,
+
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<AnyViolation>>,
 }
@@ -69,6 +78,12 @@ impl Validator<Any> for AnyValidator {
 			errors.push(e.into());
 		}
 
+		if self.reject_unknown_types && !self.validate_contents {
+			errors.push(ConsistencyError::ContradictoryInput(
+				"`reject_unknown_types` is only used when `validate_contents` is set".into(),
+			));
+		}
+
 		if errors.is_empty() {
 			Ok(())
 		} else {
@@ -87,15 +102,22 @@ impl Validator<Any> for AnyValidator {
 		let mut is_valid = IsValid::Yes;
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::Any(AnyViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&AnyViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::Any(AnyViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&AnyViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
@@ -103,27 +125,31 @@ impl Validator<Any> for AnyValidator {
 			if let Some(allowed_list) = &self.in_
 				&& !allowed_list.contains(val.type_url.as_str())
 			{
+				let values = FixedStr::__format_list(allowed_list);
+
 				handle_violation!(
 					In,
-					format!(
-						"must have one of these type URLs: {}",
-						FixedStr::__format_list(allowed_list)
-					)
+					format!("must have one of these type URLs: {values}"),
+					values = values
 				);
 			}
 
 			if let Some(forbidden_list) = &self.not_in
 				&& forbidden_list.contains(val.type_url.as_str())
 			{
+				let values = FixedStr::__format_list(forbidden_list);
+
 				handle_violation!(
 					NotIn,
-					format!(
-						"cannot have one of these type URLs: {}",
-						FixedStr::__format_list(forbidden_list)
-					)
+					format!("cannot have one of these type URLs: {values}"),
+					values = values
 				);
 			}
 
+			if self.validate_contents {
+				is_valid &= self.validate_encoded_value(ctx, val)?;
+			}
+
 			#[cfg(feature = "cel")]
 			if !self.cel.is_empty() {
 				let cel_ctx = ProgramsExecutionCtx {
@@ -152,6 +178,63 @@ impl Validator<Any> for AnyValidator {
 	}
 }
 
+impl AnyValidator {
+	fn validate_encoded_value(&self, ctx: &mut ValidationCtx, val: &Any) -> ValidationResult {
+		let validate_encoded = ctx
+			.extensions
+			.get::<TypeRegistry>()
+			.unwrap_or_else(TypeRegistry::global)
+			.__get(&val.type_url);
+
+		let Some(validate_encoded) = validate_encoded else {
+			if !self.reject_unknown_types {
+				return Ok(IsValid::Yes);
+			}
+
+			let type_url = &val.type_url;
+			let error_message = ctx
+				.localized_message(
+					"any.unknown_type",
+					&[("type_url", type_url as &dyn Display)],
+				)
+				.unwrap_or_else(|| format!("has an unknown type URL: {type_url}"));
+
+			// Not a protovalidate rule, so it's reported like a custom CEL rule
+			return ctx.add_violation_with_custom_id(
+				"any.unknown_type",
+				ViolationKind::Cel,
+				error_message,
+			);
+		};
+
+		if let Some(field_context) = &mut ctx.field_context {
+			ctx.parent_elements
+				.push(field_context.as_path_element());
+		}
+
+		let result = validate_encoded(&val.value, ctx);
+
+		if ctx.field_context.is_some() {
+			ctx.parent_elements.pop();
+		}
+
+		match result {
+			Ok(result) => result,
+			Err(e) => {
+				let error_message = ctx
+					.localized_message("any.invalid_value", &[("error", &e as &dyn Display)])
+					.unwrap_or_else(|| format!("could not be decoded: {e}"));
+
+				ctx.add_violation_with_custom_id(
+					"any.invalid_value",
+					ViolationKind::Cel,
+					error_message,
+				)
+			}
+		}
+	}
+}
+
 impl From<AnyValidator> for ProtoOption {
 	#[inline(never)]
 	#[cold]
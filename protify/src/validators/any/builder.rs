@@ -131,6 +131,36 @@ impl<S: State> AnyValidatorBuilder<S> {
 		}
 	}
 
+	/// Specifies that the value should be decoded into its concrete type and validated with its own validators.
+	///
+	/// The type is resolved with the [`TypeRegistry`] stored in the [`extensions`](ValidationCtx::extensions) of the context or, if there is none, with the [`global`](TypeRegistry::global) registry.
+	#[inline]
+	pub fn validate_contents(mut self) -> AnyValidatorBuilder<SetValidateContents<S>>
+	where
+		S::ValidateContents: IsUnset,
+	{
+		self.data.validate_contents = true;
+
+		AnyValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that type URLs which are not found in the [`TypeRegistry`] should be considered NOT valid, when `validate_contents` is set.
+	#[inline]
+	pub fn reject_unknown_types(mut self) -> AnyValidatorBuilder<SetRejectUnknownTypes<S>>
+	where
+		S::RejectUnknownTypes: IsUnset,
+	{
+		self.data.reject_unknown_types = true;
+
+		AnyValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
 	/// Builds the validator.
 	#[must_use]
 	#[inline]
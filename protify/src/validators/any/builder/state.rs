@@ -1,3 +1,11 @@
 use crate::validators::builder_internals::*;
 use protify_proc_macro::builder_state_macro;
-builder_state_macro!(Ignore, Required, In, NotIn, ErrorMessages);
+builder_state_macro!(
+	Ignore,
+	Required,
+	In,
+	NotIn,
+	ValidateContents,
+	RejectUnknownTypes,
+	ErrorMessages
+);
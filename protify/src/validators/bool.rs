@@ -21,6 +21,12 @@ pub struct BoolValidator {
 
 impl_proto_type!(bool, Bool);
 impl_proto_map_key!(bool, Bool);
+impl_wrapper_validation!(
+	crate::well_known_types::BoolValue,
+	bool,
+	BoolValidator,
+	BoolValidatorBuilder
+);
 
 impl Validator<bool> for BoolValidator {
 	type Target = bool;
@@ -68,15 +74,22 @@ impl Validator<bool> for BoolValidator {
 		let mut is_valid = IsValid::Yes;
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::Bool(BoolViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&BoolViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::Bool(BoolViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&BoolViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
@@ -89,7 +102,7 @@ impl Validator<bool> for BoolValidator {
 			&& let Some(const_val) = self.const_
 			&& val != const_val
 		{
-			handle_violation!(Const, format!("must be {const_val}"));
+			handle_violation!(Const, format!("must be {const_val}"), value = const_val);
 		}
 
 		Ok(is_valid)
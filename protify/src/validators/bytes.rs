@@ -10,6 +10,12 @@ use super::*;
 
 impl_proto_type!(Bytes, Bytes);
 impl_proto_type!(Vec<u8>, Bytes);
+impl_wrapper_validation!(
+	crate::well_known_types::BytesValue,
+	Bytes,
+	BytesValidator,
+	BytesValidatorBuilder
+);
 
 /// Validator for the [`Bytes`] type.
 #[non_exhaustive]
@@ -65,6 +71,9 @@ pub struct BytesValidator {
 
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<BytesViolation>>,
+
+	/// The [`PredefinedRule`]s applied to this field, with their assigned values.
+	pub predefined: PredefinedRules,
 }
 
 impl Eq for BytesValidator {}
@@ -90,6 +99,7 @@ impl Hash for BytesValidator {
 		self.not_in.hash(state);
 		self.const_.hash(state);
 		self.error_messages.hash(state);
+		self.predefined.hash(state);
 	}
 }
 
@@ -116,6 +126,7 @@ impl PartialEq for BytesValidator {
 			&& self.not_in == other.not_in
 			&& self.const_ == other.const_
 			&& self.error_messages == other.error_messages
+			&& self.predefined == other.predefined
 	}
 }
 
@@ -148,6 +159,7 @@ impl Validator<Bytes> for BytesValidator {
 
 		if self.const_.is_some()
 			&& (!self.cel.is_empty()
+				|| !self.predefined.is_empty()
 				|| check_prop_some!(
 					len, min_len, max_len, prefix, suffix, contains, in_, not_in, well_known
 				) || self.has_pattern())
@@ -197,6 +209,14 @@ impl Validator<Bytes> for BytesValidator {
 			errors.extend(e.into_iter().map(ConsistencyError::from));
 		}
 
+		#[cfg(feature = "cel")]
+		if let Err(e) = self
+			.predefined
+			.check_programs_with(Vec::<u8>::new(), || rules_message_of(self, "bytes"))
+		{
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
 		if let Err(e) = check_list_rules(self.in_.as_ref(), self.not_in.as_ref()) {
 			errors.push(e.into());
 		}
@@ -258,17 +278,26 @@ impl Validator<Bytes> for BytesValidator {
 		handle_ignore_if_zero_value!(&self.ignore, val.is_none_or(|v| v.is_empty()));
 
 		let mut is_valid = IsValid::Yes;
+		// The values of these rules would reveal the value of the field
+		let is_sensitive = ctx.is_sensitive();
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::Bytes(BytesViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&BytesViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::Bytes(BytesViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&BytesViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
@@ -280,10 +309,15 @@ impl Validator<Bytes> for BytesValidator {
 		if let Some(val) = val {
 			if let Some(const_val) = &self.const_ {
 				if *val != *const_val {
-					handle_violation!(
-						Const,
-						format!("must be equal to \"{}\"", const_val.escape_ascii())
-					);
+					if is_sensitive {
+						handle_violation!(Const, "must be equal to the expected value".to_string());
+					} else {
+						handle_violation!(
+							Const,
+							format!("must be equal to \"{}\"", const_val.escape_ascii()),
+							value = const_val.escape_ascii()
+						);
+					}
 				}
 
 				// Using `const` implies no other rules
@@ -295,7 +329,8 @@ impl Validator<Bytes> for BytesValidator {
 			{
 				handle_violation!(
 					Len,
-					format!("must be exactly {len} byte{} long", pluralize!(len))
+					format!("must be exactly {len} byte{} long", pluralize!(len)),
+					len = len
 				);
 			}
 
@@ -307,7 +342,8 @@ impl Validator<Bytes> for BytesValidator {
 					format!(
 						"must be at least {min_len} byte{} long",
 						pluralize!(min_len)
-					)
+					),
+					min_len = min_len
 				);
 			}
 
@@ -319,7 +355,8 @@ impl Validator<Bytes> for BytesValidator {
 					format!(
 						"cannot be longer than {max_len} byte{}",
 						pluralize!(max_len)
-					)
+					),
+					max_len = max_len
 				);
 			}
 
@@ -328,7 +365,8 @@ impl Validator<Bytes> for BytesValidator {
 			{
 				handle_violation!(
 					Prefix,
-					format!("must start with \"{}\"", prefix.escape_ascii())
+					format!("must start with \"{}\"", prefix.escape_ascii()),
+					prefix = prefix.escape_ascii()
 				);
 			}
 
@@ -337,7 +375,8 @@ impl Validator<Bytes> for BytesValidator {
 			{
 				handle_violation!(
 					Suffix,
-					format!("must end with \"{}\"", suffix.escape_ascii())
+					format!("must end with \"{}\"", suffix.escape_ascii()),
+					suffix = suffix.escape_ascii()
 				);
 			}
 
@@ -348,7 +387,8 @@ impl Validator<Bytes> for BytesValidator {
 			{
 				handle_violation!(
 					Contains,
-					format!("must contain \"{}\"", substring.escape_ascii())
+					format!("must contain \"{}\"", substring.escape_ascii()),
+					contains = substring.escape_ascii()
 				);
 			}
 
@@ -356,31 +396,43 @@ impl Validator<Bytes> for BytesValidator {
 			if let Some(pattern) = &self.pattern
 				&& !pattern.is_match(val)
 			{
-				handle_violation!(Pattern, format!("must match the pattern `{pattern}`"));
+				handle_violation!(
+					Pattern,
+					format!("must match the pattern `{pattern}`"),
+					pattern = pattern
+				);
 			}
 
 			if let Some(allowed_list) = &self.in_
 				&& !allowed_list.contains(val.as_ref())
 			{
-				handle_violation!(
-					In,
-					format!(
-						"must be one of these values: {}",
-						Bytes::__format_list(allowed_list)
-					)
-				);
+				if is_sensitive {
+					handle_violation!(In, "must be one of the allowed values".to_string());
+				} else {
+					let values = Bytes::__format_list(allowed_list);
+
+					handle_violation!(
+						In,
+						format!("must be one of these values: {values}"),
+						values = values
+					);
+				}
 			}
 
 			if let Some(forbidden_list) = &self.not_in
 				&& forbidden_list.contains(val.as_ref())
 			{
-				handle_violation!(
-					NotIn,
-					format!(
-						"cannot be one of these values: {}",
-						Bytes::__format_list(forbidden_list)
-					)
-				);
+				if is_sensitive {
+					handle_violation!(NotIn, "cannot be one of the forbidden values".to_string());
+				} else {
+					let values = Bytes::__format_list(forbidden_list);
+
+					handle_violation!(
+						NotIn,
+						format!("cannot be one of these values: {values}"),
+						values = values
+					);
+				}
 			}
 
 			if let Some(well_known) = &self.well_known {
@@ -421,6 +473,13 @@ impl Validator<Bytes> for BytesValidator {
 
 				is_valid &= cel_ctx.execute_programs()?;
 			}
+
+			#[cfg(feature = "cel")]
+			if !self.predefined.is_empty() {
+				is_valid &= self
+					.predefined
+					.execute(ctx, val.to_vec(), || rules_message_of(self, "bytes"))?;
+			}
 		}
 
 		Ok(is_valid)
@@ -493,6 +552,8 @@ impl From<BytesValidator> for ProtoOption {
 			rules.set(name, val);
 		}
 
+		validator.predefined.add_to_rules(&mut rules);
+
 		let mut outer_rules = OptionMessageBuilder::new();
 
 		if !rules.is_empty() {
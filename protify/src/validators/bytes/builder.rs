@@ -152,6 +152,23 @@ impl<S: State> BytesValidatorBuilder<S> {
 		}
 	}
 
+	/// Applies a [`PredefinedRule`] to this validator, with the given value.
+	///
+	/// If the rule was already applied, its value is replaced.
+	#[inline]
+	pub fn predefined(
+		mut self,
+		rule: &PredefinedRule,
+		value: impl Into<OptionValue>,
+	) -> BytesValidatorBuilder<S> {
+		self.data.predefined.insert(rule, value);
+
+		BytesValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
 	/// Specifies that the given `bytes` field must be of this exact length.
 	#[inline]
 	pub fn len(mut self, val: usize) -> BytesValidatorBuilder<SetLen<S>>
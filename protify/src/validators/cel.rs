@@ -1,5 +1,13 @@
 mod cel_trait;
 pub use cel_trait::*;
+#[cfg(all(feature = "cel", feature = "inventory"))]
+mod extensions;
+#[cfg(all(feature = "cel", feature = "inventory"))]
+pub use extensions::*;
+#[cfg(feature = "cel")]
+mod functions;
+mod predefined;
+pub use predefined::*;
 
 use super::*;
 
@@ -127,11 +135,24 @@ mod cel_impls {
 		}
 	}
 
-	fn initialize_context<'a, T>(value: T) -> Result<Context<'a>, CelError>
+	// The functions and variables shared by every program, which are only registered once
+	static ROOT_CONTEXT: Lazy<Context<'static>> = Lazy::new(|| {
+		let mut ctx = Context::default();
+
+		super::functions::register_protovalidate_functions(&mut ctx);
+		// Registered after the built-in functions, so that they can be overridden
+		#[cfg(feature = "inventory")]
+		super::extensions::apply_cel_extensions(&mut ctx);
+
+		ctx
+	});
+
+	// Creates the scope for a single execution, where `this` (and `now`) are defined
+	pub(super) fn initialize_context<T>(value: T) -> Result<Context<'static>, CelError>
 	where
 		T: TryIntoCel,
 	{
-		let mut ctx = Context::default();
+		let mut ctx = ROOT_CONTEXT.new_inner_scope();
 
 		ctx.add_variable_from_value("this", value.__try_into_cel()?);
 		#[cfg(all(feature = "chrono", any(feature = "std", feature = "chrono-wasm")))]
@@ -159,7 +180,7 @@ mod cel_impls {
 
 			let mut is_valid = IsValid::Yes;
 
-			let cel_ctx = match initialize_context(value) {
+			let mut cel_ctx = match initialize_context(value) {
 				Ok(cel_ctx) => cel_ctx,
 				Err(e) => {
 					let _ = ctx.add_cel_error_violation(e);
@@ -167,7 +188,15 @@ mod cel_impls {
 				}
 			};
 
+			ctx.extensions.apply_cel_variables(&mut cel_ctx);
+
 			for program in programs {
+				if ctx.field_mask.is_some()
+					&& !ctx.covers_cel_expression(&program.rule().expression)
+				{
+					continue;
+				}
+
 				match program.execute(&cel_ctx) {
 					Ok(was_successful) => {
 						if !was_successful {
@@ -182,6 +211,28 @@ mod cel_impls {
 		}
 	}
 
+	/// Executes the given programs with a value that is only known at runtime (such as a dynamic message converted into a [`Value`]), and adds the resulting violations to the [`ValidationCtx`].
+	///
+	/// # Panics
+	///
+	/// Panics if one of the programs failed to compile.
+	#[inline]
+	pub fn execute_cel_programs<T>(
+		ctx: &mut ValidationCtx,
+		programs: &[CelProgram],
+		value: T,
+	) -> ValidationResult
+	where
+		T: TryIntoCel,
+	{
+		ProgramsExecutionCtx {
+			programs,
+			value,
+			ctx,
+		}
+		.execute_programs()
+	}
+
 	#[inline(never)]
 	#[cold]
 	pub(crate) fn test_programs<T>(programs: &[CelProgram], value: T) -> Result<(), Vec<CelError>>
@@ -199,6 +250,11 @@ mod cel_impls {
 		};
 
 		for program in programs {
+			if let Err(e) = program.check_functions(&ctx) {
+				errors.extend(e);
+				continue;
+			}
+
 			if let Err(e) = program.execute(&ctx) {
 				errors.push(e);
 			}
@@ -266,6 +322,35 @@ mod cel_impls {
 				})
 			}
 		}
+
+		// Functions that are not reached with the test value would not cause
+		// an execution error, so we check the references of the whole expression
+		pub(crate) fn check_functions(&self, ctx: &Context) -> Result<(), Vec<CelError>> {
+			let errors: Vec<CelError> = self
+				.get_program()
+				.references()
+				.functions()
+				.into_iter()
+				.filter(|name| !is_operator(name) && !ctx.has_function(name))
+				.map(|name| CelError::UnknownFunction {
+					rule_id: self.inner.rule.id.clone(),
+					name: name.to_string(),
+				})
+				.collect();
+
+			if errors.is_empty() {
+				Ok(())
+			} else {
+				Err(errors)
+			}
+		}
+	}
+
+	// Operators are parsed as calls (like `_==_`, `!_` or `@in`), but they are
+	// evaluated by the interpreter, so they are not in the registry of the functions
+	fn is_operator(name: &str) -> bool {
+		name.chars()
+			.any(|c| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
 	}
 
 	impl CelError {
@@ -276,9 +361,9 @@ mod cel_impls {
 		pub fn rule_id(&self) -> Option<&str> {
 			match self {
 				Self::ConversionError(_) => None,
-				Self::NonBooleanResult { rule_id, .. } | Self::ExecutionError { rule_id, .. } => {
-					Some(rule_id.as_ref())
-				}
+				Self::NonBooleanResult { rule_id, .. }
+				| Self::ExecutionError { rule_id, .. }
+				| Self::UnknownFunction { rule_id, .. } => Some(rule_id.as_ref()),
 			}
 		}
 
@@ -351,6 +436,10 @@ mod cel_impls {
 			rule_id: FixedStr,
 			source: Box<ExecutionError>,
 		},
+		#[error(
+			"CEL program with id `{rule_id}` references the function `{name}`, which is not registered"
+		)]
+		UnknownFunction { rule_id: FixedStr, name: String },
 	}
 
 	const fn partial_eq_value_type(input: ValueType, other: ValueType) -> bool {
@@ -403,6 +492,17 @@ mod cel_impls {
 						false
 					}
 				}
+				Self::UnknownFunction { rule_id, name } => {
+					if let Self::UnknownFunction {
+						rule_id: other_rule_id,
+						name: other_name,
+					} = other
+					{
+						rule_id == other_rule_id && name == other_name
+					} else {
+						false
+					}
+				}
 			}
 		}
 	}
@@ -414,3 +514,45 @@ mod cel_impls {
 		}
 	}
 }
+
+// Formats a list of strings as a CEL list literal
+pub(crate) fn cel_string_list<'a, I>(items: I) -> String
+where
+	I: IntoIterator<Item = &'a str>,
+{
+	let mut output = String::from("[");
+
+	for (i, item) in items.into_iter().enumerate() {
+		if i > 0 {
+			output.push_str(", ");
+		}
+
+		output.push('\'');
+
+		for char in item.chars() {
+			if matches!(char, '\'' | '\\') {
+				output.push('\\');
+			}
+
+			output.push(char);
+		}
+
+		output.push('\'');
+	}
+
+	output.push(']');
+	output
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn cel_string_list_escapes() {
+		assert_eq!(
+			cel_string_list(["abc", "it's", r"back\slash"]),
+			r"['abc', 'it\'s', 'back\\slash']"
+		);
+	}
+}
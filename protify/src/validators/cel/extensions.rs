@@ -0,0 +1,94 @@
+use ::cel::Context;
+
+/// An extension for the [`Context`] of the CEL programs, registered with the [`register_cel_extension`](crate::register_cel_extension) macro (or with the [`register_cel_function`](crate::register_cel_function) and [`register_cel_variable`](crate::register_cel_variable) macros).
+#[doc(hidden)]
+pub struct CelExtension {
+	pub extension: fn(&mut Context<'static>),
+}
+
+inventory::collect!(CelExtension);
+
+pub(super) fn apply_cel_extensions(ctx: &mut Context<'static>) {
+	for entry in inventory::iter::<CelExtension>() {
+		(entry.extension)(ctx);
+	}
+}
+
+/// Registers a function that will be called when initializing the root [`Context`] shared by every CEL program, so that it can add custom functions or variables to it.
+///
+/// The extensions are collected with [`inventory`](https://docs.rs/inventory/latest/inventory/), so they are available as soon as the program starts, both when validating values and when testing the programs (such as with [`check_cel_programs_with`](crate::Validator::check_cel_programs_with)). The closure must not capture any variables.
+///
+/// For the most common use cases, the [`register_cel_function`](crate::register_cel_function) and [`register_cel_variable`](crate::register_cel_variable) macros can be used instead.
+///
+/// If multiple extensions register a function or variable with the same name, the one that is applied last (which is not guaranteed to be the one defined last) takes precedence.
+///
+/// # Examples
+///
+/// ```rust
+/// use protify::*;
+///
+/// register_cel_extension!(|ctx| {
+///   ctx.add_variable_from_value("max_upload_size", 1024u64);
+/// });
+/// ```
+#[macro_export]
+macro_rules! register_cel_extension {
+	($extension:expr $(,)?) => {
+		$crate::inventory::submit! {
+			$crate::CelExtension {
+				extension: {
+					let extension: fn(&mut $crate::cel::Context<'static>) = $extension;
+					extension
+				},
+			}
+		}
+	};
+}
+
+/// Registers a constant variable that will be available in every CEL program.
+///
+/// # Examples
+///
+/// ```rust
+/// use protify::*;
+///
+/// register_cel_variable!("max_upload_size", 1024u64);
+///
+/// let program = cel_program!(id = "size", msg = "too big", expr = "this <= max_upload_size");
+/// ```
+#[macro_export]
+macro_rules! register_cel_variable {
+	($name:expr, $value:expr $(,)?) => {
+		$crate::register_cel_extension!(|ctx| {
+			ctx.add_variable_from_value($name, $value);
+		});
+	};
+}
+
+/// Registers a custom function that will be available in every CEL program.
+///
+/// The function can be anything that can be passed to [`Context::add_function`], as long as it's a function path or a closure that doesn't capture any variables.
+///
+/// # Examples
+///
+/// ```rust
+/// use protify::*;
+/// use protify::cel::extractors::This;
+/// use std::sync::Arc;
+///
+/// fn is_valid_sku(This(sku): This<Arc<String>>) -> bool {
+///   sku.starts_with("SKU-")
+/// }
+///
+/// register_cel_function!("isValidSku", is_valid_sku);
+///
+/// let program = cel_program!(id = "sku", msg = "invalid sku", expr = "isValidSku(this)");
+/// ```
+#[macro_export]
+macro_rules! register_cel_function {
+	($name:expr, $function:expr $(,)?) => {
+		$crate::register_cel_extension!(|ctx| {
+			ctx.add_function($name, $function);
+		});
+	};
+}
@@ -0,0 +1,156 @@
+use ::cel::{
+	Context, ExecutionError, Value,
+	extractors::{Arguments, This},
+};
+
+use super::*;
+use crate::validators::string::well_known_strings::*;
+
+// Registers the custom functions defined by protovalidate, so that the rules
+// behave the same way as they do in the other implementations.
+//
+// They are only registered once, in the root context that is shared by every program.
+//
+// Reference: https://buf.build/docs/protovalidate/cel/extensions/
+pub(super) fn register_protovalidate_functions(ctx: &mut Context) {
+	ctx.add_function("isEmail", is_email);
+	ctx.add_function("isHostname", is_hostname);
+	ctx.add_function("isIp", is_ip);
+	ctx.add_function("isIpPrefix", is_ip_prefix);
+	ctx.add_function("isUri", is_uri);
+	ctx.add_function("isUriRef", is_uri_ref);
+	ctx.add_function("isHostAndPort", is_host_and_port);
+	ctx.add_function("unique", unique);
+	ctx.add_function("isNan", is_nan);
+	ctx.add_function("isInf", is_inf);
+}
+
+fn int_arg(function: &str, args: &[Value], idx: usize) -> Result<Option<i64>, ExecutionError> {
+	match args.get(idx) {
+		None => Ok(None),
+		Some(Value::Int(val)) => Ok(Some(*val)),
+		Some(Value::UInt(val)) => i64::try_from(*val)
+			.map(Some)
+			.map_err(|_| ExecutionError::function_error(function, "integer out of range")),
+		Some(_) => Err(ExecutionError::function_error(
+			function,
+			"expected an integer argument",
+		)),
+	}
+}
+
+fn bool_arg(function: &str, args: &[Value], idx: usize) -> Result<Option<bool>, ExecutionError> {
+	match args.get(idx) {
+		None => Ok(None),
+		Some(Value::Bool(val)) => Ok(Some(*val)),
+		Some(_) => Err(ExecutionError::function_error(
+			function,
+			"expected a boolean argument",
+		)),
+	}
+}
+
+fn is_email(This(val): This<Arc<String>>) -> bool {
+	is_valid_email(&val)
+}
+
+fn is_hostname(This(val): This<Arc<String>>) -> bool {
+	is_valid_hostname(&val)
+}
+
+fn is_uri(This(val): This<Arc<String>>) -> bool {
+	is_valid_uri(&val)
+}
+
+fn is_uri_ref(This(val): This<Arc<String>>) -> bool {
+	is_valid_uri_ref(&val)
+}
+
+// `isIp()` or `isIp(version)`, where version 0 means any version
+fn is_ip(This(val): This<Arc<String>>, Arguments(args): Arguments) -> Result<bool, ExecutionError> {
+	let is_valid = match int_arg("isIp", &args, 0)?.unwrap_or(0) {
+		0 => is_valid_ip(&val),
+		4 => is_valid_ipv4(&val),
+		6 => is_valid_ipv6(&val),
+		_ => false,
+	};
+
+	Ok(is_valid)
+}
+
+// `isIpPrefix()`, `isIpPrefix(version)`, `isIpPrefix(strict)` or `isIpPrefix(version, strict)`.
+// When `strict` is true, the host bits of the address must be zero.
+fn is_ip_prefix(
+	This(val): This<Arc<String>>,
+	Arguments(args): Arguments,
+) -> Result<bool, ExecutionError> {
+	let (version, strict) = if let Some(Value::Bool(strict)) = args.first() {
+		(0, *strict)
+	} else {
+		(
+			int_arg("isIpPrefix", &args, 0)?.unwrap_or(0),
+			bool_arg("isIpPrefix", &args, 1)?.unwrap_or(false),
+		)
+	};
+
+	let is_valid = match (version, strict) {
+		(0, true) => is_valid_ip_prefix(&val),
+		(0, false) => is_valid_ip_with_prefixlen(&val),
+		(4, true) => is_valid_ipv4_prefix(&val),
+		(4, false) => is_valid_ipv4_with_prefixlen(&val),
+		(6, true) => is_valid_ipv6_prefix(&val),
+		(6, false) => is_valid_ipv6_with_prefixlen(&val),
+		_ => false,
+	};
+
+	Ok(is_valid)
+}
+
+// `isHostAndPort(port_required)`
+fn is_host_and_port(
+	This(val): This<Arc<String>>,
+	Arguments(args): Arguments,
+) -> Result<bool, ExecutionError> {
+	let port_required = bool_arg("isHostAndPort", &args, 0)?.ok_or_else(|| {
+		ExecutionError::function_error("isHostAndPort", "missing `port_required` argument")
+	})?;
+
+	if is_valid_host_and_port(&val) {
+		return Ok(true);
+	}
+
+	// Without a port, IPv6 addresses must still be enclosed in brackets
+	let is_valid_host = || {
+		is_valid_hostname(&val)
+			|| is_valid_ipv4(&val)
+			|| val
+				.strip_prefix('[')
+				.and_then(|s| s.strip_suffix(']'))
+				.is_some_and(is_valid_ipv6)
+	};
+
+	Ok(!port_required && is_valid_host())
+}
+
+fn unique(This(list): This<Arc<Vec<Value>>>) -> bool {
+	// Values are not hashable, so we compare them one by one
+	list.iter()
+		.enumerate()
+		.all(|(i, item)| !list[..i].contains(item))
+}
+
+fn is_nan(This(val): This<f64>) -> bool {
+	val.is_nan()
+}
+
+// `isInf()` or `isInf(sign)`, where a positive sign only matches positive infinity,
+// a negative sign only matches negative infinity and 0 matches both
+fn is_inf(This(val): This<f64>, Arguments(args): Arguments) -> Result<bool, ExecutionError> {
+	let is_inf = match int_arg("isInf", &args, 0)?.unwrap_or(0) {
+		0 => val.is_infinite(),
+		sign if sign > 0 => val == f64::INFINITY,
+		_ => val == f64::NEG_INFINITY,
+	};
+
+	Ok(is_inf)
+}
@@ -0,0 +1,329 @@
+use super::*;
+
+/// A protovalidate [predefined rule](https://buf.build/docs/protovalidate/schemas/predefined-rules/), which is an extension of one of the rules messages (such as `buf.validate.StringRules`) that carries one or more CEL rules.
+///
+/// Besides `this`, the expressions of a predefined rule can use the `rule` variable, which contains the value assigned to the extension for a specific field, and the `rules` variable, which contains all of the rules of that field (for example, the whole `StringRules` message).
+///
+/// The extension itself can be defined with [`proto_extension`], by using one of the rules messages as the target and adding the [`option`](PredefinedRule::option) of this rule to the extension field.
+///
+/// # Examples
+///
+/// ```rust
+/// use protify::*;
+///
+/// static MAX_CHARS: Lazy<PredefinedRule> = Lazy::new(|| {
+///   PredefinedRule::new(
+///     "myapp.max_chars",
+///     [cel_program!(
+///       id = "string.max_chars",
+///       msg = "has too many characters",
+///       expr = "size(this) <= rule"
+///     )],
+///   )
+/// });
+///
+/// #[proto_extension(target = StringRules)]
+/// pub struct MyappStringRules {
+///   #[proto(tag = 1161, options = vec![MAX_CHARS.option()])]
+///   max_chars: i32,
+/// }
+///
+/// let validator = StringValidator::builder()
+///   .predefined(&MAX_CHARS, 3)
+///   .build();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PredefinedRule {
+	/// The fully qualified name of the extension, like `myapp.max_chars`.
+	pub extension: FixedStr,
+	/// The programs that will be executed when this rule is used.
+	pub programs: Vec<CelProgram>,
+}
+
+impl PredefinedRule {
+	/// Creates a new instance.
+	#[must_use]
+	#[inline]
+	pub fn new(
+		extension: impl Into<FixedStr>,
+		programs: impl IntoIterator<Item = CelProgram>,
+	) -> Self {
+		Self {
+			extension: extension.into(),
+			programs: programs.into_iter().collect(),
+		}
+	}
+
+	/// Returns the `(buf.validate.predefined)` option which must be added to the extension field that defines this rule.
+	#[must_use]
+	#[inline(never)]
+	#[cold]
+	pub fn option(&self) -> ProtoOption {
+		let mut rules = OptionMessageBuilder::new();
+
+		rules.add_cel_options(self.programs.clone());
+
+		ProtoOption {
+			name: "(buf.validate.predefined)".into(),
+			value: OptionValue::Message(rules.into()),
+		}
+	}
+}
+
+/// The list of [`PredefinedRule`]s applied to a validator, each with its assigned value.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PredefinedRules {
+	items: Vec<(PredefinedRule, OptionValue)>,
+}
+
+impl Eq for PredefinedRules {}
+
+impl Hash for PredefinedRules {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		// Option values are not hashable, but equal lists always have the same rules
+		for (rule, _) in &self.items {
+			rule.hash(state);
+		}
+	}
+}
+
+impl PredefinedRules {
+	/// Checks if the list is empty.
+	#[must_use]
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+
+	/// Returns an iterator of the rules and their respective values.
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (&PredefinedRule, &OptionValue)> {
+		self.items
+			.iter()
+			.map(|(rule, value)| (rule, value))
+	}
+
+	/// Adds a new rule with the given value. If the rule was already present, its value is replaced.
+	pub fn insert(&mut self, rule: &PredefinedRule, value: impl Into<OptionValue>) {
+		let value = value.into();
+
+		if let Some((_, existing)) = self
+			.items
+			.iter_mut()
+			.find(|(r, _)| r.extension == rule.extension)
+		{
+			*existing = value;
+		} else {
+			self.items.push((rule.clone(), value));
+		}
+	}
+
+	// Extensions are written with the `[full.name]: value` syntax inside message literals
+	pub(crate) fn add_to_rules(&self, rules: &mut OptionMessageBuilder) {
+		for (rule, value) in &self.items {
+			rules.set(format!("[{}]", rule.extension), value.clone());
+		}
+	}
+}
+
+// Extracts the type-specific rules (like `StringRules`) from the schema of a validator,
+// so that they can be bound to the `rules` variable
+#[cfg(feature = "cel")]
+#[inline(never)]
+#[cold]
+pub(crate) fn rules_message_of<V>(validator: &V, name: &str) -> Option<OptionValue>
+where
+	V: Clone + Into<ProtoOption>,
+{
+	let option: ProtoOption = validator.clone().into();
+
+	if let OptionValue::Message(field_rules) = option.value {
+		field_rules.get(name).cloned()
+	} else {
+		None
+	}
+}
+
+#[cfg(feature = "cel")]
+mod cel_impls {
+	use super::*;
+
+	use ::cel::{
+		Context, Value,
+		objects::{Key, Map},
+	};
+
+	fn option_value_to_cel(value: &OptionValue) -> Result<Value, CelError> {
+		let output = match value {
+			OptionValue::Bool(val) => Value::Bool(*val),
+			OptionValue::Int(val) => Value::Int(*val),
+			OptionValue::Uint(val) => Value::UInt(*val),
+			OptionValue::Float(val) => Value::Float(*val),
+			OptionValue::String(val) | OptionValue::Enum(val) => val.to_string().into(),
+			OptionValue::Bytes(val) => val.to_vec().into(),
+			OptionValue::Duration(val) => val.__try_into_cel()?,
+			OptionValue::Timestamp(val) => val.__try_into_cel()?,
+			OptionValue::List(list) => list
+				.iter()
+				.map(option_value_to_cel)
+				.collect::<Result<Vec<Value>, CelError>>()?
+				.into(),
+			OptionValue::Message(msg) => {
+				let mut map: HashMap<Key, Value> = HashMap::default();
+
+				for opt in msg.iter() {
+					map.insert(
+						opt.name.to_string().into(),
+						option_value_to_cel(&opt.value)?,
+					);
+				}
+
+				Value::Map(Map { map: Arc::new(map) })
+			}
+		};
+
+		Ok(output)
+	}
+
+	impl PredefinedRules {
+		fn bind_variables(
+			cel_ctx: &mut Context,
+			program: &CelProgram,
+			rule_value: &OptionValue,
+			rules_value: &mut Option<Value>,
+			rules_message: &impl Fn() -> Option<OptionValue>,
+		) -> Result<(), CelError> {
+			cel_ctx.add_variable_from_value("rule", option_value_to_cel(rule_value)?);
+
+			// Building the rules map is only necessary if the program uses it
+			if program
+				.get_program()
+				.references()
+				.has_variable("rules")
+			{
+				if rules_value.is_none() {
+					let rules = rules_message()
+						.map(|msg| option_value_to_cel(&msg))
+						.transpose()?
+						.unwrap_or_else(|| {
+							Value::Map(Map {
+								map: Arc::new(HashMap::default()),
+							})
+						});
+
+					*rules_value = Some(rules);
+				}
+
+				if let Some(rules) = rules_value {
+					cel_ctx.add_variable_from_value("rules", rules.clone());
+				}
+			}
+
+			Ok(())
+		}
+
+		pub(crate) fn execute<T>(
+			&self,
+			ctx: &mut ValidationCtx,
+			value: T,
+			rules_message: impl Fn() -> Option<OptionValue>,
+		) -> ValidationResult
+		where
+			T: TryIntoCel,
+		{
+			let mut is_valid = IsValid::Yes;
+
+			let mut cel_ctx = match super::super::cel_impls::initialize_context(value) {
+				Ok(cel_ctx) => cel_ctx,
+				Err(e) => {
+					let _ = ctx.add_cel_error_violation(e);
+					return Err(FailFast);
+				}
+			};
+
+			ctx.extensions.apply_cel_variables(&mut cel_ctx);
+
+			let mut rules_value: Option<Value> = None;
+
+			for (rule, rule_value) in &self.items {
+				for program in &rule.programs {
+					let mut scope = cel_ctx.new_inner_scope();
+
+					if let Err(e) = Self::bind_variables(
+						&mut scope,
+						program,
+						rule_value,
+						&mut rules_value,
+						&rules_message,
+					) {
+						is_valid &= ctx.add_cel_error_violation(e)?;
+						continue;
+					}
+
+					match program.execute(&scope) {
+						Ok(was_successful) => {
+							if !was_successful {
+								is_valid &= ctx.add_cel_violation(program.rule())?;
+							}
+						}
+						Err(e) => is_valid &= ctx.add_cel_error_violation(e)?,
+					};
+				}
+			}
+
+			Ok(is_valid)
+		}
+
+		#[inline(never)]
+		#[cold]
+		pub(crate) fn check_programs_with<T>(
+			&self,
+			value: T,
+			rules_message: impl Fn() -> Option<OptionValue>,
+		) -> Result<(), Vec<CelError>>
+		where
+			T: TryIntoCel,
+		{
+			let mut errors: Vec<CelError> = Vec::new();
+
+			let cel_ctx = match super::super::cel_impls::initialize_context(value) {
+				Ok(cel_ctx) => cel_ctx,
+				Err(e) => return Err(vec![e]),
+			};
+
+			let mut rules_value: Option<Value> = None;
+
+			for (rule, rule_value) in &self.items {
+				for program in &rule.programs {
+					if let Err(e) = program.check_functions(&cel_ctx) {
+						errors.extend(e);
+						continue;
+					}
+
+					let mut scope = cel_ctx.new_inner_scope();
+
+					let result = Self::bind_variables(
+						&mut scope,
+						program,
+						rule_value,
+						&mut rules_value,
+						&rules_message,
+					)
+					.and_then(|()| program.execute(&scope));
+
+					if let Err(e) = result {
+						errors.push(e);
+					}
+				}
+			}
+
+			if errors.is_empty() {
+				Ok(())
+			} else {
+				Err(errors)
+			}
+		}
+	}
+}
@@ -42,6 +42,9 @@ pub struct DurationValidator {
 
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<DurationViolation>>,
+
+	/// The [`PredefinedRule`]s applied to this field, with their assigned values.
+	pub predefined: PredefinedRules,
 }
 
 impl Validator<Duration> for DurationValidator {
@@ -61,7 +64,9 @@ impl Validator<Duration> for DurationValidator {
     }
 
 		if self.const_.is_some()
-			&& (!self.cel.is_empty() || check_prop_some!(in_, not_in, lt, lte, gt, gte))
+			&& (!self.cel.is_empty()
+				|| !self.predefined.is_empty()
+				|| check_prop_some!(in_, not_in, lt, lte, gt, gte))
 		{
 			errors.push(ConsistencyError::ConstWithOtherRules);
 		}
@@ -101,6 +106,15 @@ impl Validator<Duration> for DurationValidator {
 			errors.extend(e.into_iter().map(ConsistencyError::from));
 		}
 
+		#[cfg(feature = "cel")]
+		if let Err(e) = self
+			.predefined
+			.check_programs_with(Self::Target::default(), || {
+				rules_message_of(self, "duration")
+			}) {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
 		if let Err(e) = check_list_rules(self.in_.as_ref(), self.not_in.as_ref()) {
 			errors.push(e.into());
 		}
@@ -126,15 +140,22 @@ impl Validator<Duration> for DurationValidator {
 		let mut is_valid = IsValid::Yes;
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::Duration(DurationViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&DurationViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::Duration(DurationViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&DurationViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
@@ -143,7 +164,8 @@ impl Validator<Duration> for DurationValidator {
 				if val != const_val {
 					handle_violation!(
 						Const,
-						format!("must be equal to {}", const_val.to_human_readable_string())
+						format!("must be equal to {}", const_val.to_human_readable_string()),
+						value = const_val.to_human_readable_string()
 					);
 				}
 
@@ -156,7 +178,8 @@ impl Validator<Duration> for DurationValidator {
 			{
 				handle_violation!(
 					Gt,
-					format!("must be longer than {}", gt.to_human_readable_string())
+					format!("must be longer than {}", gt.to_human_readable_string()),
+					gt = gt.to_human_readable_string()
 				);
 			}
 
@@ -168,7 +191,8 @@ impl Validator<Duration> for DurationValidator {
 					format!(
 						"must be longer than or equal to {}",
 						gte.to_human_readable_string()
-					)
+					),
+					gte = gte.to_human_readable_string()
 				);
 			}
 
@@ -177,7 +201,8 @@ impl Validator<Duration> for DurationValidator {
 			{
 				handle_violation!(
 					Lt,
-					format!("must be shorter than {}", lt.to_human_readable_string())
+					format!("must be shorter than {}", lt.to_human_readable_string()),
+					lt = lt.to_human_readable_string()
 				);
 			}
 
@@ -189,31 +214,32 @@ impl Validator<Duration> for DurationValidator {
 					format!(
 						"must be shorter than or equal to {}",
 						lte.to_human_readable_string()
-					)
+					),
+					lte = lte.to_human_readable_string()
 				);
 			}
 
 			if let Some(allowed_list) = &self.in_
 				&& !allowed_list.contains(&val)
 			{
+				let values = Duration::__format_list(allowed_list);
+
 				handle_violation!(
 					In,
-					format!(
-						"must be one of these values: {}",
-						Duration::__format_list(allowed_list)
-					)
+					format!("must be one of these values: {values}"),
+					values = values
 				);
 			}
 
 			if let Some(forbidden_list) = &self.not_in
 				&& forbidden_list.items.contains(&val)
 			{
+				let values = Duration::__format_list(forbidden_list);
+
 				handle_violation!(
 					NotIn,
-					format!(
-						"must be one of these values: {}",
-						Duration::__format_list(forbidden_list)
-					)
+					format!("must be one of these values: {values}"),
+					values = values
 				);
 			}
 
@@ -227,6 +253,13 @@ impl Validator<Duration> for DurationValidator {
 
 				is_valid &= cel_ctx.execute_programs()?;
 			}
+
+			#[cfg(feature = "cel")]
+			if !self.predefined.is_empty() {
+				is_valid &= self
+					.predefined
+					.execute(ctx, val, || rules_message_of(self, "duration"))?;
+			}
 		} else if self.required {
 			handle_violation!(Required, "is required".to_string());
 		}
@@ -270,6 +303,8 @@ impl From<DurationValidator> for ProtoOption {
 					.map(|list| OptionValue::new_list(list)),
 			);
 
+		validator.predefined.add_to_rules(&mut rules);
+
 		let mut outer_rules = OptionMessageBuilder::new();
 
 		if !rules.is_empty() {
@@ -84,6 +84,23 @@ impl<S: State> DurationValidatorBuilder<S> {
 		}
 	}
 
+	/// Applies a [`PredefinedRule`] to this validator, with the given value.
+	///
+	/// If the rule was already applied, its value is replaced.
+	#[inline]
+	pub fn predefined(
+		mut self,
+		rule: &PredefinedRule,
+		value: impl Into<OptionValue>,
+	) -> DurationValidatorBuilder<S> {
+		self.data.predefined.insert(rule, value);
+
+		DurationValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
 	/// Specifies that this validator should always be ignored.
 	#[inline]
 	pub fn ignore_always(mut self) -> DurationValidatorBuilder<SetIgnore<S>>
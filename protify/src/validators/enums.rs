@@ -34,28 +34,48 @@ pub struct EnumValidator<T: ProtoEnum> {
 
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<EnumViolation>>,
+
+	/// The [`PredefinedRule`]s applied to this field, with their assigned values.
+	pub predefined: PredefinedRules,
 }
 
 impl<T: ProtoEnum> EnumValidator<T> {
 	fn validate_as_int(&self, ctx: &mut ValidationCtx, val: i32) -> ValidationResult {
 		let mut is_valid = IsValid::Yes;
+		// The values of these rules would reveal the value of the field
+		let is_sensitive = ctx.is_sensitive();
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::Enum(EnumViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&EnumViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::Enum(EnumViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&EnumViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
 		if let Some(const_val) = self.const_ {
 			if val != const_val {
-				handle_violation!(Const, format!("must be equal to {const_val}"));
+				if is_sensitive {
+					handle_violation!(Const, "must be equal to the expected value".to_string());
+				} else {
+					handle_violation!(
+						Const,
+						format!("must be equal to {const_val}"),
+						value = const_val
+					);
+				}
 			}
 
 			// Using `const` implies no other rules
@@ -65,25 +85,33 @@ impl<T: ProtoEnum> EnumValidator<T> {
 		if let Some(allowed_list) = &self.in_
 			&& !allowed_list.items.contains(&val)
 		{
-			handle_violation!(
-				In,
-				format!(
-					"must be one of these values: {}",
-					i32::__format_list(allowed_list)
-				)
-			);
+			if is_sensitive {
+				handle_violation!(In, "must be one of the allowed values".to_string());
+			} else {
+				let values = i32::__format_list(allowed_list);
+
+				handle_violation!(
+					In,
+					format!("must be one of these values: {values}"),
+					values = values
+				);
+			}
 		}
 
 		if let Some(forbidden_list) = &self.not_in
 			&& forbidden_list.items.contains(&val)
 		{
-			handle_violation!(
-				NotIn,
-				format!(
-					"cannot be one of these values: {}",
-					i32::__format_list(forbidden_list)
-				)
-			);
+			if is_sensitive {
+				handle_violation!(NotIn, "cannot be one of the forbidden values".to_string());
+			} else {
+				let values = i32::__format_list(forbidden_list);
+
+				handle_violation!(
+					NotIn,
+					format!("cannot be one of these values: {values}"),
+					values = values
+				);
+			}
 		}
 
 		Ok(is_valid)
@@ -103,6 +131,7 @@ impl<T: ProtoEnum> Default for EnumValidator<T> {
 			not_in: Default::default(),
 			const_: Default::default(),
 			error_messages: None,
+			predefined: Default::default(),
 		}
 	}
 }
@@ -124,7 +153,10 @@ impl<T: ProtoEnum> Validator<T> for EnumValidator<T> {
     }
 
 		if self.const_.is_some()
-			&& (!self.cel.is_empty() || self.defined_only || check_prop_some!(in_, not_in))
+			&& (!self.cel.is_empty()
+				|| !self.predefined.is_empty()
+				|| self.defined_only
+				|| check_prop_some!(in_, not_in))
 		{
 			errors.push(ConsistencyError::ConstWithOtherRules);
 		}
@@ -157,6 +189,14 @@ impl<T: ProtoEnum> Validator<T> for EnumValidator<T> {
 			errors.extend(e.into_iter().map(ConsistencyError::from));
 		}
 
+		#[cfg(feature = "cel")]
+		if let Err(e) = self
+			.predefined
+			.check_programs_with(Self::Target::default(), || rules_message_of(self, "enum"))
+		{
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
 		if let Err(e) = check_list_rules(self.in_.as_ref(), self.not_in.as_ref()) {
 			errors.push(e.into());
 		}
@@ -199,15 +239,22 @@ impl<T: ProtoEnum> Validator<T> for EnumValidator<T> {
 		let mut is_valid = IsValid::Yes;
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::Enum(EnumViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&EnumViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::Enum(EnumViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&EnumViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
@@ -233,6 +280,13 @@ impl<T: ProtoEnum> Validator<T> for EnumValidator<T> {
 
 				is_valid &= cel_ctx.execute_programs()?;
 			}
+
+			#[cfg(feature = "cel")]
+			if !self.predefined.is_empty() {
+				is_valid &= self
+					.predefined
+					.execute(ctx, val, || rules_message_of(self, "enum"))?;
+			}
 		}
 
 		Ok(is_valid)
@@ -271,6 +325,8 @@ impl<T: ProtoEnum> From<EnumValidator<T>> for ProtoOption {
 					.map(|list| OptionValue::new_list(list)),
 			);
 
+		validator.predefined.add_to_rules(&mut rules);
+
 		let mut outer_rules = OptionMessageBuilder::new();
 
 		if !rules.is_empty() {
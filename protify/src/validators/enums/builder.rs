@@ -80,6 +80,23 @@ impl<T: ProtoEnum, S: State> EnumValidatorBuilder<T, S> {
 		}
 	}
 
+	/// Applies a [`PredefinedRule`] to this validator, with the given value.
+	///
+	/// If the rule was already applied, its value is replaced.
+	#[inline]
+	pub fn predefined(
+		mut self,
+		rule: &PredefinedRule,
+		value: impl Into<OptionValue>,
+	) -> EnumValidatorBuilder<T, S> {
+		self.data.predefined.insert(rule, value);
+
+		EnumValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
 	/// Specifies that this validator should always be ignored.
 	#[inline]
 	pub fn ignore_always(mut self) -> EnumValidatorBuilder<T, SetIgnore<S>>
@@ -14,6 +14,8 @@ pub struct FieldContext {
 	pub map_value_type: Option<ProtoPrimitive>,
 	pub field_type: ProtoPrimitive,
 	pub field_kind: FieldKind,
+	/// Whether the field is marked as sensitive. If it is, the default violation messages do not include the values of the rules that could reveal the value of the field (such as those of `const` or `in`).
+	pub is_sensitive: bool,
 }
 
 impl FieldContext {
@@ -29,8 +31,17 @@ impl FieldContext {
 			map_value_type: None,
 			field_type,
 			field_kind: FieldKind::Normal,
+			is_sensitive: false,
 		}
 	}
+
+	/// Marks the field as sensitive.
+	#[inline]
+	#[must_use]
+	pub const fn sensitive(mut self) -> Self {
+		self.is_sensitive = true;
+		self
+	}
 }
 
 impl FieldContext {
@@ -123,6 +134,16 @@ pub struct ValidationCtx {
 	pub violations: ValidationErrors,
 	/// Whether validation should be interrupted at the first failure.
 	pub fail_fast: bool,
+	/// The locale and catalog used to produce localized violation messages.
+	/// If absent, the default messages are used.
+	pub localization: Option<Localization>,
+	/// The paths that should be validated, if only a subset of the fields must be validated
+	/// (for example, with [`validate_masked`](ValidatedMessage::validate_masked)).
+	///
+	/// When descending into nested messages, this is replaced with the subtree for the current field.
+	pub field_mask: Option<Arc<FieldMaskTree>>,
+	/// The request-scoped data that can be accessed by custom validators (and, for CEL variables, by CEL programs).
+	pub extensions: ValidationExtensions,
 }
 
 impl Default for ValidationCtx {
@@ -149,9 +170,97 @@ impl ValidationCtx {
 			parent_elements: vec![],
 			violations: ValidationErrors::new(),
 			fail_fast: true,
+			localization: None,
+			field_mask: None,
+			extensions: ValidationExtensions::new(),
 		}
 	}
 
+	/// Inserts a value in the [`ValidationExtensions`] of this context, so that it can be accessed by custom validators.
+	///
+	/// If a value of the same type was already present, it gets replaced.
+	#[inline]
+	#[must_use]
+	pub fn with_extension<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+		self.extensions.insert(value);
+		self
+	}
+
+	/// Adds a variable that will be available to the CEL programs executed with this context.
+	#[cfg(feature = "cel")]
+	#[inline]
+	#[must_use]
+	pub fn with_cel_variable(
+		mut self,
+		name: impl Into<String>,
+		value: impl Into<::cel::Value>,
+	) -> Self {
+		self.extensions.insert_cel_variable(name, value);
+		self
+	}
+
+	/// Sets the [`FieldMaskTree`] that determines which fields should be validated.
+	#[inline]
+	#[must_use]
+	pub fn masked(mut self, field_mask: impl Into<FieldMaskTree>) -> Self {
+		self.field_mask = Some(Arc::new(field_mask.into()));
+		self
+	}
+
+	#[doc(hidden)]
+	#[inline]
+	#[must_use]
+	pub fn __enter_masked_field(&mut self, name: &str) -> Option<Option<Arc<FieldMaskTree>>> {
+		let Some(mask) = &self.field_mask else {
+			return Some(None);
+		};
+
+		let subtree = mask.get(name)?.cloned();
+
+		Some(core::mem::replace(&mut self.field_mask, subtree))
+	}
+
+	// Oneofs are not part of the paths, so we only check if one of their fields is present,
+	// and leave the mask as is so that the variants can be checked individually
+	#[doc(hidden)]
+	#[inline]
+	#[must_use]
+	pub fn __enter_masked_oneof(&self, fields: &[&str]) -> Option<Option<Arc<FieldMaskTree>>> {
+		let Some(mask) = &self.field_mask else {
+			return Some(None);
+		};
+
+		(fields.is_empty() || fields.iter().any(|f| mask.get(f).is_some()))
+			.then(|| Some(mask.clone()))
+	}
+
+	#[doc(hidden)]
+	#[inline]
+	pub fn __exit_masked_field(&mut self, parent_mask: Option<Arc<FieldMaskTree>>) {
+		self.field_mask = parent_mask;
+	}
+
+	/// Checks if the fields referenced by a CEL expression are covered by the [`FieldMaskTree`] of this context (if there is one).
+	#[cfg(feature = "cel")]
+	#[inline(never)]
+	#[cold]
+	pub(crate) fn covers_cel_expression(&self, expression: &str) -> bool {
+		let Some(mask) = &self.field_mask else {
+			return true;
+		};
+
+		cel_referenced_paths(expression)
+			.is_some_and(|paths| paths.into_iter().all(|path| mask.covers(path)))
+	}
+
+	/// Sets the [`Localization`] used to produce the violation messages.
+	#[inline]
+	#[must_use]
+	pub fn localized(mut self, localization: Localization) -> Self {
+		self.localization = Some(localization);
+		self
+	}
+
 	/// Sets the [`FieldContext`] to [`None`].
 	///
 	/// Mainly useful for validators defined at the top level of a oneof or an unnested message, which do not have a proto
@@ -180,6 +289,50 @@ impl ValidationCtx {
 		self.add_violation_internal(None, kind, error_message.into())
 	}
 
+	/// Adds a new known violation to the list of errors, resolving its message with the [`MessageCatalog`] of the
+	/// [`Localization`], if one is present. The default message is used if the catalog does not provide one.
+	///
+	/// The arguments are passed to the catalog so that they can be used in the localized message.
+	#[inline(never)]
+	#[cold]
+	pub fn add_localized_violation(
+		&mut self,
+		kind: ViolationKind,
+		args: &[(&'static str, &dyn Display)],
+		default_message: impl Into<String>,
+	) -> ValidationResult {
+		let error_message = self
+			.localized_message(kind.data().name, args)
+			.unwrap_or_else(|| default_message.into());
+
+		self.add_violation_internal(None, kind, error_message)
+	}
+
+	/// Resolves the message for the given rule ID using the [`Localization`] of this context, if present.
+	#[inline(never)]
+	#[cold]
+	#[must_use]
+	pub fn localized_message(
+		&self,
+		rule_id: &str,
+		args: &[(&'static str, &dyn Display)],
+	) -> Option<String> {
+		let localization = self.localization.as_ref()?;
+
+		let field = self.field_context.as_ref().map(|fc| &fc.name);
+
+		localization.message(rule_id, &MessageArgs::new(field, args))
+	}
+
+	/// Returns `true` if the field being validated is marked as sensitive.
+	#[inline]
+	#[must_use]
+	pub fn is_sensitive(&self) -> bool {
+		self.field_context
+			.as_ref()
+			.is_some_and(|fc| fc.is_sensitive)
+	}
+
 	/// Extracts the [`FieldKind`]. If the [`FieldContext`] is absent (for a top level validator), it falls back to [`FieldKind::Normal`].
 	#[inline]
 	#[must_use]
@@ -222,6 +375,8 @@ impl ValidationCtx {
 	}
 
 	/// Adds a new known violation to the list of errors, overriding the rule ID.
+	///
+	/// The message is resolved with the [`MessageCatalog`] of the [`Localization`] by using the custom rule ID, if one is present. The given message is used if the catalog does not provide one.
 	#[inline(never)]
 	#[cold]
 	pub fn add_violation_with_custom_id(
@@ -230,7 +385,13 @@ impl ValidationCtx {
 		kind: ViolationKind,
 		error_message: impl Into<String>,
 	) -> ValidationResult {
-		self.add_violation_internal(Some(rule_id.into()), kind, error_message.into())
+		let rule_id: String = rule_id.into();
+
+		let error_message = self
+			.localized_message(&rule_id, &[])
+			.unwrap_or_else(|| error_message.into());
+
+		self.add_violation_internal(Some(rule_id), kind, error_message)
 	}
 
 	/// Adds a new violation related to a [`CelRule`].
@@ -249,8 +410,9 @@ impl ValidationCtx {
 		if let Some(msg) = error_message {
 			self.add_violation(ViolationKind::RequiredOneof, msg)
 		} else {
-			self.add_violation(
+			self.add_localized_violation(
 				ViolationKind::RequiredOneof,
+				&[],
 				"at least one value must be set",
 			)
 		}
@@ -263,7 +425,7 @@ impl ValidationCtx {
 		if let Some(msg) = error_message {
 			self.add_violation(ViolationKind::Required, msg)
 		} else {
-			self.add_violation(ViolationKind::Required, "is required")
+			self.add_localized_violation(ViolationKind::Required, &[], "is required")
 		}
 	}
 
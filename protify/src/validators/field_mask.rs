@@ -4,6 +4,79 @@ use proto_types::FieldMask;
 
 use super::*;
 
+/// The paths of a message which can be included in a [`FieldMask`].
+///
+/// Created with [`FieldMaskValidatorBuilder::paths_of`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagePaths {
+	/// The full name of the target message.
+	pub message: FixedStr,
+
+	/// The valid paths, including the dotted paths of the nested messages.
+	pub paths: SortedList<FixedStr>,
+}
+
+impl MessagePaths {
+	/// Collects the paths of the given message, excluding the given fields (and their nested paths).
+	///
+	/// The fields of nested messages are resolved with the messages collected with `inventory`, so without the `inventory` feature,
+	/// nested messages can only be addressed as a whole. Repeated and map fields can only be addressed as a whole, and recursive messages
+	/// can only be traversed up to their first recursion.
+	#[must_use]
+	pub fn new<T: ProtoMessage>(excluded_fields: &[&str]) -> Self {
+		let mut paths: Vec<FixedStr> = Vec::new();
+		let mut visited = vec![T::full_name()];
+
+		collect_message_paths(&T::proto_schema(), "", &mut visited, &mut paths);
+
+		paths.retain(|path| {
+			!excluded_fields.iter().any(|excluded| {
+				path.strip_prefix(excluded)
+					.is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+			})
+		});
+
+		Self {
+			message: T::full_name().into(),
+			paths: SortedList::new(paths),
+		}
+	}
+
+	/// Checks if the given path is included.
+	#[inline]
+	#[must_use]
+	pub fn contains(&self, path: &str) -> bool {
+		self.paths.contains(path)
+	}
+}
+
+#[cfg_attr(not(feature = "inventory"), allow(unused_variables))]
+fn collect_message_paths(
+	schema: &MessageSchema,
+	prefix: &str,
+	visited: &mut Vec<&'static str>,
+	paths: &mut Vec<FixedStr>,
+) {
+	for field in schema.fields() {
+		let path = format!("{prefix}{}", field.name);
+
+		#[cfg(feature = "inventory")]
+		if let FieldType::Normal(ProtoType::Message(msg_path))
+		| FieldType::Optional(ProtoType::Message(msg_path)) = &field.type_
+			&& let Some((full_name, nested)) = find_message(&msg_path.to_string())
+			&& !visited.contains(&full_name)
+		{
+			visited.push(full_name);
+			collect_message_paths(&nested, &format!("{path}."), visited, paths);
+			visited.pop();
+		}
+
+		paths.push(path.into());
+	}
+}
+
 /// Validator for the [`FieldMask`] type.
 #[non_exhaustive]
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
@@ -27,6 +100,9 @@ pub struct FieldMaskValidator {
 	/// Specifies that the FieldMask should contain exactly these paths.
 	pub const_: Option<SortedList<FixedStr>>,
 
+	/// Specifies that the FieldMask can only contain the paths that exist in the target message.
+	pub paths_of: Option<MessagePaths>,
+
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<FieldMaskViolation>>,
 }
@@ -65,7 +141,9 @@ impl Validator<FieldMask> for FieldMaskValidator {
       };
     }
 
-		if self.const_.is_some() && (!self.cel.is_empty() || check_prop_some!(in_, not_in)) {
+		if self.const_.is_some()
+			&& (!self.cel.is_empty() || check_prop_some!(in_, not_in, paths_of))
+		{
 			errors.push(ConsistencyError::ConstWithOtherRules);
 		}
 
@@ -117,15 +195,22 @@ impl Validator<FieldMask> for FieldMaskValidator {
 		let mut is_valid = IsValid::Yes;
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::FieldMask(FieldMaskViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&FieldMaskViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::FieldMask(FieldMaskViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&FieldMaskViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
@@ -147,7 +232,8 @@ impl Validator<FieldMask> for FieldMaskValidator {
 						format!(
 							"must contain exactly these paths: [ {} ]",
 							val.paths.join(", ")
-						)
+						),
+						value = FixedStr::__format_list(const_val)
 					);
 				}
 
@@ -158,12 +244,12 @@ impl Validator<FieldMask> for FieldMaskValidator {
 			if let Some(allowed_paths) = &self.in_ {
 				for path in &val.paths {
 					if !allowed_paths.contains(path.as_str()) {
+						let values = FixedStr::__format_list(allowed_paths);
+
 						handle_violation!(
 							In,
-							format!(
-								"can only contain these paths: {}",
-								FixedStr::__format_list(allowed_paths)
-							)
+							format!("can only contain these paths: {values}"),
+							values = values
 						);
 
 						break;
@@ -174,12 +260,12 @@ impl Validator<FieldMask> for FieldMaskValidator {
 			if let Some(forbidden_paths) = &self.not_in {
 				for path in &val.paths {
 					if forbidden_paths.contains(path.as_str()) {
+						let values = FixedStr::__format_list(forbidden_paths);
+
 						handle_violation!(
 							NotIn,
-							format!(
-								"cannot contain one of these paths: {}",
-								FixedStr::__format_list(forbidden_paths)
-							)
+							format!("cannot contain one of these paths: {values}"),
+							values = values
 						);
 
 						break;
@@ -187,6 +273,23 @@ impl Validator<FieldMask> for FieldMaskValidator {
 				}
 			}
 
+			if let Some(message_paths) = &self.paths_of
+				&& let Some(path) = val
+					.paths
+					.iter()
+					.find(|path| !message_paths.contains(path.as_str()))
+			{
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::PATHS_OF_ID,
+					ViolationKind::Cel,
+					if ctx.is_sensitive() {
+						Self::paths_of_message(message_paths)
+					} else {
+						format!("{}, found `{path}`", Self::paths_of_message(message_paths))
+					},
+				)?;
+			}
+
 			#[cfg(feature = "cel")]
 			if !self.cel.is_empty() {
 				let cel_ctx = ProgramsExecutionCtx {
@@ -216,6 +319,32 @@ impl Validator<FieldMask> for FieldMaskValidator {
 }
 
 impl FieldMaskValidator {
+	const PATHS_OF_ID: &str = "field_mask.paths_of";
+
+	fn paths_of_message(message_paths: &MessagePaths) -> String {
+		format!(
+			"must only contain valid paths of `{}`",
+			message_paths.message
+		)
+	}
+
+	fn paths_of_rule(message_paths: &MessagePaths) -> CelRule {
+		CelRule {
+			id: FixedStr::Static(Self::PATHS_OF_ID),
+			message: Self::paths_of_message(message_paths).into(),
+			expression: format!(
+				"this.paths.all(p, p in {})",
+				cel_string_list(
+					message_paths
+						.paths
+						.iter()
+						.map(AsRef::<str>::as_ref)
+				)
+			)
+			.into(),
+		}
+	}
+
 	fn validate_exact_small(const_val: &SortedList<FixedStr>, input_paths: &[String]) -> bool {
 		let mut visited_mask: u64 = 0;
 
@@ -295,8 +424,16 @@ impl From<FieldMaskValidator> for ProtoOption {
 			outer_rules.set("field_mask", OptionValue::Message(rules.into()));
 		}
 
+		let cel_rules: Vec<CelRule> = validator
+			.paths_of
+			.as_ref()
+			.map(FieldMaskValidator::paths_of_rule)
+			.into_iter()
+			.chain(validator.cel.into_iter().map(CelRule::from))
+			.collect();
+
 		outer_rules
-			.add_cel_options(validator.cel)
+			.add_cel_rules(cel_rules)
 			.set_required(validator.required)
 			.set_ignore(validator.ignore);
 
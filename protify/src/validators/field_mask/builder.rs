@@ -141,6 +141,39 @@ impl<S: State> FieldMaskValidatorBuilder<S> {
 		}
 	}
 
+	/// Specifies that the FieldMask can only contain the paths that exist in the target message, including the dotted paths of its nested messages (like `address.city`).
+	///
+	/// Paths inside repeated and map fields are not allowed. See [`MessagePaths::new`] for more information about how the paths are collected.
+	#[inline]
+	pub fn paths_of<T: ProtoMessage>(mut self) -> FieldMaskValidatorBuilder<SetPathsOf<S>>
+	where
+		S::PathsOf: IsUnset,
+	{
+		self.data.paths_of = Some(MessagePaths::new::<T>(&[]));
+
+		FieldMaskValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Same as [`paths_of`](FieldMaskValidatorBuilder::paths_of), but it also forbids the given fields (and their nested paths), such as fields that cannot be updated like `id` or `created_at`.
+	#[inline]
+	pub fn paths_of_except<T: ProtoMessage>(
+		mut self,
+		forbidden_fields: &[&str],
+	) -> FieldMaskValidatorBuilder<SetPathsOf<S>>
+	where
+		S::PathsOf: IsUnset,
+	{
+		self.data.paths_of = Some(MessagePaths::new::<T>(forbidden_fields));
+
+		FieldMaskValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
 	/// Builds the validator.
 	#[inline]
 	#[must_use]
@@ -1,3 +1,3 @@
 use crate::validators::builder_internals::*;
 use protify_proc_macro::builder_state_macro;
-builder_state_macro!(Const, Required, Ignore, In, NotIn, ErrorMessages);
+builder_state_macro!(Const, Required, Ignore, In, NotIn, PathsOf, ErrorMessages);
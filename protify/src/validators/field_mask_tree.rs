@@ -0,0 +1,193 @@
+use super::*;
+
+use proto_types::FieldMask;
+
+/// A tree representation of the paths in a [`FieldMask`], used to validate only the fields that are covered by it (with [`validate_masked`](ValidatedMessage::validate_masked)).
+///
+/// Each node maps the name of a field to the subtree that covers its nested fields, or to [`None`] if the field is covered in its entirety.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FieldMaskTree {
+	children: BTreeMap<String, Option<Arc<Self>>>,
+}
+
+impl From<&FieldMask> for FieldMaskTree {
+	#[inline]
+	fn from(value: &FieldMask) -> Self {
+		Self::from_paths(&value.paths)
+	}
+}
+
+impl FieldMaskTree {
+	/// Creates a new tree from a list of paths, such as `["name", "address.city"]`.
+	///
+	/// If a path is covered by another (like `address.city` by `address`), only the shorter one is retained.
+	#[must_use]
+	pub fn from_paths<I, S>(paths: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		let mut tree = Self::default();
+
+		for path in paths {
+			let path = path.as_ref();
+
+			if !path.is_empty() {
+				let segments: Vec<&str> = path.split('.').collect();
+
+				tree.insert(&segments);
+			}
+		}
+
+		tree
+	}
+
+	fn insert(&mut self, segments: &[&str]) {
+		let Some((segment, rest)) = segments.split_first() else {
+			return;
+		};
+
+		if rest.is_empty() {
+			// The whole field is covered, so the nested paths are redundant
+			self.children.insert((*segment).to_string(), None);
+			return;
+		}
+
+		let entry = self
+			.children
+			.entry((*segment).to_string())
+			.or_insert_with(|| Some(Arc::default()));
+
+		// Already covered in its entirety
+		if let Some(subtree) = entry {
+			Arc::make_mut(subtree).insert(rest);
+		}
+	}
+
+	/// Checks if the tree contains no paths.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.children.is_empty()
+	}
+
+	/// Returns the subtree for the given field. The outer [`Option`] signals whether the field is covered at all,
+	/// whereas the inner one is [`None`] if the field is covered in its entirety.
+	#[inline]
+	#[must_use]
+	pub fn get(&self, field: &str) -> Option<Option<&Arc<Self>>> {
+		self.children.get(field).map(Option::as_ref)
+	}
+
+	/// Checks if the field at the given path (such as `["address", "city"]`) is covered in its entirety by this tree.
+	#[must_use]
+	pub fn covers<'a>(&self, path: impl IntoIterator<Item = &'a str>) -> bool {
+		let mut node = self;
+
+		for segment in path {
+			match node.children.get(segment) {
+				Some(Some(subtree)) => node = subtree,
+				Some(None) => return true,
+				None => return false,
+			}
+		}
+
+		false
+	}
+}
+
+/// Extracts the paths of the fields accessed with `this` in a CEL expression (such as `this.a.b`).
+///
+/// Returns [`None`] if `this` is referenced as a whole (for example, when passed to a function).
+#[cfg(feature = "cel")]
+pub(crate) fn cel_referenced_paths(expression: &str) -> Option<Vec<Vec<&str>>> {
+	let bytes = expression.as_bytes();
+	let mut paths: Vec<Vec<&str>> = Vec::new();
+	let mut i = 0;
+
+	let is_ident_char = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+
+	let read_ident = |start: usize| {
+		let mut end = start;
+
+		while end < bytes.len() && is_ident_char(bytes[end]) {
+			end += 1;
+		}
+
+		end
+	};
+
+	let skip_whitespace = |mut pos: usize| {
+		while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+			pos += 1;
+		}
+
+		pos
+	};
+
+	while i < bytes.len() {
+		let b = bytes[i];
+
+		// String literals
+		if b == b'"' || b == b'\'' {
+			i += 1;
+
+			while i < bytes.len() && bytes[i] != b {
+				if bytes[i] == b'\\' {
+					i += 1;
+				}
+
+				i += 1;
+			}
+
+			i += 1;
+			continue;
+		}
+
+		if !is_ident_char(b) {
+			i += 1;
+			continue;
+		}
+
+		let end = read_ident(i);
+		let is_member = i > 0 && bytes[..i].trim_ascii_end().ends_with(b".");
+
+		if &expression[i..end] != "this" || is_member {
+			i = end;
+			continue;
+		}
+
+		let mut path: Vec<&str> = Vec::new();
+		let mut pos = skip_whitespace(end);
+
+		while pos < bytes.len() && bytes[pos] == b'.' {
+			let start = skip_whitespace(pos + 1);
+			let ident_end = read_ident(start);
+
+			if ident_end == start {
+				break;
+			}
+
+			path.push(&expression[start..ident_end]);
+			pos = skip_whitespace(ident_end);
+		}
+
+		if path.is_empty() {
+			return None;
+		}
+
+		// Method calls like `this.items.size()` do not access a field
+		if pos < bytes.len() && bytes[pos] == b'(' {
+			path.pop();
+
+			if path.is_empty() {
+				return None;
+			}
+		}
+
+		paths.push(path);
+		i = pos;
+	}
+
+	Some(paths)
+}
@@ -62,6 +62,9 @@ where
 
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<Num::ViolationEnum>>,
+
+	/// The [`PredefinedRule`]s applied to this field, with their assigned values.
+	pub predefined: PredefinedRules,
 }
 
 impl<Num> FloatValidator<Num>
@@ -70,16 +73,11 @@ where
 {
 	#[inline(never)]
 	#[cold]
-	fn custom_error_or_else(
-		&self,
-		violation: Num::ViolationEnum,
-		default: impl Fn() -> String,
-	) -> String {
+	fn custom_error(&self, violation: Num::ViolationEnum) -> Option<String> {
 		self.error_messages
 			.as_deref()
 			.and_then(|map| map.get(&violation))
 			.map(|m| m.to_string())
-			.unwrap_or_else(default)
 	}
 }
 
@@ -105,6 +103,7 @@ where
 			in_: Default::default(),
 			not_in: Default::default(),
 			error_messages: Default::default(),
+			predefined: Default::default(),
 		}
 	}
 }
@@ -200,6 +199,7 @@ where
 
 		if self.const_.is_some()
 			&& (!self.cel.is_empty()
+				|| !self.predefined.is_empty()
 				|| self.finite
 				|| check_prop_some!(in_, not_in, lt, lte, gt, gte))
 		{
@@ -241,6 +241,15 @@ where
 			errors.extend(e.into_iter().map(ConsistencyError::from));
 		}
 
+		#[cfg(feature = "cel")]
+		if let Err(e) = self
+			.predefined
+			.check_programs_with(Self::Target::default(), || {
+				rules_message_of(self, Num::type_name())
+			}) {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
 		if let Err(e) = check_float_list_rules(
 			self.in_.as_deref(),
 			self.not_in.as_deref(),
@@ -270,17 +279,23 @@ where
 		handle_ignore_if_zero_value!(&self.ignore, val.is_none_or(|v| v.is_default()));
 
 		let mut is_valid = IsValid::Yes;
+		// The values of these rules would reveal the value of the field
+		let is_sensitive = ctx.is_sensitive();
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
 				paste::paste! {
-				  is_valid &= ctx.add_violation(
-					Num::[< $id:snake:upper _VIOLATION >].into(),
-					self.custom_error_or_else(
-					  Num::[< $id:snake:upper _VIOLATION >],
-					  || $default
+				  let violation = Num::[< $id:snake:upper _VIOLATION >];
+
+				  is_valid &= if let Some(msg) = self.custom_error(violation) {
+					ctx.add_violation(violation.into(), msg)
+				  } else {
+					ctx.add_localized_violation(
+					  violation.into(),
+					  &[$((stringify!($arg), &$value as &dyn Display)),*],
+					  $default,
 					)
-				  )?;
+				  }?;
 				}
 			};
 		}
@@ -293,7 +308,15 @@ where
 		if let Some(&val) = val {
 			if let Some(const_val) = self.const_ {
 				if !self.float_is_eq(const_val, val) {
-					handle_violation!(Const, format!("must be equal to {const_val}"));
+					if is_sensitive {
+						handle_violation!(Const, "must be equal to the expected value".to_string());
+					} else {
+						handle_violation!(
+							Const,
+							format!("must be equal to {const_val}"),
+							value = const_val
+						);
+					}
 				}
 
 				// Using `const` implies no other rules
@@ -307,49 +330,65 @@ where
 			if let Some(gt) = self.gt
 				&& (val.is_nan() || self.float_is_eq(gt, val) || val < gt)
 			{
-				handle_violation!(Gt, format!("must be greater than {gt}"));
+				handle_violation!(Gt, format!("must be greater than {gt}"), gt = gt);
 			}
 
 			if let Some(gte) = self.gte
 				&& (val.is_nan() || !self.float_is_eq(gte, val) && val < gte)
 			{
-				handle_violation!(Gte, format!("must be greater than or equal to {gte}"));
+				handle_violation!(
+					Gte,
+					format!("must be greater than or equal to {gte}"),
+					gte = gte
+				);
 			}
 
 			if let Some(lt) = self.lt
 				&& (val.is_nan() || self.float_is_eq(lt, val) || val > lt)
 			{
-				handle_violation!(Lt, format!("must be smaller than {lt}"));
+				handle_violation!(Lt, format!("must be smaller than {lt}"), lt = lt);
 			}
 
 			if let Some(lte) = self.lte
 				&& (val.is_nan() || !self.float_is_eq(lte, val) && val > lte)
 			{
-				handle_violation!(Lte, format!("must be smaller than or equal to {lte}"));
+				handle_violation!(
+					Lte,
+					format!("must be smaller than or equal to {lte}"),
+					lte = lte
+				);
 			}
 
 			if let Some(allowed_list) = &self.in_
 				&& !float_in_list(val, allowed_list, self.abs_tolerance, self.rel_tolerance)
 			{
-				handle_violation!(
-					In,
-					format!(
-						"must be one of these values: {}",
-						OrderedFloat::<Num>::__format_list(allowed_list)
-					)
-				);
+				if is_sensitive {
+					handle_violation!(In, "must be one of the allowed values".to_string());
+				} else {
+					let values = OrderedFloat::<Num>::__format_list(allowed_list);
+
+					handle_violation!(
+						In,
+						format!("must be one of these values: {values}"),
+						values = values
+					);
+				}
 			}
 
 			if let Some(forbidden_list) = &self.not_in
 				&& float_in_list(val, forbidden_list, self.abs_tolerance, self.rel_tolerance)
 			{
-				handle_violation!(
-					NotIn,
-					format!(
-						"cannot be one of these values: {}",
-						OrderedFloat::<Num>::__format_list(forbidden_list)
-					)
-				);
+				if is_sensitive {
+					handle_violation!(NotIn, "cannot be one of the forbidden values".to_string());
+				} else {
+					let values = OrderedFloat::<Num>::__format_list(forbidden_list);
+
+					handle_violation!(
+						NotIn,
+						format!("cannot be one of these values: {values}"),
+						values = values
+					);
+				}
 			}
 
 			#[cfg(feature = "cel")]
@@ -362,6 +401,13 @@ where
 
 				is_valid &= cel_ctx.execute_programs()?;
 			}
+
+			#[cfg(feature = "cel")]
+			if !self.predefined.is_empty() {
+				is_valid &= self
+					.predefined
+					.execute(ctx, val, || rules_message_of(self, Num::type_name()))?;
+			}
 		}
 
 		Ok(is_valid)
@@ -435,6 +481,8 @@ where
 					.map(|list| OptionValue::List(list.items.iter().map(|of| of.0).collect())),
 			);
 
+		validator.predefined.add_to_rules(&mut rules);
+
 		let mut outer_rules = OptionMessageBuilder::new();
 
 		if !rules.is_empty() {
@@ -455,6 +503,18 @@ where
 
 impl_proto_type!(f32, Float);
 impl_proto_type!(f64, Double);
+impl_wrapper_validation!(
+	crate::well_known_types::FloatValue,
+	f32,
+	FloatValidator<f32>,
+	FloatValidatorBuilder<f32>
+);
+impl_wrapper_validation!(
+	crate::well_known_types::DoubleValue,
+	f64,
+	FloatValidator<f64>,
+	FloatValidatorBuilder<f64>
+);
 
 /// A sealed trait for protobuf-compatible floats
 pub trait FloatWrapper:
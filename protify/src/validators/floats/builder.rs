@@ -99,6 +99,25 @@ where
 		}
 	}
 
+	/// Applies a [`PredefinedRule`] to this validator, with the given value.
+	///
+	/// If the rule was already applied, its value is replaced.
+	#[inline]
+	#[allow(clippy::use_self, clippy::return_self_not_must_use)]
+	pub fn predefined(
+		mut self,
+		rule: &PredefinedRule,
+		value: impl Into<OptionValue>,
+	) -> FloatValidatorBuilder<Num, S> {
+		self.data.predefined.insert(rule, value);
+
+		FloatValidatorBuilder {
+			_state: PhantomData,
+			_wrapper: self._wrapper,
+			data: self.data,
+		}
+	}
+
 	/// Specifies that the field must be set (if optional) or not equal to its zero value (if not optional) in order to be valid.
 	#[inline]
 	pub fn required(mut self) -> FloatValidatorBuilder<Num, SetRequired<S>>
@@ -0,0 +1,87 @@
+use super::*;
+
+mod codes;
+pub(crate) use codes::*;
+
+pub mod date;
+pub use date::*;
+
+pub mod date_time;
+pub use date_time::*;
+
+pub mod interval;
+pub use interval::*;
+
+pub mod lat_lng;
+pub use lat_lng::*;
+
+pub mod money;
+pub use money::*;
+
+pub mod postal_address;
+pub use postal_address::*;
+
+pub mod time_of_day;
+pub use time_of_day::*;
+
+// Renders the built-in rules as CEL rules (see the "Schema Representation" section of the validators guide)
+#[inline(never)]
+#[cold]
+pub(crate) fn google_type_option(
+	known_rules: Vec<CelRule>,
+	cel: Vec<CelProgram>,
+	required: bool,
+	ignore: Ignore,
+) -> ProtoOption {
+	let mut rules = OptionMessageBuilder::new();
+
+	let cel_rules: Vec<OptionValue> = known_rules
+		.into_iter()
+		.chain(cel.into_iter().map(CelRule::from))
+		.map(OptionValue::from)
+		.collect();
+
+	if !cel_rules.is_empty() {
+		rules.set("cel", OptionValue::List(cel_rules.into()));
+	}
+
+	rules.set_required(required).set_ignore(ignore);
+
+	ProtoOption {
+		name: "(buf.validate.field)".into(),
+		value: OptionValue::Message(rules.into()),
+	}
+}
+
+#[inline]
+pub(crate) fn known_rule(
+	id: &'static str,
+	message: impl Into<FixedStr>,
+	expression: String,
+) -> CelRule {
+	CelRule {
+		id: FixedStr::Static(id),
+		message: message.into(),
+		expression: expression.into(),
+	}
+}
+
+#[inline]
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+	year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+// A year of 0 is used for dates without a year (like anniversaries), so February 29 is allowed
+#[inline]
+pub(crate) const fn days_in_month(year: i32, month: i32) -> i32 {
+	match month {
+		1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+		4 | 6 | 9 | 11 => 30,
+		2 if year == 0 || is_leap_year(year) => 29,
+		2 => 28,
+		_ => 0,
+	}
+}
+
+// The CEL equivalent of `days_in_month`, for a message with `year` and `month` fields
+pub(crate) const CEL_DAYS_IN_MONTH: &str = "[31, (this.year == 0 || (this.year % 4 == 0 && (this.year % 100 != 0 || this.year % 400 == 0))) ? 29 : 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31][this.month - 1]";
@@ -0,0 +1,260 @@
+mod builder;
+pub use builder::DateValidatorBuilder;
+
+use proto_types::Date;
+
+use super::*;
+
+/// Validator for the [`Date`] type.
+///
+/// Besides the optional bounds, this validator always checks that the date is a valid calendar date, in one of the forms allowed by `google.type.Date`:
+/// - A full date, with non-zero year, month and day
+/// - A month and day, with a zero year
+/// - A year on its own, with zero month and day
+/// - A year and month, with a zero day
+///
+/// The bounds are only checked for full dates.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateValidator {
+	/// Adds custom validation using one or more [`CelRule`]s to this field.
+	pub cel: Vec<CelProgram>,
+
+	/// The conditions upon which this validator should be skipped.
+	pub ignore: Ignore,
+
+	/// Specifies that the field must be set in order to be valid.
+	pub required: bool,
+
+	/// Specifies that this field's value will be valid only if it is earlier than the specified date.
+	pub lt: Option<Date>,
+
+	/// Specifies that this field's value will be valid only if it is earlier than, or equal to, the specified date.
+	pub lte: Option<Date>,
+
+	/// Specifies that this field's value will be valid only if it is later than the specified date.
+	pub gt: Option<Date>,
+
+	/// Specifies that this field's value will be valid only if it is later than, or equal to, the specified date.
+	pub gte: Option<Date>,
+}
+
+impl Eq for DateValidator {}
+
+impl Hash for DateValidator {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.cel.hash(state);
+		self.ignore.hash(state);
+		self.required.hash(state);
+
+		for bound in [&self.lt, &self.lte, &self.gt, &self.gte] {
+			bound.as_ref().map(date_key).hash(state);
+		}
+	}
+}
+
+pub(crate) const fn is_valid_date(date: &Date) -> bool {
+	let (year, month, day) = (date.year, date.month, date.day);
+
+	if year < 0 || year > 9999 || month < 0 || month > 12 || day < 0 {
+		return false;
+	}
+
+	if month == 0 {
+		// Only a year on its own can omit the month
+		return year != 0 && day == 0;
+	}
+
+	// Either the year or the day must be set
+	(year != 0 || day != 0) && day <= days_in_month(year, month)
+}
+
+#[inline]
+pub(crate) const fn is_full_date(date: &Date) -> bool {
+	date.year != 0 && date.month != 0 && date.day != 0
+}
+
+// Turns a date into a number that preserves its ordering, like 20240115
+#[inline]
+pub(crate) const fn date_key(date: &Date) -> i64 {
+	date.year as i64 * 10000 + date.month as i64 * 100 + date.day as i64
+}
+
+pub(crate) fn format_date(date: &Date) -> String {
+	format!("{:04}-{:02}-{:02}", date.year, date.month, date.day)
+}
+
+impl DateValidator {
+	const VALID_ID: &str = "date.valid";
+	const VALID_MSG: &str = "must be a valid calendar date";
+
+	// The id, CEL operator, description and value of each bound
+	fn bound_rules(
+		&self,
+	) -> impl Iterator<Item = (&'static str, &'static str, &'static str, &Date)> {
+		[
+			("date.lt", "<", "earlier than", self.lt.as_ref()),
+			(
+				"date.lte",
+				"<=",
+				"earlier than or equal to",
+				self.lte.as_ref(),
+			),
+			("date.gt", ">", "later than", self.gt.as_ref()),
+			(
+				"date.gte",
+				">=",
+				"later than or equal to",
+				self.gte.as_ref(),
+			),
+		]
+		.into_iter()
+		.filter_map(|(id, op, desc, bound)| bound.map(|b| (id, op, desc, b)))
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn known_rules(&self) -> Vec<CelRule> {
+		let mut rules = vec![known_rule(
+			Self::VALID_ID,
+			Self::VALID_MSG,
+			format!(
+				"this.year >= 0 && this.year <= 9999 && this.month >= 0 && this.month <= 12 && this.day >= 0 && (this.month == 0 ? (this.year != 0 && this.day == 0) : (this.day == 0 || this.day <= {CEL_DAYS_IN_MONTH})) && (this.year != 0 || this.day != 0)"
+			),
+		)];
+
+		for (id, op, desc, bound) in self.bound_rules() {
+			rules.push(known_rule(
+				id,
+				format!("must be {desc} {}", format_date(bound)),
+				format!(
+					"this.year == 0 || this.month == 0 || this.day == 0 || this.year * 10000 + this.month * 100 + this.day {op} {}",
+					date_key(bound)
+				),
+			));
+		}
+
+		rules
+	}
+}
+
+impl Validator<Date> for DateValidator {
+	type Target = Date;
+
+	impl_testing_methods!();
+
+	#[inline(never)]
+	#[cold]
+	fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+		let mut errors = Vec::new();
+
+		#[cfg(feature = "cel")]
+		if let Err(e) = self.__check_cel_programs() {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
+		for (id, _, _, bound) in self.bound_rules() {
+			if !is_full_date(bound) || !is_valid_date(bound) {
+				errors.push(ConsistencyError::ContradictoryInput(format!(
+					"The bound for `{id}` must be a full and valid date"
+				)));
+			}
+		}
+
+		if let Err(e) = check_comparable_rules(
+			self.lt.as_ref().map(date_key),
+			self.lte.as_ref().map(date_key),
+			self.gt.as_ref().map(date_key),
+			self.gte.as_ref().map(date_key),
+		) {
+			errors.push(e);
+		}
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	fn execute_validation(
+		&self,
+		ctx: &mut ValidationCtx,
+		val: Option<&Self::Target>,
+	) -> ValidationResult {
+		handle_ignore_always!(&self.ignore);
+		handle_ignore_if_zero_value!(&self.ignore, val.is_none());
+
+		let mut is_valid = IsValid::Yes;
+
+		if let Some(val) = val {
+			if !is_valid_date(val) {
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::VALID_ID,
+					ViolationKind::Cel,
+					Self::VALID_MSG,
+				)?;
+			} else if is_full_date(val) {
+				let key = date_key(val);
+
+				macro_rules! check_bound {
+					($bound:ident, $id:literal, $op:tt, $desc:literal) => {
+						if let Some(bound) = &self.$bound
+							&& !(key $op date_key(bound))
+						{
+							is_valid &= ctx.add_violation_with_custom_id(
+								$id,
+								ViolationKind::Cel,
+								format!("must be {} {}", $desc, format_date(bound)),
+							)?;
+						}
+					};
+				}
+
+				check_bound!(lt, "date.lt", <, "earlier than");
+				check_bound!(lte, "date.lte", <=, "earlier than or equal to");
+				check_bound!(gt, "date.gt", >, "later than");
+				check_bound!(gte, "date.gte", >=, "later than or equal to");
+			}
+
+			#[cfg(feature = "cel")]
+			if !self.cel.is_empty() {
+				let cel_ctx = ProgramsExecutionCtx {
+					programs: &self.cel,
+					value: val.clone(),
+					ctx,
+				};
+
+				is_valid &= cel_ctx.execute_programs()?;
+			}
+		} else if self.required {
+			is_valid &= ctx.add_required_violation(None)?;
+		}
+
+		Ok(is_valid)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn schema(&self) -> Option<ValidatorSchema> {
+		Some(ValidatorSchema {
+			schema: self.clone().into(),
+			cel_rules: self.__cel_rules(),
+			imports: vec!["buf/validate/validate.proto".into()],
+		})
+	}
+}
+
+impl From<DateValidator> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(validator: DateValidator) -> Self {
+		google_type_option(
+			validator.known_rules(),
+			validator.cel,
+			validator.required,
+			validator.ignore,
+		)
+	}
+}
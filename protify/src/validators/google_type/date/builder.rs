@@ -0,0 +1,182 @@
+#[doc(hidden)]
+pub mod state;
+use crate::validators::*;
+pub(crate) use state::*;
+
+use proto_types::Date;
+
+/// Builder for [`DateValidator`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateValidatorBuilder<S: State = Empty> {
+	_state: PhantomData<S>,
+
+	data: DateValidator,
+}
+
+impl ProtoValidation for Date {
+	#[doc(hidden)]
+	type Target = Self;
+	#[doc(hidden)]
+	type Stored = Self;
+	type Validator = DateValidator;
+	type ValidatorBuilder = DateValidatorBuilder;
+
+	#[doc(hidden)]
+	type UniqueStore<'a>
+		= LinearRefStore<'a, Self>
+	where
+		Self: 'a;
+
+	#[doc(hidden)]
+	const HAS_DEFAULT_VALIDATOR: bool = false;
+}
+
+impl ValidatedMessage for Date {
+	#[inline(always)]
+	#[doc(hidden)]
+	fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+		Ok(IsValid::Yes)
+	}
+}
+
+impl<S: State> ValidatorBuilderFor<Date> for DateValidatorBuilder<S> {
+	type Validator = DateValidator;
+	#[inline]
+	fn build_validator(self) -> DateValidator {
+		self.build()
+	}
+}
+
+impl<S: State> Default for DateValidatorBuilder<S> {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			_state: PhantomData,
+			data: DateValidator::default(),
+		}
+	}
+}
+
+impl DateValidator {
+	#[must_use]
+	#[inline]
+	pub fn builder() -> DateValidatorBuilder {
+		DateValidatorBuilder::default()
+	}
+}
+
+impl<S: State> From<DateValidatorBuilder<S>> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(value: DateValidatorBuilder<S>) -> Self {
+		value.build().into()
+	}
+}
+
+#[allow(
+	clippy::must_use_candidate,
+	clippy::use_self,
+	clippy::return_self_not_must_use
+)]
+impl<S: State> DateValidatorBuilder<S> {
+	/// Adds a [`CelProgram`] to this validator.
+	#[inline]
+	pub fn cel(mut self, program: CelProgram) -> DateValidatorBuilder<S> {
+		self.data.cel.push(program);
+
+		DateValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that this validator should always be ignored.
+	#[inline]
+	pub fn ignore_always(mut self) -> DateValidatorBuilder<SetIgnore<S>>
+	where
+		S::Ignore: IsUnset,
+	{
+		self.data.ignore = Ignore::Always;
+
+		DateValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the field must be set in order to be valid.
+	#[inline]
+	pub fn required(mut self) -> DateValidatorBuilder<SetRequired<S>>
+	where
+		S::Required: IsUnset,
+	{
+		self.data.required = true;
+
+		DateValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the date must be earlier than the specified value in order to be valid.
+	#[inline]
+	pub fn lt(mut self, val: Date) -> DateValidatorBuilder<SetLt<S>>
+	where
+		S::Lt: IsUnset,
+	{
+		self.data.lt = Some(val);
+
+		DateValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the date must be earlier than or equal to the specified value in order to be valid.
+	#[inline]
+	pub fn lte(mut self, val: Date) -> DateValidatorBuilder<SetLte<S>>
+	where
+		S::Lte: IsUnset,
+	{
+		self.data.lte = Some(val);
+
+		DateValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the date must be later than the specified value in order to be valid.
+	#[inline]
+	pub fn gt(mut self, val: Date) -> DateValidatorBuilder<SetGt<S>>
+	where
+		S::Gt: IsUnset,
+	{
+		self.data.gt = Some(val);
+
+		DateValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the date must be later than or equal to the specified value in order to be valid.
+	#[inline]
+	pub fn gte(mut self, val: Date) -> DateValidatorBuilder<SetGte<S>>
+	where
+		S::Gte: IsUnset,
+	{
+		self.data.gte = Some(val);
+
+		DateValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Builds the validator.
+	#[inline]
+	pub fn build(self) -> DateValidator {
+		self.data
+	}
+}
@@ -0,0 +1,3 @@
+use crate::validators::builder_internals::*;
+use protify_proc_macro::builder_state_macro;
+builder_state_macro!(Required, Ignore, Lt, Lte, Gt, Gte);
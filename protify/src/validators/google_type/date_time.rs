@@ -0,0 +1,127 @@
+mod builder;
+pub use builder::DateTimeValidatorBuilder;
+
+use proto_types::DateTime;
+
+use super::*;
+
+/// Validator for the [`DateTime`] type.
+///
+/// This validator always checks that the year is between 0 (which means that the year is not specified) and 9999, that the month and day form a valid calendar date, and that the time is a valid time of day.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateTimeValidator {
+	/// Adds custom validation using one or more [`CelRule`]s to this field.
+	pub cel: Vec<CelProgram>,
+
+	/// The conditions upon which this validator should be skipped.
+	pub ignore: Ignore,
+
+	/// Specifies that the field must be set in order to be valid.
+	pub required: bool,
+}
+
+impl DateTimeValidator {
+	const VALID_ID: &str = "date_time.valid";
+	const VALID_MSG: &str = "must be a valid date and time";
+
+	#[inline(never)]
+	#[cold]
+	fn known_rules() -> Vec<CelRule> {
+		vec![known_rule(
+			Self::VALID_ID,
+			Self::VALID_MSG,
+			format!(
+				"this.year >= 0 && this.year <= 9999 && this.month >= 1 && this.month <= 12 && this.day >= 1 && this.day <= {CEL_DAYS_IN_MONTH} && {CEL_VALID_TIME}"
+			),
+		)]
+	}
+}
+
+impl Validator<DateTime> for DateTimeValidator {
+	type Target = DateTime;
+
+	impl_testing_methods!();
+
+	#[inline(never)]
+	#[cold]
+	fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+		let mut errors = Vec::new();
+
+		#[cfg(feature = "cel")]
+		if let Err(e) = self.__check_cel_programs() {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	fn execute_validation(
+		&self,
+		ctx: &mut ValidationCtx,
+		val: Option<&Self::Target>,
+	) -> ValidationResult {
+		handle_ignore_always!(&self.ignore);
+		handle_ignore_if_zero_value!(&self.ignore, val.is_none());
+
+		let mut is_valid = IsValid::Yes;
+
+		if let Some(val) = val {
+			let is_valid_date = (0..=9999).contains(&val.year)
+				&& (1..=12).contains(&val.month)
+				&& val.day >= 1
+				&& val.day <= days_in_month(val.year, val.month);
+
+			if !is_valid_date || !is_valid_time(val.hours, val.minutes, val.seconds, val.nanos) {
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::VALID_ID,
+					ViolationKind::Cel,
+					Self::VALID_MSG,
+				)?;
+			}
+
+			#[cfg(feature = "cel")]
+			if !self.cel.is_empty() {
+				let cel_ctx = ProgramsExecutionCtx {
+					programs: &self.cel,
+					value: val.clone(),
+					ctx,
+				};
+
+				is_valid &= cel_ctx.execute_programs()?;
+			}
+		} else if self.required {
+			is_valid &= ctx.add_required_violation(None)?;
+		}
+
+		Ok(is_valid)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn schema(&self) -> Option<ValidatorSchema> {
+		Some(ValidatorSchema {
+			schema: self.clone().into(),
+			cel_rules: self.__cel_rules(),
+			imports: vec!["buf/validate/validate.proto".into()],
+		})
+	}
+}
+
+impl From<DateTimeValidator> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(validator: DateTimeValidator) -> Self {
+		google_type_option(
+			DateTimeValidator::known_rules(),
+			validator.cel,
+			validator.required,
+			validator.ignore,
+		)
+	}
+}
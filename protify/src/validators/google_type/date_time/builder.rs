@@ -0,0 +1,126 @@
+#[doc(hidden)]
+pub mod state;
+use crate::validators::*;
+pub(crate) use state::*;
+
+use proto_types::DateTime;
+
+/// Builder for [`DateTimeValidator`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimeValidatorBuilder<S: State = Empty> {
+	_state: PhantomData<S>,
+
+	data: DateTimeValidator,
+}
+
+impl ProtoValidation for DateTime {
+	#[doc(hidden)]
+	type Target = Self;
+	#[doc(hidden)]
+	type Stored = Self;
+	type Validator = DateTimeValidator;
+	type ValidatorBuilder = DateTimeValidatorBuilder;
+
+	#[doc(hidden)]
+	type UniqueStore<'a>
+		= LinearRefStore<'a, Self>
+	where
+		Self: 'a;
+
+	#[doc(hidden)]
+	const HAS_DEFAULT_VALIDATOR: bool = false;
+}
+
+impl ValidatedMessage for DateTime {
+	#[inline(always)]
+	#[doc(hidden)]
+	fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+		Ok(IsValid::Yes)
+	}
+}
+
+impl<S: State> ValidatorBuilderFor<DateTime> for DateTimeValidatorBuilder<S> {
+	type Validator = DateTimeValidator;
+	#[inline]
+	fn build_validator(self) -> DateTimeValidator {
+		self.build()
+	}
+}
+
+impl<S: State> Default for DateTimeValidatorBuilder<S> {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			_state: PhantomData,
+			data: DateTimeValidator::default(),
+		}
+	}
+}
+
+impl DateTimeValidator {
+	#[must_use]
+	#[inline]
+	pub fn builder() -> DateTimeValidatorBuilder {
+		DateTimeValidatorBuilder::default()
+	}
+}
+
+impl<S: State> From<DateTimeValidatorBuilder<S>> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(value: DateTimeValidatorBuilder<S>) -> Self {
+		value.build().into()
+	}
+}
+
+#[allow(
+	clippy::must_use_candidate,
+	clippy::use_self,
+	clippy::return_self_not_must_use
+)]
+impl<S: State> DateTimeValidatorBuilder<S> {
+	/// Adds a [`CelProgram`] to this validator.
+	#[inline]
+	pub fn cel(mut self, program: CelProgram) -> DateTimeValidatorBuilder<S> {
+		self.data.cel.push(program);
+
+		DateTimeValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that this validator should always be ignored.
+	#[inline]
+	pub fn ignore_always(mut self) -> DateTimeValidatorBuilder<SetIgnore<S>>
+	where
+		S::Ignore: IsUnset,
+	{
+		self.data.ignore = Ignore::Always;
+
+		DateTimeValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the field must be set in order to be valid.
+	#[inline]
+	pub fn required(mut self) -> DateTimeValidatorBuilder<SetRequired<S>>
+	where
+		S::Required: IsUnset,
+	{
+		self.data.required = true;
+
+		DateTimeValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Builds the validator.
+	#[inline]
+	pub fn build(self) -> DateTimeValidator {
+		self.data
+	}
+}
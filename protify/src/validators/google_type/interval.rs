@@ -0,0 +1,124 @@
+mod builder;
+pub use builder::IntervalValidatorBuilder;
+
+use proto_types::Interval;
+
+use super::*;
+
+/// Validator for the [`Interval`] type.
+///
+/// This validator always checks that, if both the start and the end of the interval are set, the end is not earlier than the start.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntervalValidator {
+	/// Adds custom validation using one or more [`CelRule`]s to this field.
+	pub cel: Vec<CelProgram>,
+
+	/// The conditions upon which this validator should be skipped.
+	pub ignore: Ignore,
+
+	/// Specifies that the field must be set in order to be valid.
+	pub required: bool,
+}
+
+impl IntervalValidator {
+	const VALID_ID: &str = "interval.valid";
+	const VALID_MSG: &str = "the end of the interval cannot be earlier than its start";
+
+	#[inline(never)]
+	#[cold]
+	fn known_rules() -> Vec<CelRule> {
+		vec![known_rule(
+			Self::VALID_ID,
+			Self::VALID_MSG,
+			"!has(this.start_time) || !has(this.end_time) || this.start_time <= this.end_time"
+				.to_string(),
+		)]
+	}
+}
+
+impl Validator<Interval> for IntervalValidator {
+	type Target = Interval;
+
+	impl_testing_methods!();
+
+	#[inline(never)]
+	#[cold]
+	fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+		let mut errors = Vec::new();
+
+		#[cfg(feature = "cel")]
+		if let Err(e) = self.__check_cel_programs() {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	fn execute_validation(
+		&self,
+		ctx: &mut ValidationCtx,
+		val: Option<&Self::Target>,
+	) -> ValidationResult {
+		handle_ignore_always!(&self.ignore);
+		handle_ignore_if_zero_value!(&self.ignore, val.is_none());
+
+		let mut is_valid = IsValid::Yes;
+
+		if let Some(val) = val {
+			if let Some(start) = val.start_time
+				&& let Some(end) = val.end_time
+				&& end < start
+			{
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::VALID_ID,
+					ViolationKind::Cel,
+					Self::VALID_MSG,
+				)?;
+			}
+
+			#[cfg(feature = "cel")]
+			if !self.cel.is_empty() {
+				let cel_ctx = ProgramsExecutionCtx {
+					programs: &self.cel,
+					value: val.clone(),
+					ctx,
+				};
+
+				is_valid &= cel_ctx.execute_programs()?;
+			}
+		} else if self.required {
+			is_valid &= ctx.add_required_violation(None)?;
+		}
+
+		Ok(is_valid)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn schema(&self) -> Option<ValidatorSchema> {
+		Some(ValidatorSchema {
+			schema: self.clone().into(),
+			cel_rules: self.__cel_rules(),
+			imports: vec!["buf/validate/validate.proto".into()],
+		})
+	}
+}
+
+impl From<IntervalValidator> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(validator: IntervalValidator) -> Self {
+		google_type_option(
+			IntervalValidator::known_rules(),
+			validator.cel,
+			validator.required,
+			validator.ignore,
+		)
+	}
+}
@@ -0,0 +1,126 @@
+#[doc(hidden)]
+pub mod state;
+use crate::validators::*;
+pub(crate) use state::*;
+
+use proto_types::Interval;
+
+/// Builder for [`IntervalValidator`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalValidatorBuilder<S: State = Empty> {
+	_state: PhantomData<S>,
+
+	data: IntervalValidator,
+}
+
+impl ProtoValidation for Interval {
+	#[doc(hidden)]
+	type Target = Self;
+	#[doc(hidden)]
+	type Stored = Self;
+	type Validator = IntervalValidator;
+	type ValidatorBuilder = IntervalValidatorBuilder;
+
+	#[doc(hidden)]
+	type UniqueStore<'a>
+		= LinearRefStore<'a, Self>
+	where
+		Self: 'a;
+
+	#[doc(hidden)]
+	const HAS_DEFAULT_VALIDATOR: bool = false;
+}
+
+impl ValidatedMessage for Interval {
+	#[inline(always)]
+	#[doc(hidden)]
+	fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+		Ok(IsValid::Yes)
+	}
+}
+
+impl<S: State> ValidatorBuilderFor<Interval> for IntervalValidatorBuilder<S> {
+	type Validator = IntervalValidator;
+	#[inline]
+	fn build_validator(self) -> IntervalValidator {
+		self.build()
+	}
+}
+
+impl<S: State> Default for IntervalValidatorBuilder<S> {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			_state: PhantomData,
+			data: IntervalValidator::default(),
+		}
+	}
+}
+
+impl IntervalValidator {
+	#[must_use]
+	#[inline]
+	pub fn builder() -> IntervalValidatorBuilder {
+		IntervalValidatorBuilder::default()
+	}
+}
+
+impl<S: State> From<IntervalValidatorBuilder<S>> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(value: IntervalValidatorBuilder<S>) -> Self {
+		value.build().into()
+	}
+}
+
+#[allow(
+	clippy::must_use_candidate,
+	clippy::use_self,
+	clippy::return_self_not_must_use
+)]
+impl<S: State> IntervalValidatorBuilder<S> {
+	/// Adds a [`CelProgram`] to this validator.
+	#[inline]
+	pub fn cel(mut self, program: CelProgram) -> IntervalValidatorBuilder<S> {
+		self.data.cel.push(program);
+
+		IntervalValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that this validator should always be ignored.
+	#[inline]
+	pub fn ignore_always(mut self) -> IntervalValidatorBuilder<SetIgnore<S>>
+	where
+		S::Ignore: IsUnset,
+	{
+		self.data.ignore = Ignore::Always;
+
+		IntervalValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the field must be set in order to be valid.
+	#[inline]
+	pub fn required(mut self) -> IntervalValidatorBuilder<SetRequired<S>>
+	where
+		S::Required: IsUnset,
+	{
+		self.data.required = true;
+
+		IntervalValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Builds the validator.
+	#[inline]
+	pub fn build(self) -> IntervalValidator {
+		self.data
+	}
+}
@@ -0,0 +1,138 @@
+mod builder;
+pub use builder::LatLngValidatorBuilder;
+
+use proto_types::LatLng;
+
+use super::*;
+
+/// Validator for the [`LatLng`] type.
+///
+/// This validator always checks that the latitude is in the range [-90.0, +90.0] and that the longitude is in the range [-180.0, +180.0].
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LatLngValidator {
+	/// Adds custom validation using one or more [`CelRule`]s to this field.
+	pub cel: Vec<CelProgram>,
+
+	/// The conditions upon which this validator should be skipped.
+	pub ignore: Ignore,
+
+	/// Specifies that the field must be set in order to be valid.
+	pub required: bool,
+}
+
+impl LatLngValidator {
+	const LATITUDE_ID: &str = "lat_lng.latitude";
+	const LATITUDE_MSG: &str = "latitude must be between -90 and 90";
+	const LONGITUDE_ID: &str = "lat_lng.longitude";
+	const LONGITUDE_MSG: &str = "longitude must be between -180 and 180";
+
+	#[inline(never)]
+	#[cold]
+	fn known_rules() -> Vec<CelRule> {
+		vec![
+			known_rule(
+				Self::LATITUDE_ID,
+				Self::LATITUDE_MSG,
+				"this.latitude >= -90.0 && this.latitude <= 90.0".to_string(),
+			),
+			known_rule(
+				Self::LONGITUDE_ID,
+				Self::LONGITUDE_MSG,
+				"this.longitude >= -180.0 && this.longitude <= 180.0".to_string(),
+			),
+		]
+	}
+}
+
+impl Validator<LatLng> for LatLngValidator {
+	type Target = LatLng;
+
+	impl_testing_methods!();
+
+	#[inline(never)]
+	#[cold]
+	fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+		let mut errors = Vec::new();
+
+		#[cfg(feature = "cel")]
+		if let Err(e) = self.__check_cel_programs() {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	fn execute_validation(
+		&self,
+		ctx: &mut ValidationCtx,
+		val: Option<&Self::Target>,
+	) -> ValidationResult {
+		handle_ignore_always!(&self.ignore);
+		handle_ignore_if_zero_value!(&self.ignore, val.is_none());
+
+		let mut is_valid = IsValid::Yes;
+
+		if let Some(val) = val {
+			// NaN values are out of range as well
+			if !(-90.0..=90.0).contains(&val.latitude) {
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::LATITUDE_ID,
+					ViolationKind::Cel,
+					Self::LATITUDE_MSG,
+				)?;
+			}
+
+			if !(-180.0..=180.0).contains(&val.longitude) {
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::LONGITUDE_ID,
+					ViolationKind::Cel,
+					Self::LONGITUDE_MSG,
+				)?;
+			}
+
+			#[cfg(feature = "cel")]
+			if !self.cel.is_empty() {
+				let cel_ctx = ProgramsExecutionCtx {
+					programs: &self.cel,
+					value: val.clone(),
+					ctx,
+				};
+
+				is_valid &= cel_ctx.execute_programs()?;
+			}
+		} else if self.required {
+			is_valid &= ctx.add_required_violation(None)?;
+		}
+
+		Ok(is_valid)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn schema(&self) -> Option<ValidatorSchema> {
+		Some(ValidatorSchema {
+			schema: self.clone().into(),
+			cel_rules: self.__cel_rules(),
+			imports: vec!["buf/validate/validate.proto".into()],
+		})
+	}
+}
+
+impl From<LatLngValidator> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(validator: LatLngValidator) -> Self {
+		google_type_option(
+			LatLngValidator::known_rules(),
+			validator.cel,
+			validator.required,
+			validator.ignore,
+		)
+	}
+}
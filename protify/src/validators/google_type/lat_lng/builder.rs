@@ -0,0 +1,126 @@
+#[doc(hidden)]
+pub mod state;
+use crate::validators::*;
+pub(crate) use state::*;
+
+use proto_types::LatLng;
+
+/// Builder for [`LatLngValidator`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LatLngValidatorBuilder<S: State = Empty> {
+	_state: PhantomData<S>,
+
+	data: LatLngValidator,
+}
+
+impl ProtoValidation for LatLng {
+	#[doc(hidden)]
+	type Target = Self;
+	#[doc(hidden)]
+	type Stored = Self;
+	type Validator = LatLngValidator;
+	type ValidatorBuilder = LatLngValidatorBuilder;
+
+	#[doc(hidden)]
+	type UniqueStore<'a>
+		= LinearRefStore<'a, Self>
+	where
+		Self: 'a;
+
+	#[doc(hidden)]
+	const HAS_DEFAULT_VALIDATOR: bool = false;
+}
+
+impl ValidatedMessage for LatLng {
+	#[inline(always)]
+	#[doc(hidden)]
+	fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+		Ok(IsValid::Yes)
+	}
+}
+
+impl<S: State> ValidatorBuilderFor<LatLng> for LatLngValidatorBuilder<S> {
+	type Validator = LatLngValidator;
+	#[inline]
+	fn build_validator(self) -> LatLngValidator {
+		self.build()
+	}
+}
+
+impl<S: State> Default for LatLngValidatorBuilder<S> {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			_state: PhantomData,
+			data: LatLngValidator::default(),
+		}
+	}
+}
+
+impl LatLngValidator {
+	#[must_use]
+	#[inline]
+	pub fn builder() -> LatLngValidatorBuilder {
+		LatLngValidatorBuilder::default()
+	}
+}
+
+impl<S: State> From<LatLngValidatorBuilder<S>> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(value: LatLngValidatorBuilder<S>) -> Self {
+		value.build().into()
+	}
+}
+
+#[allow(
+	clippy::must_use_candidate,
+	clippy::use_self,
+	clippy::return_self_not_must_use
+)]
+impl<S: State> LatLngValidatorBuilder<S> {
+	/// Adds a [`CelProgram`] to this validator.
+	#[inline]
+	pub fn cel(mut self, program: CelProgram) -> LatLngValidatorBuilder<S> {
+		self.data.cel.push(program);
+
+		LatLngValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that this validator should always be ignored.
+	#[inline]
+	pub fn ignore_always(mut self) -> LatLngValidatorBuilder<SetIgnore<S>>
+	where
+		S::Ignore: IsUnset,
+	{
+		self.data.ignore = Ignore::Always;
+
+		LatLngValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the field must be set in order to be valid.
+	#[inline]
+	pub fn required(mut self) -> LatLngValidatorBuilder<SetRequired<S>>
+	where
+		S::Required: IsUnset,
+	{
+		self.data.required = true;
+
+		LatLngValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Builds the validator.
+	#[inline]
+	pub fn build(self) -> LatLngValidator {
+		self.data
+	}
+}
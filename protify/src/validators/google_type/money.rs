@@ -0,0 +1,200 @@
+mod builder;
+pub use builder::MoneyValidatorBuilder;
+
+use proto_types::Money;
+
+use super::*;
+
+/// Validator for the [`Money`] type.
+///
+/// This validator always checks that:
+/// - The currency code is a valid ISO 4217 code
+/// - The nanos are between -999,999,999 and +999,999,999
+/// - The nanos have the same sign as the units (or either of them is zero)
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoneyValidator {
+	/// Adds custom validation using one or more [`CelRule`]s to this field.
+	pub cel: Vec<CelProgram>,
+
+	/// The conditions upon which this validator should be skipped.
+	pub ignore: Ignore,
+
+	/// Specifies that the field must be set in order to be valid.
+	pub required: bool,
+
+	/// Specifies that only the currencies in this list will be considered valid for this field.
+	pub in_: Option<SortedList<FixedStr>>,
+}
+
+impl MoneyValidator {
+	const CURRENCY_ID: &str = "money.currency_code";
+	const CURRENCY_MSG: &str = "must have a valid ISO 4217 currency code";
+	const NANOS_ID: &str = "money.nanos";
+	const NANOS_MSG: &str =
+		"nanos must be between -999999999 and 999999999 and have the same sign as the units";
+	const IN_ID: &str = "money.in";
+
+	fn in_message(allowed_list: &SortedList<FixedStr>) -> String {
+		format!(
+			"must have one of these currency codes: {}",
+			FixedStr::__format_list(allowed_list)
+		)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn known_rules(&self) -> Vec<CelRule> {
+		let mut rules = vec![
+			known_rule(
+				Self::CURRENCY_ID,
+				Self::CURRENCY_MSG,
+				format!(
+					"this.currency_code in {}",
+					cel_string_list(CURRENCY_CODES.iter().copied())
+				),
+			),
+			known_rule(
+				Self::NANOS_ID,
+				Self::NANOS_MSG,
+				"this.nanos >= -999999999 && this.nanos <= 999999999 && (this.units <= 0 || this.nanos >= 0) && (this.units >= 0 || this.nanos <= 0)"
+					.to_string(),
+			),
+		];
+
+		if let Some(allowed_list) = &self.in_ {
+			rules.push(known_rule(
+				Self::IN_ID,
+				Self::in_message(allowed_list),
+				format!(
+					"this.currency_code in {}",
+					cel_string_list(allowed_list.iter().map(AsRef::<str>::as_ref))
+				),
+			));
+		}
+
+		rules
+	}
+}
+
+impl Validator<Money> for MoneyValidator {
+	type Target = Money;
+
+	impl_testing_methods!();
+
+	#[inline(never)]
+	#[cold]
+	fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+		let mut errors = Vec::new();
+
+		#[cfg(feature = "cel")]
+		if let Err(e) = self.__check_cel_programs() {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
+		if let Some(allowed_list) = &self.in_ {
+			let invalid_codes: Vec<&str> = allowed_list
+				.iter()
+				.map(AsRef::<str>::as_ref)
+				.filter(|code| !is_valid_currency_code(code))
+				.collect();
+
+			if !invalid_codes.is_empty() {
+				errors.push(ConsistencyError::ContradictoryInput(format!(
+					"The `in` list contains invalid currency codes: {invalid_codes:?}"
+				)));
+			}
+		}
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	fn execute_validation(
+		&self,
+		ctx: &mut ValidationCtx,
+		val: Option<&Self::Target>,
+	) -> ValidationResult {
+		handle_ignore_always!(&self.ignore);
+		handle_ignore_if_zero_value!(&self.ignore, val.is_none());
+
+		let mut is_valid = IsValid::Yes;
+
+		if let Some(val) = val {
+			if !is_valid_currency_code(&val.currency_code) {
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::CURRENCY_ID,
+					ViolationKind::Cel,
+					Self::CURRENCY_MSG,
+				)?;
+			}
+
+			let has_consistent_sign =
+				(val.units <= 0 || val.nanos >= 0) && (val.units >= 0 || val.nanos <= 0);
+
+			if !(-999_999_999..=999_999_999).contains(&val.nanos) || !has_consistent_sign {
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::NANOS_ID,
+					ViolationKind::Cel,
+					Self::NANOS_MSG,
+				)?;
+			}
+
+			if let Some(allowed_list) = &self.in_
+				&& !allowed_list.contains(val.currency_code.as_str())
+			{
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::IN_ID,
+					ViolationKind::Cel,
+					if ctx.is_sensitive() {
+						"must have one of the allowed currency codes".to_string()
+					} else {
+						Self::in_message(allowed_list)
+					},
+				)?;
+			}
+
+			#[cfg(feature = "cel")]
+			if !self.cel.is_empty() {
+				let cel_ctx = ProgramsExecutionCtx {
+					programs: &self.cel,
+					value: val.clone(),
+					ctx,
+				};
+
+				is_valid &= cel_ctx.execute_programs()?;
+			}
+		} else if self.required {
+			is_valid &= ctx.add_required_violation(None)?;
+		}
+
+		Ok(is_valid)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn schema(&self) -> Option<ValidatorSchema> {
+		Some(ValidatorSchema {
+			schema: self.clone().into(),
+			cel_rules: self.__cel_rules(),
+			imports: vec!["buf/validate/validate.proto".into()],
+		})
+	}
+}
+
+impl From<MoneyValidator> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(validator: MoneyValidator) -> Self {
+		google_type_option(
+			validator.known_rules(),
+			validator.cel,
+			validator.required,
+			validator.ignore,
+		)
+	}
+}
@@ -0,0 +1,140 @@
+#[doc(hidden)]
+pub mod state;
+use crate::validators::*;
+pub(crate) use state::*;
+
+use proto_types::Money;
+
+/// Builder for [`MoneyValidator`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MoneyValidatorBuilder<S: State = Empty> {
+	_state: PhantomData<S>,
+
+	data: MoneyValidator,
+}
+
+impl ProtoValidation for Money {
+	#[doc(hidden)]
+	type Target = Self;
+	#[doc(hidden)]
+	type Stored = Self;
+	type Validator = MoneyValidator;
+	type ValidatorBuilder = MoneyValidatorBuilder;
+
+	#[doc(hidden)]
+	type UniqueStore<'a>
+		= LinearRefStore<'a, Self>
+	where
+		Self: 'a;
+
+	#[doc(hidden)]
+	const HAS_DEFAULT_VALIDATOR: bool = false;
+}
+
+impl ValidatedMessage for Money {
+	#[inline(always)]
+	#[doc(hidden)]
+	fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+		Ok(IsValid::Yes)
+	}
+}
+
+impl<S: State> ValidatorBuilderFor<Money> for MoneyValidatorBuilder<S> {
+	type Validator = MoneyValidator;
+	#[inline]
+	fn build_validator(self) -> MoneyValidator {
+		self.build()
+	}
+}
+
+impl<S: State> Default for MoneyValidatorBuilder<S> {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			_state: PhantomData,
+			data: MoneyValidator::default(),
+		}
+	}
+}
+
+impl MoneyValidator {
+	#[must_use]
+	#[inline]
+	pub fn builder() -> MoneyValidatorBuilder {
+		MoneyValidatorBuilder::default()
+	}
+}
+
+impl<S: State> From<MoneyValidatorBuilder<S>> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(value: MoneyValidatorBuilder<S>) -> Self {
+		value.build().into()
+	}
+}
+
+#[allow(
+	clippy::must_use_candidate,
+	clippy::use_self,
+	clippy::return_self_not_must_use
+)]
+impl<S: State> MoneyValidatorBuilder<S> {
+	/// Adds a [`CelProgram`] to this validator.
+	#[inline]
+	pub fn cel(mut self, program: CelProgram) -> MoneyValidatorBuilder<S> {
+		self.data.cel.push(program);
+
+		MoneyValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that this validator should always be ignored.
+	#[inline]
+	pub fn ignore_always(mut self) -> MoneyValidatorBuilder<SetIgnore<S>>
+	where
+		S::Ignore: IsUnset,
+	{
+		self.data.ignore = Ignore::Always;
+
+		MoneyValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the field must be set in order to be valid.
+	#[inline]
+	pub fn required(mut self) -> MoneyValidatorBuilder<SetRequired<S>>
+	where
+		S::Required: IsUnset,
+	{
+		self.data.required = true;
+
+		MoneyValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that only the currency codes in this list will be considered valid for this field.
+	#[inline]
+	pub fn in_(mut self, val: impl IntoSortedList<FixedStr>) -> MoneyValidatorBuilder<SetIn<S>>
+	where
+		S::In: IsUnset,
+	{
+		self.data.in_ = Some(val.into_sorted_list());
+
+		MoneyValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Builds the validator.
+	#[inline]
+	pub fn build(self) -> MoneyValidator {
+		self.data
+	}
+}
@@ -0,0 +1,175 @@
+mod builder;
+pub use builder::PostalAddressValidatorBuilder;
+
+use proto_types::PostalAddress;
+
+use super::*;
+
+/// Validator for the [`PostalAddress`] type.
+///
+/// This validator always checks that the region code is a valid CLDR region code (which corresponds to an ISO 3166-1 alpha-2 code in most cases).
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PostalAddressValidator {
+	/// Adds custom validation using one or more [`CelRule`]s to this field.
+	pub cel: Vec<CelProgram>,
+
+	/// The conditions upon which this validator should be skipped.
+	pub ignore: Ignore,
+
+	/// Specifies that the field must be set in order to be valid.
+	pub required: bool,
+
+	/// Specifies that only the regions in this list will be considered valid for this field.
+	pub in_: Option<SortedList<FixedStr>>,
+}
+
+impl PostalAddressValidator {
+	const REGION_ID: &str = "postal_address.region_code";
+	const REGION_MSG: &str = "must have a valid region code";
+	const IN_ID: &str = "postal_address.in";
+
+	fn in_message(allowed_list: &SortedList<FixedStr>) -> String {
+		format!(
+			"must have one of these region codes: {}",
+			FixedStr::__format_list(allowed_list)
+		)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn known_rules(&self) -> Vec<CelRule> {
+		let mut rules = vec![known_rule(
+			Self::REGION_ID,
+			Self::REGION_MSG,
+			format!(
+				"this.region_code in {}",
+				cel_string_list(REGION_CODES.iter().copied())
+			),
+		)];
+
+		if let Some(allowed_list) = &self.in_ {
+			rules.push(known_rule(
+				Self::IN_ID,
+				Self::in_message(allowed_list),
+				format!(
+					"this.region_code in {}",
+					cel_string_list(allowed_list.iter().map(AsRef::<str>::as_ref))
+				),
+			));
+		}
+
+		rules
+	}
+}
+
+impl Validator<PostalAddress> for PostalAddressValidator {
+	type Target = PostalAddress;
+
+	impl_testing_methods!();
+
+	#[inline(never)]
+	#[cold]
+	fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+		let mut errors = Vec::new();
+
+		#[cfg(feature = "cel")]
+		if let Err(e) = self.__check_cel_programs() {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
+		if let Some(allowed_list) = &self.in_ {
+			let invalid_codes: Vec<&str> = allowed_list
+				.iter()
+				.map(AsRef::<str>::as_ref)
+				.filter(|code| !is_valid_region_code(code))
+				.collect();
+
+			if !invalid_codes.is_empty() {
+				errors.push(ConsistencyError::ContradictoryInput(format!(
+					"The `in` list contains invalid region codes: {invalid_codes:?}"
+				)));
+			}
+		}
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	fn execute_validation(
+		&self,
+		ctx: &mut ValidationCtx,
+		val: Option<&Self::Target>,
+	) -> ValidationResult {
+		handle_ignore_always!(&self.ignore);
+		handle_ignore_if_zero_value!(&self.ignore, val.is_none());
+
+		let mut is_valid = IsValid::Yes;
+
+		if let Some(val) = val {
+			if !is_valid_region_code(&val.region_code) {
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::REGION_ID,
+					ViolationKind::Cel,
+					Self::REGION_MSG,
+				)?;
+			}
+
+			if let Some(allowed_list) = &self.in_
+				&& !allowed_list.contains(val.region_code.as_str())
+			{
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::IN_ID,
+					ViolationKind::Cel,
+					if ctx.is_sensitive() {
+						"must have one of the allowed region codes".to_string()
+					} else {
+						Self::in_message(allowed_list)
+					},
+				)?;
+			}
+
+			#[cfg(feature = "cel")]
+			if !self.cel.is_empty() {
+				let cel_ctx = ProgramsExecutionCtx {
+					programs: &self.cel,
+					value: val.clone(),
+					ctx,
+				};
+
+				is_valid &= cel_ctx.execute_programs()?;
+			}
+		} else if self.required {
+			is_valid &= ctx.add_required_violation(None)?;
+		}
+
+		Ok(is_valid)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn schema(&self) -> Option<ValidatorSchema> {
+		Some(ValidatorSchema {
+			schema: self.clone().into(),
+			cel_rules: self.__cel_rules(),
+			imports: vec!["buf/validate/validate.proto".into()],
+		})
+	}
+}
+
+impl From<PostalAddressValidator> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(validator: PostalAddressValidator) -> Self {
+		google_type_option(
+			validator.known_rules(),
+			validator.cel,
+			validator.required,
+			validator.ignore,
+		)
+	}
+}
@@ -0,0 +1,143 @@
+#[doc(hidden)]
+pub mod state;
+use crate::validators::*;
+pub(crate) use state::*;
+
+use proto_types::PostalAddress;
+
+/// Builder for [`PostalAddressValidator`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PostalAddressValidatorBuilder<S: State = Empty> {
+	_state: PhantomData<S>,
+
+	data: PostalAddressValidator,
+}
+
+impl ProtoValidation for PostalAddress {
+	#[doc(hidden)]
+	type Target = Self;
+	#[doc(hidden)]
+	type Stored = Self;
+	type Validator = PostalAddressValidator;
+	type ValidatorBuilder = PostalAddressValidatorBuilder;
+
+	#[doc(hidden)]
+	type UniqueStore<'a>
+		= LinearRefStore<'a, Self>
+	where
+		Self: 'a;
+
+	#[doc(hidden)]
+	const HAS_DEFAULT_VALIDATOR: bool = false;
+}
+
+impl ValidatedMessage for PostalAddress {
+	#[inline(always)]
+	#[doc(hidden)]
+	fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+		Ok(IsValid::Yes)
+	}
+}
+
+impl<S: State> ValidatorBuilderFor<PostalAddress> for PostalAddressValidatorBuilder<S> {
+	type Validator = PostalAddressValidator;
+	#[inline]
+	fn build_validator(self) -> PostalAddressValidator {
+		self.build()
+	}
+}
+
+impl<S: State> Default for PostalAddressValidatorBuilder<S> {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			_state: PhantomData,
+			data: PostalAddressValidator::default(),
+		}
+	}
+}
+
+impl PostalAddressValidator {
+	#[must_use]
+	#[inline]
+	pub fn builder() -> PostalAddressValidatorBuilder {
+		PostalAddressValidatorBuilder::default()
+	}
+}
+
+impl<S: State> From<PostalAddressValidatorBuilder<S>> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(value: PostalAddressValidatorBuilder<S>) -> Self {
+		value.build().into()
+	}
+}
+
+#[allow(
+	clippy::must_use_candidate,
+	clippy::use_self,
+	clippy::return_self_not_must_use
+)]
+impl<S: State> PostalAddressValidatorBuilder<S> {
+	/// Adds a [`CelProgram`] to this validator.
+	#[inline]
+	pub fn cel(mut self, program: CelProgram) -> PostalAddressValidatorBuilder<S> {
+		self.data.cel.push(program);
+
+		PostalAddressValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that this validator should always be ignored.
+	#[inline]
+	pub fn ignore_always(mut self) -> PostalAddressValidatorBuilder<SetIgnore<S>>
+	where
+		S::Ignore: IsUnset,
+	{
+		self.data.ignore = Ignore::Always;
+
+		PostalAddressValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the field must be set in order to be valid.
+	#[inline]
+	pub fn required(mut self) -> PostalAddressValidatorBuilder<SetRequired<S>>
+	where
+		S::Required: IsUnset,
+	{
+		self.data.required = true;
+
+		PostalAddressValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that only the region codes in this list will be considered valid for this field.
+	#[inline]
+	pub fn in_(
+		mut self,
+		val: impl IntoSortedList<FixedStr>,
+	) -> PostalAddressValidatorBuilder<SetIn<S>>
+	where
+		S::In: IsUnset,
+	{
+		self.data.in_ = Some(val.into_sorted_list());
+
+		PostalAddressValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Builds the validator.
+	#[inline]
+	pub fn build(self) -> PostalAddressValidator {
+		self.data
+	}
+}
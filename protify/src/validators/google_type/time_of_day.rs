@@ -0,0 +1,134 @@
+mod builder;
+pub use builder::TimeOfDayValidatorBuilder;
+
+use proto_types::TimeOfDay;
+
+use super::*;
+
+/// Validator for the [`TimeOfDay`] type.
+///
+/// This validator always checks that the hours are between 0 and 23, the minutes and seconds are between 0 and 59, and the nanos are between 0 and 999,999,999.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeOfDayValidator {
+	/// Adds custom validation using one or more [`CelRule`]s to this field.
+	pub cel: Vec<CelProgram>,
+
+	/// The conditions upon which this validator should be skipped.
+	pub ignore: Ignore,
+
+	/// Specifies that the field must be set in order to be valid.
+	pub required: bool,
+}
+
+#[inline]
+pub(crate) const fn is_valid_time(hours: i32, minutes: i32, seconds: i32, nanos: i32) -> bool {
+	hours >= 0
+		&& hours <= 23
+		&& minutes >= 0
+		&& minutes <= 59
+		&& seconds >= 0
+		&& seconds <= 59
+		&& nanos >= 0
+		&& nanos <= 999_999_999
+}
+
+pub(crate) const CEL_VALID_TIME: &str = "this.hours >= 0 && this.hours <= 23 && this.minutes >= 0 && this.minutes <= 59 && this.seconds >= 0 && this.seconds <= 59 && this.nanos >= 0 && this.nanos <= 999999999";
+
+impl TimeOfDayValidator {
+	const VALID_ID: &str = "time_of_day.valid";
+	const VALID_MSG: &str = "must be a valid time of day";
+
+	#[inline(never)]
+	#[cold]
+	fn known_rules() -> Vec<CelRule> {
+		vec![known_rule(
+			Self::VALID_ID,
+			Self::VALID_MSG,
+			CEL_VALID_TIME.to_string(),
+		)]
+	}
+}
+
+impl Validator<TimeOfDay> for TimeOfDayValidator {
+	type Target = TimeOfDay;
+
+	impl_testing_methods!();
+
+	#[inline(never)]
+	#[cold]
+	fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+		let mut errors = Vec::new();
+
+		#[cfg(feature = "cel")]
+		if let Err(e) = self.__check_cel_programs() {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	fn execute_validation(
+		&self,
+		ctx: &mut ValidationCtx,
+		val: Option<&Self::Target>,
+	) -> ValidationResult {
+		handle_ignore_always!(&self.ignore);
+		handle_ignore_if_zero_value!(&self.ignore, val.is_none());
+
+		let mut is_valid = IsValid::Yes;
+
+		if let Some(val) = val {
+			if !is_valid_time(val.hours, val.minutes, val.seconds, val.nanos) {
+				is_valid &= ctx.add_violation_with_custom_id(
+					Self::VALID_ID,
+					ViolationKind::Cel,
+					Self::VALID_MSG,
+				)?;
+			}
+
+			#[cfg(feature = "cel")]
+			if !self.cel.is_empty() {
+				let cel_ctx = ProgramsExecutionCtx {
+					programs: &self.cel,
+					value: val.clone(),
+					ctx,
+				};
+
+				is_valid &= cel_ctx.execute_programs()?;
+			}
+		} else if self.required {
+			is_valid &= ctx.add_required_violation(None)?;
+		}
+
+		Ok(is_valid)
+	}
+
+	#[inline(never)]
+	#[cold]
+	fn schema(&self) -> Option<ValidatorSchema> {
+		Some(ValidatorSchema {
+			schema: self.clone().into(),
+			cel_rules: self.__cel_rules(),
+			imports: vec!["buf/validate/validate.proto".into()],
+		})
+	}
+}
+
+impl From<TimeOfDayValidator> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(validator: TimeOfDayValidator) -> Self {
+		google_type_option(
+			TimeOfDayValidator::known_rules(),
+			validator.cel,
+			validator.required,
+			validator.ignore,
+		)
+	}
+}
@@ -0,0 +1,126 @@
+#[doc(hidden)]
+pub mod state;
+use crate::validators::*;
+pub(crate) use state::*;
+
+use proto_types::TimeOfDay;
+
+/// Builder for [`TimeOfDayValidator`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimeOfDayValidatorBuilder<S: State = Empty> {
+	_state: PhantomData<S>,
+
+	data: TimeOfDayValidator,
+}
+
+impl ProtoValidation for TimeOfDay {
+	#[doc(hidden)]
+	type Target = Self;
+	#[doc(hidden)]
+	type Stored = Self;
+	type Validator = TimeOfDayValidator;
+	type ValidatorBuilder = TimeOfDayValidatorBuilder;
+
+	#[doc(hidden)]
+	type UniqueStore<'a>
+		= LinearRefStore<'a, Self>
+	where
+		Self: 'a;
+
+	#[doc(hidden)]
+	const HAS_DEFAULT_VALIDATOR: bool = false;
+}
+
+impl ValidatedMessage for TimeOfDay {
+	#[inline(always)]
+	#[doc(hidden)]
+	fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+		Ok(IsValid::Yes)
+	}
+}
+
+impl<S: State> ValidatorBuilderFor<TimeOfDay> for TimeOfDayValidatorBuilder<S> {
+	type Validator = TimeOfDayValidator;
+	#[inline]
+	fn build_validator(self) -> TimeOfDayValidator {
+		self.build()
+	}
+}
+
+impl<S: State> Default for TimeOfDayValidatorBuilder<S> {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			_state: PhantomData,
+			data: TimeOfDayValidator::default(),
+		}
+	}
+}
+
+impl TimeOfDayValidator {
+	#[must_use]
+	#[inline]
+	pub fn builder() -> TimeOfDayValidatorBuilder {
+		TimeOfDayValidatorBuilder::default()
+	}
+}
+
+impl<S: State> From<TimeOfDayValidatorBuilder<S>> for ProtoOption {
+	#[inline(never)]
+	#[cold]
+	fn from(value: TimeOfDayValidatorBuilder<S>) -> Self {
+		value.build().into()
+	}
+}
+
+#[allow(
+	clippy::must_use_candidate,
+	clippy::use_self,
+	clippy::return_self_not_must_use
+)]
+impl<S: State> TimeOfDayValidatorBuilder<S> {
+	/// Adds a [`CelProgram`] to this validator.
+	#[inline]
+	pub fn cel(mut self, program: CelProgram) -> TimeOfDayValidatorBuilder<S> {
+		self.data.cel.push(program);
+
+		TimeOfDayValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that this validator should always be ignored.
+	#[inline]
+	pub fn ignore_always(mut self) -> TimeOfDayValidatorBuilder<SetIgnore<S>>
+	where
+		S::Ignore: IsUnset,
+	{
+		self.data.ignore = Ignore::Always;
+
+		TimeOfDayValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Specifies that the field must be set in order to be valid.
+	#[inline]
+	pub fn required(mut self) -> TimeOfDayValidatorBuilder<SetRequired<S>>
+	where
+		S::Required: IsUnset,
+	{
+		self.data.required = true;
+
+		TimeOfDayValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
+	/// Builds the validator.
+	#[inline]
+	pub fn build(self) -> TimeOfDayValidator {
+		self.data
+	}
+}
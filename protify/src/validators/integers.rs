@@ -49,6 +49,9 @@ where
 
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<Num::ViolationEnum>>,
+
+	/// The [`PredefinedRule`]s applied to this field, with their assigned values.
+	pub predefined: PredefinedRules,
 }
 
 impl<Num> Default for IntValidator<Num>
@@ -70,6 +73,7 @@ where
 			in_: Default::default(),
 			not_in: Default::default(),
 			error_messages: Default::default(),
+			predefined: Default::default(),
 		}
 	}
 }
@@ -80,16 +84,11 @@ where
 {
 	#[inline(never)]
 	#[cold]
-	fn custom_error_or_else(
-		&self,
-		violation: Num::ViolationEnum,
-		default: impl Fn() -> String,
-	) -> String {
+	fn custom_error(&self, violation: Num::ViolationEnum) -> Option<String> {
 		self.error_messages
 			.as_deref()
 			.and_then(|map| map.get(&violation))
 			.map(|m| m.to_string())
-			.unwrap_or_else(default)
 	}
 }
 
@@ -124,7 +123,9 @@ where
     }
 
 		if self.const_.is_some()
-			&& (!self.cel.is_empty() || check_prop_some!(in_, not_in, lt, lte, gt, gte))
+			&& (!self.cel.is_empty()
+				|| !self.predefined.is_empty()
+				|| check_prop_some!(in_, not_in, lt, lte, gt, gte))
 		{
 			errors.push(ConsistencyError::ConstWithOtherRules);
 		}
@@ -163,6 +164,15 @@ where
 			errors.extend(e.into_iter().map(ConsistencyError::from));
 		}
 
+		#[cfg(feature = "cel")]
+		if let Err(e) = self
+			.predefined
+			.check_programs_with(Self::Target::default(), || {
+				rules_message_of(self, Num::type_name())
+			}) {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
 		if let Err(e) = check_list_rules(self.in_.as_ref(), self.not_in.as_ref()) {
 			errors.push(e.into());
 		}
@@ -187,17 +197,23 @@ where
 		handle_ignore_if_zero_value!(&self.ignore, val.is_none_or(|v| v.is_default()));
 
 		let mut is_valid = IsValid::Yes;
+		// The values of these rules would reveal the value of the field
+		let is_sensitive = ctx.is_sensitive();
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
 				paste::paste! {
-				  is_valid &= ctx.add_violation(
-					Num::[< $id:snake:upper _VIOLATION >].into(),
-					self.custom_error_or_else(
-					  Num::[< $id:snake:upper _VIOLATION >],
-					  || $default
+				  let violation = Num::[< $id:snake:upper _VIOLATION >];
+
+				  is_valid &= if let Some(msg) = self.custom_error(violation) {
+					ctx.add_violation(violation.into(), msg)
+				  } else {
+					ctx.add_localized_violation(
+					  violation.into(),
+					  &[$((stringify!($arg), &$value as &dyn Display)),*],
+					  $default,
 					)
-				  )?;
+				  }?;
 				}
 			};
 		}
@@ -210,7 +226,15 @@ where
 		if let Some(&val) = val {
 			if let Some(const_val) = self.const_ {
 				if val != const_val {
-					handle_violation!(Const, format!("must be equal to {const_val}"));
+					if is_sensitive {
+						handle_violation!(Const, "must be equal to the expected value".to_string());
+					} else {
+						handle_violation!(
+							Const,
+							format!("must be equal to {const_val}"),
+							value = const_val
+						);
+					}
 				}
 
 				// Using `const` implies no other rules
@@ -220,49 +244,65 @@ where
 			if let Some(gt) = self.gt
 				&& val <= gt
 			{
-				handle_violation!(Gt, format!("must be greater than {gt}"));
+				handle_violation!(Gt, format!("must be greater than {gt}"), gt = gt);
 			}
 
 			if let Some(gte) = self.gte
 				&& val < gte
 			{
-				handle_violation!(Gte, format!("must be greater than or equal to {gte}"));
+				handle_violation!(
+					Gte,
+					format!("must be greater than or equal to {gte}"),
+					gte = gte
+				);
 			}
 
 			if let Some(lt) = self.lt
 				&& val >= lt
 			{
-				handle_violation!(Lt, format!("must be smaller than {lt}"));
+				handle_violation!(Lt, format!("must be smaller than {lt}"), lt = lt);
 			}
 
 			if let Some(lte) = self.lte
 				&& val > lte
 			{
-				handle_violation!(Lte, format!("must be smaller than or equal to {lte}"));
+				handle_violation!(
+					Lte,
+					format!("must be smaller than or equal to {lte}"),
+					lte = lte
+				);
 			}
 
 			if let Some(allowed_list) = &self.in_
 				&& !allowed_list.contains(&val)
 			{
-				handle_violation!(
-					In,
-					format!(
-						"must be one of these values: {}",
-						Num::RustType::__format_list(allowed_list)
-					)
-				);
+				if is_sensitive {
+					handle_violation!(In, "must be one of the allowed values".to_string());
+				} else {
+					let values = Num::RustType::__format_list(allowed_list);
+
+					handle_violation!(
+						In,
+						format!("must be one of these values: {values}"),
+						values = values
+					);
+				}
 			}
 
 			if let Some(forbidden_list) = &self.not_in
 				&& forbidden_list.contains(&val)
 			{
-				handle_violation!(
-					NotIn,
-					format!(
-						"cannot be one of these values: {}",
-						Num::RustType::__format_list(forbidden_list)
-					)
-				);
+				if is_sensitive {
+					handle_violation!(NotIn, "cannot be one of the forbidden values".to_string());
+				} else {
+					let values = Num::RustType::__format_list(forbidden_list);
+
+					handle_violation!(
+						NotIn,
+						format!("cannot be one of these values: {values}"),
+						values = values
+					);
+				}
 			}
 
 			#[cfg(feature = "cel")]
@@ -275,6 +315,13 @@ where
 
 				is_valid &= cel_ctx.execute_programs()?;
 			}
+
+			#[cfg(feature = "cel")]
+			if !self.predefined.is_empty() {
+				is_valid &= self
+					.predefined
+					.execute(ctx, val, || rules_message_of(self, Num::type_name()))?;
+			}
 		}
 
 		Ok(is_valid)
@@ -337,6 +384,8 @@ where
 					.map(|list| OptionValue::new_list(list)),
 			);
 
+		validator.predefined.add_to_rules(&mut rules);
+
 		let mut outer_rules = OptionMessageBuilder::new();
 
 		if !rules.is_empty() {
@@ -491,3 +540,28 @@ impl_int!(i32, Int32, primitive);
 impl_int!(i64, Int64, primitive);
 impl_int!(u32, Uint32, primitive);
 impl_int!(u64, Uint64, primitive);
+
+impl_wrapper_validation!(
+	crate::well_known_types::Int32Value,
+	i32,
+	IntValidator<i32>,
+	IntValidatorBuilder<i32>
+);
+impl_wrapper_validation!(
+	crate::well_known_types::Int64Value,
+	i64,
+	IntValidator<i64>,
+	IntValidatorBuilder<i64>
+);
+impl_wrapper_validation!(
+	crate::well_known_types::UInt32Value,
+	u32,
+	IntValidator<u32>,
+	IntValidatorBuilder<u32>
+);
+impl_wrapper_validation!(
+	crate::well_known_types::UInt64Value,
+	u64,
+	IntValidator<u64>,
+	IntValidatorBuilder<u64>
+);
@@ -0,0 +1,561 @@
+use super::*;
+
+/// A catalog of localized violation messages.
+///
+/// When a [`ValidationCtx`] has a [`Localization`], the catalog is asked to resolve the rule ID of each
+/// violation (such as `string.min_len`) into a message for the selected locale. If it returns [`None`],
+/// the default (english) message is used.
+///
+/// Custom messages defined in the validators (with `error_messages`) always take precedence over the catalog.
+///
+/// It is implemented for [`FluentCatalog`], which loads messages from a Fluent-style file format, and for closures with the same signature as [`message`](MessageCatalog::message).
+pub trait MessageCatalog: Send + Sync {
+	/// Resolves the message for the given rule ID and locale.
+	fn message(&self, locale: &str, rule_id: &str, args: &MessageArgs) -> Option<String>;
+}
+
+impl<F> MessageCatalog for F
+where
+	F: Fn(&str, &str, &MessageArgs) -> Option<String> + Send + Sync,
+{
+	#[inline]
+	fn message(&self, locale: &str, rule_id: &str, args: &MessageArgs) -> Option<String> {
+		self(locale, rule_id, args)
+	}
+}
+
+/// The parameters of a violation, which can be used to build a localized message.
+///
+/// The parameters are named after the rule that caused the violation (for example, `min_len` for `string.min_len`, or `values` for `string.in`), and the name of the field is always available as `field` (unless the validation is happening at the top level).
+///
+/// The parameters that could reveal the value of a field marked as sensitive (such as those of `const` or `in`) are not provided for those fields.
+#[derive(Clone, Copy)]
+pub struct MessageArgs<'a> {
+	field: Option<&'a FixedStr>,
+	args: &'a [(&'static str, &'a dyn Display)],
+}
+
+impl Debug for MessageArgs<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let mut map = f.debug_map();
+
+		if let Some(field) = self.field {
+			map.entry(&"field", &field.as_ref());
+		}
+
+		for (name, value) in self.args {
+			map.entry(name, &format_args!("{value}"));
+		}
+
+		map.finish()
+	}
+}
+
+impl<'a> MessageArgs<'a> {
+	/// Creates a new instance.
+	#[inline]
+	#[must_use]
+	pub const fn new(
+		field: Option<&'a FixedStr>,
+		args: &'a [(&'static str, &'a dyn Display)],
+	) -> Self {
+		Self { field, args }
+	}
+
+	/// Returns the value of the parameter with the given name, if present.
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&'a dyn Display> {
+		if name == "field" {
+			return self.field.map(|f| f as &dyn Display);
+		}
+
+		self.args
+			.iter()
+			.find(|(arg_name, _)| *arg_name == name)
+			.map(|(_, value)| *value)
+	}
+
+	/// Returns the name of the field, if present.
+	#[inline]
+	#[must_use]
+	pub fn field(&self) -> Option<&'a str> {
+		self.field.map(AsRef::as_ref)
+	}
+
+	/// Returns an iterator over the parameters of the rule (excluding the field name).
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (&'static str, &'a dyn Display)> + '_ {
+		self.args
+			.iter()
+			.map(|(name, value)| (*name, *value))
+	}
+}
+
+/// The locale and the [`MessageCatalog`] used to produce the messages of the violations in a [`ValidationCtx`].
+///
+/// Since the catalog is behind an [`Arc`], this is cheap to clone, so the same catalog can be shared between all requests while the locale changes for each one.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Localization {
+	pub locale: FixedStr,
+	pub catalog: Arc<dyn MessageCatalog>,
+}
+
+impl Localization {
+	/// Creates a new instance.
+	#[inline]
+	#[must_use]
+	pub fn new(locale: impl Into<FixedStr>, catalog: Arc<dyn MessageCatalog>) -> Self {
+		Self {
+			locale: locale.into(),
+			catalog,
+		}
+	}
+
+	#[inline(never)]
+	#[cold]
+	pub(crate) fn message(&self, rule_id: &str, args: &MessageArgs) -> Option<String> {
+		self.catalog.message(&self.locale, rule_id, args)
+	}
+}
+
+impl Debug for Localization {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("Localization")
+			.field("locale", &self.locale)
+			.finish_non_exhaustive()
+	}
+}
+
+impl PartialEq for Localization {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.locale == other.locale && Arc::ptr_eq(&self.catalog, &other.catalog)
+	}
+}
+
+impl Eq for Localization {}
+
+impl Hash for Localization {
+	#[inline]
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.locale.hash(state);
+	}
+}
+
+/// A [`MessageCatalog`] that loads its messages from a Fluent-style format, so that translators can work on plain text files.
+///
+/// Each message is identified by a rule ID, followed by `=` and the message itself, which can continue on the following indented lines. Lines starting with `#` are comments.
+///
+/// Parameters can be inserted with `{ $name }`, and select expressions can be used to pick a variant based on the value of a parameter. The variant keys are compared with the value of the parameter, with the exception of `one`, which matches the value `1`. The variant marked with `*` is the default.
+///
+/// If a message uses a parameter which is not available (for example, because the field is sensitive), the default message is used instead.
+///
+/// When a message is missing for a locale with a region (such as `pt-BR`), the language alone (`pt`) is used as a fallback.
+///
+/// # Examples
+///
+/// ```rust
+/// use protify::*;
+///
+/// let catalog = FluentCatalog::new()
+///   .with_resource("it", r#"
+/// # Stringhe
+/// string.min_len = { $min_len ->
+///     [one] deve contenere almeno un carattere
+///    *[other] deve contenere almeno { $min_len } caratteri
+///   }
+/// required = è obbligatorio
+/// "#).unwrap();
+///
+/// let ctx = ValidationCtx::new().localized(Localization::new("it", Arc::new(catalog)));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FluentCatalog {
+	locales: HashMap<String, HashMap<String, Pattern>>,
+}
+
+/// An error that occurred while parsing a resource for a [`FluentCatalog`].
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("Invalid message catalog for locale `{locale}` at line {line}: {reason}")]
+#[non_exhaustive]
+pub struct CatalogParseError {
+	pub locale: String,
+	pub line: usize,
+	pub reason: String,
+}
+
+impl FluentCatalog {
+	/// Creates a new, empty catalog.
+	#[inline]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds the messages of a resource to the given locale. Messages with an ID that was already defined for the same locale are overridden.
+	pub fn add_resource(
+		&mut self,
+		locale: impl Into<String>,
+		source: &str,
+	) -> Result<(), CatalogParseError> {
+		let locale: String = locale.into();
+
+		let messages = parse_resource(source).map_err(|(line, reason)| CatalogParseError {
+			locale: locale.clone(),
+			line,
+			reason,
+		})?;
+
+		self.locales
+			.entry(locale)
+			.or_default()
+			.extend(messages);
+
+		Ok(())
+	}
+
+	/// Adds the messages of a resource to the given locale, and returns the catalog.
+	pub fn with_resource(
+		mut self,
+		locale: impl Into<String>,
+		source: &str,
+	) -> Result<Self, CatalogParseError> {
+		self.add_resource(locale, source)?;
+		Ok(self)
+	}
+
+	/// Checks if the catalog contains a message for the given locale and rule ID (without falling back to the language).
+	#[must_use]
+	pub fn contains(&self, locale: &str, rule_id: &str) -> bool {
+		self.locales
+			.get(locale)
+			.is_some_and(|messages| messages.contains_key(rule_id))
+	}
+}
+
+impl MessageCatalog for FluentCatalog {
+	fn message(&self, locale: &str, rule_id: &str, args: &MessageArgs) -> Option<String> {
+		let language = locale.split(['-', '_']).next();
+
+		let pattern = self
+			.locales
+			.get(locale)
+			.and_then(|messages| messages.get(rule_id))
+			.or_else(|| {
+				language
+					.filter(|lang| *lang != locale)
+					.and_then(|lang| self.locales.get(lang))
+					.and_then(|messages| messages.get(rule_id))
+			})?;
+
+		let mut output = String::new();
+
+		pattern
+			.format(args, &mut output)
+			.then_some(output)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Pattern(Vec<PatternElement>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PatternElement {
+	Text(String),
+	Arg(String),
+	Select {
+		arg: String,
+		variants: Vec<(String, Pattern)>,
+		default: usize,
+	},
+}
+
+impl Pattern {
+	// Returns false if a parameter is missing
+	fn format(&self, args: &MessageArgs, output: &mut String) -> bool {
+		for element in &self.0 {
+			match element {
+				PatternElement::Text(text) => output.push_str(text),
+				PatternElement::Arg(name) => {
+					let Some(value) = args.get(name) else {
+						return false;
+					};
+
+					let _ = write!(output, "{value}");
+				}
+				PatternElement::Select {
+					arg,
+					variants,
+					default,
+				} => {
+					let Some(value) = args.get(arg) else {
+						return false;
+					};
+
+					let value = value.to_string();
+
+					let (_, variant) = variants
+						.iter()
+						.find(|(key, _)| *key == value || (key == "one" && value == "1"))
+						.unwrap_or(&variants[*default]);
+
+					if !variant.format(args, output) {
+						return false;
+					}
+				}
+			}
+		}
+
+		true
+	}
+}
+
+type ParseResult<T> = Result<T, (usize, String)>;
+
+fn is_identifier(id: &str) -> bool {
+	let mut chars = id.chars();
+
+	chars
+		.next()
+		.is_some_and(|c| c.is_ascii_alphabetic())
+		&& chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+fn parse_resource(source: &str) -> ParseResult<Vec<(String, Pattern)>> {
+	let mut messages: Vec<(String, Pattern)> = Vec::new();
+	let lines: Vec<&str> = source.lines().collect();
+	let mut i = 0;
+
+	while i < lines.len() {
+		let line = lines[i];
+		let line_number = i + 1;
+		i += 1;
+
+		let trimmed = line.trim();
+
+		if trimmed.is_empty() || trimmed.starts_with('#') {
+			continue;
+		}
+
+		if line.starts_with(char::is_whitespace) {
+			return Err((
+				line_number,
+				"unexpected indented line outside of a message".to_string(),
+			));
+		}
+
+		let Some((id, first_line)) = line.split_once('=') else {
+			return Err((line_number, "expected `<id> = <message>`".to_string()));
+		};
+
+		let id = id.trim();
+
+		if !is_identifier(id) {
+			return Err((line_number, format!("invalid message id `{id}`")));
+		}
+
+		let mut value = first_line.trim().to_string();
+
+		// Indented lines belong to the same message
+		while i < lines.len()
+			&& (lines[i].starts_with(char::is_whitespace) || lines[i].trim().is_empty())
+		{
+			let continuation = lines[i].trim();
+
+			if !continuation.is_empty() {
+				if !value.is_empty() {
+					value.push('\n');
+				}
+
+				value.push_str(continuation);
+			}
+
+			i += 1;
+		}
+
+		if value.is_empty() {
+			return Err((line_number, format!("message `{id}` has no value")));
+		}
+
+		let pattern = PatternParser {
+			chars: value.chars().collect(),
+			pos: 0,
+			line: line_number,
+		}
+		.parse_pattern(false)?;
+
+		messages.push((id.to_string(), pattern));
+	}
+
+	Ok(messages)
+}
+
+struct PatternParser {
+	chars: Vec<char>,
+	pos: usize,
+	line: usize,
+}
+
+impl PatternParser {
+	fn error<T>(&self, reason: impl Into<String>) -> ParseResult<T> {
+		Err((self.line, reason.into()))
+	}
+
+	fn peek(&self) -> Option<char> {
+		self.chars.get(self.pos).copied()
+	}
+
+	fn skip_whitespace(&mut self) {
+		while self.peek().is_some_and(char::is_whitespace) {
+			self.pos += 1;
+		}
+	}
+
+	fn expect(&mut self, expected: char) -> ParseResult<()> {
+		if self.peek() == Some(expected) {
+			self.pos += 1;
+			Ok(())
+		} else {
+			self.error(format!("expected `{expected}`"))
+		}
+	}
+
+	// Parses a pattern until the end of the input or, for variants, until the end of the line
+	fn parse_pattern(&mut self, is_variant: bool) -> ParseResult<Pattern> {
+		let mut elements: Vec<PatternElement> = Vec::new();
+		let mut text = String::new();
+
+		while let Some(c) = self.peek() {
+			if is_variant && (c == '\n' || c == '}') {
+				break;
+			}
+
+			if c == '{' {
+				if !text.is_empty() {
+					elements.push(PatternElement::Text(core::mem::take(&mut text)));
+				}
+
+				self.pos += 1;
+				elements.push(self.parse_placeable()?);
+			} else if c == '}' {
+				return self.error("unmatched `}`");
+			} else {
+				text.push(c);
+				self.pos += 1;
+			}
+		}
+
+		if !text.is_empty() {
+			let text = if is_variant {
+				text.trim_end().to_string()
+			} else {
+				text
+			};
+
+			elements.push(PatternElement::Text(text));
+		}
+
+		Ok(Pattern(elements))
+	}
+
+	fn parse_identifier(&mut self) -> ParseResult<String> {
+		let start = self.pos;
+
+		while self
+			.peek()
+			.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+		{
+			self.pos += 1;
+		}
+
+		if start == self.pos {
+			return self.error("expected an identifier");
+		}
+
+		Ok(self.chars[start..self.pos].iter().collect())
+	}
+
+	fn parse_placeable(&mut self) -> ParseResult<PatternElement> {
+		self.skip_whitespace();
+
+		// String literals, for escaping braces
+		if self.peek() == Some('"') {
+			self.pos += 1;
+
+			let start = self.pos;
+
+			while self.peek().is_some_and(|c| c != '"') {
+				self.pos += 1;
+			}
+
+			let literal: String = self.chars[start..self.pos].iter().collect();
+
+			self.expect('"')?;
+			self.skip_whitespace();
+			self.expect('}')?;
+
+			return Ok(PatternElement::Text(literal));
+		}
+
+		self.expect('$')?;
+		let arg = self.parse_identifier()?;
+		self.skip_whitespace();
+
+		if self.peek() == Some('}') {
+			self.pos += 1;
+			return Ok(PatternElement::Arg(arg));
+		}
+
+		self.expect('-')?;
+		self.expect('>')?;
+
+		let mut variants: Vec<(String, Pattern)> = Vec::new();
+		let mut default: Option<usize> = None;
+
+		loop {
+			self.skip_whitespace();
+
+			match self.peek() {
+				Some('}') => {
+					self.pos += 1;
+					break;
+				}
+				Some('*') => {
+					if default.is_some() {
+						return self.error(format!("multiple default variants for `${arg}`"));
+					}
+
+					self.pos += 1;
+					default = Some(variants.len());
+				}
+				Some('[') => {}
+				_ => return self.error("expected a variant or `}`"),
+			};
+
+			self.expect('[')?;
+			self.skip_whitespace();
+			let key = self.parse_identifier()?;
+			self.skip_whitespace();
+			self.expect(']')?;
+
+			while self.peek().is_some_and(|c| c == ' ' || c == '\t') {
+				self.pos += 1;
+			}
+
+			let pattern = self.parse_pattern(true)?;
+
+			variants.push((key, pattern));
+		}
+
+		let Some(default) = default else {
+			return self.error(format!("missing default variant for `${arg}`"));
+		};
+
+		Ok(PatternElement::Select {
+			arg,
+			variants,
+			default,
+		})
+	}
+}
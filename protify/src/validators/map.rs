@@ -419,22 +419,33 @@ where
 
 		if let Some(val) = val {
 			macro_rules! handle_violation {
-				($id:ident, $default:expr) => {
-					is_valid &= ctx.add_violation(
-						ViolationKind::Map(MapViolation::$id),
-						self.error_messages
-							.as_deref()
-							.and_then(|map| map.get(&MapViolation::$id))
-							.map(|m| Cow::Borrowed(m.as_ref()))
-							.unwrap_or_else(|| Cow::Owned($default)),
-					)?;
+				($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+					let kind = ViolationKind::Map(MapViolation::$id);
+
+					is_valid &= if let Some(msg) = self
+						.error_messages
+						.as_deref()
+						.and_then(|map| map.get(&MapViolation::$id))
+					{
+						ctx.add_violation(kind, msg.to_string())
+					} else {
+						ctx.add_localized_violation(
+							kind,
+							&[$((stringify!($arg), &$value as &dyn Display)),*],
+							$default,
+						)
+					}?;
 				};
 			}
 
 			if let Some(min_pairs) = self.min_pairs
 				&& val.__length() < min_pairs
 			{
-				handle_violation!(MinPairs, format!("must contain at least {min_pairs} pairs"));
+				handle_violation!(
+					MinPairs,
+					format!("must contain at least {min_pairs} pairs"),
+					min_pairs = min_pairs
+				);
 			}
 
 			if let Some(max_pairs) = self.max_pairs
@@ -442,7 +453,8 @@ where
 			{
 				handle_violation!(
 					MaxPairs,
-					format!("cannot contain more than {max_pairs} pairs")
+					format!("cannot contain more than {max_pairs} pairs"),
+					max_pairs = max_pairs
 				);
 			}
 
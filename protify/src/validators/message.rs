@@ -21,6 +21,9 @@ pub trait ValidatedMessage: ProtoValidation + Default + Clone {
 			parent_elements: vec![],
 			violations: ValidationErrors::new(),
 			fail_fast: false,
+			localization: None,
+			field_mask: None,
+			extensions: ValidationExtensions::new(),
 		};
 
 		let _ = self.validate_with_ctx(&mut ctx);
@@ -53,6 +56,78 @@ pub trait ValidatedMessage: ProtoValidation + Default + Clone {
 		}
 	}
 
+	/// Executes validation only on the fields whose paths are covered by the [`FieldMask`](proto_types::FieldMask), which is useful for partial updates.
+	///
+	/// The nested paths (such as `address.city`) are used to validate only some fields of nested messages.
+	///
+	/// The CEL rules defined at the top level of a message are only executed if all the fields that they reference are covered by the mask,
+	/// whereas custom validators (which may access any field) are only executed if the message is covered in its entirety.
+	///
+	/// Uses the default values for [`ValidationCtx`], including `fail_fast: true`.
+	#[inline]
+	fn validate_masked(&self, mask: &proto_types::FieldMask) -> Result<(), ValidationErrors> {
+		if !Self::HAS_DEFAULT_VALIDATOR {
+			return Ok(());
+		}
+
+		let mut ctx = ValidationCtx::default().masked(mask);
+
+		let _ = self.validate_with_ctx(&mut ctx);
+
+		if ctx.violations.is_empty() {
+			Ok(())
+		} else {
+			Err(ctx.violations)
+		}
+	}
+
+	/// Executes validation on this message with the given [`ValidationCtx`], which can be used to pass
+	/// request-scoped data to custom validators and CEL programs via its [`ValidationExtensions`].
+	///
+	/// Unlike [`validate_with_ctx`](ValidatedMessage::validate_with_ctx), it takes ownership of the context and returns the violations directly.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use protify::*;
+	/// use protify::proto_types::protovalidate::ViolationKind;
+	///
+	/// struct CallerRole(&'static str);
+	///
+	/// fn admin_only(ctx: &mut ValidationCtx, _: Option<&Document>) -> ValidationResult {
+	///   if ctx.extensions.get::<CallerRole>().is_some_and(|role| role.0 == "admin") {
+	///     Ok(IsValid::Yes)
+	///   } else {
+	///     ctx.add_violation_with_custom_id("admin_only", ViolationKind::Required, "must be an admin")
+	///   }
+	/// }
+	///
+	/// #[proto_message]
+	/// #[proto(validate = from_fn(admin_only))]
+	/// pub struct Document {
+	///   pub id: i32,
+	/// }
+	///
+	/// let doc = Document::default();
+	///
+	/// assert!(doc.validate().is_err());
+	/// assert!(doc.validate_with(ValidationCtx::new().with_extension(CallerRole("admin"))).is_ok());
+	/// ```
+	#[inline]
+	fn validate_with(&self, mut ctx: ValidationCtx) -> Result<(), ValidationErrors> {
+		if !Self::HAS_DEFAULT_VALIDATOR {
+			return Ok(());
+		}
+
+		let _ = self.validate_with_ctx(&mut ctx);
+
+		if ctx.violations.is_empty() {
+			Ok(())
+		} else {
+			Err(ctx.violations)
+		}
+	}
+
 	/// Executes validation on this message, triggering the validators that have been assigned to it
 	/// via macro attributes, and returns `true` if the validation was successful.
 	///
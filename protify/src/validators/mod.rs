@@ -368,6 +368,18 @@ mod field_context;
 #[doc(inline)]
 pub use field_context::*;
 
+mod localization;
+#[doc(inline)]
+pub use localization::*;
+
+mod field_mask_tree;
+#[doc(inline)]
+pub use field_mask_tree::*;
+
+mod validation_extensions;
+#[doc(inline)]
+pub use validation_extensions::*;
+
 pub mod map;
 pub use map::*;
 
@@ -392,6 +404,11 @@ pub use integers::*;
 pub mod field_mask;
 pub use field_mask::*;
 
+#[cfg(feature = "common-types")]
+pub mod google_type;
+#[cfg(feature = "common-types")]
+pub use google_type::*;
+
 mod lookup;
 pub use lookup::*;
 
@@ -402,3 +419,15 @@ pub use oneof::*;
 mod violations;
 #[doc(inline)]
 pub use violations::*;
+
+mod report;
+#[doc(inline)]
+pub use report::*;
+
+mod type_registry;
+#[doc(inline)]
+pub use type_registry::*;
+
+mod proxy_conversions;
+#[doc(inline)]
+pub use proxy_conversions::*;
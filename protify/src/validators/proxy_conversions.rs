@@ -0,0 +1,298 @@
+use core::convert::Infallible;
+
+use prost::UnknownEnumValue;
+use proto_types::protovalidate::field_path_element::Subscript;
+
+use super::*;
+
+/// A custom error for the `from_proto` conversions of the fields of fallible proxies.
+///
+/// It gets converted into a violation with the given rule ID, kind and message, for the field that is being converted.
+///
+/// # Examples
+///
+/// ```rust
+/// use protify::*;
+/// use protify::proto_types::protovalidate::{ViolationKind, violations_data::StringViolation};
+///
+/// fn parse_sku(sku: String) -> Result<u64, ConversionError> {
+///   sku.parse().map_err(|_| {
+///     ConversionError::new(
+///       "sku.format",
+///       ViolationKind::String(StringViolation::Pattern),
+///       "must be a number",
+///     )
+///   })
+/// }
+///
+/// #[proto_message(proxied, fallible)]
+/// pub struct Product {
+///   #[proto(string, from_proto = parse_sku, into_proto = |v: u64| v.to_string())]
+///   pub sku: u64,
+/// }
+///
+/// let errors = Product::try_from(ProductProto { sku: "abc".to_string() }).unwrap_err();
+///
+/// assert_eq!(errors.iter().next().unwrap().data.rule_id(), "sku.format");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConversionError {
+	pub rule_id: FixedStr,
+	pub kind: ViolationKind,
+	pub message: FixedStr,
+}
+
+impl ConversionError {
+	/// Creates a new instance.
+	#[inline]
+	#[must_use]
+	pub fn new(
+		rule_id: impl Into<FixedStr>,
+		kind: ViolationKind,
+		message: impl Into<FixedStr>,
+	) -> Self {
+		Self {
+			rule_id: rule_id.into(),
+			kind,
+			message: message.into(),
+		}
+	}
+}
+
+impl Display for ConversionError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let Self {
+			rule_id, message, ..
+		} = self;
+
+		write!(f, "[{rule_id}] {message}")
+	}
+}
+
+impl core::error::Error for ConversionError {}
+
+/// Implemented by the errors that can be produced by the conversions of fallible proxies.
+///
+/// The errors are added as violations to the [`ValidationCtx`], under the path of the field that is being converted.
+pub trait ProxyConversionError {
+	/// Adds the error to the violations of the [`ValidationCtx`].
+	fn add_to_ctx(self, ctx: &mut ValidationCtx);
+}
+
+impl ProxyConversionError for Infallible {
+	#[inline]
+	fn add_to_ctx(self, _: &mut ValidationCtx) {
+		match self {}
+	}
+}
+
+impl ProxyConversionError for ConversionError {
+	#[inline(never)]
+	#[cold]
+	fn add_to_ctx(self, ctx: &mut ValidationCtx) {
+		let _ = ctx.add_violation_with_custom_id(&*self.rule_id, self.kind, &*self.message);
+	}
+}
+
+impl ProxyConversionError for UnknownEnumValue {
+	#[inline(never)]
+	#[cold]
+	fn add_to_ctx(self, ctx: &mut ValidationCtx) {
+		let _ = ctx.add_localized_violation(
+			ViolationKind::Enum(EnumViolation::DefinedOnly),
+			&[],
+			"must be a known enum value",
+		);
+	}
+}
+
+// The violations of nested messages are placed under the path of the current field
+impl ProxyConversionError for ValidationErrors {
+	#[inline(never)]
+	#[cold]
+	fn add_to_ctx(self, ctx: &mut ValidationCtx) {
+		let mut prefix = ctx.parent_elements.clone();
+
+		if let Some(field_context) = &ctx.field_context {
+			prefix.push(field_context.as_path_element());
+		}
+
+		for mut violation in self {
+			if !prefix.is_empty() {
+				let field = violation
+					.data
+					.field
+					.get_or_insert_with(FieldPath::default);
+
+				field
+					.elements
+					.splice(0..0, prefix.iter().cloned());
+			}
+
+			ctx.violations.push(violation);
+		}
+	}
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn __try_convert<T, E: ProxyConversionError>(
+	ctx: &mut ValidationCtx,
+	result: Result<T, E>,
+) -> Option<T> {
+	match result {
+		Ok(value) => Some(value),
+		Err(e) => {
+			e.add_to_ctx(ctx);
+			None
+		}
+	}
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn __try_convert_required<P, T, E: ProxyConversionError>(
+	ctx: &mut ValidationCtx,
+	value: Option<P>,
+	convert: impl FnOnce(P) -> Result<T, E>,
+) -> Option<T> {
+	if let Some(value) = value {
+		__try_convert(ctx, convert(value))
+	} else {
+		let _ = ctx.add_required_violation(None);
+		None
+	}
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn __try_convert_optional<P, T, E: ProxyConversionError>(
+	ctx: &mut ValidationCtx,
+	value: Option<P>,
+	convert: impl FnOnce(P) -> Result<T, E>,
+) -> Option<Option<T>> {
+	match value {
+		Some(value) => __try_convert(ctx, convert(value)).map(Some),
+		None => Some(None),
+	}
+}
+
+/// Implemented by the [`proto_oneof`] macro for proxied oneofs, for the conversions of the fallible proxies that contain them.
+///
+/// Each variant is converted under its own field path, so that unknown enum values and failed message conversions are added to the [`ValidationCtx`].
+/// The blanket impl covers the oneofs that are not proxied.
+#[doc(hidden)]
+pub trait __TryFromOneof<P>: Sized {
+	fn __try_from_oneof(value: P, ctx: &mut ValidationCtx) -> Option<Self>;
+}
+
+impl<T> __TryFromOneof<T> for T {
+	#[inline]
+	fn __try_from_oneof(value: T, _: &mut ValidationCtx) -> Option<Self> {
+		Some(value)
+	}
+}
+
+// Oneofs do not have a field path of their own
+#[doc(hidden)]
+#[inline]
+pub fn __try_convert_required_oneof<P, T: __TryFromOneof<P>>(
+	ctx: &mut ValidationCtx,
+	value: Option<P>,
+) -> Option<T> {
+	if let Some(value) = value {
+		T::__try_from_oneof(value, ctx)
+	} else {
+		ctx.without_field_context();
+
+		let _ = ctx.add_required_oneof_violation(None);
+		None
+	}
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn __try_convert_optional_oneof<P, T: __TryFromOneof<P>>(
+	ctx: &mut ValidationCtx,
+	value: Option<P>,
+) -> Option<Option<T>> {
+	match value {
+		Some(value) => T::__try_from_oneof(value, ctx).map(Some),
+		None => Some(None),
+	}
+}
+
+#[doc(hidden)]
+pub fn __try_convert_repeated<P, T, E, C>(
+	ctx: &mut ValidationCtx,
+	items: impl IntoIterator<Item = P>,
+	mut convert: impl FnMut(P) -> Result<T, E>,
+) -> Option<C>
+where
+	E: ProxyConversionError,
+	C: FromIterator<T>,
+{
+	let mut converted: Vec<T> = Vec::new();
+	let mut has_errors = false;
+
+	for (i, item) in items.into_iter().enumerate() {
+		match convert(item) {
+			Ok(value) => converted.push(value),
+			Err(e) => {
+				has_errors = true;
+
+				if let Some(fc) = ctx.field_context.as_mut() {
+					fc.subscript = Some(Subscript::Index(i as u64));
+					fc.field_kind = FieldKind::RepeatedItem;
+				}
+
+				e.add_to_ctx(ctx);
+			}
+		}
+	}
+
+	if let Some(fc) = ctx.field_context.as_mut() {
+		fc.subscript = None;
+		fc.field_kind = FieldKind::default();
+	}
+
+	(!has_errors).then(|| converted.into_iter().collect())
+}
+
+#[doc(hidden)]
+pub fn __try_convert_map<K, P, K2, T, E, C>(
+	ctx: &mut ValidationCtx,
+	items: impl IntoIterator<Item = (K, P)>,
+	mut convert: impl FnMut(P) -> Result<T, E>,
+) -> Option<C>
+where
+	K: Into<Subscript> + Into<K2>,
+	E: ProxyConversionError,
+	C: FromIterator<(K2, T)>,
+{
+	let mut converted: Vec<(K2, T)> = Vec::new();
+	let mut has_errors = false;
+
+	for (k, v) in items {
+		match convert(v) {
+			Ok(value) => converted.push((Into::<K2>::into(k), value)),
+			Err(e) => {
+				has_errors = true;
+
+				if let Some(fc) = ctx.field_context.as_mut() {
+					fc.subscript = Some(Into::<Subscript>::into(k));
+					fc.field_kind = FieldKind::MapValue;
+				}
+
+				e.add_to_ctx(ctx);
+			}
+		}
+	}
+
+	if let Some(fc) = ctx.field_context.as_mut() {
+		fc.subscript = None;
+		fc.field_kind = FieldKind::default();
+	}
+
+	(!has_errors).then(|| converted.into_iter().collect())
+}
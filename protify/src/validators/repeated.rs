@@ -269,15 +269,22 @@ where
 
 		if let Some(val) = val {
 			macro_rules! handle_violation {
-				($id:ident, $default:expr) => {
-					is_valid &= ctx.add_violation(
-						ViolationKind::Repeated(RepeatedViolation::$id),
-						self.error_messages
-							.as_deref()
-							.and_then(|map| map.get(&RepeatedViolation::$id))
-							.map(|m| Cow::Borrowed(m.as_ref()))
-							.unwrap_or_else(|| Cow::Owned($default)),
-					)?;
+				($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+					let kind = ViolationKind::Repeated(RepeatedViolation::$id);
+
+					is_valid &= if let Some(msg) = self
+						.error_messages
+						.as_deref()
+						.and_then(|map| map.get(&RepeatedViolation::$id))
+					{
+						ctx.add_violation(kind, msg.to_string())
+					} else {
+						ctx.add_localized_violation(
+							kind,
+							&[$((stringify!($arg), &$value as &dyn Display)),*],
+							$default,
+						)
+					}?;
 				};
 			}
 
@@ -286,7 +293,8 @@ where
 			{
 				handle_violation!(
 					MinItems,
-					format!("must contain at least {min} item{}", pluralize!(min))
+					format!("must contain at least {min} item{}", pluralize!(min)),
+					min_items = min
 				);
 			}
 
@@ -295,7 +303,8 @@ where
 			{
 				handle_violation!(
 					MaxItems,
-					format!("cannot contain more than {max} item{}", pluralize!(max))
+					format!("cannot contain more than {max} item{}", pluralize!(max)),
+					max_items = max
 				);
 			}
 
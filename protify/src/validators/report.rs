@@ -0,0 +1,308 @@
+use proto_types::protovalidate::field_path_element::Subscript;
+
+use super::*;
+
+/// Converts a [`FieldPath`] into a dotted path, such as `items[2].name` or `labels["env"]`.
+#[must_use]
+pub fn field_path_to_string(path: &FieldPath) -> String {
+	let mut output = String::new();
+
+	for element in &path.elements {
+		if !output.is_empty() {
+			output.push('.');
+		}
+
+		output.push_str(element.field_name());
+
+		match &element.subscript {
+			Some(Subscript::Index(i)) => {
+				let _ = write!(output, "[{i}]");
+			}
+			Some(Subscript::BoolKey(key)) => {
+				let _ = write!(output, "[{key}]");
+			}
+			Some(Subscript::IntKey(key)) => {
+				let _ = write!(output, "[{key}]");
+			}
+			Some(Subscript::UintKey(key)) => {
+				let _ = write!(output, "[{key}]");
+			}
+			Some(Subscript::StringKey(key)) => {
+				let _ = write!(output, "[{key:?}]");
+			}
+			None => {}
+		};
+	}
+
+	output
+}
+
+/// Converts a [`FieldPath`] into a [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901), such as `/items/2/name`, which can be used to map violations to the fields of a REST payload.
+#[must_use]
+pub fn field_path_to_json_pointer(path: &FieldPath) -> String {
+	fn push_segment(output: &mut String, segment: &str) {
+		output.push('/');
+
+		for char in segment.chars() {
+			match char {
+				'~' => output.push_str("~0"),
+				'/' => output.push_str("~1"),
+				_ => output.push(char),
+			}
+		}
+	}
+
+	let mut output = String::new();
+
+	for element in &path.elements {
+		push_segment(&mut output, element.field_name());
+
+		match &element.subscript {
+			Some(Subscript::Index(i)) => push_segment(&mut output, &i.to_string()),
+			Some(Subscript::BoolKey(key)) => push_segment(&mut output, &key.to_string()),
+			Some(Subscript::IntKey(key)) => push_segment(&mut output, &key.to_string()),
+			Some(Subscript::UintKey(key)) => push_segment(&mut output, &key.to_string()),
+			Some(Subscript::StringKey(key)) => push_segment(&mut output, key),
+			None => {}
+		};
+	}
+
+	output
+}
+
+/// The output format of a [`ValidationReport`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ReportFormat {
+	/// A plain text report, grouped by field path.
+	#[default]
+	Plain,
+	/// Same as [`Plain`](ReportFormat::Plain), but with colors for terminal output.
+	Colored,
+	/// A JSON object that maps each field path to the list of its violations.
+	///
+	/// The violations of the top level validators are listed under the empty path (`""`).
+	Json,
+}
+
+/// A human-readable report for [`ValidationErrors`], which groups the violations by field path.
+///
+/// Created with [`ValidationErrors::report`].
+///
+/// # Examples
+///
+/// ```rust
+/// use protify::*;
+///
+/// #[proto_message]
+/// pub struct User {
+///   #[proto(validate = |v| v.min_len(3))]
+///   pub name: String,
+/// }
+///
+/// let errors = User::default().validate().unwrap_err();
+///
+/// assert_eq!(
+///   errors.report(ReportFormat::Plain).to_string(),
+///   "1 violation:\n  name:\n    - [string.min_len] must be at least 3 characters long\n"
+/// );
+///
+/// assert_eq!(
+///   errors.report(ReportFormat::Json).to_string(),
+///   r#"{"name":[{"rule_id":"string.min_len","message":"must be at least 3 characters long"}]}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ValidationReport<'a> {
+	errors: &'a ValidationErrors,
+	format: ReportFormat,
+}
+
+impl<'a> ValidationReport<'a> {
+	/// Returns the [`ReportFormat`] of this report.
+	#[inline]
+	#[must_use]
+	pub const fn format(&self) -> ReportFormat {
+		self.format
+	}
+
+	// Groups the violations by path, in the order in which they first appear
+	fn groups(&self) -> Vec<(String, Vec<&'a Violation>)> {
+		let mut groups: Vec<(String, Vec<&'a Violation>)> = Vec::new();
+
+		for violation in self.errors.iter().map(|v| v.data) {
+			let path = violation
+				.field
+				.as_ref()
+				.map(field_path_to_string)
+				.unwrap_or_default();
+
+			if let Some((_, list)) = groups.iter_mut().find(|(p, _)| *p == path) {
+				list.push(violation);
+			} else {
+				groups.push((path, vec![violation]));
+			}
+		}
+
+		groups
+	}
+
+	fn fmt_text(&self, f: &mut core::fmt::Formatter<'_>, colored: bool) -> core::fmt::Result {
+		let len = self.errors.len();
+
+		writeln!(
+			f,
+			"{len} {}:",
+			if len == 1 { "violation" } else { "violations" }
+		)?;
+
+		for (path, violations) in self.groups() {
+			let path = if path.is_empty() {
+				"(top level)".to_string()
+			} else {
+				path
+			};
+
+			if colored {
+				writeln!(f, "  {}:", path.bright_yellow())?;
+			} else {
+				writeln!(f, "  {path}:")?;
+			}
+
+			for violation in violations {
+				let rule_id = violation.rule_id();
+				let message = violation.message();
+
+				if colored {
+					writeln!(f, "    - [{}] {message}", rule_id.bright_cyan())?;
+				} else {
+					writeln!(f, "    - [{rule_id}] {message}")?;
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	fn fmt_json(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		fn write_json_str(f: &mut core::fmt::Formatter<'_>, value: &str) -> core::fmt::Result {
+			f.write_char('"')?;
+
+			for char in value.chars() {
+				match char {
+					'"' => f.write_str("\\\"")?,
+					'\\' => f.write_str("\\\\")?,
+					'\n' => f.write_str("\\n")?,
+					'\r' => f.write_str("\\r")?,
+					'\t' => f.write_str("\\t")?,
+					c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+					c => f.write_char(c)?,
+				};
+			}
+
+			f.write_char('"')
+		}
+
+		f.write_char('{')?;
+
+		for (i, (path, violations)) in self.groups().into_iter().enumerate() {
+			if i != 0 {
+				f.write_char(',')?;
+			}
+
+			write_json_str(f, &path)?;
+			f.write_str(":[")?;
+
+			for (j, violation) in violations.into_iter().enumerate() {
+				if j != 0 {
+					f.write_char(',')?;
+				}
+
+				f.write_str("{\"rule_id\":")?;
+				write_json_str(f, violation.rule_id())?;
+				f.write_str(",\"message\":")?;
+				write_json_str(f, violation.message())?;
+				f.write_char('}')?;
+			}
+
+			f.write_char(']')?;
+		}
+
+		f.write_char('}')
+	}
+}
+
+impl Display for ValidationReport<'_> {
+	#[inline(never)]
+	#[cold]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self.format {
+			ReportFormat::Plain => self.fmt_text(f, false),
+			ReportFormat::Colored => self.fmt_text(f, true),
+			ReportFormat::Json => self.fmt_json(f),
+		}
+	}
+}
+
+impl ValidationErrors {
+	/// Creates a [`ValidationReport`] with the given [`ReportFormat`].
+	#[inline]
+	#[must_use]
+	pub const fn report(&self, format: ReportFormat) -> ValidationReport<'_> {
+		ValidationReport {
+			errors: self,
+			format,
+		}
+	}
+}
+
+impl Display for ValidationErrors {
+	/// Renders a plain [`ValidationReport`].
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.report(ReportFormat::Plain).fmt(f)
+	}
+}
+
+impl core::error::Error for ValidationErrors {}
+
+impl ViolationCtxRef<'_> {
+	/// Returns the path of the field that caused this violation in its dotted form (such as `items[2].name`),
+	/// or [`None`] if the violation was produced by a top level validator.
+	#[inline]
+	#[must_use]
+	pub fn field_path_str(&self) -> Option<String> {
+		self.data.field.as_ref().map(field_path_to_string)
+	}
+
+	/// Returns the path of the field that caused this violation as a JSON Pointer (such as `/items/2/name`),
+	/// or [`None`] if the violation was produced by a top level validator.
+	#[inline]
+	#[must_use]
+	pub fn json_pointer(&self) -> Option<String> {
+		self.data
+			.field
+			.as_ref()
+			.map(field_path_to_json_pointer)
+	}
+}
+
+impl ViolationCtx {
+	/// Returns the path of the field that caused this violation in its dotted form (such as `items[2].name`),
+	/// or [`None`] if the violation was produced by a top level validator.
+	#[inline]
+	#[must_use]
+	pub fn field_path_str(&self) -> Option<String> {
+		self.data.field.as_ref().map(field_path_to_string)
+	}
+
+	/// Returns the path of the field that caused this violation as a JSON Pointer (such as `/items/2/name`),
+	/// or [`None`] if the violation was produced by a top level validator.
+	#[inline]
+	#[must_use]
+	pub fn json_pointer(&self) -> Option<String> {
+		self.data
+			.field
+			.as_ref()
+			.map(field_path_to_json_pointer)
+	}
+}
@@ -250,6 +250,9 @@ pub struct StringValidator {
 
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<StringViolation>>,
+
+	/// The [`PredefinedRule`]s applied to this field, with their assigned values.
+	pub predefined: PredefinedRules,
 }
 
 impl Hash for StringValidator {
@@ -277,6 +280,7 @@ impl Hash for StringValidator {
 		self.not_in.hash(state);
 		self.const_.hash(state);
 		self.error_messages.hash(state);
+		self.predefined.hash(state);
 	}
 }
 
@@ -309,6 +313,7 @@ impl PartialEq for StringValidator {
 			&& self.not_in == other.not_in
 			&& self.const_ == other.const_
 			&& self.error_messages == other.error_messages
+			&& self.predefined == other.predefined
 	}
 }
 
@@ -328,6 +333,12 @@ impl StringValidator {
 
 impl_proto_type!(String, String);
 impl_proto_map_key!(String, String);
+impl_wrapper_validation!(
+	crate::well_known_types::StringValue,
+	String,
+	StringValidator,
+	StringValidatorBuilder
+);
 
 impl Validator<String> for StringValidator {
 	type Target = str;
@@ -377,6 +388,7 @@ impl Validator<String> for StringValidator {
 
 		if self.const_.is_some()
 			&& (!self.cel.is_empty()
+				|| !self.predefined.is_empty()
 				|| check_prop_some!(
 					in_,
 					not_in,
@@ -446,6 +458,14 @@ impl Validator<String> for StringValidator {
 			errors.extend(e.into_iter().map(ConsistencyError::from));
 		}
 
+		#[cfg(feature = "cel")]
+		if let Err(e) = self
+			.predefined
+			.check_programs_with(String::new(), || rules_message_of(self, "string"))
+		{
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
 		if let Some(forbidden_substr) = self.not_contains.as_deref() {
 			if let Some(required_substr) = self.contains.as_deref()
 				&& required_substr.contains(forbidden_substr)
@@ -518,17 +538,26 @@ impl Validator<String> for StringValidator {
 		handle_ignore_if_zero_value!(&self.ignore, val.is_none_or(|v| v.is_empty()));
 
 		let mut is_valid = IsValid::Yes;
+		// The values of these rules would reveal the value of the field
+		let is_sensitive = ctx.is_sensitive();
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::String(StringViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&StringViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::String(StringViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&StringViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
@@ -540,7 +569,15 @@ impl Validator<String> for StringValidator {
 		if let Some(val) = val {
 			if let Some(const_val) = &self.const_ {
 				if val != const_val.as_ref() {
-					handle_violation!(Const, format!("must be equal to \"{const_val}\""));
+					if is_sensitive {
+						handle_violation!(Const, "must be equal to the expected value".to_string());
+					} else {
+						handle_violation!(
+							Const,
+							format!("must be equal to \"{const_val}\""),
+							value = const_val
+						);
+					}
 				}
 
 				// Using `const` implies no other rules
@@ -552,7 +589,8 @@ impl Validator<String> for StringValidator {
 			{
 				handle_violation!(
 					Len,
-					format!("must be exactly {len} character{} long", pluralize!(len))
+					format!("must be exactly {len} character{} long", pluralize!(len)),
+					len = len
 				);
 			}
 
@@ -564,7 +602,8 @@ impl Validator<String> for StringValidator {
 					format!(
 						"must be at least {min_len} character{} long",
 						pluralize!(min_len)
-					)
+					),
+					min_len = min_len
 				);
 			}
 
@@ -576,7 +615,8 @@ impl Validator<String> for StringValidator {
 					format!(
 						"cannot be longer than {max_len} character{}",
 						pluralize!(max_len)
-					)
+					),
+					max_len = max_len
 				);
 			}
 
@@ -588,7 +628,8 @@ impl Validator<String> for StringValidator {
 					format!(
 						"must be exactly {len_bytes} byte{} long",
 						pluralize!(len_bytes)
-					)
+					),
+					len_bytes = len_bytes
 				);
 			}
 
@@ -600,7 +641,8 @@ impl Validator<String> for StringValidator {
 					format!(
 						"must be at least {min_bytes} byte{} long",
 						pluralize!(min_bytes)
-					)
+					),
+					min_bytes = min_bytes
 				);
 			}
 
@@ -612,63 +654,92 @@ impl Validator<String> for StringValidator {
 					format!(
 						"cannot be longer than {max_bytes} byte{}",
 						pluralize!(max_bytes)
-					)
+					),
+					max_bytes = max_bytes
 				);
 			}
 
 			if let Some(prefix) = &self.prefix
 				&& !val.starts_with(&**prefix)
 			{
-				handle_violation!(Prefix, format!("must start with \"{prefix}\""));
+				handle_violation!(
+					Prefix,
+					format!("must start with \"{prefix}\""),
+					prefix = prefix
+				);
 			}
 
 			if let Some(suffix) = &self.suffix
 				&& !val.ends_with(&**suffix)
 			{
-				handle_violation!(Suffix, format!("must end with \"{suffix}\""));
+				handle_violation!(
+					Suffix,
+					format!("must end with \"{suffix}\""),
+					suffix = suffix
+				);
 			}
 
 			if let Some(substring) = &self.contains
 				&& !val.contains(substring.as_ref())
 			{
-				handle_violation!(Contains, format!("must contain \"{substring}\""));
+				handle_violation!(
+					Contains,
+					format!("must contain \"{substring}\""),
+					contains = substring
+				);
 			}
 
 			if let Some(substring) = &self.not_contains
 				&& val.contains(substring.as_ref())
 			{
-				handle_violation!(NotContains, format!("cannot contain \"{substring}\""));
+				handle_violation!(
+					NotContains,
+					format!("cannot contain \"{substring}\""),
+					not_contains = substring
+				);
 			}
 
 			#[cfg(feature = "regex")]
 			if let Some(pattern) = &self.pattern
 				&& !pattern.is_match(val)
 			{
-				handle_violation!(Pattern, format!("must match the pattern `{pattern}`"));
+				handle_violation!(
+					Pattern,
+					format!("must match the pattern `{pattern}`"),
+					pattern = pattern
+				);
 			}
 
 			if let Some(allowed_list) = &self.in_
 				&& !allowed_list.contains(val)
 			{
-				handle_violation!(
-					In,
-					format!(
-						"must be one of these values: {}",
-						FixedStr::__format_list(allowed_list)
-					)
-				);
+				if is_sensitive {
+					handle_violation!(In, "must be one of the allowed values".to_string());
+				} else {
+					let values = FixedStr::__format_list(allowed_list);
+
+					handle_violation!(
+						In,
+						format!("must be one of these values: {values}"),
+						values = values
+					);
+				}
 			}
 
 			if let Some(forbidden_list) = &self.not_in
 				&& forbidden_list.contains(val)
 			{
-				handle_violation!(
-					NotIn,
-					format!(
-						"cannot be one of these values: {}",
-						FixedStr::__format_list(forbidden_list)
-					)
-				);
+				if is_sensitive {
+					handle_violation!(NotIn, "cannot be one of the forbidden values".to_string());
+				} else {
+					let values = FixedStr::__format_list(forbidden_list);
+
+					handle_violation!(
+						NotIn,
+						format!("cannot be one of these values: {values}"),
+						values = values
+					);
+				}
 			}
 
 			macro_rules! impl_well_known_check {
@@ -694,7 +765,6 @@ impl Validator<String> for StringValidator {
 					WellKnownStrings::Ipv6 => {
 						impl_well_known_check!(is_valid_ipv6, Ipv6, "ipv6 address");
 					}
-					#[cfg(feature = "regex")]
 					WellKnownStrings::Email => {
 						impl_well_known_check!(is_valid_email, Email, "email address");
 					}
@@ -804,6 +874,13 @@ impl Validator<String> for StringValidator {
 
 				is_valid &= cel_ctx.execute_programs()?;
 			}
+
+			#[cfg(feature = "cel")]
+			if !self.predefined.is_empty() {
+				is_valid &= self
+					.predefined
+					.execute(ctx, val, || rules_message_of(self, "string"))?;
+			}
 		}
 
 		Ok(is_valid)
@@ -875,6 +952,8 @@ impl From<StringValidator> for ProtoOption {
 			rules.set_boolean("strict", is_strict);
 		}
 
+		validator.predefined.add_to_rules(&mut rules);
+
 		// This is the outer rule grouping, "(buf.validate.field)"
 		let mut outer_rules = OptionMessageBuilder::new();
 
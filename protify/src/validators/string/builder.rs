@@ -82,6 +82,23 @@ impl<S: State> StringValidatorBuilder<S> {
 		}
 	}
 
+	/// Applies a [`PredefinedRule`] to this validator, with the given value.
+	///
+	/// If the rule was already applied, its value is replaced.
+	#[inline]
+	pub fn predefined(
+		mut self,
+		rule: &PredefinedRule,
+		value: impl Into<OptionValue>,
+	) -> StringValidatorBuilder<S> {
+		self.data.predefined.insert(rule, value);
+
+		StringValidatorBuilder {
+			_state: PhantomData,
+			data: self.data,
+		}
+	}
+
 	/// Specifies that this validator should always be ignored.
 	#[inline]
 	pub fn ignore_always(mut self) -> StringValidatorBuilder<SetIgnore<S>>
@@ -357,7 +374,6 @@ macro_rules! well_known_impl {
 }
 
 impl<S: State> StringValidatorBuilder<S> {
-	#[cfg(feature = "regex")]
 	well_known_impl!(
 		Email,
 		r#"
@@ -5,7 +5,6 @@ use super::*;
 #[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WellKnownStrings {
-	#[cfg(feature = "regex")]
 	Email,
 	Hostname,
 	Ip,
@@ -46,7 +45,6 @@ impl WellKnownStrings {
 		let name = match self {
 			#[cfg(feature = "regex")]
 			Self::Ulid => "ulid",
-			#[cfg(feature = "regex")]
 			Self::Email => "email",
 			Self::Hostname => "hostname",
 			Self::Ip => "ip",
@@ -110,14 +108,6 @@ mod regex_checks {
 
 	use regex::Regex;
 
-	static EMAIL_REGEX: Lazy<Regex> = Lazy::new(|| {
-		Regex::new(r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$").expect("Failed to create email regex")
-	});
-
-	pub(crate) fn is_valid_email(s: &str) -> bool {
-		EMAIL_REGEX.is_match(s)
-	}
-
 	static HTTP_HEADER_NAME_STRICT_REGEX: Lazy<Regex> =
 		Lazy::new(|| Regex::new(r"^:?[0-9a-zA-Z!#$%&'*+-.^_|~`]+$").unwrap());
 
@@ -269,6 +259,34 @@ pub(crate) fn is_valid_address(s: &str) -> bool {
 }
 
 #[must_use]
+// Follows the definition of the HTML standard, like protovalidate:
+// https://html.spec.whatwg.org/multipage/input.html#valid-e-mail-address
+#[must_use]
+pub(crate) fn is_valid_email(s: &str) -> bool {
+	let Some((local, domain)) = s.split_once('@') else {
+		return false;
+	};
+
+	if local.is_empty()
+		|| !local
+			.chars()
+			.all(|c| c.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(c))
+	{
+		return false;
+	}
+
+	// Unlike hostnames, the domain can end with a label made of digits, but not with a dot
+	domain.split('.').all(|label| {
+		!label.is_empty()
+			&& label.len() <= 63
+			&& !label.starts_with('-')
+			&& !label.ends_with('-')
+			&& label
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || c == '-')
+	})
+}
+
 pub(crate) fn is_valid_hostname(hostname: &str) -> bool {
 	let s = hostname.strip_suffix('.').unwrap_or(hostname);
 	if s.len() > 253 {
@@ -413,6 +431,19 @@ mod test {
 		assert!(!is_valid_host_and_port("192.168.1.120"));
 		assert!(!is_valid_host_and_port("2001:0DB8:ABCD:0012::F1"));
 
+		assert!(is_valid_email("obiwan@force.com"));
+		assert!(is_valid_email("obi-wan.kenobi+jedi@force-1.com"));
+		assert!(is_valid_email("obiwan@localhost"));
+		assert!(is_valid_email("obiwan@127.0.0.1"));
+		assert!(!is_valid_email("anakin@dark@force.com"));
+		assert!(!is_valid_email("@force.com"));
+		assert!(!is_valid_email("anakin@"));
+		assert!(!is_valid_email("anakin@force..com"));
+		assert!(!is_valid_email("anakin@force.com."));
+		assert!(!is_valid_email("anakin@-force.com"));
+		assert!(!is_valid_email("anakin skywalker@force.com"));
+		assert!(!is_valid_email("anakin@fórce.com"));
+
 		assert!(is_valid_hostname("obiwan.force.com"));
 		assert!(!is_valid_hostname("-anakin.darkforce.com"));
 		assert!(!is_valid_hostname("anakin.darkforce.com-"));
@@ -425,10 +456,7 @@ mod test {
 
 		#[test]
 		fn identifiers() {
-			use super::{is_valid_email, is_valid_tuuid, is_valid_uuid};
-
-			assert!(is_valid_email("obiwan@force.com"));
-			assert!(!is_valid_email("anakin@dark@force.com"));
+			use super::{is_valid_tuuid, is_valid_uuid};
 
 			assert!(is_valid_uuid("d3b8f2d5-7e10-4c6e-8a1a-3b9c7d4f6e2c"));
 			assert!(!is_valid_uuid("d3b8f2d57e104c6e8a1a3b9c7d4f6e2c"));
@@ -54,14 +54,20 @@ pub struct TimestampValidator {
 
 	/// A map of custom error messages.
 	pub error_messages: Option<ErrorMessages<TimestampViolation>>,
+
+	/// The [`PredefinedRule`]s applied to this field, with their assigned values.
+	pub predefined: PredefinedRules,
 }
 
 impl TimestampValidator {
 	#[inline(never)]
 	#[cold]
 	const fn has_props(&self) -> bool {
-		let mut has_props =
-			self.lt.is_some() || self.lte.is_some() || self.gt.is_some() || self.gte.is_some();
+		let mut has_props = self.lt.is_some()
+			|| self.lte.is_some()
+			|| self.gt.is_some()
+			|| self.gte.is_some()
+			|| !self.predefined.is_empty();
 
 		#[cfg(all(feature = "chrono", any(feature = "std", feature = "chrono-wasm")))]
 		{
@@ -135,6 +141,15 @@ impl Validator<Timestamp> for TimestampValidator {
 			errors.extend(e.into_iter().map(ConsistencyError::from));
 		}
 
+		#[cfg(feature = "cel")]
+		if let Err(e) = self
+			.predefined
+			.check_programs_with(Self::Target::default(), || {
+				rules_message_of(self, "timestamp")
+			}) {
+			errors.extend(e.into_iter().map(ConsistencyError::from));
+		}
+
 		if let Err(e) = check_comparable_rules(self.lt, self.lte, self.gt, self.gte) {
 			errors.push(e);
 		}
@@ -177,22 +192,33 @@ impl Validator<Timestamp> for TimestampValidator {
 		let mut is_valid = IsValid::Yes;
 
 		macro_rules! handle_violation {
-			($id:ident, $default:expr) => {
-				is_valid &= ctx.add_violation(
-					ViolationKind::Timestamp(TimestampViolation::$id),
-					self.error_messages
-						.as_deref()
-						.and_then(|map| map.get(&TimestampViolation::$id))
-						.map(|m| Cow::Borrowed(m.as_ref()))
-						.unwrap_or_else(|| Cow::Owned($default)),
-				)?;
+			($id:ident, $default:expr $(, $arg:ident = $value:expr)* $(,)?) => {
+				let kind = ViolationKind::Timestamp(TimestampViolation::$id);
+
+				is_valid &= if let Some(msg) = self
+					.error_messages
+					.as_deref()
+					.and_then(|map| map.get(&TimestampViolation::$id))
+				{
+					ctx.add_violation(kind, msg.to_string())
+				} else {
+					ctx.add_localized_violation(
+						kind,
+						&[$((stringify!($arg), &$value as &dyn Display)),*],
+						$default,
+					)
+				}?;
 			};
 		}
 
 		if let Some(&val) = val {
 			if let Some(const_val) = self.const_ {
 				if val != const_val {
-					handle_violation!(Const, format!("must be equal to {const_val}"));
+					handle_violation!(
+						Const,
+						format!("must be equal to {const_val}"),
+						value = const_val
+					);
 				}
 
 				// Using `const` implies no other rules
@@ -202,25 +228,33 @@ impl Validator<Timestamp> for TimestampValidator {
 			if let Some(gt) = self.gt
 				&& val <= gt
 			{
-				handle_violation!(Gt, format!("must be later than {gt}"));
+				handle_violation!(Gt, format!("must be later than {gt}"), gt = gt);
 			}
 
 			if let Some(gte) = self.gte
 				&& val < gte
 			{
-				handle_violation!(Gte, format!("must be later than or equal to {gte}"));
+				handle_violation!(
+					Gte,
+					format!("must be later than or equal to {gte}"),
+					gte = gte
+				);
 			}
 
 			if let Some(lt) = self.lt
 				&& val >= lt
 			{
-				handle_violation!(Lt, format!("must be earlier than {lt}"));
+				handle_violation!(Lt, format!("must be earlier than {lt}"), lt = lt);
 			}
 
 			if let Some(lte) = self.lte
 				&& val > lte
 			{
-				handle_violation!(Lte, format!("must be earlier than or equal to {lte}"));
+				handle_violation!(
+					Lte,
+					format!("must be earlier than or equal to {lte}"),
+					lte = lte
+				);
 			}
 
 			#[cfg(all(feature = "chrono", any(feature = "std", feature = "chrono-wasm")))]
@@ -236,7 +270,11 @@ impl Validator<Timestamp> for TimestampValidator {
 				if let Some(range) = self.within
 					&& !val.is_within_range_from_now(range)
 				{
-					handle_violation!(Within, format!("must be within {range} from now"));
+					handle_violation!(
+						Within,
+						format!("must be within {range} from now"),
+						within = range
+					);
 				}
 			}
 
@@ -250,6 +288,13 @@ impl Validator<Timestamp> for TimestampValidator {
 
 				is_valid &= cel_ctx.execute_programs()?;
 			}
+
+			#[cfg(feature = "cel")]
+			if !self.predefined.is_empty() {
+				is_valid &= self
+					.predefined
+					.execute(ctx, val, || rules_message_of(self, "timestamp"))?;
+			}
 		} else if self.required {
 			handle_violation!(Required, "is required".to_string());
 		}
@@ -295,6 +340,8 @@ impl From<TimestampValidator> for ProtoOption {
 
 		rules.maybe_set("const", validator.const_);
 
+		validator.predefined.add_to_rules(&mut rules);
+
 		let mut outer_rules = OptionMessageBuilder::new();
 
 		if !rules.is_empty() {
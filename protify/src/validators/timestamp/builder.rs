@@ -82,6 +82,23 @@ impl<S: State> TimestampValidatorBuilder<S> {
 		}
 	}
 
+	/// Applies a [`PredefinedRule`] to this validator, with the given value.
+	///
+	/// If the rule was already applied, its value is replaced.
+	#[inline]
+	pub fn predefined(
+		mut self,
+		rule: &PredefinedRule,
+		value: impl Into<OptionValue>,
+	) -> TimestampValidatorBuilder<S> {
+		self.data.predefined.insert(rule, value);
+
+		TimestampValidatorBuilder {
+			_state: PhantomData,
+			..self
+		}
+	}
+
 	/// Specifies that this validator should always be ignored.
 	#[inline]
 	pub fn ignore_always(mut self) -> TimestampValidatorBuilder<SetIgnore<S>>
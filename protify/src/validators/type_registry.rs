@@ -0,0 +1,191 @@
+use prost::{DecodeError, Message};
+
+use super::*;
+
+#[doc(hidden)]
+pub type ValidateEncodedFn = fn(&[u8], &mut ValidationCtx) -> Result<ValidationResult, DecodeError>;
+
+#[doc(hidden)]
+pub fn __validate_encoded<T>(
+	bytes: &[u8],
+	ctx: &mut ValidationCtx,
+) -> Result<ValidationResult, DecodeError>
+where
+	T: ValidatedMessage + Message,
+{
+	let msg = T::decode(bytes)?;
+
+	Ok(msg.validate_with_ctx(ctx))
+}
+
+// The prefix of the type URL (like `type.googleapis.com`) is not relevant to identify the message
+fn type_name(type_url: &str) -> &str {
+	type_url
+		.rsplit_once('/')
+		.map_or(type_url, |(_, name)| name)
+}
+
+/// A runtime registry of message types, used by the [`AnyValidator`] to decode and validate the contents of an [`Any`](proto_types::Any) (when `validate_contents` is set).
+///
+/// The types are keyed by their [`type_url`](ProtoMessage::type_url), and the prefix before the last `/` (such as `type.googleapis.com`) is ignored during lookups.
+///
+/// A registry can be added to the [`ValidationCtx`] with [`with_extension`](ValidationCtx::with_extension). If it's missing, the [`AnyValidator`] uses the [`global`](TypeRegistry::global) registry,
+/// which contains all the messages collected with `inventory` (or none, if the `inventory` feature is disabled).
+///
+/// # Examples
+///
+/// ```rust
+/// use prost::Message;
+/// use protify::*;
+/// use protify::proto_types::Any;
+///
+/// #[proto_message]
+/// pub struct CreateOrder {
+///   #[proto(validate = |v| v.gt(0))]
+///   pub quantity: i32,
+/// }
+///
+/// #[proto_message]
+/// pub struct Envelope {
+///   #[proto(any, validate = |v| v.validate_contents().reject_unknown_types())]
+///   pub payload: Option<Any>,
+/// }
+///
+/// let envelope = Envelope {
+///   payload: Some(Any {
+///     type_url: format!("type.googleapis.com/{}", CreateOrder::full_name()),
+///     value: CreateOrder { quantity: 0 }.encode_to_vec(),
+///   }),
+/// };
+///
+/// let ctx = ValidationCtx::new().with_extension(TypeRegistry::new().with::<CreateOrder>());
+/// let errors = envelope.validate_with(ctx).unwrap_err();
+///
+/// let violation = errors.iter().next().unwrap();
+///
+/// assert_eq!(violation.field_path_str().unwrap(), "payload.quantity");
+/// ```
+#[derive(Clone, Default)]
+pub struct TypeRegistry {
+	types: BTreeMap<String, ValidateEncodedFn>,
+}
+
+impl Debug for TypeRegistry {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("TypeRegistry")
+			.field("types", &self.types.keys().collect::<Vec<_>>())
+			.finish()
+	}
+}
+
+impl TypeRegistry {
+	/// Creates a new, empty registry.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			types: BTreeMap::new(),
+		}
+	}
+
+	/// Registers a message type.
+	#[inline]
+	pub fn register<T>(&mut self) -> &mut Self
+	where
+		T: ProtoMessage + ValidatedMessage + Message,
+	{
+		self.types.insert(
+			type_name(T::type_url()).to_string(),
+			__validate_encoded::<T>,
+		);
+		self
+	}
+
+	/// Registers a message type and returns the registry.
+	#[inline]
+	#[must_use]
+	pub fn with<T>(mut self) -> Self
+	where
+		T: ProtoMessage + ValidatedMessage + Message,
+	{
+		self.register::<T>();
+		self
+	}
+
+	/// Checks if the registry contains the type with the given type URL.
+	#[inline]
+	#[must_use]
+	pub fn contains(&self, type_url: &str) -> bool {
+		self.types.contains_key(type_name(type_url))
+	}
+
+	/// Returns the number of registered types.
+	#[inline]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.types.len()
+	}
+
+	/// Checks if the registry contains no types.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.types.is_empty()
+	}
+
+	/// Decodes the value into the type with the given type URL and validates it.
+	///
+	/// Returns [`None`] if the type is not registered.
+	#[inline]
+	pub fn validate_encoded(
+		&self,
+		type_url: &str,
+		value: &[u8],
+		ctx: &mut ValidationCtx,
+	) -> Option<Result<ValidationResult, DecodeError>> {
+		self.types
+			.get(type_name(type_url))
+			.map(|validate| validate(value, ctx))
+	}
+
+	#[doc(hidden)]
+	#[inline]
+	#[must_use]
+	pub fn __get(&self, type_url: &str) -> Option<ValidateEncodedFn> {
+		self.types.get(type_name(type_url)).copied()
+	}
+
+	/// Creates a registry with all the messages collected with `inventory`.
+	#[cfg(feature = "inventory")]
+	#[must_use]
+	pub fn from_inventory() -> Self {
+		let mut registry = Self::new();
+
+		for entry in inventory::iter::<RegistryMessage>() {
+			registry.types.insert(
+				type_name((entry.type_url)()).to_string(),
+				entry.validate_encoded,
+			);
+		}
+
+		registry
+	}
+
+	/// Returns the global registry, which contains all the messages collected with `inventory` (or none, if the `inventory` feature is disabled).
+	#[must_use]
+	pub fn global() -> &'static Self {
+		#[cfg(feature = "inventory")]
+		{
+			static REGISTRY: Lazy<TypeRegistry> = Lazy::new(TypeRegistry::from_inventory);
+
+			&REGISTRY
+		}
+
+		#[cfg(not(feature = "inventory"))]
+		{
+			static REGISTRY: TypeRegistry = TypeRegistry::new();
+
+			&REGISTRY
+		}
+	}
+}
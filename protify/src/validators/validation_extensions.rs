@@ -0,0 +1,187 @@
+use core::any::{Any, TypeId};
+
+#[cfg(feature = "cel")]
+use ::cel::{Context, Value};
+
+use super::*;
+
+/// A type-safe map that holds request-scoped data (such as the configuration of a tenant or the role of the caller),
+/// which can be accessed by custom validators through the [`ValidationCtx`].
+///
+/// Each type can only be stored once. The values are stored behind an [`Arc`], so cloning the map is cheap.
+///
+/// # Examples
+///
+/// ```rust
+/// use protify::*;
+/// use protify::proto_types::protovalidate::{ViolationKind, violations_data::RepeatedViolation};
+///
+/// struct TenantLimits {
+///   max_items: usize,
+/// }
+///
+/// fn validate_items(ctx: &mut ValidationCtx, val: Option<&Vec<i32>>) -> ValidationResult {
+///   let max_items = ctx
+///     .extensions
+///     .get::<TenantLimits>()
+///     .map_or(usize::MAX, |limits| limits.max_items);
+///
+///   if val.is_some_and(|items| items.len() > max_items) {
+///     return ctx.add_violation(
+///       ViolationKind::Repeated(RepeatedViolation::MaxItems),
+///       format!("cannot contain more than {max_items} items"),
+///     );
+///   }
+///
+///   Ok(IsValid::Yes)
+/// }
+///
+/// let ctx = ValidationCtx::new().with_extension(TenantLimits { max_items: 2 });
+///
+/// assert_eq!(ctx.extensions.get::<TenantLimits>().unwrap().max_items, 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct ValidationExtensions {
+	map: BTreeMap<TypeId, Arc<dyn Any + Send + Sync>>,
+	#[cfg(feature = "cel")]
+	cel_variables: BTreeMap<String, Arc<Value>>,
+}
+
+impl ValidationExtensions {
+	/// Creates a new, empty map.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			map: BTreeMap::new(),
+			#[cfg(feature = "cel")]
+			cel_variables: BTreeMap::new(),
+		}
+	}
+
+	/// Inserts a value in the map, returning the previous value of the same type, if there was one.
+	#[inline]
+	pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) -> Option<Arc<T>> {
+		self.map
+			.insert(TypeId::of::<T>(), Arc::new(value))
+			.and_then(|prev| prev.downcast::<T>().ok())
+	}
+
+	/// Returns a reference to the value of the given type, if there is one.
+	#[inline]
+	#[must_use]
+	pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+		self.map
+			.get(&TypeId::of::<T>())
+			.and_then(|value| value.downcast_ref::<T>())
+	}
+
+	/// Removes the value of the given type from the map, and returns it if it was present.
+	#[inline]
+	pub fn remove<T: Send + Sync + 'static>(&mut self) -> Option<Arc<T>> {
+		self.map
+			.remove(&TypeId::of::<T>())
+			.and_then(|value| value.downcast::<T>().ok())
+	}
+
+	/// Checks if the map contains a value of the given type.
+	#[inline]
+	#[must_use]
+	pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+		self.map.contains_key(&TypeId::of::<T>())
+	}
+
+	/// Checks if the map contains no values (including the CEL variables, if the `cel` feature is enabled).
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		#[cfg(feature = "cel")]
+		if !self.cel_variables.is_empty() {
+			return false;
+		}
+
+		self.map.is_empty()
+	}
+
+	/// Adds a variable that will be available to all the CEL programs executed with this context.
+	///
+	/// Unlike the variables registered with [`register_cel_variable`](crate::register_cel_variable), these are only available for a single validation, so they can hold request-scoped data.
+	/// They are added after the registered variables, so they take precedence in case of a name conflict.
+	#[cfg(feature = "cel")]
+	#[inline]
+	pub fn insert_cel_variable(&mut self, name: impl Into<String>, value: impl Into<Value>) {
+		self.cel_variables
+			.insert(name.into(), Arc::new(value.into()));
+	}
+
+	/// Returns the value of the CEL variable with the given name, if there is one.
+	#[cfg(feature = "cel")]
+	#[inline]
+	#[must_use]
+	pub fn get_cel_variable(&self, name: &str) -> Option<&Value> {
+		self.cel_variables.get(name).map(|v| &**v)
+	}
+
+	#[cfg(feature = "cel")]
+	pub(crate) fn apply_cel_variables(&self, ctx: &mut Context) {
+		for (name, value) in &self.cel_variables {
+			ctx.add_variable_from_value(name.clone(), (**value).clone());
+		}
+	}
+}
+
+impl Debug for ValidationExtensions {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let mut debug = f.debug_struct("ValidationExtensions");
+
+		debug.field("len", &self.map.len());
+
+		#[cfg(feature = "cel")]
+		debug.field(
+			"cel_variables",
+			&self.cel_variables.keys().collect::<Vec<_>>(),
+		);
+
+		debug.finish()
+	}
+}
+
+// The values are compared by identity, since they are not required to implement `PartialEq`
+impl PartialEq for ValidationExtensions {
+	fn eq(&self, other: &Self) -> bool {
+		let map_eq = self.map.len() == other.map.len()
+			&& self
+				.map
+				.iter()
+				.zip(&other.map)
+				.all(|((a_id, a), (b_id, b))| a_id == b_id && Arc::ptr_eq(a, b));
+
+		#[cfg(feature = "cel")]
+		let map_eq = map_eq
+			&& self.cel_variables.len() == other.cel_variables.len()
+			&& self
+				.cel_variables
+				.iter()
+				.zip(&other.cel_variables)
+				.all(|((a_name, a), (b_name, b))| a_name == b_name && Arc::ptr_eq(a, b));
+
+		map_eq
+	}
+}
+
+impl Eq for ValidationExtensions {}
+
+impl Hash for ValidationExtensions {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		for (id, value) in &self.map {
+			id.hash(state);
+			Arc::as_ptr(value).cast::<()>().hash(state);
+		}
+
+		#[cfg(feature = "cel")]
+		for (name, value) in &self.cel_variables {
+			name.hash(state);
+			Arc::as_ptr(value).hash(state);
+		}
+	}
+}
@@ -1,7 +1,17 @@
+//! The `google.protobuf` wrapper types, along with `Struct`, `Value` and `ListValue`, which are not included in [`proto_types`].
+//!
+//! They can be used as message fields in [`proto_message`], and they are imported automatically when rendering the files that use them, just like the `google.protobuf`, `google.type` and `google.rpc` types from [`proto_types`], whose schema implementations are also defined here.
+
 use proto_types::{Any, Code, Duration, Empty, FieldMask, Status, Timestamp};
 
 use crate::*;
 
+mod wrappers;
+pub use wrappers::*;
+
+mod struct_types;
+pub use struct_types::*;
+
 impl_known_type!(target = Status, package = "google.rpc");
 impl_known_type!(target = Code, package = "google.rpc", type_ = Enum);
 
@@ -83,39 +93,39 @@ mod google_dot_type {
   }
 
 	impl_types!(
-		Date,
-		Interval,
-		Money,
 		Color,
 		Fraction,
 		Decimal,
-		PostalAddress,
 		PhoneNumber,
 		Quaternion,
 		LocalizedText,
 		Expr
 	);
 
-	impl_known_type!(
-		target = DateTime,
-		package = "google.type",
-		file = "google/type/datetime.proto"
+	// These types have their own validators
+	macro_rules! impl_validated_types {
+    ($($name:ident $(=> $file:literal)?),*) => {
+      $(
+        impl_known_type!(target = $name, package = "google.type", impl_validator = false $(, file = $file)?);
+      )*
+    };
+  }
+
+	impl_validated_types!(
+		Date,
+		Interval,
+		Money,
+		PostalAddress,
+		DateTime => "google/type/datetime.proto",
+		LatLng => "google/type/latlng.proto",
+		TimeOfDay => "google/type/timeofday.proto"
 	);
+
 	impl_known_type!(
 		target = TimeZone,
 		package = "google.type",
 		file = "google/type/datetime.proto"
 	);
-	impl_known_type!(
-		target = LatLng,
-		package = "google.type",
-		file = "google/type/latlng.proto"
-	);
-	impl_known_type!(
-		target = TimeOfDay,
-		package = "google.type",
-		file = "google/type/timeofday.proto"
-	);
 	impl_known_type!(
 		target = CalendarPeriod,
 		package = "google.type",
@@ -0,0 +1,175 @@
+#[cfg(feature = "cel")]
+use proto_types::cel::CelConversionError;
+
+use super::*;
+
+/// A structured data value, consisting of fields which map to dynamically typed values.
+///
+/// Corresponds to `google.protobuf.Struct`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Struct {
+	/// Unordered map of dynamically typed values.
+	#[prost(btree_map = "string, message", tag = "1")]
+	pub fields: BTreeMap<String, Value>,
+}
+
+/// A dynamically typed value which can be either null, a number, a string, a boolean, a
+/// recursive struct value, or a list of values.
+///
+/// Corresponds to `google.protobuf.Value`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Value {
+	/// The kind of value.
+	#[prost(oneof = "value::Kind", tags = "1, 2, 3, 4, 5, 6")]
+	pub kind: Option<value::Kind>,
+}
+
+/// Nested items for [`Value`].
+pub mod value {
+	/// The kind of value.
+	#[derive(Clone, PartialEq, ::prost::Oneof)]
+	pub enum Kind {
+		/// Represents a null value.
+		#[prost(enumeration = "super::NullValue", tag = "1")]
+		NullValue(i32),
+		/// Represents a double value.
+		#[prost(double, tag = "2")]
+		NumberValue(f64),
+		/// Represents a string value.
+		#[prost(string, tag = "3")]
+		StringValue(::prost::alloc::string::String),
+		/// Represents a boolean value.
+		#[prost(bool, tag = "4")]
+		BoolValue(bool),
+		/// Represents a structured value.
+		#[prost(message, tag = "5")]
+		StructValue(super::Struct),
+		/// Represents a repeated `Value`.
+		#[prost(message, tag = "6")]
+		ListValue(super::ListValue),
+	}
+}
+
+/// A repeated field of dynamically typed values.
+///
+/// Corresponds to `google.protobuf.ListValue`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListValue {
+	/// Repeated field of dynamically typed values.
+	#[prost(message, repeated, tag = "1")]
+	pub values: Vec<Value>,
+}
+
+/// The null value for the [`Value`] type.
+///
+/// Corresponds to `google.protobuf.NullValue`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NullValue {
+	/// Null value.
+	NullValue = 0,
+}
+
+impl From<value::Kind> for Value {
+	#[inline]
+	fn from(kind: value::Kind) -> Self {
+		Self { kind: Some(kind) }
+	}
+}
+
+macro_rules! impl_struct_types {
+	($($name:ident),*) => {
+		$(
+			impl ProtoValidation for $name {
+				#[doc(hidden)]
+				type Target = Self;
+				#[doc(hidden)]
+				type Stored = Self;
+				#[doc(hidden)]
+				type Validator = MessageValidator;
+				#[doc(hidden)]
+				type ValidatorBuilder = MessageValidatorBuilder;
+
+				#[doc(hidden)]
+				type UniqueStore<'a>
+					= LinearRefStore<'a, Self>
+				where
+					Self: 'a;
+
+				#[doc(hidden)]
+				const HAS_DEFAULT_VALIDATOR: bool = false;
+			}
+
+			impl ValidatedMessage for $name {
+				#[inline(always)]
+				#[doc(hidden)]
+				fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+					Ok(IsValid::Yes)
+				}
+			}
+
+			impl_known_type!(
+				target = $name,
+				impl_validator = false,
+				package = "google.protobuf",
+				file = "google/protobuf/struct.proto"
+			);
+		)*
+	};
+}
+
+impl_struct_types!(Struct, Value, ListValue);
+
+// `Struct` is represented as a map in CEL expressions, `ListValue` as a list,
+// and `Value` as the value that it contains
+#[cfg(feature = "cel")]
+mod cel_impls {
+	use super::*;
+
+	use ::cel::objects::{Key, Map};
+
+	impl TryFrom<Struct> for ::cel::Value {
+		type Error = CelConversionError;
+
+		fn try_from(value: Struct) -> Result<Self, Self::Error> {
+			let mut map: HashMap<Key, Self> = HashMap::with_capacity(value.fields.len());
+
+			for (key, val) in value.fields {
+				map.insert(key.into(), val.try_into()?);
+			}
+
+			Ok(Self::Map(Map { map: Arc::new(map) }))
+		}
+	}
+
+	impl TryFrom<ListValue> for ::cel::Value {
+		type Error = CelConversionError;
+
+		fn try_from(value: ListValue) -> Result<Self, Self::Error> {
+			let values = value
+				.values
+				.into_iter()
+				.map(Self::try_from)
+				.collect::<Result<Vec<Self>, CelConversionError>>()?;
+
+			Ok(values.into())
+		}
+	}
+
+	impl TryFrom<Value> for ::cel::Value {
+		type Error = CelConversionError;
+
+		fn try_from(value: Value) -> Result<Self, Self::Error> {
+			let output = match value.kind {
+				None | Some(value::Kind::NullValue(_)) => Self::Null,
+				Some(value::Kind::NumberValue(num)) => Self::Float(num),
+				Some(value::Kind::StringValue(str)) => str.into(),
+				Some(value::Kind::BoolValue(boolean)) => Self::Bool(boolean),
+				Some(value::Kind::StructValue(struct_)) => struct_.try_into()?,
+				Some(value::Kind::ListValue(list)) => list.try_into()?,
+			};
+
+			Ok(output)
+		}
+	}
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "cel")]
+use proto_types::cel::CelConversionError;
+
+use super::*;
+
+macro_rules! wrapper_types {
+	($($name:ident => $inner:ty, [$($prost_attr:tt)*], $cel_type:ty);* $(;)?) => {
+		$(
+			#[doc = concat!("Wrapper message for `", stringify!($inner), "`, which makes it possible to distinguish between an unset value and the default value.")]
+			///
+			#[doc = concat!("Corresponds to `google.protobuf.", stringify!($name), "`.")]
+			#[derive(Clone, PartialEq, ::prost::Message)]
+			pub struct $name {
+				/// The wrapped value.
+				#[prost($($prost_attr)*, tag = "1")]
+				pub value: $inner,
+			}
+
+			impl From<$inner> for $name {
+				#[inline]
+				fn from(value: $inner) -> Self {
+					Self { value }
+				}
+			}
+
+			impl From<$name> for $inner {
+				#[inline]
+				fn from(value: $name) -> Self {
+					value.value
+				}
+			}
+
+			impl ValidatedMessage for $name {
+				#[inline(always)]
+				#[doc(hidden)]
+				fn validate_with_ctx(&self, _: &mut ValidationCtx) -> ValidationResult {
+					Ok(IsValid::Yes)
+				}
+			}
+
+			// Wrappers are represented by the wrapped value in CEL expressions
+			#[cfg(feature = "cel")]
+			impl TryFrom<$name> for ::cel::Value {
+				type Error = CelConversionError;
+
+				#[inline]
+				fn try_from(value: $name) -> Result<Self, Self::Error> {
+					Ok(<$cel_type>::from(value.value).into())
+				}
+			}
+
+			impl_known_type!(
+				target = $name,
+				impl_validator = false,
+				package = "google.protobuf",
+				file = "google/protobuf/wrappers.proto"
+			);
+		)*
+	};
+}
+
+wrapper_types!(
+	DoubleValue => f64, [double], f64;
+	FloatValue => f32, [float], f64;
+	Int64Value => i64, [int64], i64;
+	UInt64Value => u64, [uint64], u64;
+	Int32Value => i32, [int32], i64;
+	UInt32Value => u32, [uint32], u64;
+	BoolValue => bool, [bool], bool;
+	StringValue => String, [string], String;
+	BytesValue => Bytes, [bytes = "bytes"], Vec<u8>;
+);
@@ -69,6 +69,7 @@ mod test {
 	mod bool_tests;
 	mod bytes_tests;
 	mod const_rules_tests;
+	mod descriptor_set_tests;
 	mod duration_tests;
 	mod enums_tests;
 	mod fail_fast_tests;
@@ -0,0 +1,121 @@
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage, ExtensionDescriptor};
+use protify::{
+	prost_types::FileDescriptorSet,
+	proto_types::protovalidate::{FieldRules, MessageRules, OneofRules, field_rules},
+};
+use test_schemas::TEST_SCHEMAS;
+
+use super::*;
+
+// The pool built by `protoc`, and the same pool with the package replaced by the descriptors built by protify
+fn pools() -> (DescriptorPool, DescriptorPool) {
+	let protoc_set = FileDescriptorSet::decode(
+		std::fs::read(env!("PROTO_DESCRIPTOR_SET"))
+			.unwrap()
+			.as_slice(),
+	)
+	.unwrap();
+
+	let protoc_pool = DescriptorPool::from_file_descriptor_set(protoc_set.clone()).unwrap();
+
+	let dependencies = FileDescriptorSet {
+		file: protoc_set
+			.file
+			.into_iter()
+			.filter(|file| file.package() != "test_schemas.v1")
+			.collect(),
+	};
+
+	let mut protify_pool = DescriptorPool::from_file_descriptor_set(dependencies).unwrap();
+
+	protify_pool
+		.decode_file_descriptor_set(
+			TEST_SCHEMAS::get_package()
+				.encode_file_descriptor_set()
+				.as_slice(),
+		)
+		.unwrap();
+
+	(protoc_pool, protify_pool)
+}
+
+#[track_caller]
+fn read_rules<T: Message + Default>(
+	pool: &DescriptorPool,
+	options: &DynamicMessage,
+	extension: &str,
+) -> T {
+	let extension: ExtensionDescriptor = pool.get_extension_by_name(extension).unwrap();
+
+	options
+		.get_extension(&extension)
+		.as_message()
+		.unwrap()
+		.transcode_to()
+		.unwrap()
+}
+
+#[test]
+fn field_rules() {
+	let (protoc_pool, protify_pool) = pools();
+
+	let field_rules = |pool: &DescriptorPool| -> FieldRules {
+		let field = pool
+			.get_message_by_name("test_schemas.v1.SimpleMsg")
+			.unwrap()
+			.get_field_by_name("name")
+			.unwrap();
+
+		read_rules(pool, &field.options(), "buf.validate.field")
+	};
+
+	let rules = field_rules(&protify_pool);
+
+	assert_eq_pretty!(rules, field_rules(&protoc_pool));
+
+	let Some(field_rules::Type::String(string_rules)) = rules.r#type else {
+		panic!("Expected string rules, found {rules:#?}");
+	};
+
+	assert_eq_pretty!(string_rules.min_len, Some(2));
+}
+
+#[test]
+fn message_rules() {
+	let (protoc_pool, protify_pool) = pools();
+
+	let message_rules = |pool: &DescriptorPool| -> MessageRules {
+		let msg = pool
+			.get_message_by_name("test_schemas.v1.DefaultValidatorTestCel")
+			.unwrap();
+
+		read_rules(pool, &msg.options(), "buf.validate.message")
+	};
+
+	let rules = message_rules(&protify_pool);
+
+	assert_eq_pretty!(rules, message_rules(&protoc_pool));
+	assert_eq_pretty!(rules.cel[0].expression(), "this.id == 1");
+}
+
+#[test]
+fn oneof_rules() {
+	let (protoc_pool, protify_pool) = pools();
+
+	let oneof_rules = |pool: &DescriptorPool| -> OneofRules {
+		let oneof = pool
+			.get_message_by_name("test_schemas.v1.DefaultValidatorRequiredOneof")
+			.unwrap()
+			.oneofs()
+			.find(|oneof| oneof.name() == "validator_required_oneof")
+			.unwrap();
+
+		read_rules(pool, &oneof.options(), "buf.validate.oneof")
+	};
+
+	let rules = oneof_rules(&protify_pool);
+
+	assert_eq_pretty!(rules, oneof_rules(&protoc_pool));
+	assert_eq_pretty!(rules.required, Some(true));
+}
@@ -0,0 +1,151 @@
+use super::*;
+use prost::Message;
+use proto_types::Any;
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct AnyPayload {
+	#[proto(validate = |v| v.min_len(3))]
+	pub name: String,
+	#[proto(validate = |v| v.gt(0))]
+	pub quantity: i32,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct AnyEnvelope {
+	#[proto(any, validate = |v| v.validate_contents())]
+	pub payload: Option<Any>,
+	#[proto(any, validate = |v| v.validate_contents().reject_unknown_types())]
+	pub strict_payload: Option<Any>,
+}
+
+fn invalid_payload() -> AnyPayload {
+	AnyPayload {
+		name: "a".to_string(),
+		quantity: 0,
+	}
+}
+
+fn rule_paths(errors: &ValidationErrors) -> Vec<String> {
+	errors
+		.iter()
+		.map(|v| v.data.rule_path_str().unwrap_or_default())
+		.collect()
+}
+
+fn packed(type_url: &str, value: Vec<u8>) -> Option<Any> {
+	Some(Any {
+		type_url: type_url.to_string(),
+		value,
+	})
+}
+
+#[test]
+fn nested_violations() {
+	let msg = AnyEnvelope {
+		payload: packed(
+			"type.googleapis.com/test_schemas.v1.AnyPayload",
+			invalid_payload().encode_to_vec(),
+		),
+		strict_payload: None,
+	};
+
+	let mut ctx = ValidationCtx::new().with_extension(TypeRegistry::new().with::<AnyPayload>());
+	ctx.fail_fast = false;
+
+	let errors = msg.validate_with(ctx).unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![
+			("payload.name".to_string(), "string.min_len".to_string()),
+			("payload.quantity".to_string(), "int32.gt".to_string())
+		]
+	);
+}
+
+#[test]
+fn global_registry() {
+	assert!(TypeRegistry::global().contains(AnyPayload::type_url()));
+
+	let msg = AnyEnvelope {
+		payload: packed(AnyPayload::type_url(), invalid_payload().encode_to_vec()),
+		strict_payload: None,
+	};
+
+	let errors = msg.validate_all().unwrap_err();
+
+	assert_eq_pretty!(errors.len(), 2);
+
+	let valid_msg = AnyEnvelope {
+		payload: packed(
+			AnyPayload::type_url(),
+			AnyPayload {
+				name: "abc".to_string(),
+				quantity: 1,
+			}
+			.encode_to_vec(),
+		),
+		strict_payload: None,
+	};
+
+	assert!(valid_msg.validate_all().is_ok());
+}
+
+#[test]
+fn unknown_types() {
+	let msg = AnyEnvelope {
+		payload: packed("/unknown.Type", vec![]),
+		strict_payload: packed("/unknown.Type", vec![]),
+	};
+
+	let errors = msg.validate_all().unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![("strict_payload".to_string(), "any.unknown_type".to_string())]
+	);
+
+	// Types which are known globally are unknown for a custom registry
+	let msg = AnyEnvelope {
+		payload: None,
+		strict_payload: packed(AnyPayload::type_url(), invalid_payload().encode_to_vec()),
+	};
+
+	let errors = msg
+		.validate_with(ValidationCtx::new().with_extension(TypeRegistry::new()))
+		.unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![("strict_payload".to_string(), "any.unknown_type".to_string())]
+	);
+	// Not attributed to the rules of `Any`
+	assert_eq_pretty!(rule_paths(&errors), vec!["cel".to_string()]);
+}
+
+#[test]
+fn invalid_value() {
+	let msg = AnyEnvelope {
+		payload: packed(AnyPayload::type_url(), vec![0xff, 0xff]),
+		strict_payload: None,
+	};
+
+	let errors = msg.validate_all().unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![("payload".to_string(), "any.invalid_value".to_string())]
+	);
+	assert_eq_pretty!(rule_paths(&errors), vec!["cel".to_string()]);
+}
+
+#[test]
+fn reject_unknown_types_without_validate_contents() {
+	let validator = AnyValidator::builder()
+		.reject_unknown_types()
+		.build();
+
+	assert!(validator.check_consistency().is_err());
+}
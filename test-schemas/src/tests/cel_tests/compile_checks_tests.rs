@@ -0,0 +1,52 @@
+use super::*;
+
+#[proto_oneof]
+#[proto(skip_checks(all))]
+pub enum CheckedContact {
+	#[proto(tag = 2)]
+	Email(String),
+	#[proto(tag = 3, name = "phone_number")]
+	Phone(String),
+}
+
+// The fields of the oneof are checked with a const assertion
+#[proto_message]
+#[proto(skip_checks(all))]
+#[proto(validate = |v| v
+	.cel(cel_program!(id = "name", msg = "abc", expr = "this.name != ''"))
+	.cel(cel_program!(id = "contact", msg = "abc", expr = "has(this.email) || has(this.phone_number)"))
+	// String literals are ignored
+	.cel(cel_program!(id = "literal", msg = "abc", expr = "this.name != 'this.unknown'"))
+)]
+pub struct CheckedCelFields {
+	#[proto(tag = 1)]
+	pub name: String,
+	#[proto(oneof(tags(2, 3)))]
+	pub contact: Option<CheckedContact>,
+}
+
+#[test]
+fn checked_cel_fields() {
+	let mut msg = CheckedCelFields {
+		name: "abc".to_string(),
+		contact: Some(CheckedContact::Phone("123".to_string())),
+	};
+
+	assert!(msg.validate().is_ok());
+
+	msg.contact = None;
+
+	let violations = msg.validate_all().unwrap_err().into_violations();
+
+	assert_eq_pretty!(violations.violations[0].rule_id(), "contact");
+}
+
+#[test]
+fn contains_field_name() {
+	const NAMES: &[&[&str]] = &[&["email"], &["phone_number", "name"]];
+
+	assert!(__contains_field_name(NAMES, "phone_number"));
+	assert!(__contains_field_name(NAMES, "name"));
+	assert!(!__contains_field_name(NAMES, "phone"));
+	assert!(!__contains_field_name(NAMES, "emai"));
+}
@@ -0,0 +1,137 @@
+use super::*;
+use protify::cel::extractors::This;
+use std::sync::{Arc, LazyLock};
+
+fn is_valid_sku(This(sku): This<Arc<String>>) -> bool {
+	sku.starts_with("SKU-")
+}
+
+register_cel_function!("isValidSku", is_valid_sku);
+register_cel_variable!("max_upload_size", 1024u64);
+
+#[proto_message]
+#[proto(validate = |v| v
+	.cel(cel_program!(id = "sku", msg = "invalid sku", expr = "isValidSku(this.sku)"))
+	.cel(cel_program!(id = "upload_size", msg = "upload too big", expr = "this.upload_size <= max_upload_size"))
+)]
+pub struct CustomExtensions {
+	pub sku: String,
+	pub upload_size: u64,
+}
+
+#[test]
+fn custom_extensions() {
+	let mut msg = CustomExtensions {
+		sku: "SKU-123".to_string(),
+		upload_size: 1024,
+	};
+
+	assert!(msg.validate().is_ok());
+
+	msg.sku = "123".to_string();
+	msg.upload_size = 2048;
+
+	let violations = msg.validate_all().unwrap_err().into_violations();
+
+	let ids: Vec<String> = violations
+		.into_iter()
+		.map(|v| v.rule_id().to_string())
+		.collect();
+
+	assert_eq_pretty!(ids, vec!["sku", "upload_size"]);
+}
+
+#[test]
+fn unknown_function() {
+	let validator = StringValidator::builder()
+		.cel(cel_program!(
+			id = "unknown",
+			msg = "abc",
+			expr = "this == '' || isNotRegistered(this)"
+		))
+		.build();
+
+	let errors = validator
+		.check_cel_programs_with(String::new())
+		.unwrap_err();
+
+	assert_eq_pretty!(
+		errors,
+		vec![CelError::UnknownFunction {
+			rule_id: "unknown".into(),
+			name: "isNotRegistered".to_string(),
+		}]
+	);
+}
+
+// Operators are parsed as calls, but they are not registered as functions
+#[test]
+fn operators_are_not_unknown_functions() {
+	let validator = StringValidator::builder()
+		.cel(cel_program!(
+			id = "operators",
+			msg = "abc",
+			expr = "!(this == 'a') && (this != 'b' || size(this) > 1) && !(this in ['c', 'd']) \
+				&& [1, 2][0] == 1 && {'a': 1}['a'] >= 1 && -1 < 0 && (2 * 3 / 1 % 4 + 1 - 1) <= 2 \
+				&& (this == '' ? true : false) && ['e'].exists(x, x != this)"
+		))
+		.build();
+
+	assert_eq_pretty!(validator.check_cel_programs_with(String::new()), Ok(()));
+}
+
+static UNKNOWN_FUNCTION_RULE: LazyLock<PredefinedRule> = LazyLock::new(|| {
+	PredefinedRule::new(
+		"test_schemas.unknown_function",
+		[cel_program!(
+			id = "string.unknown_function",
+			msg = "abc",
+			expr = "this == rule || isNotRegistered(this)"
+		)],
+	)
+});
+
+#[test]
+fn predefined_unknown_function() {
+	let errors = StringValidator::builder()
+		.predefined(&UNKNOWN_FUNCTION_RULE, "")
+		.build()
+		.check_consistency()
+		.unwrap_err();
+
+	assert_eq_pretty!(
+		errors,
+		vec![ConsistencyError::CelError(CelError::UnknownFunction {
+			rule_id: "string.unknown_function".into(),
+			name: "isNotRegistered".to_string(),
+		})]
+	);
+}
+
+// The default value, which can be overridden by the variables of the context
+register_cel_variable!("quota", 100u64);
+
+#[proto_message]
+#[proto(validate = |v| v.cel(cel_program!(id = "quota", msg = "quota exceeded", expr = "this.used <= quota")))]
+pub struct ScopedCelVariables {
+	pub used: u64,
+}
+
+#[test]
+fn scoped_cel_variables() {
+	let msg = ScopedCelVariables { used: 10 };
+
+	assert!(msg.validate().is_ok());
+
+	assert!(
+		msg.validate_with(ValidationCtx::new().with_cel_variable("quota", 20u64))
+			.is_ok()
+	);
+
+	let violations = msg
+		.validate_with(ValidationCtx::new().with_cel_variable("quota", 5u64))
+		.unwrap_err()
+		.into_violations();
+
+	assert_eq_pretty!(violations.first().unwrap().rule_id(), "quota");
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+#[proto_message]
+#[proto(skip_checks(all))]
+#[proto(validate = |v| v
+	.cel(cel_program!(id = "email", msg = "abc", expr = "this.email.isEmail()"))
+	.cel(cel_program!(id = "hostname", msg = "abc", expr = "this.host.isHostname()"))
+	.cel(cel_program!(id = "ip", msg = "abc", expr = "this.ip.isIp() && this.ip.isIp(4) && !this.ip.isIp(6)"))
+	.cel(cel_program!(id = "ip_prefix", msg = "abc", expr = "this.prefix.isIpPrefix(4, true) && !this.ip.isIpPrefix()"))
+	.cel(cel_program!(id = "uri", msg = "abc", expr = "this.uri.isUri() && this.uri.isUriRef()"))
+	.cel(cel_program!(id = "host_and_port", msg = "abc", expr = "this.host.isHostAndPort(false) && !this.host.isHostAndPort(true)"))
+	.cel(cel_program!(id = "unique", msg = "abc", expr = "this.tags.unique()"))
+	.cel(cel_program!(id = "nan", msg = "abc", expr = "!this.num.isNan() && !this.num.isInf()"))
+)]
+pub struct ExtensionFunctions {
+	pub email: String,
+	pub host: String,
+	pub ip: String,
+	pub prefix: String,
+	pub uri: String,
+	pub tags: Vec<String>,
+	pub num: f64,
+}
+
+fn valid_msg() -> ExtensionFunctions {
+	ExtensionFunctions {
+		email: "frodo@shire.com".to_string(),
+		host: "shire.com".to_string(),
+		ip: "192.168.0.1".to_string(),
+		prefix: "192.168.0.0/16".to_string(),
+		uri: "https://shire.com/hobbits".to_string(),
+		tags: vec!["a".to_string(), "b".to_string()],
+		num: 1.0,
+	}
+}
+
+#[test]
+fn valid_extension_functions() {
+	assert!(valid_msg().validate().is_ok());
+}
+
+#[test]
+fn invalid_extension_functions() {
+	let msg = ExtensionFunctions {
+		email: "frodo".to_string(),
+		host: "-shire".to_string(),
+		ip: "::1".to_string(),
+		prefix: "192.168.0.1/16".to_string(),
+		uri: "not a uri".to_string(),
+		tags: vec!["a".to_string(), "a".to_string()],
+		num: f64::INFINITY,
+	};
+
+	let violations = msg.validate_all().unwrap_err().into_violations();
+
+	let ids: Vec<String> = violations
+		.into_iter()
+		.map(|v| v.rule_id().to_string())
+		.collect();
+
+	assert_eq_pretty!(
+		ids,
+		vec![
+			"email",
+			"hostname",
+			"ip",
+			"ip_prefix",
+			"uri",
+			"host_and_port",
+			"unique",
+			"nan"
+		]
+	);
+}
@@ -1,3 +1,7 @@
 use super::*;
 
+mod compile_checks_tests;
+mod custom_extensions_tests;
+mod extension_functions_tests;
+mod predefined_rules_tests;
 mod unique_rules_tests;
@@ -0,0 +1,108 @@
+use super::*;
+use std::sync::LazyLock;
+
+static NOT_EQUAL_TO: LazyLock<PredefinedRule> = LazyLock::new(|| {
+	PredefinedRule::new(
+		"test_schemas.not_equal_to",
+		[cel_program!(
+			id = "string.not_equal_to",
+			msg = "cannot be equal to the forbidden value",
+			expr = "this != rule"
+		)],
+	)
+});
+
+static DOUBLE_MIN_LEN: LazyLock<PredefinedRule> = LazyLock::new(|| {
+	PredefinedRule::new(
+		"test_schemas.double_min_len",
+		[cel_program!(
+			id = "string.double_min_len",
+			msg = "must be at least twice as long as the minimum length",
+			expr = "!rule || size(this) >= int(rules.min_len) * 2"
+		)],
+	)
+});
+
+#[proto_extension(target = StringRules)]
+pub struct TestStringRules {
+	#[proto(tag = 1161, options = vec![NOT_EQUAL_TO.option()])]
+	not_equal_to: String,
+
+	#[proto(tag = 1162, options = vec![DOUBLE_MIN_LEN.option()])]
+	double_min_len: bool,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct PredefinedRulesMsg {
+	#[proto(validate = |v| v.predefined(&NOT_EQUAL_TO, "abc"))]
+	pub name: String,
+
+	#[proto(validate = |v| v.min_len(2).predefined(&DOUBLE_MIN_LEN, true))]
+	pub code: String,
+}
+
+#[test]
+fn predefined_rules() {
+	let mut msg = PredefinedRulesMsg {
+		name: "abcd".to_string(),
+		code: "abcd".to_string(),
+	};
+
+	assert!(msg.validate().is_ok());
+
+	msg.name = "abc".to_string();
+
+	assert_violation_id(&msg, "string.not_equal_to", "rule variable");
+
+	msg.name = "abcd".to_string();
+	msg.code = "abc".to_string();
+
+	assert_violation_id(&msg, "string.double_min_len", "rules variable");
+}
+
+#[test]
+fn predefined_rules_schema() {
+	let option: ProtoOption = StringValidator::builder()
+		.min_len(2)
+		.predefined(&NOT_EQUAL_TO, "abc")
+		.build()
+		.into();
+
+	let OptionValue::Message(field_rules) = option.value else {
+		panic!("Expected a message");
+	};
+
+	let Some(OptionValue::Message(string_rules)) = field_rules.get("string") else {
+		panic!("Expected the string rules");
+	};
+
+	assert_eq_pretty!(
+		string_rules.get("[test_schemas.not_equal_to]"),
+		Some(&OptionValue::String("abc".into()))
+	);
+}
+
+#[test]
+fn predefined_rule_value_replacement() {
+	let validator = StringValidator::builder()
+		.predefined(&NOT_EQUAL_TO, "abc")
+		.predefined(&NOT_EQUAL_TO, "def")
+		.build();
+
+	let values: Vec<&OptionValue> = validator
+		.predefined
+		.iter()
+		.map(|(_, value)| value)
+		.collect();
+
+	assert_eq_pretty!(values, vec![&OptionValue::String("def".into())]);
+}
+
+#[test]
+fn predefined_rule_option() {
+	let schema = TestStringRules::proto_schema();
+
+	assert_eq_pretty!(schema.target, ExtensionTarget::StringRules);
+	assert_eq_pretty!(schema.fields[0].options[0], NOT_EQUAL_TO.option());
+}
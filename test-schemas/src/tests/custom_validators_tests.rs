@@ -252,3 +252,85 @@ fn custom_top_level_validators() {
 
 	assert_eq_pretty!(violations.len(), 4);
 }
+
+struct TenantLimits {
+	max_id: i32,
+}
+
+fn tenant_limits_validator(ctx: &mut ValidationCtx, val: Option<&i32>) -> ValidationResult {
+	let max_id = ctx
+		.extensions
+		.get::<TenantLimits>()
+		.map_or(i32::MAX, |limits| limits.max_id);
+
+	if val.is_some_and(|id| *id > max_id) {
+		ctx.add_violation_with_custom_id(
+			"tenant.max_id",
+			ViolationKind::Cel,
+			format!("must be at most {max_id}"),
+		)
+	} else {
+		Ok(IsValid::Yes)
+	}
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+struct ExtensionsMsg {
+	#[proto(validate = from_fn(tenant_limits_validator))]
+	id: i32,
+	#[proto(message)]
+	nested: Option<NestedExtensionsMsg>,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+struct NestedExtensionsMsg {
+	#[proto(validate = from_fn(tenant_limits_validator))]
+	id: i32,
+}
+
+#[test]
+fn validation_extensions() {
+	let msg = ExtensionsMsg {
+		id: 5,
+		nested: Some(NestedExtensionsMsg { id: 20 }),
+	};
+
+	assert!(msg.validate().is_ok());
+
+	let violations = msg
+		.validate_with(ValidationCtx::new().with_extension(TenantLimits { max_id: 10 }))
+		.unwrap_err()
+		.into_violations();
+
+	// The extensions are also available to the validators of nested messages
+	assert_eq_pretty!(violations.len(), 1);
+	assert_eq_pretty!(violations.first().unwrap().rule_id(), "tenant.max_id");
+}
+
+#[test]
+fn validation_extensions_map() {
+	let mut extensions = ValidationExtensions::new();
+
+	assert!(extensions.is_empty());
+	assert!(
+		extensions
+			.insert(TenantLimits { max_id: 1 })
+			.is_none()
+	);
+	assert!(extensions.contains::<TenantLimits>());
+
+	let prev = extensions
+		.insert(TenantLimits { max_id: 2 })
+		.unwrap();
+
+	assert_eq_pretty!(prev.max_id, 1);
+	assert_eq_pretty!(extensions.get::<TenantLimits>().unwrap().max_id, 2);
+
+	// Clones share the same values
+	assert_eq!(extensions.clone(), extensions);
+
+	assert!(extensions.remove::<TenantLimits>().is_some());
+	assert!(extensions.get::<TenantLimits>().is_none());
+}
@@ -0,0 +1,243 @@
+use super::*;
+use protify::proto_types::protovalidate::{ViolationKind, violations_data::StringViolation};
+
+#[proto_enum]
+pub enum FallibleStatus {
+	Unspecified,
+	Active,
+}
+
+#[proto_oneof(proxied)]
+#[proto(skip_checks(all))]
+#[derive(PartialEq)]
+pub enum FallibleOneof {
+	#[proto(tag = 1)]
+	A(String),
+	#[proto(tag = 2)]
+	B(i32),
+	#[proto(tag = 3, enum_)]
+	Status(FallibleStatus),
+}
+
+impl Default for FallibleOneofProto {
+	fn default() -> Self {
+		Self::B(1)
+	}
+}
+
+#[proto_message(proxied, fallible)]
+#[proto(skip_checks(all))]
+#[derive(PartialEq)]
+pub struct FallibleItem {
+	#[proto(enum_)]
+	pub status: FallibleStatus,
+	#[proto(string, from_proto = parse_code, into_proto = |v: u32| v.to_string())]
+	pub code: u32,
+}
+
+fn parse_code(code: String) -> Result<u32, ConversionError> {
+	code.parse().map_err(|_| {
+		ConversionError::new(
+			"code.format",
+			ViolationKind::String(StringViolation::Pattern),
+			"must be a number",
+		)
+	})
+}
+
+#[proto_oneof(proxied, fallible)]
+#[proto(skip_checks(all))]
+#[derive(PartialEq)]
+pub enum FallibleItemOneof {
+	#[proto(tag = 10, message(proxied))]
+	NestedItem(FallibleItem),
+	#[proto(tag = 11, string, from_proto = parse_code, into_proto = |v: u32| v.to_string())]
+	Code(u32),
+}
+
+#[proto_message(proxied, fallible)]
+#[proto(skip_checks(all))]
+#[derive(PartialEq)]
+pub struct FallibleContainer {
+	pub name: String,
+	#[proto(message(proxied, default))]
+	pub item: FallibleItem,
+	#[proto(message(proxied))]
+	pub optional_item: Option<FallibleItem>,
+	#[proto(repeated(message(proxied)))]
+	pub items: Vec<FallibleItem>,
+	#[proto(map(string, message(proxied)))]
+	pub items_map: HashMap<String, FallibleItem>,
+	#[proto(repeated(enum_))]
+	pub statuses: Vec<FallibleStatus>,
+	#[proto(oneof(proxied, default, tags(1, 2, 3)))]
+	pub oneof: FallibleOneof,
+	#[proto(oneof(proxied, tags(10, 11)))]
+	pub item_oneof: Option<FallibleItemOneof>,
+}
+
+fn valid_item() -> FallibleItemProto {
+	FallibleItemProto {
+		status: FallibleStatus::Active as i32,
+		code: "15".to_string(),
+	}
+}
+
+fn invalid_item() -> FallibleItemProto {
+	FallibleItemProto {
+		status: 10,
+		code: "abc".to_string(),
+	}
+}
+
+fn valid_container() -> FallibleContainerProto {
+	FallibleContainerProto {
+		name: "abc".to_string(),
+		item: Some(valid_item()),
+		optional_item: None,
+		items: vec![valid_item()],
+		items_map: HashMap::from_iter([("a".to_string(), valid_item())]),
+		statuses: vec![FallibleStatus::Active as i32],
+		oneof: Some(FallibleOneofProto::A("abc".to_string())),
+		item_oneof: None,
+	}
+}
+
+#[test]
+fn successful_conversion() {
+	let proxy = FallibleContainer::try_from(valid_container()).unwrap();
+
+	assert_eq_pretty!(proxy.item.code, 15);
+	assert_eq_pretty!(proxy.item.status, FallibleStatus::Active);
+	assert_eq_pretty!(proxy.oneof, FallibleOneof::A("abc".to_string()));
+
+	// Roundtrip
+	assert_eq_pretty!(proxy.into_message(), valid_container());
+}
+
+#[test]
+fn missing_required_fields() {
+	let msg = FallibleContainerProto {
+		item: None,
+		oneof: None,
+		..valid_container()
+	};
+
+	let errors = FallibleContainer::try_from_message(msg).unwrap_err();
+	let violations = violations(&errors);
+
+	assert_eq_pretty!(violations.len(), 2);
+	assert_eq_pretty!(violations[0], ("item".to_string(), "required".to_string()));
+	// Oneofs are not part of the field paths
+	assert_eq_pretty!(violations[1].0, "");
+}
+
+#[test]
+fn nested_paths() {
+	let msg = FallibleContainerProto {
+		item: Some(invalid_item()),
+		optional_item: Some(invalid_item()),
+		items: vec![valid_item(), invalid_item()],
+		items_map: HashMap::from_iter([("b".to_string(), invalid_item())]),
+		statuses: vec![1, 20],
+		..valid_container()
+	};
+
+	let errors = FallibleContainer::try_from(msg).unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![
+			("item.status".to_string(), "enum.defined_only".to_string()),
+			("item.code".to_string(), "code.format".to_string()),
+			(
+				"optional_item.status".to_string(),
+				"enum.defined_only".to_string()
+			),
+			("optional_item.code".to_string(), "code.format".to_string()),
+			(
+				"items[1].status".to_string(),
+				"enum.defined_only".to_string()
+			),
+			("items[1].code".to_string(), "code.format".to_string()),
+			(
+				"items_map[\"b\"].status".to_string(),
+				"enum.defined_only".to_string()
+			),
+			(
+				"items_map[\"b\"].code".to_string(),
+				"code.format".to_string()
+			),
+			("statuses[1]".to_string(), "enum.defined_only".to_string())
+		]
+	);
+}
+
+#[test]
+fn conversion_after_validation() {
+	// The validation succeeds, but the conversion does not
+	let msg = FallibleItemProto {
+		status: 10,
+		code: "1".to_string(),
+	};
+
+	let errors = FallibleItem::from_validated_message(msg).unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![("status".to_string(), "enum.defined_only".to_string())]
+	);
+}
+
+#[test]
+fn oneof_variants() {
+	let msg = FallibleContainerProto {
+		oneof: Some(FallibleOneofProto::Status(10)),
+		..valid_container()
+	};
+
+	let errors = FallibleContainer::try_from(msg).unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![("status".to_string(), "enum.defined_only".to_string())]
+	);
+
+	let msg = FallibleContainerProto {
+		item_oneof: Some(FallibleItemOneofProto::NestedItem(invalid_item())),
+		..valid_container()
+	};
+
+	let errors = FallibleContainer::try_from(msg).unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![
+			(
+				"nested_item.status".to_string(),
+				"enum.defined_only".to_string()
+			),
+			("nested_item.code".to_string(), "code.format".to_string())
+		]
+	);
+}
+
+#[test]
+fn fallible_oneof() {
+	let oneof =
+		FallibleItemOneof::try_from_oneof(FallibleItemOneofProto::Code("15".to_string())).unwrap();
+
+	assert_eq_pretty!(oneof, FallibleItemOneof::Code(15));
+
+	let errors = FallibleItemOneof::try_from_oneof(FallibleItemOneofProto::Code("abc".to_string()))
+		.unwrap_err();
+
+	assert_eq_pretty!(
+		violations(&errors),
+		vec![("code".to_string(), "code.format".to_string())]
+	);
+	assert_eq_pretty!(
+		errors.iter().next().unwrap().meta.kind,
+		ViolationKind::String(StringViolation::Pattern)
+	);
+}
@@ -0,0 +1,156 @@
+use super::*;
+use proto_types::{FieldMask, Timestamp};
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct PathsTarget {
+	pub id: i32,
+	pub name: String,
+	#[proto(message)]
+	pub address: Option<PathsAddress>,
+	#[proto(repeated(string))]
+	pub tags: Vec<String>,
+	#[proto(map(string, string))]
+	pub labels: HashMap<String, String>,
+	#[proto(timestamp)]
+	pub created_at: Option<Timestamp>,
+	#[proto(message)]
+	pub parent: Option<Box<PathsTarget>>,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct PathsAddress {
+	pub city: String,
+	pub zip: String,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct PathsUpdate {
+	#[proto(field_mask, validate = |v| v.paths_of::<PathsTarget>())]
+	pub mask: Option<FieldMask>,
+	#[proto(field_mask, validate = |v| v.paths_of_except::<PathsTarget>(&["id", "created_at"]))]
+	pub restricted_mask: Option<FieldMask>,
+}
+
+fn mask(paths: &[&str]) -> Option<FieldMask> {
+	Some(FieldMask {
+		paths: paths.iter().map(|p| p.to_string()).collect(),
+	})
+}
+
+#[track_caller]
+fn assert_paths_of_violation(msg: &PathsUpdate) {
+	let violations = msg.validate().unwrap_err().into_violations();
+
+	assert_eq_pretty!(violations.violations[0].rule_id(), "field_mask.paths_of");
+}
+
+#[test]
+fn message_paths() {
+	let paths = MessagePaths::new::<PathsTarget>(&[]);
+
+	let collected: Vec<&str> = paths
+		.paths
+		.iter()
+		.map(AsRef::<str>::as_ref)
+		.collect();
+
+	// Recursive messages are not traversed again, and repeated and map fields
+	// can only be addressed as a whole
+	assert_eq_pretty!(
+		collected,
+		vec![
+			"address",
+			"address.city",
+			"address.zip",
+			"created_at",
+			"id",
+			"labels",
+			"name",
+			"parent",
+			"tags"
+		]
+	);
+
+	let paths = MessagePaths::new::<PathsTarget>(&["address", "id"]);
+
+	assert!(!paths.contains("address.city"));
+	assert!(!paths.contains("id"));
+	assert!(paths.contains("name"));
+}
+
+#[test]
+fn valid_paths() {
+	let msg = PathsUpdate {
+		mask: mask(&["name", "address.city", "tags", "labels", "created_at"]),
+		restricted_mask: mask(&["name", "address"]),
+	};
+
+	assert!(msg.validate().is_ok());
+}
+
+#[test]
+fn invalid_paths() {
+	for paths in [
+		&["unknown"][..],
+		&["name", "address.country"],
+		&["tags.value"],
+		&["labels.key"],
+	] {
+		let msg = PathsUpdate {
+			mask: mask(paths),
+			restricted_mask: None,
+		};
+
+		assert_paths_of_violation(&msg);
+	}
+}
+
+#[test]
+fn forbidden_fields() {
+	for paths in [
+		&["id"][..],
+		&["created_at.seconds"],
+		&["name", "created_at"],
+	] {
+		let msg = PathsUpdate {
+			mask: None,
+			restricted_mask: mask(paths),
+		};
+
+		assert_paths_of_violation(&msg);
+	}
+}
+
+#[test]
+fn paths_of_schema() {
+	let option: ProtoOption = FieldMaskValidator::builder()
+		.paths_of_except::<PathsAddress>(&["zip"])
+		.build()
+		.into();
+
+	let OptionValue::Message(field_rules) = option.value else {
+		panic!("Expected a message");
+	};
+
+	let Some(OptionValue::List(cel_rules)) = field_rules.get("cel") else {
+		panic!("Expected the cel rules");
+	};
+
+	let OptionValue::Message(rule) = &cel_rules[0] else {
+		panic!("Expected a message");
+	};
+
+	assert_eq_pretty!(
+		rule.get("id"),
+		Some(&OptionValue::String("field_mask.paths_of".into()))
+	);
+	assert_eq_pretty!(
+		rule.get("expression"),
+		Some(&OptionValue::String(
+			"this.paths.all(p, p in ['city'])".into()
+		))
+	);
+}
@@ -0,0 +1,179 @@
+use protify::proto_types::protovalidate::violations_data::StringViolation;
+use proto_types::LatLng;
+
+use super::*;
+
+const ITALIAN: &str = r#"
+# Stringhe
+string.min_len = { $min_len ->
+    [one] deve contenere almeno un carattere
+   *[other] deve contenere almeno { $min_len } caratteri
+  }
+string.prefix = deve iniziare con "{ $prefix }"
+
+int32.gt = { $field } deve essere maggiore di { $gt }
+required = è obbligatorio
+
+# Tipi comuni
+lat_lng.latitude = la latitudine deve essere compresa tra -90 e 90
+"#;
+
+fn catalog() -> Arc<FluentCatalog> {
+	Arc::new(
+		FluentCatalog::new()
+			.with_resource("it", ITALIAN)
+			.unwrap(),
+	)
+}
+
+fn validate_localized(msg: &impl ValidatedMessage, locale: &str) -> Vec<String> {
+	let mut ctx = ValidationCtx::new().localized(Localization::new(locale.to_string(), catalog()));
+	ctx.fail_fast = false;
+
+	let _ = msg.validate_with_ctx(&mut ctx);
+
+	ctx.violations
+		.into_violations()
+		.violations
+		.into_iter()
+		.map(|v| v.message().to_string())
+		.collect()
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+struct LocalizedMsg {
+	#[proto(validate = |v| v.min_len(3))]
+	name: String,
+	#[proto(validate = |v| v.min_len(1))]
+	nickname: String,
+	#[proto(validate = |v| v.prefix("abc"))]
+	code: String,
+	#[proto(validate = |v| v.gt(10))]
+	amount: i32,
+	#[proto(validate = |v| v.lt(10))]
+	limit: i32,
+	#[proto(message, validate = |v| v.required())]
+	required: Option<DirectMsg>,
+}
+
+fn invalid_msg() -> LocalizedMsg {
+	LocalizedMsg {
+		name: "ab".to_string(),
+		nickname: String::new(),
+		code: "xyz".to_string(),
+		amount: 1,
+		limit: 20,
+		required: None,
+	}
+}
+
+#[test]
+fn localized_messages() {
+	let messages = validate_localized(&invalid_msg(), "it");
+
+	assert_eq_pretty!(
+		messages,
+		[
+			"deve contenere almeno 3 caratteri",
+			"deve contenere almeno un carattere",
+			"deve iniziare con \"abc\"",
+			"amount deve essere maggiore di 10",
+			// Missing from the catalog
+			"must be smaller than 10",
+			"è obbligatorio",
+		]
+	);
+}
+
+#[test]
+fn localized_region_fallback() {
+	let messages = validate_localized(&invalid_msg(), "it-IT");
+
+	assert_eq_pretty!(
+		messages.first().unwrap(),
+		"deve contenere almeno 3 caratteri"
+	);
+}
+
+#[test]
+fn unknown_locale_uses_default_messages() {
+	let messages = validate_localized(&invalid_msg(), "fr");
+
+	assert_eq_pretty!(
+		messages.first().unwrap(),
+		"must be at least 3 characters long"
+	);
+}
+
+#[test]
+fn custom_messages_take_precedence() {
+	#[proto_message]
+	#[proto(skip_checks(all))]
+	struct CustomMsg {
+		#[proto(validate = |v| v.min_len(3).with_error_messages([(StringViolation::MinLen, "custom")]))]
+		name: String,
+	}
+
+	let msg = CustomMsg {
+		name: "ab".to_string(),
+	};
+
+	assert_eq_pretty!(validate_localized(&msg, "it"), ["custom"]);
+}
+
+#[test]
+fn closure_catalog() {
+	let catalog = |_: &str, rule_id: &str, args: &MessageArgs| {
+		(rule_id == "string.min_len").then(|| format!("min: {}", args.get("min_len").unwrap()))
+	};
+
+	let mut ctx = ValidationCtx::new().localized(Localization::new("en", Arc::new(catalog)));
+
+	let _ = invalid_msg().validate_with_ctx(&mut ctx);
+
+	assert_eq_pretty!(
+		ctx.violations.into_violations().violations[0].message(),
+		"min: 3"
+	);
+}
+
+#[test]
+fn localized_common_types() {
+	#[proto_message]
+	#[proto(skip_checks(all))]
+	struct LocalizedLocation {
+		#[proto(message, validate = |v| v.required())]
+		location: Option<LatLng>,
+	}
+
+	let msg = LocalizedLocation {
+		location: Some(LatLng {
+			latitude: 100.0,
+			longitude: 0.0,
+		}),
+	};
+
+	assert_eq_pretty!(
+		validate_localized(&msg, "it"),
+		["la latitudine deve essere compresa tra -90 e 90"]
+	);
+}
+
+#[test]
+fn catalog_parse_errors() {
+	let err = FluentCatalog::new()
+		.with_resource(
+			"it",
+			"required = ok\n  continuation\n\nstring.min_len { $min_len }",
+		)
+		.unwrap_err();
+
+	assert_eq_pretty!(err.line, 4);
+
+	let err = FluentCatalog::new()
+		.with_resource("it", "string.min_len = { $min_len -> [one] uno }")
+		.unwrap_err();
+
+	assert_eq_pretty!(err.line, 1);
+}
@@ -0,0 +1,177 @@
+use super::*;
+use proto_types::FieldMask;
+
+fn mask(paths: &[&str]) -> FieldMask {
+	FieldMask {
+		paths: paths.iter().map(|p| p.to_string()).collect(),
+	}
+}
+
+#[track_caller]
+fn masked_violation_ids(msg: &impl ValidatedMessage, paths: &[&str]) -> Vec<String> {
+	let mut ctx = ValidationCtx::new().masked(&mask(paths));
+	ctx.fail_fast = false;
+
+	let _ = msg.validate_with_ctx(&mut ctx);
+
+	ctx.violations
+		.into_violations()
+		.violations
+		.into_iter()
+		.map(|v| v.rule_id().to_string())
+		.collect()
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct MaskedAddress {
+	#[proto(validate = |v| v.min_len(1))]
+	pub city: String,
+	#[proto(validate = |v| v.len(5))]
+	pub zip: String,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+#[proto(validate = |v| v.cel(cel_program!(id = "name_not_nickname", msg = "abc", expr = "this.name != this.nickname")))]
+pub struct MaskedUser {
+	#[proto(validate = |v| v.min_len(3))]
+	pub name: String,
+	#[proto(validate = |v| v.min_len(3))]
+	pub nickname: String,
+	#[proto(message, validate = |v| v.required())]
+	pub address: Option<MaskedAddress>,
+	#[proto(oneof(tags(4, 5)))]
+	#[proto(validate = |v| v.required())]
+	pub contact: Option<MaskedContact>,
+}
+
+#[proto_oneof]
+#[proto(skip_checks(all))]
+pub enum MaskedContact {
+	#[proto(tag = 4, validate = |v| v.min_len(5))]
+	Email(String),
+	#[proto(tag = 5, validate = |v| v.min_len(7))]
+	Phone(String),
+}
+
+#[test]
+fn skips_fields_outside_of_mask() {
+	let msg = MaskedUser {
+		name: "abcde".to_string(),
+		..Default::default()
+	};
+
+	assert!(msg.validate().is_err());
+	assert!(msg.validate_masked(&mask(&["name"])).is_ok());
+
+	assert_eq_pretty!(
+		masked_violation_ids(&msg, &["nickname", "address"]),
+		["string.min_len", "required"]
+	);
+}
+
+#[test]
+fn nested_paths() {
+	let msg = MaskedUser {
+		address: Some(MaskedAddress {
+			city: String::new(),
+			zip: "123".to_string(),
+		}),
+		..Default::default()
+	};
+
+	assert_eq_pretty!(masked_violation_ids(&msg, &["address.zip"]), ["string.len"]);
+
+	assert_eq_pretty!(
+		masked_violation_ids(&msg, &["address"]),
+		["string.min_len", "string.len"]
+	);
+
+	// The shorter path takes precedence
+	assert_eq_pretty!(
+		masked_violation_ids(&msg, &["address.zip", "address"]),
+		["string.min_len", "string.len"]
+	);
+}
+
+#[test]
+fn masked_oneofs() {
+	let msg = MaskedUser {
+		name: "abcde".to_string(),
+		contact: Some(MaskedContact::Phone("123".to_string())),
+		..Default::default()
+	};
+
+	assert_eq_pretty!(masked_violation_ids(&msg, &["email"]), [] as [&str; 0]);
+	assert_eq_pretty!(masked_violation_ids(&msg, &["phone"]), ["string.min_len"]);
+
+	let msg = MaskedUser {
+		name: "abcde".to_string(),
+		..Default::default()
+	};
+
+	assert_eq_pretty!(masked_violation_ids(&msg, &["phone"]), ["required"]);
+}
+
+#[test]
+fn top_level_cel_rules() {
+	let msg = MaskedUser {
+		name: "abcde".to_string(),
+		nickname: "abcde".to_string(),
+		..Default::default()
+	};
+
+	// Only some of the referenced fields are covered
+	assert!(msg.validate_masked(&mask(&["name"])).is_ok());
+
+	assert_eq_pretty!(
+		masked_violation_ids(&msg, &["name", "nickname"]),
+		["name_not_nickname"]
+	);
+}
+
+#[proto_message(proxied)]
+#[proto(skip_checks(all))]
+pub struct MaskedProxied {
+	#[proto(validate = |v| v.gt(0))]
+	pub id: i32,
+	#[proto(message(proxied))]
+	pub inner: Option<MaskedProxiedInner>,
+}
+
+#[proto_message(proxied)]
+#[proto(skip_checks(all))]
+pub struct MaskedProxiedInner {
+	#[proto(validate = |v| v.gt(0))]
+	pub id: i32,
+	#[proto(validate = |v| v.min_len(1))]
+	pub name: String,
+}
+
+#[test]
+fn proxied_messages() {
+	let msg = MaskedProxied {
+		id: 0,
+		inner: Some(MaskedProxiedInner {
+			id: 0,
+			name: "abc".to_string(),
+		}),
+	}
+	.into_message();
+
+	assert!(
+		msg.validate_masked(&mask(&["inner.name"]))
+			.is_ok()
+	);
+
+	assert_eq_pretty!(
+		masked_violation_ids(&msg, &["inner.id", "inner.name"]),
+		["int32.gt"]
+	);
+
+	assert_eq_pretty!(
+		masked_violation_ids(&msg, &["id", "inner"]),
+		["int32.gt", "int32.gt"]
+	);
+}
@@ -11,13 +11,20 @@ mod consistency_tests;
 use super::RustKeywords;
 use similar_asserts::assert_eq as assert_eq_pretty;
 
+mod any_contents_tests;
 mod cel_tests;
 mod custom_validators_tests;
 mod enum_methods_tests;
 mod extern_paths_tests;
+mod fallible_proxy_tests;
+mod field_mask_paths_tests;
+mod localization_tests;
+mod masked_validation_tests;
 mod message_methods_tests;
 mod oneof_tags_tests;
 mod recursion_tests;
+mod report_tests;
+mod tag_lock_tests;
 mod validation_tests;
 
 mod custom_errors_tests;
@@ -50,6 +57,19 @@ pub(crate) fn assert_violation_id(msg: &impl ValidatedMessage, expected: &str, e
 	assert_eq!(violations.first().unwrap().rule_id(), expected, "{error}");
 }
 
+// Extracts the field path and the rule ID of each violation
+pub(crate) fn violations(errors: &ValidationErrors) -> Vec<(String, String)> {
+	errors
+		.iter()
+		.map(|v| {
+			(
+				v.field_path_str().unwrap_or_default(),
+				v.rule_id().to_string(),
+			)
+		})
+		.collect()
+}
+
 #[proto_message]
 #[proto(skip_checks(all))]
 pub struct DirectMsg {
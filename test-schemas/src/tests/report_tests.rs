@@ -0,0 +1,107 @@
+use super::*;
+
+#[proto_message]
+#[proto(skip_checks(all))]
+#[proto(validate = |v| v.cel(cel_program!(id = "top_level", msg = "top level \"rule\"", expr = "this.items.size() == 0")))]
+pub struct ReportMsg {
+	#[proto(repeated(message))]
+	pub items: Vec<ReportItem>,
+	#[proto(map(string, string), validate = |v| v.values(|v| v.min_len(2)))]
+	pub labels: HashMap<String, String>,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct ReportItem {
+	#[proto(validate = |v| v.min_len(3).prefix("a"))]
+	pub name: String,
+}
+
+fn report_msg() -> ReportMsg {
+	let mut labels = HashMap::new();
+	labels.insert("a/b".to_string(), "x".to_string());
+
+	ReportMsg {
+		items: vec![
+			ReportItem {
+				name: "abc".to_string(),
+			},
+			ReportItem {
+				name: "b".to_string(),
+			},
+		],
+		labels,
+	}
+}
+
+#[test]
+fn plain_report() {
+	let errors = report_msg().validate_all().unwrap_err();
+
+	assert_eq_pretty!(
+		errors.to_string(),
+		r#"4 violations:
+  items[1].name:
+    - [string.min_len] must be at least 3 characters long
+    - [string.prefix] must start with "a"
+  labels["a/b"]:
+    - [string.min_len] must be at least 2 characters long
+  (top level):
+    - [top_level] top level "rule"
+"#
+	);
+
+	// Can be used as a standard error
+	let error: Box<dyn std::error::Error> = Box::new(errors);
+
+	assert!(error.to_string().starts_with("4 violations:"));
+}
+
+#[test]
+fn json_report() {
+	let errors = report_msg().validate_all().unwrap_err();
+
+	assert_eq_pretty!(
+		errors.report(ReportFormat::Json).to_string(),
+		r#"{"items[1].name":[{"rule_id":"string.min_len","message":"must be at least 3 characters long"},{"rule_id":"string.prefix","message":"must start with \"a\""}],"labels[\"a/b\"]":[{"rule_id":"string.min_len","message":"must be at least 2 characters long"}],"":[{"rule_id":"top_level","message":"top level \"rule\""}]}"#
+	);
+}
+
+#[test]
+fn colored_report() {
+	let errors = report_msg().validate_all().unwrap_err();
+
+	let report = errors.report(ReportFormat::Colored).to_string();
+
+	assert!(report.contains("\u{1b}["));
+	assert!(report.contains("must start with \"a\""));
+}
+
+#[test]
+fn violation_paths() {
+	let errors = report_msg().validate_all().unwrap_err();
+
+	let paths: Vec<(Option<String>, Option<String>)> = errors
+		.iter()
+		.map(|v| (v.field_path_str(), v.json_pointer()))
+		.collect();
+
+	assert_eq_pretty!(
+		paths,
+		[
+			(
+				Some("items[1].name".to_string()),
+				Some("/items/1/name".to_string())
+			),
+			(
+				Some("items[1].name".to_string()),
+				Some("/items/1/name".to_string())
+			),
+			(
+				Some("labels[\"a/b\"]".to_string()),
+				Some("/labels/a~1b".to_string())
+			),
+			(None, None),
+		]
+	);
+}
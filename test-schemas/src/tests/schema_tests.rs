@@ -2,12 +2,19 @@ use rendering_tests::TestEnum;
 use std::{collections::HashMap, sync::Arc};
 
 use super::*;
+mod compat_tests;
 mod conversions_tests;
 mod deprecated_tests;
+mod descriptor_tests;
+mod doc_comments_tests;
+mod editions_tests;
+mod field_options_tests;
 mod inference_tests;
+mod json_schema_tests;
 mod nested_items_tests;
 mod rendering_tests;
 mod type_resolution_tests;
+mod well_known_types_tests;
 
 #[proto_oneof(proxied)]
 #[proto(skip_checks(all))]
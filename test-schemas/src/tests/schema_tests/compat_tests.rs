@@ -0,0 +1,185 @@
+use protify::compat::*;
+
+use super::*;
+
+proto_package!(COMPAT_PKG, name = "compat_test", no_cel_test);
+
+define_proto_file!(
+	COMPAT_FILE,
+	name = "compat_test.proto",
+	package = COMPAT_PKG
+);
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct CompatMsg {
+	pub id: i32,
+	pub name: String,
+	pub notes: Option<String>,
+}
+
+#[proto_enum]
+pub enum CompatEnum {
+	Unspecified,
+	Active,
+}
+
+#[proto_service]
+pub enum CompatService {
+	Get {
+		request: CompatMsg,
+		response: CompatMsg,
+	},
+}
+
+fn message_mut(pkg: &mut Package) -> &mut MessageSchema {
+	&mut pkg.files[0].messages[0]
+}
+
+fn fields_mut(pkg: &mut Package) -> impl Iterator<Item = &mut Field> {
+	message_mut(pkg)
+		.entries
+		.iter_mut()
+		.filter_map(|e| match e {
+			MessageEntry::Field(f) => Some(f),
+			MessageEntry::Oneof(_) => None,
+		})
+}
+
+#[test]
+fn unchanged_package() {
+	let report = check_compatibility(&COMPAT_PKG::get_package(), &COMPAT_PKG::get_package());
+
+	assert!(report.changes.is_empty());
+}
+
+#[test]
+fn removed_fields() {
+	let old = COMPAT_PKG::get_package();
+	let mut new = COMPAT_PKG::get_package();
+
+	message_mut(&mut new)
+		.entries
+		.retain(|e| !matches!(e, MessageEntry::Field(f) if f.tag != 1));
+	message_mut(&mut new).reserved_numbers = vec![3..4];
+
+	let report = check_compatibility(&old, &new);
+
+	assert_eq_pretty!(report.changes.len(), 2);
+	assert_eq_pretty!(report.changes[0].severity, Severity::Wire);
+	assert!(matches!(
+		&report.changes[0].kind,
+		BreakingChangeKind::FieldRemoved { field, tag: 2, number_reserved: false, .. } if *field == "name"
+	));
+	assert_eq_pretty!(report.changes[1].severity, Severity::WireJson);
+	assert!(matches!(
+		&report.changes[1].kind,
+		BreakingChangeKind::FieldRemoved { field, tag: 3, number_reserved: true, name_reserved: false, .. } if *field == "notes"
+	));
+
+	assert!(!report.is_compatible(Severity::Wire));
+	assert_eq_pretty!(report.breaking_changes(Severity::Wire).count(), 1);
+	assert_eq_pretty!(report.breaking_changes(Severity::File).count(), 2);
+}
+
+#[test]
+fn changed_fields() {
+	let mut old = COMPAT_PKG::get_package();
+	let mut new = COMPAT_PKG::get_package();
+
+	old.files[0].messages[0].reserved_numbers = vec![10..11];
+
+	for field in fields_mut(&mut new) {
+		match field.tag {
+			1 => field.tag = 10,
+			2 => field.name = "title".into(),
+			3 => field.type_ = FieldType::Normal(ProtoType::Scalar(ProtoScalar::String)),
+			_ => {}
+		}
+	}
+
+	let report = check_compatibility(&old, &new);
+	let kinds: Vec<_> = report
+		.changes
+		.iter()
+		.map(|c| (c.severity, &c.kind))
+		.collect();
+
+	assert!(
+		kinds
+			.iter()
+			.any(|(severity, kind)| *severity == Severity::Wire
+				&& matches!(kind, BreakingChangeKind::FieldRemoved { tag: 1, .. }))
+	);
+	assert!(
+		kinds
+			.iter()
+			.any(|(severity, kind)| *severity == Severity::Wire
+				&& matches!(kind, BreakingChangeKind::ReservedTagUsed { tag: 10, .. }))
+	);
+	assert!(kinds.iter().any(|(severity, kind)| *severity == Severity::WireJson
+		&& matches!(kind, BreakingChangeKind::TagReused { tag: 2, new_field, .. } if *new_field == "title")));
+	// Dropping explicit presence does not change the encoding
+	assert!(
+		kinds
+			.iter()
+			.any(|(severity, kind)| *severity == Severity::File
+				&& matches!(kind, BreakingChangeKind::FieldTypeChanged { field, .. } if *field == "notes"))
+	);
+}
+
+#[test]
+fn renumbered_enum_variant() {
+	let old = COMPAT_PKG::get_package();
+	let mut new = COMPAT_PKG::get_package();
+
+	new.files[0].enums[0].variants[1].tag = 5;
+
+	let report = check_compatibility(&old, &new);
+
+	assert_eq_pretty!(report.changes.len(), 1);
+	assert_eq_pretty!(report.changes[0].severity, Severity::Wire);
+	assert!(matches!(
+		report.changes[0].kind,
+		BreakingChangeKind::EnumVariantRenumbered {
+			old_tag: 1,
+			new_tag: 5,
+			..
+		}
+	));
+}
+
+#[test]
+fn moved_message() {
+	let old = COMPAT_PKG::get_package();
+	let mut new = COMPAT_PKG::get_package();
+
+	new.name = "compat_test.v2".into();
+	message_mut(&mut new).package = "compat_test.v2".into();
+
+	let report = check_compatibility(&old, &new);
+
+	assert!(report.changes.iter().any(|c| matches!(
+		&c.kind,
+		BreakingChangeKind::MessageMoved { message, new_package, .. } if *message == "CompatMsg" && *new_package == "compat_test.v2"
+	)));
+}
+
+#[test]
+fn changed_handler() {
+	let old = COMPAT_PKG::get_package();
+	let mut new = COMPAT_PKG::get_package();
+
+	new.files[0].services[0].handlers[0]
+		.response
+		.is_stream = true;
+
+	let report = check_compatibility(&old, &new);
+
+	assert_eq_pretty!(report.changes.len(), 1);
+	assert_eq_pretty!(report.changes[0].severity, Severity::Wire);
+	assert_eq_pretty!(
+		report.changes[0].to_string(),
+		"[WIRE] The response of handler `CompatService.Get` changed from `compat_test.CompatMsg` to `stream compat_test.CompatMsg`"
+	);
+}
@@ -0,0 +1,219 @@
+use protify::prost_types::{
+	field_descriptor_proto::{Label, Type},
+	uninterpreted_option::NamePart,
+};
+
+use super::*;
+
+proto_package!(DESCRIPTOR_PKG, name = "descriptor_test", no_cel_test);
+
+define_proto_file!(
+	DESCRIPTOR_FILE,
+	name = "descriptor_test.proto",
+	package = DESCRIPTOR_PKG
+);
+
+fn field_options() -> Vec<ProtoOption> {
+	vec![
+		proto_option!("deprecated" => true),
+		proto_option!("(custom.rule)" => { "min" => 1 }),
+	]
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct DescriptorMsg {
+	#[proto(options = field_options())]
+	pub snake_case_id: i32,
+	pub optional_name: Option<String>,
+	pub tags: Vec<String>,
+	pub counters: HashMap<String, u64>,
+	#[proto(enum_(DescriptorEnum))]
+	pub status: i32,
+	#[proto(oneof(tags(10, 11)))]
+	pub choice: Option<DescriptorOneof>,
+}
+
+#[proto_oneof]
+#[proto(skip_checks(all))]
+pub enum DescriptorOneof {
+	#[proto(tag = 10)]
+	A(i32),
+	#[proto(tag = 11)]
+	B(String),
+}
+
+#[proto_enum]
+pub enum DescriptorEnum {
+	Unspecified,
+	Active,
+}
+
+#[proto_service]
+pub enum DescriptorService {
+	Get {
+		request: DescriptorMsg,
+		#[stream]
+		response: DescriptorMsg,
+	},
+}
+
+#[test]
+fn file_descriptor_set() {
+	let set = DESCRIPTOR_PKG::get_package().to_file_descriptor_set();
+
+	assert_eq_pretty!(set.file.len(), 1);
+
+	let file = &set.file[0];
+
+	assert_eq_pretty!(file.name(), "descriptor_test.proto");
+	assert_eq_pretty!(file.package(), "descriptor_test");
+	assert_eq_pretty!(file.syntax(), "proto3");
+
+	let msg = file
+		.message_type
+		.iter()
+		.find(|m| m.name() == "DescriptorMsg")
+		.unwrap();
+
+	let field = |name: &str| {
+		msg.field
+			.iter()
+			.find(|f| f.name() == name)
+			.unwrap()
+	};
+
+	let id = field("snake_case_id");
+	assert_eq_pretty!(id.json_name(), "snakeCaseId");
+	assert_eq_pretty!(id.r#type(), Type::Int32);
+
+	let id_options = id.options.as_ref().unwrap();
+	assert_eq_pretty!(id_options.deprecated, Some(true));
+	assert_eq_pretty!(id_options.uninterpreted_option.len(), 1);
+
+	let custom_option = &id_options.uninterpreted_option[0];
+	assert_eq_pretty!(
+		custom_option.name,
+		vec![NamePart {
+			name_part: "custom.rule".to_string(),
+			is_extension: true
+		}]
+	);
+	assert_eq_pretty!(custom_option.aggregate_value(), "min: 1");
+
+	// Proto3 optional fields get a synthetic oneof, placed after the real ones
+	let optional_name = field("optional_name");
+	assert_eq_pretty!(optional_name.proto3_optional, Some(true));
+	assert_eq_pretty!(
+		msg.oneof_decl[optional_name.oneof_index() as usize].name(),
+		"_optional_name"
+	);
+
+	assert_eq_pretty!(field("tags").label(), Label::Repeated);
+
+	let counters = field("counters");
+	assert_eq_pretty!(counters.label(), Label::Repeated);
+	assert_eq_pretty!(
+		counters.type_name(),
+		".descriptor_test.DescriptorMsg.CountersEntry"
+	);
+
+	let map_entry = msg
+		.nested_type
+		.iter()
+		.find(|m| m.name() == "CountersEntry")
+		.unwrap();
+	assert_eq_pretty!(map_entry.options.as_ref().unwrap().map_entry, Some(true));
+	assert_eq_pretty!(map_entry.field[1].r#type(), Type::Uint64);
+
+	let status = field("status");
+	assert_eq_pretty!(status.r#type(), Type::Enum);
+	assert_eq_pretty!(status.type_name(), ".descriptor_test.DescriptorEnum");
+
+	assert_eq_pretty!(msg.oneof_decl[0].name(), "choice");
+	assert_eq_pretty!(field("a").oneof_index, Some(0));
+	assert_eq_pretty!(field("b").oneof_index, Some(0));
+
+	let enum_ = file
+		.enum_type
+		.iter()
+		.find(|e| e.name() == "DescriptorEnum")
+		.unwrap();
+	assert_eq_pretty!(enum_.value.len(), 2);
+	assert_eq_pretty!(enum_.value[0].number(), 0);
+
+	let method = &file.service[0].method[0];
+	assert_eq_pretty!(method.name(), "Get");
+	assert_eq_pretty!(method.input_type(), ".descriptor_test.DescriptorMsg");
+	assert_eq_pretty!(method.client_streaming(), false);
+	assert_eq_pretty!(method.server_streaming(), true);
+}
+
+mod root_package {
+	use super::*;
+
+	proto_package!(ROOT_DESCRIPTOR_PKG, name = "", no_cel_test);
+	define_proto_file!(
+		ROOT_DESCRIPTOR_FILE,
+		name = "root_descriptor_test.proto",
+		package = ROOT_DESCRIPTOR_PKG
+	);
+
+	#[proto_message]
+	#[proto(skip_checks(all))]
+	pub struct RootDescriptorMsg {
+		pub id: i32,
+		#[proto(message)]
+		pub child: Option<RootDescriptorChild>,
+		#[proto(enum_(RootDescriptorEnum))]
+		pub status: i32,
+	}
+
+	#[proto_message]
+	#[proto(skip_checks(all))]
+	pub struct RootDescriptorChild {
+		pub id: i32,
+	}
+
+	#[proto_enum]
+	pub enum RootDescriptorEnum {
+		Unspecified,
+		Active,
+	}
+
+	#[proto_service]
+	pub enum RootDescriptorService {
+		Get {
+			request: RootDescriptorMsg,
+			response: RootDescriptorChild,
+		},
+	}
+
+	#[test]
+	fn root_package_type_names() {
+		let set = ROOT_DESCRIPTOR_PKG::get_package().to_file_descriptor_set();
+		let file = &set.file[0];
+
+		assert_eq_pretty!(file.package, None);
+
+		let msg = file
+			.message_type
+			.iter()
+			.find(|m| m.name() == "RootDescriptorMsg")
+			.unwrap();
+
+		let field = |name: &str| {
+			msg.field
+				.iter()
+				.find(|f| f.name() == name)
+				.unwrap()
+		};
+
+		assert_eq_pretty!(field("child").type_name(), ".RootDescriptorChild");
+		assert_eq_pretty!(field("status").type_name(), ".RootDescriptorEnum");
+
+		let method = &file.service[0].method[0];
+		assert_eq_pretty!(method.input_type(), ".RootDescriptorMsg");
+		assert_eq_pretty!(method.output_type(), ".RootDescriptorChild");
+	}
+}
@@ -0,0 +1,184 @@
+use super::*;
+
+/// A documented message.
+///
+/// With multiple paragraphs.
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct DocumentedMsg {
+	/// A documented field.
+	pub id: i32,
+
+	pub undocumented: i32,
+
+	/// Docs from the oneof field.
+	#[proto(oneof(tags(1, 2)))]
+	pub documented_oneof: Option<DocumentedOneof>,
+}
+
+/// Docs from the oneof enum.
+#[proto_oneof]
+#[proto(skip_checks(all))]
+pub enum DocumentedOneof {
+	/// A documented variant.
+	#[proto(tag = 1)]
+	A(i32),
+	#[proto(tag = 2)]
+	B(u32),
+}
+
+/// A documented parent.
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct DocumentedParent {
+	#[proto(message)]
+	pub nested: Option<DocumentedNested>,
+}
+
+/// A documented nested message.
+#[proto_message]
+#[proto(skip_checks(all))]
+#[proto(parent_message = DocumentedParent)]
+pub struct DocumentedNested {
+	/// A documented nested field.
+	pub id: i32,
+}
+
+/// A documented nested enum.
+#[proto_enum]
+#[proto(parent_message = DocumentedNested)]
+pub enum DocumentedNestedEnum {
+	Unspecified,
+	A,
+}
+
+/// A documented enum.
+#[proto_enum]
+pub enum DocumentedEnum {
+	/// A documented variant.
+	Unspecified,
+	A,
+}
+
+/// A documented service.
+#[proto_service]
+pub enum DocumentedService {
+	/// A documented handler.
+	Handler {
+		request: DocumentedMsg,
+		response: DocumentedMsg,
+	},
+}
+
+#[test]
+fn message_comments() {
+	let schema = DocumentedMsg::proto_schema();
+
+	assert_eq_pretty!(
+		schema.comments.as_deref(),
+		Some("A documented message.\n\nWith multiple paragraphs.")
+	);
+
+	let mut fields = schema.fields();
+
+	assert_eq_pretty!(
+		fields.next().unwrap().comments.as_deref(),
+		Some("A documented field.")
+	);
+	assert_eq_pretty!(fields.next().unwrap().comments, None);
+
+	let oneof = schema
+		.entries
+		.iter()
+		.find_map(|e| e.as_oneof())
+		.unwrap();
+
+	// The docs of the field should override those of the oneof
+	assert_eq_pretty!(
+		oneof.comments.as_deref(),
+		Some("Docs from the oneof field.")
+	);
+	assert_eq_pretty!(
+		DocumentedOneof::proto_schema()
+			.comments
+			.as_deref(),
+		Some("Docs from the oneof enum.")
+	);
+
+	let rendered = schema.render_schema().unwrap();
+
+	assert!(rendered.starts_with(
+		"// A documented message.\n//\n// With multiple paragraphs.\nmessage DocumentedMsg {"
+	));
+	assert!(rendered.contains("  // A documented field.\n  int32 id = "));
+	assert!(rendered.contains("  // Docs from the oneof field.\n  oneof documented_oneof {"));
+	assert!(rendered.contains("    // A documented variant.\n    int32 a = 1;"));
+}
+
+#[test]
+fn nested_comments() {
+	let mut nested = DocumentedNested::proto_schema();
+
+	assert_eq_pretty!(
+		nested.comments.as_deref(),
+		Some("A documented nested message.")
+	);
+
+	nested
+		.enums
+		.push(DocumentedNestedEnum::proto_schema());
+
+	let mut parent = DocumentedParent::proto_schema();
+	parent.messages.push(nested);
+
+	let rendered = parent.render_schema().unwrap();
+
+	assert!(rendered.starts_with("// A documented parent.\nmessage DocumentedParent {"));
+	// The comments of nested items are indented along with them
+	assert!(rendered.contains("  // A documented nested message.\n  message DocumentedNested {"));
+	assert!(rendered.contains("    // A documented nested field.\n    int32 id = "));
+	assert!(rendered.contains("    // A documented nested enum.\n    enum DocumentedNestedEnum {"));
+}
+
+#[test]
+fn enum_comments() {
+	let schema = DocumentedEnum::proto_schema();
+
+	assert_eq_pretty!(schema.comments.as_deref(), Some("A documented enum."));
+	assert_eq_pretty!(
+		schema
+			.variants
+			.first()
+			.unwrap()
+			.comments
+			.as_deref(),
+		Some("A documented variant.")
+	);
+	assert_eq_pretty!(schema.variants.last().unwrap().comments, None);
+
+	let rendered = schema.render_schema().unwrap();
+
+	assert!(rendered.starts_with("// A documented enum.\nenum DocumentedEnum {"));
+	assert!(rendered.contains("  // A documented variant.\n  DOCUMENTED_ENUM_UNSPECIFIED = 0;"));
+}
+
+#[test]
+fn service_comments() {
+	let schema = DocumentedService::proto_schema();
+
+	assert_eq_pretty!(schema.comments.as_deref(), Some("A documented service."));
+	assert_eq_pretty!(
+		schema
+			.handlers
+			.first()
+			.unwrap()
+			.comments
+			.as_deref(),
+		Some("A documented handler.")
+	);
+
+	let rendered = schema.render_schema().unwrap();
+
+	assert!(rendered.starts_with("// A documented service.\nservice DocumentedService {"));
+	assert!(rendered.contains("  // A documented handler.\n  rpc Handler ("));
+}
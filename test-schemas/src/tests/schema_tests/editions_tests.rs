@@ -0,0 +1,164 @@
+use super::*;
+
+proto_package!(EDITIONS_PKG, name = "editions_test", no_cel_test);
+
+define_proto_file!(
+	EDITIONS_FILE,
+	name = "editions_test.proto",
+	package = EDITIONS_PKG,
+	edition = Edition::E2024,
+	features(enum_type = Closed)
+);
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct EditionsMsg {
+	pub id: i32,
+	pub name: Option<String>,
+	#[proto(message)]
+	pub nested: Option<EditionsNested>,
+	#[proto(features(utf8_validation = None))]
+	pub raw: String,
+	pub tags: Vec<String>,
+	#[proto(packed = false)]
+	pub expanded_nums: Vec<i32>,
+	#[proto(packed = true)]
+	pub packed_nums: Vec<i32>,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct EditionsNested {
+	pub id: i32,
+	pub name: Option<String>,
+}
+
+mod implicit_file {
+	use super::*;
+
+	define_proto_file!(
+		EDITIONS_IMPLICIT_FILE,
+		name = "editions_implicit_test.proto",
+		package = EDITIONS_PKG,
+		edition = Edition::E2024,
+		features(field_presence = Implicit)
+	);
+
+	#[proto_message]
+	#[proto(skip_checks(all))]
+	pub struct EditionsImplicit {
+		pub id: i32,
+		pub name: Option<String>,
+	}
+}
+
+fn presence_option(presence: FieldPresence) -> ProtoOption {
+	proto_option!("features.field_presence" => presence)
+}
+
+fn find_file<'a>(pkg: &'a Package, name: &str) -> &'a ProtoFile {
+	pkg.files.iter().find(|f| f.name == name).unwrap()
+}
+
+#[test]
+fn feature_options() {
+	let pkg = EDITIONS_PKG::get_package();
+	let file = find_file(&pkg, "editions_test.proto");
+
+	assert_eq_pretty!(file.edition, Edition::E2024);
+	assert_eq_pretty!(
+		file.options,
+		vec![proto_option!("features.enum_type" => EnumType::Closed)]
+	);
+
+	let msg = file
+		.messages
+		.iter()
+		.find(|m| m.name == "EditionsMsg")
+		.unwrap();
+	let fields: HashMap<&str, &Field> = msg
+		.fields()
+		.map(|f| (f.name.as_ref(), f))
+		.collect();
+
+	assert_eq_pretty!(
+		fields["id"].options,
+		vec![presence_option(FieldPresence::Implicit)]
+	);
+
+	// Explicit presence is the default in editions
+	assert_eq_pretty!(
+		fields["name"].type_,
+		FieldType::Normal(ProtoType::Scalar(ProtoScalar::String))
+	);
+	assert!(fields["name"].options.is_empty());
+	assert!(fields["nested"].options.is_empty());
+	assert!(fields["tags"].options.is_empty());
+
+	assert_eq_pretty!(
+		fields["raw"].options,
+		vec![
+			proto_option!("features.utf8_validation" => Utf8Validation::None),
+			presence_option(FieldPresence::Implicit)
+		]
+	);
+}
+
+#[test]
+fn inherited_presence() {
+	let pkg = EDITIONS_PKG::get_package();
+	let file = find_file(&pkg, "editions_implicit_test.proto");
+
+	assert_eq_pretty!(file.options, vec![presence_option(FieldPresence::Implicit)]);
+
+	let msg = file
+		.messages
+		.iter()
+		.find(|m| m.name == "EditionsImplicit")
+		.unwrap();
+
+	assert!(msg.options.is_empty());
+
+	let fields: Vec<&Field> = msg.fields().collect();
+
+	assert!(fields[0].options.is_empty());
+	assert_eq_pretty!(
+		fields[1].options,
+		vec![presence_option(FieldPresence::Explicit)]
+	);
+
+	let rendered = msg.render_schema().unwrap();
+
+	assert!(!rendered.contains("optional"));
+	assert!(rendered.contains("string name = 2"));
+	assert!(rendered.contains("features.field_presence = EXPLICIT"));
+}
+
+#[test]
+fn packed_option() {
+	let pkg = EDITIONS_PKG::get_package();
+	let file = find_file(&pkg, "editions_test.proto");
+
+	let msg = file
+		.messages
+		.iter()
+		.find(|m| m.name == "EditionsMsg")
+		.unwrap();
+	let fields: HashMap<&str, &Field> = msg
+		.fields()
+		.map(|f| (f.name.as_ref(), f))
+		.collect();
+
+	// The `packed` option does not exist in editions
+	assert_eq_pretty!(
+		fields["expanded_nums"].options,
+		vec![proto_option!("features.repeated_field_encoding" => RepeatedFieldEncoding::Expanded)]
+	);
+	// Packed is the default encoding
+	assert!(fields["packed_nums"].options.is_empty());
+
+	let rendered = msg.render_schema().unwrap();
+
+	assert!(!rendered.contains("packed ="));
+	assert!(rendered.contains("features.repeated_field_encoding = EXPANDED"));
+}
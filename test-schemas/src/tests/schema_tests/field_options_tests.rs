@@ -0,0 +1,89 @@
+use prost::Message as _;
+
+use super::*;
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct FieldOptionsMsg {
+	#[proto(json_name = "userId")]
+	pub user_id: i32,
+	#[proto(packed = false)]
+	pub numbers: Vec<i32>,
+	#[proto(retention = Source, targets(Message, Field))]
+	pub option_field: String,
+	#[proto(debug_redact)]
+	pub secret: String,
+	#[proto(message, lazy)]
+	pub nested: Option<FieldOptionsNested>,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct FieldOptionsNested {
+	pub id: i32,
+}
+
+#[test]
+fn standard_field_options() {
+	let schema = FieldOptionsMsg::proto_schema();
+	let fields: HashMap<&str, &Field> = schema
+		.fields()
+		.map(|f| (f.name.as_ref(), f))
+		.collect();
+
+	assert_eq_pretty!(
+		fields["user_id"].options,
+		vec![proto_option!("json_name" => "userId")]
+	);
+	assert_eq_pretty!(
+		fields["numbers"].options,
+		vec![proto_option!("packed" => false)]
+	);
+	assert_eq_pretty!(
+		fields["option_field"].options,
+		vec![
+			proto_option!("retention" => OptionValue::Enum("RETENTION_SOURCE".into())),
+			proto_option!("targets" => OptionValue::Enum("TARGET_TYPE_MESSAGE".into())),
+			proto_option!("targets" => OptionValue::Enum("TARGET_TYPE_FIELD".into())),
+		]
+	);
+	assert_eq_pretty!(
+		fields["secret"].options,
+		vec![proto_option!("debug_redact" => true)]
+	);
+	assert_eq_pretty!(
+		fields["nested"].options,
+		vec![proto_option!("lazy" => true)]
+	);
+
+	let rendered = schema.render_schema().unwrap();
+
+	assert!(rendered.contains("json_name = \"userId\""));
+	assert!(rendered.contains("retention = RETENTION_SOURCE"));
+}
+
+#[test]
+fn unpacked_encoding() {
+	let msg = FieldOptionsMsg {
+		numbers: vec![1, 2],
+		..Default::default()
+	};
+
+	// Each item is encoded with its own tag
+	assert_eq_pretty!(msg.encode_to_vec(), vec![0x10, 1, 0x10, 2]);
+}
+
+#[test]
+fn redacted_debug() {
+	let msg = FieldOptionsMsg {
+		user_id: 1,
+		secret: "hunter2".to_string(),
+		..Default::default()
+	};
+
+	let output = format!("{msg:?}");
+
+	assert!(output.contains("user_id: 1"));
+	assert!(output.contains("secret: [REDACTED]"));
+	assert!(!output.contains("hunter2"));
+}
@@ -0,0 +1,122 @@
+use super::*;
+
+proto_package!(JSON_SCHEMA_PKG, name = "json_schema_test", no_cel_test);
+
+define_proto_file!(
+	JSON_SCHEMA_FILE,
+	name = "json_schema_test.proto",
+	package = JSON_SCHEMA_PKG
+);
+
+#[proto_enum]
+pub enum JsonSchemaEnum {
+	Unspecified,
+	Active,
+	Inactive,
+}
+
+#[proto_oneof]
+#[proto(skip_checks(all))]
+pub enum JsonSchemaOneof {
+	#[proto(tag = 10)]
+	Email(String),
+	#[proto(tag = 11)]
+	Phone(String),
+}
+
+/// A user of the service.
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct JsonSchemaMsg {
+	#[proto(validate = |v| v.min_len(3).max_len(20).pattern("^[a-z]+$"))]
+	pub user_name: String,
+	#[proto(validate = |v| v.email())]
+	pub contact_email: String,
+	#[proto(validate = |v| v.gt(0).lte(100))]
+	pub age: i32,
+	pub big_number: i64,
+	#[proto(validate = |v| v.min_items(1).unique())]
+	pub tags: Vec<String>,
+	#[proto(enum_(JsonSchemaEnum), validate = |v| v.in_([1, 2]))]
+	pub status: i32,
+	#[proto(timestamp)]
+	pub created_at: Option<Timestamp>,
+	#[proto(message, validate = |v| v.required())]
+	pub nested: Option<JsonSchemaNested>,
+	#[proto(oneof(tags(10, 11)))]
+	pub contact: Option<JsonSchemaOneof>,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct JsonSchemaNested {
+	pub id: i32,
+}
+
+#[test]
+fn json_schema() {
+	let schema = JSON_SCHEMA_PKG::get_package().to_json_schema();
+
+	assert_eq_pretty!(
+		schema["$schema"],
+		"https://json-schema.org/draft/2020-12/schema"
+	);
+
+	let msg = &schema["$defs"]["json_schema_test.JsonSchemaMsg"];
+	let props = &msg["properties"];
+
+	assert_eq_pretty!(msg["type"], "object");
+	assert_eq_pretty!(msg["description"], "A user of the service.");
+	assert_eq_pretty!(msg["required"][0], "nested");
+
+	assert_eq_pretty!(props["userName"]["minLength"], 3);
+	assert_eq_pretty!(props["userName"]["maxLength"], 20);
+	assert_eq_pretty!(props["userName"]["pattern"], "^[a-z]+$");
+	assert_eq_pretty!(props["contactEmail"]["format"], "email");
+
+	assert_eq_pretty!(props["age"]["type"], "integer");
+	assert_eq_pretty!(props["age"]["exclusiveMinimum"], 0);
+	assert_eq_pretty!(props["age"]["maximum"], 100);
+
+	// 64-bit integers are encoded as strings
+	assert_eq_pretty!(props["bigNumber"]["type"], "string");
+
+	assert_eq_pretty!(props["tags"]["type"], "array");
+	assert_eq_pretty!(props["tags"]["minItems"], 1);
+	assert_eq_pretty!(props["tags"]["uniqueItems"], true);
+
+	assert_eq_pretty!(
+		props["status"]["$ref"],
+		"#/$defs/json_schema_test.JsonSchemaEnum"
+	);
+	assert_eq_pretty!(props["status"]["enum"][0], "JSON_SCHEMA_ENUM_ACTIVE");
+	assert_eq_pretty!(props["status"]["enum"][1], "JSON_SCHEMA_ENUM_INACTIVE");
+
+	assert_eq_pretty!(props["createdAt"]["format"], "date-time");
+	assert_eq_pretty!(
+		props["nested"]["$ref"],
+		"#/$defs/json_schema_test.JsonSchemaNested"
+	);
+
+	// The oneof is not required, so there is an extra branch for when it's unset
+	let oneof_branches = msg["allOf"][0]["oneOf"].as_array().unwrap();
+
+	assert_eq_pretty!(oneof_branches.len(), 3);
+	assert_eq_pretty!(oneof_branches[0]["required"][0], "email");
+	assert!(props.get("phone").is_some());
+
+	assert_eq_pretty!(
+		schema["$defs"]["json_schema_test.JsonSchemaEnum"]["enum"][0],
+		"JSON_SCHEMA_ENUM_UNSPECIFIED"
+	);
+}
+
+#[test]
+fn openapi_schemas() {
+	let schemas = JSON_SCHEMA_PKG::get_package().to_openapi_schemas();
+
+	assert_eq_pretty!(
+		schemas["json_schema_test.JsonSchemaMsg"]["properties"]["nested"]["$ref"],
+		"#/components/schemas/json_schema_test.JsonSchemaNested"
+	);
+}
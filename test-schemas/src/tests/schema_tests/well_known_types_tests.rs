@@ -0,0 +1,126 @@
+use super::*;
+
+use protify::well_known_types::{
+	BoolValue, BytesValue, DoubleValue, Int64Value, ListValue, StringValue, Struct, UInt32Value,
+	Value, value::Kind,
+};
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct WellKnownTypes {
+	#[proto(message, validate = |v| v.min_len(3))]
+	pub name: Option<StringValue>,
+	#[proto(message, validate = |v| v.gt(0))]
+	pub count: Option<Int64Value>,
+	#[proto(message)]
+	pub ratio: Option<DoubleValue>,
+	#[proto(message)]
+	pub flag: Option<BoolValue>,
+	#[proto(message)]
+	pub data: Option<BytesValue>,
+	#[proto(message)]
+	pub limit: Option<UInt32Value>,
+	#[proto(message)]
+	pub metadata: Option<Struct>,
+	#[proto(message)]
+	pub value: Option<Value>,
+	#[proto(message)]
+	pub list: Option<ListValue>,
+}
+
+#[test]
+fn well_known_types_schema() {
+	let schema = WellKnownTypes::proto_schema();
+
+	let expected_types = [
+		("StringValue", "wrappers"),
+		("Int64Value", "wrappers"),
+		("DoubleValue", "wrappers"),
+		("BoolValue", "wrappers"),
+		("BytesValue", "wrappers"),
+		("UInt32Value", "wrappers"),
+		("Struct", "struct"),
+		("Value", "struct"),
+		("ListValue", "struct"),
+	];
+
+	for (field, (name, file)) in schema.fields().zip(expected_types) {
+		let FieldType::Normal(ProtoType::Message(path)) = &field.type_ else {
+			panic!("Expected field {} to be a message", field.name);
+		};
+
+		assert_eq_pretty!(path.name, name);
+		assert_eq_pretty!(path.package, "google.protobuf");
+		assert_eq_pretty!(path.file, format!("google/protobuf/{file}.proto"));
+	}
+
+	let mut file = ProtoFile::new("well_known_types.proto", "well_known_types");
+
+	file.with_messages(vec![schema]);
+
+	assert!(
+		file.imports
+			.set
+			.contains("google/protobuf/wrappers.proto")
+	);
+	assert!(
+		file.imports
+			.set
+			.contains("google/protobuf/struct.proto")
+	);
+}
+
+#[test]
+fn wrapper_validation() {
+	let mut msg = WellKnownTypes {
+		name: Some("Frodo".to_string().into()),
+		count: Some(1.into()),
+		..Default::default()
+	};
+
+	assert!(msg.validate().is_ok());
+
+	// Unset wrappers are not validated unless they are required
+	msg.name = None;
+	msg.count = None;
+
+	assert!(msg.validate().is_ok());
+
+	msg.name = Some("Sam".to_string().into());
+	msg.count = Some(0.into());
+
+	assert_violation_id(&msg, "int64.gt", "wrapper should use the int validator");
+
+	msg.name = Some("Pi".to_string().into());
+	msg.count = Some(1.into());
+
+	assert_violation_id(
+		&msg,
+		"string.min_len",
+		"wrapper should use the string validator",
+	);
+}
+
+#[test]
+fn struct_value_cel_conversion() {
+	let list = ListValue {
+		values: vec![Kind::NumberValue(1.0).into(), Kind::BoolValue(true).into()],
+	};
+
+	let struct_ = Struct {
+		fields: [("list".to_string(), Kind::ListValue(list).into())]
+			.into_iter()
+			.collect(),
+	};
+
+	let cel_value: protify::cel::Value = struct_.try_into().unwrap();
+
+	let program = protify::cel::Program::compile("this.list[0] == 1.0 && this.list[1]").unwrap();
+	let mut ctx = protify::cel::Context::default();
+	ctx.add_variable_from_value("this", cel_value);
+
+	assert_eq_pretty!(
+		program.execute(&ctx).unwrap(),
+		protify::cel::Value::Bool(true)
+	);
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+// The tags of these items are recorded in the `protify.lock` file at the root of the crate
+
+#[proto_message]
+#[proto(skip_checks(all))]
+#[proto(reserved_numbers(3))]
+pub struct LockedMsg {
+	// Inserted before the locked fields
+	pub new_field: i32,
+	pub id: i32,
+	pub name: String,
+}
+
+#[proto_enum]
+pub enum LockedEnum {
+	Unspecified,
+	// Reordered
+	B,
+	C,
+	A,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct LockedParentA {
+	pub id: i32,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct LockedParentB {
+	pub id: i32,
+}
+
+// Same name as the message below, but with a different parent
+#[proto_message]
+#[proto(skip_checks(all))]
+#[proto(name = "Inner", parent_message = LockedParentA)]
+pub struct LockedInnerA {
+	pub first: i32,
+	pub second: i32,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+#[proto(name = "Inner", parent_message = LockedParentB)]
+pub struct LockedInnerB {
+	pub first: i32,
+	pub second: i32,
+}
+
+#[test]
+fn locked_message_tags() {
+	let schema = LockedMsg::proto_schema();
+
+	let tags: Vec<(&str, i32)> = schema
+		.fields()
+		.map(|f| (f.name.as_str(), f.tag))
+		.collect();
+
+	// The locked tags stay the same, and the new field gets the first available tag
+	assert_eq_pretty!(tags, [("new_field", 4), ("id", 2), ("name", 1)]);
+}
+
+#[test]
+fn locked_enum_tags() {
+	assert_eq_pretty!(LockedEnum::Unspecified as i32, 0);
+	assert_eq_pretty!(LockedEnum::A as i32, 1);
+	assert_eq_pretty!(LockedEnum::B as i32, 2);
+	assert_eq_pretty!(LockedEnum::C as i32, 3);
+}
+
+#[test]
+fn locked_tags_with_same_name() {
+	let schema_a = LockedInnerA::proto_schema();
+	let schema_b = LockedInnerB::proto_schema();
+
+	let tags_a: Vec<(&str, i32)> = schema_a
+		.fields()
+		.map(|f| (f.name.as_str(), f.tag))
+		.collect();
+	let tags_b: Vec<(&str, i32)> = schema_b
+		.fields()
+		.map(|f| (f.name.as_str(), f.tag))
+		.collect();
+
+	// Each message uses the section of its own fully qualified name
+	assert_eq_pretty!(tags_a, [("first", 2), ("second", 1)]);
+	assert_eq_pretty!(tags_b, [("first", 1), ("second", 2)]);
+}
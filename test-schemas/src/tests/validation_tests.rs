@@ -1,4 +1,5 @@
 use super::*;
+use proto_types::{Date, Interval, LatLng, Money, PostalAddress, TimeOfDay, Timestamp};
 
 #[proto_message(proxied)]
 #[proto(skip_checks(all))]
@@ -23,3 +24,257 @@ fn proxied_message_in_collections() {
 
 	assert!(msg.validate().is_ok());
 }
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct SensitiveFields {
+	#[proto(sensitive, validate = |v| v.const_("hunter2"))]
+	pub password: String,
+	#[proto(sensitive, validate = |v| v.in_(["abc", "def"]))]
+	pub token: String,
+	#[proto(validate = |v| v.const_("visible"))]
+	pub name: String,
+}
+
+#[test]
+fn sensitive_fields() {
+	let msg = SensitiveFields {
+		password: "hunter3".to_string(),
+		token: "ghi".to_string(),
+		name: "other".to_string(),
+	};
+
+	let violations = msg.validate_all().unwrap_err().into_violations();
+
+	assert_eq_pretty!(violations.len(), 3);
+	assert_eq_pretty!(
+		violations[0].message(),
+		"must be equal to the expected value"
+	);
+	assert_eq_pretty!(violations[1].message(), "must be one of the allowed values");
+	assert_eq_pretty!(violations[2].message(), "must be equal to \"visible\"");
+
+	let output = format!("{msg:?}");
+
+	assert!(!output.contains("hunter3"));
+	assert!(!output.contains("ghi"));
+	assert!(output.contains("other"));
+
+	let schema = SensitiveFields::proto_schema();
+
+	assert!(
+		schema
+			.fields()
+			.filter(|f| f.name != "name")
+			.all(|f| f.options == vec![proto_option!("debug_redact" => true)])
+	);
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct SensitiveNumbers {
+	#[proto(sensitive, validate = |v| v.const_(1234))]
+	pub pin: i32,
+	#[proto(sensitive, validate = |v| v.in_([1.5, 2.5]))]
+	pub score: f64,
+	#[proto(sensitive, enum_(SimpleEnum), validate = |v| v.not_in([1]))]
+	pub level: i32,
+}
+
+#[test]
+fn sensitive_numbers() {
+	let msg = SensitiveNumbers {
+		pin: 1,
+		score: 3.5,
+		level: 1,
+	};
+
+	let violations = msg.validate_all().unwrap_err().into_violations();
+
+	assert_eq_pretty!(violations.len(), 3);
+	assert_eq_pretty!(
+		violations[0].message(),
+		"must be equal to the expected value"
+	);
+	assert_eq_pretty!(violations[1].message(), "must be one of the allowed values");
+	assert_eq_pretty!(
+		violations[2].message(),
+		"cannot be one of the forbidden values"
+	);
+}
+
+#[proto_oneof(proxied)]
+#[proto(skip_checks(all))]
+#[derive(Debug)]
+pub enum SensitiveOneof {
+	#[proto(tag = 1, sensitive)]
+	Token(String),
+	#[proto(tag = 2)]
+	Code(i32),
+}
+
+#[proto_message(proxied)]
+#[proto(skip_checks(all))]
+#[derive(Debug, Clone)]
+pub struct SensitiveProxy {
+	#[proto(sensitive)]
+	pub password: String,
+	#[proto(oneof(proxied, tags(1, 2)))]
+	pub secret: Option<SensitiveOneof>,
+	pub name: String,
+}
+
+#[test]
+fn sensitive_proxy_debug() {
+	let proxy = SensitiveProxy {
+		password: "hunter2".to_string(),
+		secret: Some(SensitiveOneof::Token("abcde".to_string())),
+		name: "visible".to_string(),
+	};
+
+	for output in [
+		format!("{proxy:?}"),
+		format!("{:?}", proxy.clone().into_message()),
+	] {
+		assert!(!output.contains("hunter2"));
+		assert!(!output.contains("abcde"));
+		assert!(output.contains("visible"));
+	}
+
+	let output = format!("{:?}", SensitiveOneof::Code(15));
+
+	assert!(output.contains("15"));
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct CommonTypesValidation {
+	#[proto(message, validate = |v| v.gte(Date { year: 2000, month: 1, day: 1 }))]
+	pub date: Option<Date>,
+	#[proto(message, validate = |v| v.required())]
+	pub time: Option<TimeOfDay>,
+	#[proto(message, validate = |v| v.required())]
+	pub location: Option<LatLng>,
+	#[proto(message, validate = |v| v.in_(["EUR", "USD"]))]
+	pub price: Option<Money>,
+	#[proto(message, validate = |v| v.required())]
+	pub period: Option<Interval>,
+	#[proto(message, validate = |v| v.required())]
+	pub address: Option<PostalAddress>,
+}
+
+fn valid_common_types() -> CommonTypesValidation {
+	CommonTypesValidation {
+		date: Some(Date {
+			year: 2024,
+			month: 2,
+			day: 29,
+		}),
+		time: Some(TimeOfDay {
+			hours: 23,
+			minutes: 59,
+			seconds: 59,
+			nanos: 0,
+		}),
+		location: Some(LatLng {
+			latitude: 45.0,
+			longitude: -180.0,
+		}),
+		price: Some(Money {
+			currency_code: "EUR".to_string(),
+			units: -5,
+			nanos: -500_000_000,
+		}),
+		period: Some(Interval {
+			start_time: Some(Timestamp::new(1, 0)),
+			end_time: Some(Timestamp::new(1, 0)),
+		}),
+		address: Some(PostalAddress {
+			region_code: "IT".to_string(),
+			..Default::default()
+		}),
+	}
+}
+
+#[test]
+fn common_types_validation() {
+	assert!(valid_common_types().validate().is_ok());
+
+	macro_rules! assert_invalid {
+		($rule_id:literal, |$msg:ident| $mutation:expr) => {
+			let mut $msg = valid_common_types();
+			$mutation;
+			assert_violation_id(&$msg, $rule_id, stringify!($mutation));
+		};
+	}
+
+	assert_invalid!("date.valid", |msg| msg.date.as_mut().unwrap().year = 2023);
+	assert_invalid!("date.valid", |msg| msg.date.as_mut().unwrap().month = 13);
+	assert_invalid!("date.gte", |msg| msg.date.as_mut().unwrap().year = 1999);
+	assert_invalid!("time_of_day.valid", |msg| msg
+		.time
+		.as_mut()
+		.unwrap()
+		.hours = 24);
+	assert_invalid!("lat_lng.latitude", |msg| msg
+		.location
+		.as_mut()
+		.unwrap()
+		.latitude = 90.5);
+	assert_invalid!("lat_lng.longitude", |msg| msg
+		.location
+		.as_mut()
+		.unwrap()
+		.longitude = f64::NAN);
+	assert_invalid!("money.nanos", |msg| msg.price.as_mut().unwrap().nanos = 1);
+	assert_invalid!("money.in", |msg| msg
+		.price
+		.as_mut()
+		.unwrap()
+		.currency_code = "GBP".to_string());
+	assert_invalid!("interval.valid", |msg| msg
+		.period
+		.as_mut()
+		.unwrap()
+		.start_time =
+		Some(Timestamp::new(2, 0)));
+	assert_invalid!("postal_address.region_code", |msg| msg
+		.address
+		.as_mut()
+		.unwrap()
+		.region_code =
+		"ZZ".to_string());
+	assert_invalid!("required", |msg| msg.location = None);
+
+	// Partial dates are valid, but they are not checked against the bounds
+	assert!(
+		CommonTypesValidation {
+			date: Some(Date {
+				year: 0,
+				month: 2,
+				day: 29
+			}),
+			..valid_common_types()
+		}
+		.validate()
+		.is_ok()
+	);
+
+	let schema = CommonTypesValidation::proto_schema()
+		.render_schema()
+		.unwrap();
+
+	assert!(schema.contains("this.year * 10000 + this.month * 100 + this.day >= 20000101"));
+	assert!(schema.contains("this.currency_code in ['EUR', 'USD']"));
+	assert!(schema.contains("this.latitude >= -90.0 && this.latitude <= 90.0"));
+
+	let invalid_bound = DateValidator::builder()
+		.gt(Date {
+			year: 2000,
+			month: 0,
+			day: 0,
+		})
+		.build();
+
+	assert!(Validator::<Date>::check_consistency(&invalid_bound).is_err());
+}
@@ -29,6 +29,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 	tonic_prost_build::configure()
 		.build_client(true)
+		// Validates the requests with the mode of the `ValidationLayer`
+		.codec_path("::protify::ValidatingCodec")
 		.compile_with_config(config, files, include_paths)?;
 
 	Ok(())
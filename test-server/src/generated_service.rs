@@ -0,0 +1,225 @@
+use protify::*;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status, transport::Channel};
+
+use greeter_service_client::GreeterServiceClient;
+use greeter_service_server::{GreeterService as GreeterServiceTrait, GreeterServiceServer};
+
+proto_package!(GREETER_PKG, name = "greeter", no_cel_test);
+define_proto_file!(GREETER_FILE, name = "greeter.proto", package = GREETER_PKG);
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct HelloRequest {
+	#[proto(validate = |v| v.min_len(1))]
+	pub name: String,
+}
+
+#[proto_message]
+#[proto(skip_checks(all))]
+pub struct HelloReply {
+	pub message: String,
+}
+
+/// Greets people.
+#[proto_service(tonic)]
+enum GreeterService {
+	SayHello {
+		request: HelloRequest,
+		response: HelloReply,
+	},
+	SayHelloMany {
+		request: HelloRequest,
+		#[stream]
+		response: HelloReply,
+	},
+	SayHelloToAll {
+		#[stream]
+		request: HelloRequest,
+		response: HelloReply,
+	},
+}
+
+struct Greeter;
+
+type ReplyStream = std::pin::Pin<Box<dyn Stream<Item = Result<HelloReply, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl GreeterServiceTrait for Greeter {
+	type SayHelloManyStream = ReplyStream;
+
+	async fn say_hello(
+		&self,
+		request: Request<HelloRequest>,
+	) -> Result<Response<HelloReply>, Status> {
+		let HelloRequest { name } = request.into_inner();
+
+		Ok(Response::new(HelloReply {
+			message: format!("Hello, {name}!"),
+		}))
+	}
+
+	async fn say_hello_many(
+		&self,
+		request: Request<HelloRequest>,
+	) -> Result<Response<Self::SayHelloManyStream>, Status> {
+		let HelloRequest { name } = request.into_inner();
+
+		let replies = (1..=3).map(move |i| {
+			Ok(HelloReply {
+				message: format!("Hello #{i}, {name}!"),
+			})
+		});
+
+		Ok(Response::new(Box::pin(tokio_stream::iter(replies))))
+	}
+
+	async fn say_hello_to_all(
+		&self,
+		request: Request<tonic::Streaming<HelloRequest>>,
+	) -> Result<Response<HelloReply>, Status> {
+		let mut stream = request.into_inner();
+		let mut names: Vec<String> = Vec::new();
+
+		while let Some(req) = stream.next().await {
+			names.push(req?.name);
+		}
+
+		Ok(Response::new(HelloReply {
+			message: format!("Hello, {}!", names.join(" and ")),
+		}))
+	}
+}
+
+#[test]
+fn service_paths() {
+	assert_eq!(
+		greeter_service_server::SERVICE_NAME,
+		"greeter.GreeterService"
+	);
+	assert_eq!(
+		greeter_service_server::SAY_HELLO_PATH,
+		"/greeter.GreeterService/SayHello"
+	);
+	assert_eq!(
+		greeter_service_client::SAY_HELLO_MANY_PATH,
+		"/greeter.GreeterService/SayHelloMany"
+	);
+}
+
+mod root_package {
+	use super::*;
+
+	proto_package!(ROOT_PKG, name = "", no_cel_test);
+	define_proto_file!(ROOT_FILE, name = "root.proto", package = ROOT_PKG);
+
+	#[proto_service(tonic)]
+	enum RootService {
+		Ping {
+			request: HelloRequest,
+			response: HelloReply,
+		},
+	}
+
+	#[test]
+	fn root_package_service_paths() {
+		assert_eq!(root_service_server::SERVICE_NAME, "RootService");
+		assert_eq!(root_service_server::PING_PATH, "/RootService/Ping");
+	}
+}
+
+async fn connect(layer: ValidationLayer) -> GreeterServiceClient<Channel> {
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+		.await
+		.unwrap();
+	let addr = listener.local_addr().unwrap();
+
+	tokio::spawn(async move {
+		tonic::transport::Server::builder()
+			.layer(layer)
+			.add_service(GreeterServiceServer::new(Greeter))
+			.serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+			.await
+			.unwrap();
+	});
+
+	let channel = Channel::from_shared(format!("http://{addr}"))
+		.unwrap()
+		.connect()
+		.await
+		.unwrap();
+
+	GreeterServiceClient::new(channel)
+}
+
+#[tokio::test]
+async fn generated_service() {
+	let mut client = connect(ValidationLayer::with_mode(ValidationMode::Disabled)).await;
+
+	let reply = client
+		.say_hello(HelloRequest {
+			name: "Gandalf".to_string(),
+		})
+		.await
+		.unwrap()
+		.into_inner();
+
+	assert_eq!(reply.message, "Hello, Gandalf!");
+
+	let replies: Vec<String> = client
+		.say_hello_many(HelloRequest {
+			name: "Frodo".to_string(),
+		})
+		.await
+		.unwrap()
+		.into_inner()
+		.map(|reply| reply.unwrap().message)
+		.collect()
+		.await;
+
+	assert_eq!(
+		replies,
+		["Hello #1, Frodo!", "Hello #2, Frodo!", "Hello #3, Frodo!"]
+	);
+
+	let names = ["Merry", "Pippin"].map(|name| HelloRequest {
+		name: name.to_string(),
+	});
+
+	let reply = client
+		.say_hello_to_all(tokio_stream::iter(names))
+		.await
+		.unwrap()
+		.into_inner();
+
+	assert_eq!(reply.message, "Hello, Merry and Pippin!");
+}
+
+#[tokio::test]
+async fn request_validation() {
+	let mut client = connect(ValidationLayer::new()).await;
+
+	let status = client
+		.say_hello(HelloRequest {
+			name: String::new(),
+		})
+		.await
+		.unwrap_err();
+
+	assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+	// The violations are sent in the details of the status
+	assert!(!status.details().is_empty());
+
+	// Each message in a stream is validated individually
+	let names = ["Merry", ""].map(|name| HelloRequest {
+		name: name.to_string(),
+	});
+
+	let status = client
+		.say_hello_to_all(tokio_stream::iter(names))
+		.await
+		.unwrap_err();
+
+	assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
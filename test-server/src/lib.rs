@@ -1,13 +1,19 @@
 // The models are defined in test-schemas/src/server_models.rs
 
+// Services generated directly by the `proto_service` macro
+#[cfg(test)]
+mod generated_service;
+
 #[cfg(test)]
 mod test {
 	use deadpool_diesel::sqlite::{Manager, Pool, Runtime};
 	use diesel::prelude::*;
-	use protify::ValidatedMessage;
+	use protify::{ValidationLayer, ValidationMode};
 	use proto_types::Empty;
 	use test_schemas::server_models::{User, UserId, users::dsl::*};
-	use tonic::{Request as TonicRequest, Response as TonicResponse, Status};
+	use tonic::{
+		Request as TonicRequest, Response as TonicResponse, Status, transport::Channel,
+	};
 
 	mod proto {
 		tonic::include_proto!("db_test");
@@ -50,7 +56,8 @@ mod test {
 			&self,
 			request: tonic::Request<User>,
 		) -> Result<tonic::Response<Empty>, tonic::Status> {
-			let msg = request.into_inner().validated()?;
+			// Already validated by the codec
+			let msg = request.into_inner();
 
 			let conn = self
 				.pool
@@ -85,9 +92,7 @@ mod test {
 			.expect("Failed to create the table");
 	}
 
-	#[tokio::test]
-	async fn test() {
-		let db_url = "file:test_db_1?mode=memory&cache=shared";
+	async fn connect(db_url: &str, layer: ValidationLayer) -> UserServiceClient<Channel> {
 		let manager = Manager::new(db_url, Runtime::Tokio1);
 		let pool = Pool::builder(manager)
 			.max_size(1)
@@ -108,19 +113,29 @@ mod test {
 			.unwrap();
 		let addr = listener.local_addr().unwrap();
 
-		let service = UserService { pool: pool.clone() };
+		let service = UserService { pool };
 
 		tokio::spawn(async move {
 			tonic::transport::Server::builder()
+				.layer(layer)
 				.add_service(UserServiceServer::new(service))
 				.serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
 				.await
 				.unwrap();
 		});
 
-		let mut client = UserServiceClient::connect(format!("http://{addr}"))
+		UserServiceClient::connect(format!("http://{addr}"))
 			.await
-			.unwrap();
+			.unwrap()
+	}
+
+	#[tokio::test]
+	async fn test() {
+		let mut client = connect(
+			"file:test_db_1?mode=memory&cache=shared",
+			ValidationLayer::new(),
+		)
+		.await;
 
 		let insert_req = TonicRequest::new(User {
 			id: 0,
@@ -129,9 +144,40 @@ mod test {
 		});
 		let _ = client.insert_user(insert_req).await.unwrap();
 
+		let invalid_req = TonicRequest::new(User {
+			id: 0,
+			name: "Mo".to_string(),
+			created_at: None,
+		});
+		let status = client.insert_user(invalid_req).await.unwrap_err();
+
+		assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
 		let req = tonic::Request::new(UserId { id: 1 });
 		let user = client.get_user(req).await.unwrap().into_inner();
 
 		assert_eq!(user.name, "Gandalf");
 	}
+
+	#[tokio::test]
+	async fn disabled_validation() {
+		// The codec picks up the mode of the layer
+		let mut client = connect(
+			"file:test_db_2?mode=memory&cache=shared",
+			ValidationLayer::with_mode(ValidationMode::Disabled),
+		)
+		.await;
+
+		let invalid_req = TonicRequest::new(User {
+			id: 0,
+			name: "Mo".to_string(),
+			created_at: None,
+		});
+		let _ = client.insert_user(invalid_req).await.unwrap();
+
+		let req = tonic::Request::new(UserId { id: 1 });
+		let user = client.get_user(req).await.unwrap().into_inner();
+
+		assert_eq!(user.name, "Mo");
+	}
 }